@@ -70,6 +70,10 @@ pub struct AboutConfig {
     pub lines: Vec<AboutLine>,
     pub links: Vec<AboutLink>,
     pub system_info_collector: Option<SystemInfoCollector>,
+    /// Label of the button showing the system information
+    pub system_info_label: SharedString,
+    /// Label of the button copying the system information
+    pub copy_label: SharedString,
 }
 
 /// A reusable About page component.
@@ -139,7 +143,7 @@ impl Render for ZedisAboutPage {
         if self.config.system_info_collector.is_some() {
             links_row = links_row.child(
                 Button::new("sysinfo")
-                    .label("System Info")
+                    .label(self.config.system_info_label.clone())
                     .small()
                     .on_click(cx.listener(|this, _, window, cx| {
                         if let Some(collector) = &this.config.system_info_collector {
@@ -154,6 +158,7 @@ impl Render for ZedisAboutPage {
         // System info panel
         if let Some(info) = &self.system_info {
             let info_for_copy = info.clone();
+            let copy_label = self.config.copy_label.clone();
             page = page.child(
                 v_flex()
                     .my_2()
@@ -169,7 +174,7 @@ impl Render for ZedisAboutPage {
                     .child(
                         h_flex().justify_end().absolute().right_2().top_2().child(
                             Button::new("copy-sysinfo")
-                                .label("Copy")
+                                .label(copy_label)
                                 .xsmall()
                                 .on_click(move |_, _window, cx| {
                                     cx.write_to_clipboard(ClipboardItem::new_string(info_for_copy.clone()));
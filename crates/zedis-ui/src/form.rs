@@ -90,6 +90,8 @@ pub struct ZedisFormField {
     /// Options list for `RadioGroup` fields.
     options: Option<Vec<SharedString>>,
     validate: Option<ZedisFormValidateHandler>,
+    /// Live hint shown below the field, recomputed as the user types.
+    hint: Option<ZedisFormValidateHandler>,
     mask: bool,
     required: bool,
     /// Whether this field should receive focus on the first render.
@@ -119,6 +121,7 @@ impl ZedisFormField {
             field_type: ZedisFormFieldType::Input,
             options: None,
             validate: None,
+            hint: None,
             tab_index: None,
             required: false,
             focus: false,
@@ -159,6 +162,13 @@ impl ZedisFormField {
         self
     }
 
+    /// Attach a live hint (e.g. a preview or a non-blocking warning) shown below
+    /// the field. Unlike `validate`, the hint never blocks submission.
+    pub fn hint(mut self, hint: impl Fn(&str) -> Option<SharedString> + 'static) -> Self {
+        self.hint = Some(Rc::new(hint));
+        self
+    }
+
     /// Set the initial value for this field.
     pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
         self.default_value = Some(value.into());
@@ -867,13 +877,21 @@ impl Render for ZedisForm {
                         form_container = form_container
                             .child(new_field(field).child(NumberInput::new(state).disabled(field.readonly)));
                     } else {
+                        let hint = field
+                            .hint
+                            .as_ref()
+                            .and_then(|hint| hint(state.read(cx).value().as_str()));
                         form_container = form_container.child(
-                            new_field(field).child(
-                                Input::new(state)
-                                    .disabled(field.readonly)
-                                    .when(field.mask, |this| this.mask_toggle())
-                                    .refine_style(&field.style),
-                            ),
+                            new_field(field)
+                                .child(
+                                    Input::new(state)
+                                        .disabled(field.readonly)
+                                        .when(field.mask, |this| this.mask_toggle())
+                                        .refine_style(&field.style),
+                                )
+                                .when_some(hint, |this, hint| {
+                                    this.child(Label::new(hint).text_xs().text_color(cx.theme().muted_foreground))
+                                }),
                         );
                     }
                 }
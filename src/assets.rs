@@ -52,9 +52,12 @@ pub enum CustomIconName {
     ChevronUp,
     FileCheckCorner,
     Clock3,
+    History,
+    ClipboardPaste,
     Zap,
     Network,
     Equal,
+    GitCompare,
     Activity,
     Languages,
     RotateCw,
@@ -66,6 +69,7 @@ pub enum CustomIconName {
     Binary,
     ALargeSmall,
     ListChecvronsDownUp,
+    ListChevronsUpDown,
     Lock,
     LockOpen,
     SwatchBook,
@@ -79,7 +83,12 @@ pub enum CustomIconName {
     Rss,
     Laptop,
     HardDrive,
+    HardDriveDownload,
+    HardDriveUpload,
     Radar,
+    Pause,
+    Play,
+    Scissors,
 }
 
 impl CustomIconName {
@@ -95,9 +104,12 @@ impl CustomIconName {
             CustomIconName::ChevronUp => "icons/chevron-up.svg",
             CustomIconName::FileCheckCorner => "icons/file-check-corner.svg",
             CustomIconName::Clock3 => "icons/clock-3.svg",
+            CustomIconName::History => "icons/history.svg",
+            CustomIconName::ClipboardPaste => "icons/clipboard-paste.svg",
             CustomIconName::Zap => "icons/zap.svg",
             CustomIconName::Network => "icons/network.svg",
             CustomIconName::Equal => "icons/equal.svg",
+            CustomIconName::GitCompare => "icons/git-compare.svg",
             CustomIconName::Activity => "icons/activity.svg",
             CustomIconName::Languages => "icons/languages.svg",
             CustomIconName::RotateCw => "icons/rotate-cw.svg",
@@ -109,6 +121,7 @@ impl CustomIconName {
             CustomIconName::Binary => "icons/binary.svg",
             CustomIconName::ALargeSmall => "icons/a-large-small.svg",
             CustomIconName::ListChecvronsDownUp => "icons/list-chevrons-down-up.svg",
+            CustomIconName::ListChevronsUpDown => "icons/list-chevrons-up-down.svg",
             CustomIconName::Lock => "icons/lock.svg",
             CustomIconName::LockOpen => "icons/lock-open.svg",
             CustomIconName::SwatchBook => "icons/swatch-book.svg",
@@ -122,7 +135,12 @@ impl CustomIconName {
             CustomIconName::Rss => "icons/rss.svg",
             CustomIconName::Laptop => "icons/laptop.svg",
             CustomIconName::HardDrive => "icons/hard-drive.svg",
+            CustomIconName::HardDriveDownload => "icons/hard-drive-download.svg",
+            CustomIconName::HardDriveUpload => "icons/hard-drive-upload.svg",
             CustomIconName::Radar => "icons/radar.svg",
+            CustomIconName::Pause => "icons/pause.svg",
+            CustomIconName::Play => "icons/play.svg",
+            CustomIconName::Scissors => "icons/scissors.svg",
         }
         .into()
     }
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hex_dump;
 mod kv_delegate;
 mod kv_types;
 
+pub use hex_dump::ZedisHexDump;
 pub use kv_delegate::{INDEX_COLUMN_NAME, ZedisKvDelegate, ZedisKvFetcher};
 pub use kv_types::{KvTableColumn, KvTableColumnType, KvTableMode};
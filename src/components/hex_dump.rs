@@ -0,0 +1,160 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::get_font_family;
+use crate::states::{RawBytesSource, i18n_common, i18n_kv_table};
+use gpui::{Context, SharedString, Task, Window, div, prelude::*, px, uniform_list};
+use gpui_component::{ActiveTheme, h_flex, label::Label, v_flex};
+use humansize::{DECIMAL, format_size};
+use std::rc::Rc;
+use tracing::error;
+
+/// Bytes shown per line, like `xxd`
+const BYTES_PER_LINE: usize = 16;
+const LINE_HEIGHT: f32 = 22.;
+const LIST_HEIGHT: f32 = 400.;
+
+/// One `offset / hex / ascii` line of the dump
+struct HexDumpLine {
+    offset: SharedString,
+    hex: SharedString,
+    ascii: SharedString,
+}
+
+/// Formats bytes as `xxd`-style lines, non-printable bytes are shown as `.` in the ASCII column.
+fn hex_dump_lines(bytes: &[u8]) -> Vec<HexDumpLine> {
+    bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut hex = String::with_capacity(BYTES_PER_LINE * 3);
+            for (i, byte) in chunk.iter().enumerate() {
+                if i > 0 {
+                    hex.push(' ');
+                }
+                hex.push_str(&format!("{byte:02x}"));
+            }
+            // Pad the last line so the ASCII column stays aligned
+            let width = BYTES_PER_LINE * 3 - 1;
+            let hex = format!("{hex:<width$}");
+            let ascii: String = chunk
+                .iter()
+                .map(|byte| {
+                    if byte.is_ascii_graphic() || *byte == b' ' {
+                        *byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            HexDumpLine {
+                offset: format!("{:08x}", index * BYTES_PER_LINE).into(),
+                hex: hex.into(),
+                ascii: ascii.into(),
+            }
+        })
+        .collect()
+}
+
+/// Hex dump (offset / hex / ASCII columns) of a value's original bytes.
+///
+/// The bytes are loaded from the source when the view is created.
+pub struct ZedisHexDump {
+    lines: Rc<Vec<HexDumpLine>>,
+    size: usize,
+    loading: bool,
+    missing: bool,
+    error: Option<SharedString>,
+    _load_task: Task<()>,
+}
+
+impl ZedisHexDump {
+    pub fn new(source: RawBytesSource, cx: &mut Context<Self>) -> Self {
+        let load_task = cx.spawn(async move |handle, cx| {
+            let task = cx.background_spawn(async move { source.load().await });
+            let result = task.await;
+            let _ = handle.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(Some(bytes)) => {
+                        this.size = bytes.len();
+                        this.lines = Rc::new(hex_dump_lines(&bytes));
+                    }
+                    Ok(None) => {
+                        this.missing = true;
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to load raw bytes");
+                        this.error = Some(e.to_string().into());
+                    }
+                }
+                cx.notify();
+            });
+        });
+        Self {
+            lines: Rc::default(),
+            size: 0,
+            loading: true,
+            missing: false,
+            error: None,
+            _load_task: load_task,
+        }
+    }
+}
+
+impl Render for ZedisHexDump {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let message = if self.loading {
+            Some(i18n_common(cx, "loading"))
+        } else if let Some(error) = &self.error {
+            Some(error.clone())
+        } else if self.missing {
+            Some(i18n_kv_table(cx, "raw_bytes_missing"))
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            return v_flex().w_full().child(Label::new(message).text_sm().text_color(muted));
+        }
+
+        let offset_color = cx.theme().primary;
+        let lines = self.lines.clone();
+        let font_family = get_font_family();
+        let height = (lines.len() as f32 * LINE_HEIGHT).min(LIST_HEIGHT);
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(Label::new(format_size(self.size, DECIMAL)).text_sm().text_color(muted))
+            .child(
+                uniform_list("hex-dump-lines", lines.len(), move |range, _window, _cx| {
+                    range
+                        .map(|index| {
+                            let line = &lines[index];
+                            h_flex()
+                                .h(px(LINE_HEIGHT))
+                                .gap_4()
+                                .text_sm()
+                                .font_family(font_family.clone())
+                                .child(div().text_color(offset_color).child(line.offset.clone()))
+                                .child(div().child(line.hex.clone()))
+                                .child(div().text_color(muted).child(line.ascii.clone()))
+                        })
+                        .collect()
+                })
+                .w_full()
+                .h(px(height)),
+            )
+    }
+}
@@ -12,18 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{KvTableColumn, KvTableColumnType};
-use crate::states::{KeyType, RedisValue, ZedisServerState, i18n_common};
+use super::{KvTableColumn, KvTableColumnType, ZedisHexDump};
+use crate::assets::CustomIconName;
+use crate::states::{
+    KeyType, RawBytesSource, RedisValue, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_kv_table,
+};
 use gpui::{App, ClipboardItem, Edges, Entity, SharedString, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme, IconName, StyledExt, WindowExt,
+    ActiveTheme, IconName, Sizable, StyledExt, WindowExt,
     button::{Button, ButtonVariants},
     h_flex,
     label::Label,
     notification::Notification,
-    table::{Column, TableDelegate, TableState},
+    table::{Column, ColumnSort, TableDelegate, TableState},
+    tooltip::Tooltip,
 };
+use humansize::{DECIMAL, format_size};
 use std::{cell::Cell, rc::Rc, sync::Arc};
+use zedis_ui::ZedisDialog;
 
 pub const INDEX_COLUMN_NAME: &str = "#";
 
@@ -76,6 +82,50 @@ pub trait ZedisKvFetcher: 'static {
         false
     }
 
+    /// Returns the column definitions derived from the loaded data.
+    ///
+    /// Fetchers with a data-driven schema (e.g. Stream) return `Some`, and the table
+    /// rebuilds its columns whenever they change, such as after loading more pages.
+    fn columns(&self) -> Option<Vec<KvTableColumn>> {
+        None
+    }
+
+    /// Returns every field-value pair of a row for the detail panel.
+    ///
+    /// Fetchers whose rows don't share a fixed column schema (e.g. Stream entries)
+    /// return `Some`, and the table shows a vertical field list instead of the edit form.
+    fn row_detail(&self, _row_ix: usize) -> Option<Vec<(SharedString, SharedString)>> {
+        None
+    }
+
+    /// Returns the column index whose byte length the optional length column shows.
+    ///
+    /// Collections return `Some` to let users spot oversized entries.
+    fn value_column(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns a hover tooltip for a cell, such as the decoded time of a stream entry id.
+    fn cell_tooltip(&self, _row_ix: usize, _col_ix: usize, _cx: &App) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns how to read the original bytes of a cell for the hex dump.
+    /// Displayed values are decoded lossily, so binary payloads are fetched again.
+    fn raw_bytes_source(&self, _row_ix: usize, _col_ix: usize, _cx: &App) -> Option<RawBytesSource> {
+        None
+    }
+
+    /// Returns true while the cell's value is loaded lazily and not available yet.
+    ///
+    /// Only called for the rendered cells, so implementations can request the value here.
+    fn request_cell(&self, _row_ix: usize, _col_ix: usize, _cx: &mut App) -> bool {
+        false
+    }
+
+    /// Sorts the currently loaded rows by the specified column (client-side only).
+    fn sort(&self, _col_ix: usize, _sort: ColumnSort, _cx: &mut App) {}
+
     /// Filters data based on a keyword.
     ///
     /// Filtering strategy varies by data type:
@@ -95,6 +145,11 @@ pub trait ZedisKvFetcher: 'static {
     fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self;
 }
 
+/// Returns the element id of a table cell, unique per cell even for wide (e.g. stream) tables.
+fn cell_id(row_ix: usize, col_ix: usize, columns_count: usize) -> usize {
+    row_ix * columns_count + col_ix
+}
+
 /// A Table Delegate that manages the display and editing of Key-Value pairs.
 /// It bridges the UI (Table) and the Data Source (ZedisKvFetcher).
 pub struct ZedisKvDelegate<T: ZedisKvFetcher> {
@@ -117,12 +172,22 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
     /// * `window` - GPUI window context
     /// * `cx` - GPUI application context
     pub fn new(columns: Vec<KvTableColumn>, fetcher: Arc<T>, _window: &mut Window, _cx: &mut App) -> Self {
-        // Convert KvTableColumns to UI Columns and initialize input states
-        let ui_columns = columns
+        Self {
+            columns: Self::new_ui_columns(&columns),
+            table_columns: columns,
+            fetcher,
+            processing: Rc::new(Cell::new(false)),
+        }
+    }
+
+    /// Converts KvTableColumns to UI Columns.
+    fn new_ui_columns(columns: &[KvTableColumn]) -> Vec<Column> {
+        columns
             .iter()
             .map(|item| {
                 Column::new(item.name.clone(), item.name.clone())
                     .when_some(item.width, |col, width| col.width(width))
+                    .when(item.sortable, |col| col.sortable())
                     .map(|mut col| {
                         if let Some(align) = item.align {
                             col.align = align;
@@ -136,14 +201,18 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
                         col
                     })
             })
-            .collect();
+            .collect()
+    }
 
-        Self {
-            table_columns: columns,
-            columns: ui_columns,
-            fetcher,
-            processing: Rc::new(Cell::new(false)),
-        }
+    /// Replaces the column definitions (e.g., when a stream reveals new fields).
+    pub fn set_columns(&mut self, columns: Vec<KvTableColumn>) {
+        self.columns = Self::new_ui_columns(&columns);
+        self.table_columns = columns;
+    }
+
+    /// Returns the stable ids of the table columns, in display order.
+    pub fn column_ids(&self) -> Vec<Option<SharedString>> {
+        self.table_columns.iter().map(|column| column.id.clone()).collect()
     }
 
     /// Returns a cloned Arc reference to the current fetcher.
@@ -151,6 +220,25 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         self.fetcher.clone()
     }
 
+    /// Returns the text of an index or length cell, `None` for the other column types.
+    ///
+    /// Only the cell's own row is read from the fetcher, so rendering stays O(1) per cell.
+    fn computed_cell_text(&self, row_ix: usize, col_ix: usize) -> Option<String> {
+        match self.table_columns.get(col_ix).map(|item| &item.column_type) {
+            // Index column: row number (1-based)
+            Some(KvTableColumnType::Index) => Some((row_ix + 1).to_string()),
+            // Length column: byte length of the value column
+            Some(KvTableColumnType::Length) => Some(
+                self.fetcher
+                    .value_column()
+                    .and_then(|value_col| self.fetcher.get(row_ix, value_col))
+                    .map(|value| format_size(value.len(), DECIMAL))
+                    .unwrap_or_else(|| "--".to_string()),
+            ),
+            _ => None,
+        }
+    }
+
     /// Replaces the current fetcher with a new one (e.g., when switching keys).
     /// Resets processing state to ensure clean transition.
     pub fn set_fetcher(&mut self, fetcher: Arc<T>) {
@@ -195,6 +283,9 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
     /// - Index: Shows row number
     /// - Action: Shows edit/save/cancel/delete buttons
     /// - Value: Shows editable input or static label
+    ///
+    /// `DataTable` virtualizes its rows, so this is only called for the rows in
+    /// the viewport; keep it O(1) per cell, as collections can hold 100k+ loaded rows.
     fn render_td(
         &mut self,
         row_ix: usize,
@@ -208,40 +299,106 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
             .when_some(column.paddings, |this, paddings| this.paddings(paddings));
 
         // Handle special column types
-        if self
-            .table_columns
-            .get(col_ix)
-            .map(|item| item.column_type == KvTableColumnType::Index)
-            .unwrap_or_default()
-        {
-            // Index column: Display row number (1-based)
-            return base.child(Label::new((row_ix + 1).to_string()).text_align(column.align).w_full());
+        if let Some(text) = self.computed_cell_text(row_ix, col_ix) {
+            return base.child(Label::new(text).text_align(column.align).w_full());
+        }
+
+        if self.fetcher.request_cell(row_ix, col_ix, cx) {
+            return base.child(
+                Label::new(i18n_common(cx, "loading"))
+                    .text_color(cx.theme().muted_foreground)
+                    .text_align(column.align)
+                    .w_full(),
+            );
         }
 
         // Default: Render value as label with copy button on hover
         let value = self.fetcher.get(row_ix, col_ix).unwrap_or_else(|| "--".into());
         let group_name: SharedString = format!("td-{}-{}", row_ix, col_ix).into();
+        let cell_id = cell_id(row_ix, col_ix, self.columns.len());
         let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
+        let tooltip = self.fetcher.cell_tooltip(row_ix, col_ix, cx);
+        let raw_bytes_source = self.fetcher.raw_bytes_source(row_ix, col_ix, cx);
+        // Long values only render their first `max_truncate_length` chars,
+        // the full value is shown in a dialog from the truncated indicator
+        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        let truncated = value.len() > max_truncate_length && value.chars().count() > max_truncate_length;
+        let display: SharedString = if truncated {
+            value.chars().take(max_truncate_length).collect::<String>().into()
+        } else {
+            value.clone()
+        };
+        let label = Label::new(display).text_align(column.align).text_ellipsis();
         base.group(group_name.clone())
             .overflow_hidden()
-            .child(
-                Label::new(value.clone())
-                    .text_align(column.align)
-                    .text_ellipsis()
-                    .flex_1()
-                    .min_w_0(),
-            )
+            .map(|this| match tooltip {
+                Some(tooltip) => this.child(
+                    div()
+                        .id(("td-tooltip", cell_id))
+                        .flex_1()
+                        .min_w_0()
+                        .child(label)
+                        .tooltip(move |window, cx| Tooltip::new(tooltip.clone()).build(window, cx)),
+                ),
+                None => this.child(label.flex_1().min_w_0()),
+            })
+            .when(truncated, |this| {
+                let title = i18n_kv_table(cx, "full_value_title");
+                let full_value = value.clone();
+                this.child(
+                    Button::new(("td-truncated", cell_id))
+                        .ghost()
+                        .xsmall()
+                        .flex_none()
+                        .label(i18n_kv_table(cx, "truncated"))
+                        .tooltip(i18n_kv_table(cx, "truncated_tooltip"))
+                        .on_click(move |_, window, cx: &mut App| {
+                            cx.stop_propagation();
+                            let full_value = full_value.clone();
+                            ZedisDialog::new(title.clone())
+                                .alert()
+                                .child(move || {
+                                    div()
+                                        .id("kv-table-full-value")
+                                        .w_full()
+                                        .max_h(px(480.))
+                                        .overflow_y_scroll()
+                                        .child(full_value.clone())
+                                })
+                                .open(window, cx);
+                        }),
+                )
+            })
             .child(
                 div()
-                    .id(("copy-wrapper", row_ix * 100 + col_ix))
+                    .id(("copy-wrapper", cell_id))
                     .invisible()
                     .group_hover(group_name, |style| style.visible())
                     .flex_none()
+                    .flex()
                     .on_click(|_, _, cx: &mut App| cx.stop_propagation())
+                    .when_some(raw_bytes_source, |this, source| {
+                        let title = i18n_kv_table(cx, "raw_bytes_title");
+                        this.child(
+                            Button::new(("raw-bytes-cell", cell_id))
+                                .ghost()
+                                .icon(CustomIconName::Binary)
+                                .tooltip(i18n_kv_table(cx, "raw_bytes_tooltip"))
+                                .on_click(move |_, window, cx: &mut App| {
+                                    let view = cx.new(|cx| ZedisHexDump::new(source.clone(), cx));
+                                    ZedisDialog::new(title.clone())
+                                        .alert()
+                                        .child(move || view.clone())
+                                        .open(window, cx);
+                                }),
+                        )
+                    })
                     .child(
-                        Button::new(("copy-cell", row_ix * 100 + col_ix))
+                        Button::new(("copy-cell", cell_id))
                             .ghost()
                             .icon(IconName::Copy)
+                            .tooltip(copy_tooltip.clone())
                             .on_click(move |_, window, cx: &mut App| {
                                 cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                                 window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -249,6 +406,16 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
                     ),
             )
     }
+    /// Sorts the loaded rows through the fetcher, which reorders the values in server state.
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) {
+        self.fetcher.sort(col_ix, sort, cx);
+    }
     /// Returns whether all data has been loaded (end of file).
     fn has_more(&self, _: &App) -> bool {
         !self.fetcher.is_eof()
@@ -271,3 +438,111 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
         self.fetcher.load_more(window, cx);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+
+    const ROWS: usize = 100_000;
+    const VALUE_COL: usize = 2;
+
+    /// A fetcher with 100k loaded rows, recording the rows it is read for.
+    struct LargeFetcher {
+        read_rows: RefCell<Vec<usize>>,
+    }
+
+    impl ZedisKvFetcher for LargeFetcher {
+        fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+            self.read_rows.borrow_mut().push(row_ix);
+            (row_ix < ROWS).then(|| format!("value-{row_ix}-{col_ix}").into())
+        }
+        fn count(&self) -> usize {
+            ROWS
+        }
+        fn rows_count(&self) -> usize {
+            ROWS
+        }
+        fn is_done(&self) -> bool {
+            true
+        }
+        fn value_column(&self) -> Option<usize> {
+            Some(VALUE_COL)
+        }
+        fn load_more(&self, _window: &mut Window, _cx: &mut App) {}
+        fn remove(&self, _index: usize, _cx: &mut App) {}
+        fn filter(&self, _keyword: SharedString, _cx: &mut App) {}
+        fn handle_add_value(&self, _values: Vec<SharedString>, _window: &mut Window, _cx: &mut App) {}
+        fn new(_server_state: Entity<ZedisServerState>, _value: RedisValue) -> Self {
+            unreachable!("the test fetcher is built directly")
+        }
+    }
+
+    /// A hash-like table: index, field, value and byte length columns.
+    fn large_delegate() -> ZedisKvDelegate<LargeFetcher> {
+        let columns = vec![
+            KvTableColumn {
+                column_type: KvTableColumnType::Index,
+                ..KvTableColumn::new(INDEX_COLUMN_NAME, Some(80.))
+            },
+            KvTableColumn::new("Field", None),
+            KvTableColumn::new("Value", None),
+            KvTableColumn {
+                column_type: KvTableColumnType::Length,
+                ..KvTableColumn::new("Length", Some(100.))
+            },
+        ];
+        ZedisKvDelegate {
+            columns: ZedisKvDelegate::<LargeFetcher>::new_ui_columns(&columns),
+            table_columns: columns,
+            processing: Rc::new(Cell::new(false)),
+            fetcher: Arc::new(LargeFetcher {
+                read_rows: RefCell::new(vec![]),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_large_collection_reads_only_rendered_rows() {
+        let delegate = large_delegate();
+        assert_eq!(delegate.fetcher.rows_count(), ROWS);
+        assert_eq!(delegate.columns.len(), 4);
+
+        // The table renders only the rows of the viewport, e.g. near the end of the list
+        let viewport = ROWS - 30..ROWS;
+        let mut ids = HashSet::new();
+        for row_ix in viewport.clone() {
+            for col_ix in 0..delegate.columns.len() {
+                assert!(ids.insert(cell_id(row_ix, col_ix, delegate.columns.len())));
+            }
+            assert_eq!(delegate.computed_cell_text(row_ix, 0), Some((row_ix + 1).to_string()));
+            assert_eq!(delegate.computed_cell_text(row_ix, 1), None);
+            assert_eq!(delegate.computed_cell_text(row_ix, VALUE_COL), None);
+            let value_len = format!("value-{row_ix}-{VALUE_COL}").len();
+            assert_eq!(
+                delegate.computed_cell_text(row_ix, 3),
+                Some(format_size(value_len, DECIMAL))
+            );
+        }
+        assert_eq!(delegate.computed_cell_text(ROWS - 1, 0), Some(ROWS.to_string()));
+
+        // One read per length cell, none outside the viewport
+        let read_rows = delegate.fetcher.read_rows.borrow();
+        assert_eq!(read_rows.len(), viewport.len());
+        assert!(read_rows.iter().all(|row_ix| viewport.contains(row_ix)));
+    }
+
+    #[test]
+    fn test_cell_id_unique_for_large_collections() {
+        let delegate = large_delegate();
+        let columns_count = delegate.columns.len();
+        let mut ids = HashSet::with_capacity(ROWS * columns_count);
+        for row_ix in 0..ROWS {
+            for col_ix in 0..columns_count {
+                assert!(ids.insert(cell_id(row_ix, col_ix, columns_count)));
+            }
+        }
+        assert_eq!(ids.len(), ROWS * columns_count);
+    }
+}
@@ -45,6 +45,8 @@ pub enum KvTableColumnType {
     Value,
     /// Row index/number column
     Index,
+    /// Byte length of the row's value, see `ZedisKvFetcher::value_column`
+    Length,
 }
 
 /// Configuration for a table column including name, width, and alignment.
@@ -60,12 +62,16 @@ pub struct KvTableColumn {
     pub column_type: KvTableColumnType,
     /// Display name of the column
     pub name: SharedString,
+    /// Stable id the resized width is saved under, `None` for columns derived from the data
+    pub id: Option<SharedString>,
     /// Optional fixed width in pixels
     pub width: Option<f32>,
     /// Text alignment (left, center, right)
     pub align: Option<TextAlign>,
     /// Whether the column is auto-created
     pub auto_created: bool,
+    /// Whether the loaded rows can be sorted by this column
+    pub sortable: bool,
 }
 
 impl KvTableColumn {
@@ -73,6 +79,7 @@ impl KvTableColumn {
     pub fn new(name: &str, width: Option<f32>) -> Self {
         Self {
             name: name.to_string().into(),
+            id: Some(name.to_string().into()),
             width,
             ..Default::default()
         }
@@ -80,6 +87,7 @@ impl KvTableColumn {
     pub fn new_flex(name: &str) -> Self {
         Self {
             name: name.to_string().into(),
+            id: Some(name.to_string().into()),
             flex: true,
             ..Default::default()
         }
@@ -87,6 +95,7 @@ impl KvTableColumn {
     pub fn new_auto_created(name: &str) -> Self {
         Self {
             name: name.to_string().into(),
+            id: Some(name.to_string().into()),
             auto_created: true,
             ..Default::default()
         }
@@ -95,4 +104,13 @@ impl KvTableColumn {
         self.field_type = Some(field_type);
         self
     }
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+    /// Marks a column derived from the data (e.g. a stream field), its width is not saved.
+    pub fn data_driven(mut self) -> Self {
+        self.id = None;
+        self
+    }
 }
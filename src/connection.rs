@@ -14,17 +14,21 @@
 
 use tracing::info;
 
+mod acl;
 mod async_connection;
 mod command;
+mod command_log;
 mod config;
 mod manager;
 mod ssh_cluster_connection;
 mod ssh_stream;
 mod ssh_tunnel;
 
-pub use async_connection::{RedisAsyncConn, open_monitor_connection, open_single_connection, set_redis_connection_timeout, set_redis_response_timeout};
-pub use config::{RedisServer, get_server, get_servers, save_servers};
-pub use manager::{AccessMode, KeyMemoryUsage, RedisClientDescription, SlowLogEntry, get_connection_manager};
+pub use acl::AclPermissions;
+pub use async_connection::{DEFAULT_CLIENT_NAME_TEMPLATE, RedisAsyncConn, open_guarded_connection, open_monitor_connection, open_single_connection, set_client_name_template, set_redis_connection_timeout, set_redis_response_timeout};
+pub use command_log::{CommandLogEntry, clear_command_logs, list_command_logs, set_command_log_enabled};
+pub use config::{CommandPolicy, RedisServer, SERVER_COLORS, get_server, get_servers, save_servers, set_server_password};
+pub use manager::{AccessMode, ClusterHealth, DumpedKey, KeyFrequency, KeyMemoryUsage, NodeLatency, RedisClientDescription, ServerFlavor, SlowLogEntry, get_connection_manager};
 pub fn clear_expired_cache() {
     let (removed_count, total_count) = async_connection::clear_expired_connection_pool();
     if removed_count > 0 {
@@ -41,4 +45,11 @@ pub fn clear_expired_cache() {
         info!(removed_count, total_count, "clear expired ssh session")
     }
 }
+/// Drops every cached client, connection and SSH session, closing them, e.g. when the app quits.
+pub fn close_all_connections() {
+    let clients = manager::clear_clients();
+    let connections = async_connection::clear_connection_pool();
+    let ssh_sessions = ssh_tunnel::clear_ssh_sessions();
+    info!(clients, connections, ssh_sessions, "close all connections");
+}
 pub use command::*;
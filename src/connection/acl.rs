@@ -0,0 +1,227 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commands the ACL user of a connection may run, learned when the client is created.
+//!
+//! The command rules of `ACL GETUSER` decide when they name the command (or
+//! `@all`). Category rules are not resolved, and restricted users usually may
+//! not run `ACL GETUSER` at all, so undecided commands are probed with a
+//! harmless call and only a `NOPERM` error counts as denied. Commands the
+//! server's command policy blocks are denied without being sent.
+
+use super::async_connection::RedisAsyncConn;
+use super::config::CommandPolicy;
+use gpui::SharedString;
+use redis::{Value, cmd};
+use tracing::debug;
+
+/// Commands features depend on, with a cheap call used to probe them
+const GATED_COMMANDS: &[(&str, &[&str])] = &[
+    ("INFO", &["INFO", "server"]),
+    ("SLOWLOG GET", &["SLOWLOG", "GET", "0"]),
+    ("CLIENT LIST", &["CLIENT", "LIST", "ID", "1"]),
+    ("CONFIG GET", &["CONFIG", "GET", "zedis-acl-probe"]),
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct AclPermissions {
+    /// ACL user of the connection, empty if the server has no ACL
+    pub user: SharedString,
+    /// Gated commands the user is not allowed to run
+    denied: Vec<&'static str>,
+}
+
+impl AclPermissions {
+    /// Checks whether the command (e.g. `SLOWLOG GET`) may be run, commands
+    /// that are not gated are always allowed.
+    pub fn allows(&self, command: &str) -> bool {
+        !self.denied.iter().any(|item| item.eq_ignore_ascii_case(command))
+    }
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::BulkString(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+        Value::SimpleString(s) => Some(s.clone()),
+        Value::VerbatimString { text, .. } => Some(text.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the `commands` rules of `ACL GETUSER`, e.g. `+@all -config`.
+async fn get_command_rules(conn: &mut RedisAsyncConn, user: &str) -> Option<String> {
+    let reply: Value = cmd("ACL").arg("GETUSER").arg(user).query_async(conn).await.ok()?;
+    let pairs: Vec<(&Value, &Value)> = match &reply {
+        Value::Array(items) => items.chunks_exact(2).map(|pair| (&pair[0], &pair[1])).collect(),
+        Value::Map(items) => items.iter().map(|(k, v)| (k, v)).collect(),
+        _ => return None,
+    };
+    pairs
+        .into_iter()
+        .find(|(name, _)| value_to_string(name).as_deref() == Some("commands"))
+        .and_then(|(_, value)| value_to_string(value))
+}
+
+/// Evaluates the command rules in order, `None` if a category rule makes it undecidable.
+fn rules_verdict(rules: &str, command: &str) -> Option<bool> {
+    let command = command.to_lowercase();
+    let (name, subcommand) = match command.split_once(' ') {
+        Some((name, subcommand)) => (name, Some(format!("{name}|{subcommand}"))),
+        None => (command.as_str(), None),
+    };
+    // users start without any command
+    let mut verdict = Some(false);
+    for rule in rules.split_whitespace() {
+        let rule = rule.to_lowercase();
+        match rule.as_str() {
+            "allcommands" | "+@all" => verdict = Some(true),
+            "nocommands" | "-@all" => verdict = Some(false),
+            _ => {
+                let (allowed, target) = if let Some(target) = rule.strip_prefix('+') {
+                    (true, target)
+                } else if let Some(target) = rule.strip_prefix('-') {
+                    (false, target)
+                } else {
+                    continue;
+                };
+                if target.starts_with('@') {
+                    verdict = None;
+                } else if target == name || subcommand.as_deref() == Some(target) {
+                    verdict = Some(allowed);
+                }
+            }
+        }
+    }
+    verdict
+}
+
+/// Whether the command policy blocks a command given as `NAME [SUBCOMMAND]`.
+fn policy_blocks(policy: &CommandPolicy, command: &str) -> bool {
+    let mut parts = command.split(' ');
+    let name = parts.next().unwrap_or_default();
+    policy.check(name, parts.next()).is_some()
+}
+
+async fn probe_command(conn: &mut RedisAsyncConn, args: &[&str]) -> bool {
+    let mut probe = cmd(args[0]);
+    for arg in &args[1..] {
+        probe.arg(*arg);
+    }
+    let result: redis::RedisResult<Value> = probe.query_async(conn).await;
+    match result {
+        Ok(_) => true,
+        Err(e) => e.code() != Some("NOPERM"),
+    }
+}
+
+/// Learns which gated commands the user of the connection may run.
+///
+/// Servers without ACL (before Redis 6) allow everything the command policy does.
+pub(crate) async fn load_acl_permissions(mut conn: RedisAsyncConn, policy: &CommandPolicy) -> AclPermissions {
+    let user: String = if policy_blocks(policy, "ACL WHOAMI") {
+        String::new()
+    } else {
+        cmd("ACL")
+            .arg("WHOAMI")
+            .query_async(&mut conn)
+            .await
+            .unwrap_or_default()
+    };
+    let rules = if user.is_empty() || policy_blocks(policy, "ACL GETUSER") {
+        None
+    } else {
+        get_command_rules(&mut conn, &user).await
+    };
+    let mut denied = vec![];
+    for (command, probe) in GATED_COMMANDS {
+        if policy_blocks(policy, command) {
+            denied.push(*command);
+            continue;
+        }
+        if user.is_empty() {
+            continue;
+        }
+        let verdict = rules.as_deref().and_then(|rules| rules_verdict(rules, command));
+        let allowed = match verdict {
+            Some(allowed) => allowed,
+            None => probe_command(&mut conn, probe).await,
+        };
+        if !allowed {
+            denied.push(*command);
+        }
+    }
+    debug!(user = user.as_str(), denied = ?denied, "acl permissions");
+    AclPermissions {
+        user: user.into(),
+        denied,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::RedisServer;
+
+    #[test]
+    fn test_rules_verdict_all_commands() {
+        assert_eq!(rules_verdict("+@all -config", "INFO"), Some(true));
+        assert_eq!(rules_verdict("+@all -config", "CONFIG GET"), Some(false));
+        assert_eq!(rules_verdict("allcommands -slowlog", "slowlog get"), Some(false));
+        assert_eq!(rules_verdict("", "INFO"), Some(false));
+    }
+
+    #[test]
+    fn test_rules_verdict_subcommand() {
+        assert_eq!(rules_verdict("-@all +config|get", "CONFIG GET"), Some(true));
+        assert_eq!(rules_verdict("-@all +config|get", "CONFIG SET"), Some(false));
+        assert_eq!(rules_verdict("-@all +config|get", "INFO"), Some(false));
+    }
+
+    #[test]
+    fn test_rules_verdict_category() {
+        assert_eq!(rules_verdict("-@all +@admin", "CONFIG GET"), None);
+        assert_eq!(rules_verdict("+@all -@dangerous", "CLIENT LIST"), None);
+        // A later command rule decides again
+        assert_eq!(rules_verdict("-@all +@admin +config|get", "CONFIG GET"), Some(true));
+    }
+
+    #[test]
+    fn test_rules_verdict_order() {
+        // The last matching rule wins, whether it names the subcommand or the whole command
+        assert_eq!(rules_verdict("+@all -config +config|get", "CONFIG GET"), Some(true));
+        assert_eq!(rules_verdict("+@all -config +config|get", "CONFIG SET"), Some(false));
+        assert_eq!(rules_verdict("-@all +config|get -config", "CONFIG GET"), Some(false));
+        assert_eq!(rules_verdict("-@all -client|kill +client", "CLIENT LIST"), Some(true));
+    }
+
+    #[test]
+    fn test_policy_blocks() {
+        let policy = RedisServer {
+            denied_commands: Some("config".to_string()),
+            ..Default::default()
+        }
+        .command_policy();
+        assert!(policy_blocks(&policy, "CONFIG GET"));
+        assert!(!policy_blocks(&policy, "CLIENT LIST"));
+
+        let policy = RedisServer {
+            allowed_commands: Some("INFO, SLOWLOG GET".to_string()),
+            ..Default::default()
+        }
+        .command_policy();
+        assert!(policy_blocks(&policy, "ACL WHOAMI"));
+        assert!(!policy_blocks(&policy, "SLOWLOG GET"));
+        assert!(!policy_blocks(&policy, "INFO"));
+    }
+}
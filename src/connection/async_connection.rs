@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::config::RedisServer;
+use super::command_log::{command_log_enabled, log_command, log_pipeline};
+use super::config::{CommandPolicy, RedisServer};
 use super::ssh_cluster_connection::SshMultiplexedConnection;
 use super::ssh_tunnel::open_single_ssh_tunnel_connection;
 use crate::error::Error;
@@ -20,7 +21,8 @@ use crate::helpers::{TtlCache, now_secs};
 use arc_swap::ArcSwap;
 use futures::future::try_join_all;
 use redis::{
-    AsyncConnectionConfig, Client, Cmd, FromRedisValue, Pipeline, RedisFuture, Value,
+    Arg, AsyncConnectionConfig, Client, Cmd, ErrorKind, FromRedisValue, IntoConnectionInfo, Pipeline, ProtocolVersion,
+    RedisError, RedisFuture, Value,
     aio::{ConnectionLike, MultiplexedConnection},
     cluster_async::ClusterConnection,
     cmd,
@@ -32,10 +34,26 @@ use std::sync::{
 use std::{sync::LazyLock, time::Duration};
 use tracing::{debug, error};
 
-const CLIENT_NAME: &str = concat!("zedis:v", env!("CARGO_PKG_VERSION"));
+/// Name template of the connections, `{hostname}` and `{version}` are replaced
+pub const DEFAULT_CLIENT_NAME_TEMPLATE: &str = "zedis-{hostname}";
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+static HOSTNAME: LazyLock<String> = LazyLock::new(|| {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            let output = std::process::Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+});
+
+static CLIENT_NAME: LazyLock<ArcSwap<String>> =
+    LazyLock::new(|| ArcSwap::from_pointee(format_client_name(DEFAULT_CLIENT_NAME_TEMPLATE)));
+
 static DELAY: LazyLock<Option<Duration>> = LazyLock::new(|| {
     let value = std::env::var("REDIS_DELAY").unwrap_or_default();
     humantime::parse_duration(&value).ok()
@@ -72,6 +90,11 @@ pub fn clear_expired_connection_pool() -> (usize, usize) {
     CONNECTION_POOL.clear_expired()
 }
 
+/// Drops all connections of the connection pool.
+pub fn clear_connection_pool() -> usize {
+    CONNECTION_POOL.clear()
+}
+
 struct RedisConfig {
     connection_timeout: Duration,
     response_timeout: Duration,
@@ -109,9 +132,29 @@ pub fn get_redis_response_timeout() -> Duration {
     GLOBAL_REDIS_CONFIG.load().response_timeout
 }
 
+/// Expands the name template, spaces are not allowed in a client name so they become dashes.
+fn format_client_name(template: &str) -> String {
+    template
+        .trim()
+        .replace("{hostname}", &HOSTNAME)
+        .replace("{version}", env!("CARGO_PKG_VERSION"))
+        .chars()
+        .map(|c| if c.is_whitespace() || c.is_control() { '-' } else { c })
+        .collect()
+}
+
+/// Sets the name template of new connections, an empty template leaves them unnamed.
+pub fn set_client_name_template(template: &str) {
+    CLIENT_NAME.store(Arc::new(format_client_name(template)));
+}
+
 pub(crate) async fn set_client_name(conn: &mut impl ConnectionLike) {
+    let name = CLIENT_NAME.load();
+    if name.is_empty() {
+        return;
+    }
     // ignore error
-    if let Err(err) = cmd("CLIENT").arg("SETNAME").arg(CLIENT_NAME).exec_async(conn).await {
+    if let Err(err) = cmd("CLIENT").arg("SETNAME").arg(name.as_str()).exec_async(conn).await {
         error!(error = %err, "set client name failed");
     }
 }
@@ -145,11 +188,29 @@ pub async fn open_single_connection(config: &RedisServer, db: usize, use_cache:
         return Ok(conn);
     }
 
+    let conn = connect_single(config, db, ProtocolVersion::RESP2).await?;
+    if !use_cache {
+        return Ok(conn);
+    }
+
+    // Cache the connection in the pool for future reuse if caching is enabled
+    CONNECTION_POOL.insert(
+        key,
+        Arc::new(MultiplexedConnectionCache {
+            conn: conn.clone(),
+            check_time: AtomicU64::new(now_secs()),
+        }),
+    );
+
+    Ok(conn)
+}
+/// Creates a new single connection speaking `protocol` and selects the database.
+async fn connect_single(config: &RedisServer, db: usize, protocol: ProtocolVersion) -> Result<MultiplexedConnection> {
     // Create a new connection: SSH tunnel or direct connection
     let mut conn = if config.is_ssh_tunnel() {
-        open_single_ssh_tunnel_connection(config).await?
+        open_single_ssh_tunnel_connection(config, protocol).await?
     } else {
-        let client = open_single_client(config)?;
+        let client = open_single_client_with_protocol(config, protocol)?;
         // Configure connection with timeouts
         let cfg = AsyncConnectionConfig::default()
             .set_connection_timeout(Some(get_redis_connection_timeout()))
@@ -163,21 +224,22 @@ pub async fn open_single_connection(config: &RedisServer, db: usize, use_cache:
         let _: () = cmd("SELECT").arg(db).query_async(&mut conn).await?;
         debug!(name = config.name, db, "select database");
     }
-    if !use_cache {
-        return Ok(conn);
-    }
-
-    // Cache the connection in the pool for future reuse if caching is enabled
-    CONNECTION_POOL.insert(
-        key,
-        Arc::new(MultiplexedConnectionCache {
-            conn: conn.clone(),
-            check_time: AtomicU64::new(now_secs()),
-        }),
-    );
-
     Ok(conn)
 }
+/// Opens a new, unpooled single connection speaking RESP3, guarded like `open_guarded_connection`.
+///
+/// The client is built for RESP3, so redis-rs sends `HELLO 3` on connect and parses
+/// push frames and attributes accordingly.
+pub async fn open_resp3_connection(config: &RedisServer, db: usize) -> Result<RedisAsyncConn> {
+    let conn = connect_single(config, db, ProtocolVersion::RESP3).await?;
+    Ok(RedisAsyncConn::Single(conn).with_command_policy(config.command_policy()))
+}
+/// Opens a single connection like `open_single_connection`, wrapped so its commands are
+/// checked against the server's command policy and written to the command log.
+pub async fn open_guarded_connection(config: &RedisServer, db: usize, use_cache: bool) -> Result<RedisAsyncConn> {
+    let conn = open_single_connection(config, db, use_cache).await?;
+    Ok(RedisAsyncConn::Single(conn).with_command_policy(config.command_policy()))
+}
 pub fn remove_connection_from_pool(config: &RedisServer, db: usize) {
     let key = config.get_hash(db);
     CONNECTION_POOL.remove(&key);
@@ -195,12 +257,18 @@ pub fn remove_connection_from_pool(config: &RedisServer, db: usize) {
 ///
 /// A Redis client ready to establish connections
 pub fn open_single_client(config: &RedisServer) -> Result<Client> {
-    let url = config.get_connection_url();
+    open_single_client_with_protocol(config, ProtocolVersion::RESP2)
+}
+
+fn open_single_client_with_protocol(config: &RedisServer, protocol: ProtocolVersion) -> Result<Client> {
+    let info = config.get_connection_url().into_connection_info()?;
+    let redis_settings = info.redis_settings().clone().set_protocol(protocol);
+    let info = info.set_redis_settings(redis_settings);
     // Build client with TLS if certificates are provided
     let client = if let Some(certificates) = config.tls_certificates() {
-        Client::build_with_tls(url, certificates)?
+        Client::build_with_tls(info, certificates)?
     } else {
-        Client::open(url)?
+        Client::open(info)?
     };
     Ok(client)
 }
@@ -210,11 +278,43 @@ pub fn open_single_client(config: &RedisServer) -> Result<Client> {
 /// This creates a non-multiplexed connection suitable for the Redis MONITOR
 /// command, which streams all commands received by the server.
 pub async fn open_monitor_connection(config: &RedisServer) -> Result<redis::aio::Monitor> {
+    ensure_command_allowed(config, &cmd("MONITOR"))?;
     let client = open_single_client(config)?;
     let monitor = client.get_async_monitor().await?;
     Ok(monitor)
 }
 
+/// Checks the command against the server's command policy.
+///
+/// The command name and its first argument are matched, so entries such as
+/// `CONFIG SET` block only that subcommand.
+fn check_command_policy(policy: &CommandPolicy, cmd: &Cmd) -> Result<(), RedisError> {
+    let mut args = cmd.args_iter().filter_map(|arg| match arg {
+        Arg::Simple(value) => Some(String::from_utf8_lossy(value).to_string()),
+        _ => None,
+    });
+    let name = args.next().unwrap_or_default();
+    let sub_command = args.next();
+    match policy.check(&name, sub_command.as_deref()) {
+        Some(blocked) => Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "Command blocked by the server's command allowlist/denylist",
+            blocked,
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns an error if the command is blocked by the server's command policy.
+pub(crate) fn ensure_command_allowed(config: &RedisServer, cmd: &Cmd) -> Result<()> {
+    let policy = config.command_policy();
+    if policy.is_empty() {
+        return Ok(());
+    }
+    check_command_policy(&policy, cmd)?;
+    Ok(())
+}
+
 /// A wrapper enum for Redis asynchronous connections.
 ///
 /// This unifies `MultiplexedConnection` (for single nodes) and
@@ -225,22 +325,44 @@ pub enum RedisAsyncConn {
     Single(MultiplexedConnection),
     Cluster(ClusterConnection),
     SshCluster(ClusterConnection<SshMultiplexedConnection>),
+    /// A connection whose commands are checked against a command allowlist/denylist
+    /// before being sent, so every feature (including the console) respects it.
+    Guarded(Box<RedisAsyncConn>, Arc<CommandPolicy>),
+}
+
+impl RedisAsyncConn {
+    /// Wraps the connection with the command policy, if the policy has any entry.
+    pub fn with_command_policy(self, policy: CommandPolicy) -> Self {
+        if policy.is_empty() {
+            return self;
+        }
+        RedisAsyncConn::Guarded(Box::new(self), Arc::new(policy))
+    }
 }
 
 impl ConnectionLike for RedisAsyncConn {
     #[inline]
     fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
-        let cmd_future = match self {
+        let mut cmd_future = match self {
+            RedisAsyncConn::Guarded(conn, policy) => {
+                if let Err(e) = check_command_policy(policy, cmd) {
+                    return Box::pin(async move { Err(e) });
+                }
+                return conn.req_packed_command(cmd);
+            }
             RedisAsyncConn::Single(conn) => conn.req_packed_command(cmd),
             RedisAsyncConn::Cluster(conn) => conn.req_packed_command(cmd),
             RedisAsyncConn::SshCluster(conn) => conn.req_packed_command(cmd),
         };
         if let Some(delay) = *DELAY {
-            return Box::pin(async move {
+            cmd_future = Box::pin(async move {
                 smol::Timer::after(delay).await;
                 cmd_future.await
             });
         }
+        if command_log_enabled() {
+            return log_command(cmd, cmd_future);
+        }
         cmd_future
     }
     #[inline]
@@ -250,17 +372,26 @@ impl ConnectionLike for RedisAsyncConn {
         offset: usize,
         count: usize,
     ) -> RedisFuture<'a, Vec<Value>> {
-        let cmd_future = match self {
+        let mut cmd_future = match self {
+            RedisAsyncConn::Guarded(conn, policy) => {
+                if let Err(e) = cmd.cmd_iter().try_for_each(|item| check_command_policy(policy, item)) {
+                    return Box::pin(async move { Err(e) });
+                }
+                return conn.req_packed_commands(cmd, offset, count);
+            }
             RedisAsyncConn::Single(conn) => conn.req_packed_commands(cmd, offset, count),
             RedisAsyncConn::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
             RedisAsyncConn::SshCluster(conn) => conn.req_packed_commands(cmd, offset, count),
         };
         if let Some(delay) = *DELAY {
-            return Box::pin(async move {
+            cmd_future = Box::pin(async move {
                 smol::Timer::after(delay).await;
                 cmd_future.await
             });
         }
+        if command_log_enabled() {
+            return log_pipeline(cmd, cmd_future);
+        }
         cmd_future
     }
     #[inline]
@@ -269,6 +400,7 @@ impl ConnectionLike for RedisAsyncConn {
             RedisAsyncConn::Single(conn) => conn.get_db(),
             RedisAsyncConn::Cluster(_) => 0,
             RedisAsyncConn::SshCluster(conn) => conn.get_db(),
+            RedisAsyncConn::Guarded(conn, _) => conn.get_db(),
         }
     }
 }
@@ -303,6 +435,7 @@ pub(crate) async fn query_async_masters<T: FromRedisValue>(
             let Some(current_cmd) = current_cmd else {
                 return Ok::<Option<T>, Error>(None);
             };
+            ensure_command_allowed(addr, &current_cmd)?;
             // Establish a multiplexed async connection to the specific node.
             let mut conn = open_single_connection(addr, db, true).await?;
 
@@ -348,6 +481,9 @@ pub(crate) async fn query_async_masters_pipeline(
             let Some(current_pipe) = current_pipe else {
                 return Ok::<Option<Vec<Value>>, Error>(None);
             };
+            for item in current_pipe.cmd_iter() {
+                ensure_command_allowed(&addr, item)?;
+            }
             let mut conn = open_single_connection(&addr, db, true).await?;
 
             let values: Vec<Value> = current_pipe.query_async(&mut conn).await?;
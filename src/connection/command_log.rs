@@ -0,0 +1,204 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Developer log of every command sent through [`super::RedisAsyncConn`].
+//!
+//! When enabled, each command is recorded with its duration and outcome in an
+//! in-memory ring buffer (shown in the settings) and emitted as a `tracing`
+//! event with the `zedis::command` target. Only the command name and its first
+//! argument (usually the key or subcommand) are kept, values are redacted.
+
+use crate::helpers::unix_ts_millis;
+use gpui::SharedString;
+use parking_lot::RwLock;
+use redis::{Arg, Cmd, Pipeline, RedisFuture};
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+use tracing::info;
+
+/// Maximum number of entries kept, the oldest are dropped first
+const MAX_COMMAND_LOGS: usize = 500;
+/// Commands of a pipeline listed in its log entry
+const MAX_PIPELINE_COMMANDS: usize = 5;
+/// Commands whose arguments are all secrets
+const SECRET_COMMANDS: &[&str] = &["AUTH", "HELLO", "MIGRATE"];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COMMAND_LOGS: LazyLock<RwLock<VecDeque<CommandLogEntry>>> = LazyLock::new(|| RwLock::new(VecDeque::new()));
+
+#[derive(Debug, Clone)]
+pub struct CommandLogEntry {
+    pub timestamp_ms: i64,
+    /// Redacted command, e.g. `SET user:1 <5 bytes>`
+    pub command: SharedString,
+    pub elapsed_ms: u64,
+    /// Error message if the command failed
+    pub error: Option<SharedString>,
+}
+
+pub fn set_command_log_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn command_log_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the recorded commands, oldest first.
+pub fn list_command_logs() -> Vec<CommandLogEntry> {
+    COMMAND_LOGS.read().iter().cloned().collect()
+}
+
+pub fn clear_command_logs() {
+    COMMAND_LOGS.write().clear();
+}
+
+/// Formats a command as its name and first argument, other arguments are replaced by their size.
+fn redact_command(cmd: &Cmd) -> String {
+    let mut args = cmd.args_iter().map(|arg| match arg {
+        Arg::Simple(value) => value,
+        Arg::Cursor => b"<cursor>".as_slice(),
+    });
+    let name = String::from_utf8_lossy(args.next().unwrap_or_default()).to_uppercase();
+    let secret = SECRET_COMMANDS.contains(&name.as_str());
+    let mut parts = vec![name];
+    for (index, arg) in args.enumerate() {
+        if secret {
+            parts.push("***".to_string());
+        } else if index == 0 {
+            parts.push(String::from_utf8_lossy(arg).to_string());
+        } else {
+            parts.push(format!("<{} bytes>", arg.len()));
+        }
+    }
+    parts.join(" ")
+}
+
+fn redact_pipeline(pipeline: &Pipeline) -> String {
+    let commands: Vec<&Cmd> = pipeline.cmd_iter().collect();
+    let mut parts: Vec<String> = commands
+        .iter()
+        .take(MAX_PIPELINE_COMMANDS)
+        .map(|cmd| redact_command(cmd))
+        .collect();
+    if commands.len() > MAX_PIPELINE_COMMANDS {
+        parts.push(format!("... +{}", commands.len() - MAX_PIPELINE_COMMANDS));
+    }
+    format!("PIPELINE({}) {}", commands.len(), parts.join("; "))
+}
+
+fn record<T>(command: String, started: Instant, result: &redis::RedisResult<T>) {
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    let error = result.as_ref().err().map(|e| e.to_string());
+    info!(
+        target: "zedis::command",
+        command = command.as_str(),
+        elapsed_ms,
+        error = error.as_deref(),
+        "redis command"
+    );
+    let mut logs = COMMAND_LOGS.write();
+    if logs.len() >= MAX_COMMAND_LOGS {
+        logs.pop_front();
+    }
+    logs.push_back(CommandLogEntry {
+        timestamp_ms: unix_ts_millis(),
+        command: command.into(),
+        elapsed_ms,
+        error: error.map(Into::into),
+    });
+}
+
+/// Wraps the future of a command so it is timed and recorded when it completes.
+pub(crate) fn log_command<'a, T: Send + 'a>(cmd: &Cmd, future: RedisFuture<'a, T>) -> RedisFuture<'a, T> {
+    let command = redact_command(cmd);
+    Box::pin(async move {
+        let started = Instant::now();
+        let result = future.await;
+        record(command, started, &result);
+        result
+    })
+}
+
+/// Wraps the future of a pipeline so it is timed and recorded as one entry.
+pub(crate) fn log_pipeline<'a, T: Send + 'a>(pipeline: &Pipeline, future: RedisFuture<'a, T>) -> RedisFuture<'a, T> {
+    let command = redact_pipeline(pipeline);
+    Box::pin(async move {
+        let started = Instant::now();
+        let result = future.await;
+        record(command, started, &result);
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::{cmd, pipe};
+
+    #[test]
+    fn test_redact_secret_commands() {
+        assert_eq!(redact_command(cmd("AUTH").arg("secret")), "AUTH ***");
+        assert_eq!(redact_command(cmd("auth").arg("admin").arg("secret")), "AUTH *** ***");
+        assert_eq!(
+            redact_command(cmd("HELLO").arg(3).arg("AUTH").arg("admin").arg("secret")),
+            "HELLO *** *** *** ***"
+        );
+        assert_eq!(
+            redact_command(
+                cmd("MIGRATE")
+                    .arg("127.0.0.1")
+                    .arg(6380)
+                    .arg("key")
+                    .arg(0)
+                    .arg(1000)
+                    .arg("AUTH")
+                    .arg("secret")
+            ),
+            "MIGRATE *** *** *** *** *** *** ***"
+        );
+    }
+
+    #[test]
+    fn test_redact_command() {
+        assert_eq!(redact_command(&cmd("PING")), "PING");
+        assert_eq!(redact_command(cmd("get").arg("user:1")), "GET user:1");
+        assert_eq!(
+            redact_command(cmd("SET").arg("user:1").arg("zedis")),
+            "SET user:1 <5 bytes>"
+        );
+        assert_eq!(
+            redact_command(cmd("CONFIG").arg("SET").arg("requirepass").arg("secret")),
+            "CONFIG SET <11 bytes> <6 bytes>"
+        );
+    }
+
+    #[test]
+    fn test_redact_pipeline() {
+        let mut pipeline = pipe();
+        pipeline.cmd("AUTH").arg("secret").cmd("SET").arg("user:1").arg("zedis");
+        assert_eq!(redact_pipeline(&pipeline), "PIPELINE(2) AUTH ***; SET user:1 <5 bytes>");
+
+        let mut pipeline = pipe();
+        for index in 0..7 {
+            pipeline.cmd("GET").arg(format!("key:{index}"));
+        }
+        assert_eq!(
+            redact_pipeline(&pipeline),
+            "PIPELINE(7) GET key:0; GET key:1; GET key:2; GET key:3; GET key:4; ... +2"
+        );
+    }
+}
@@ -89,7 +89,70 @@ pub struct RedisServer {
     pub ssh_username: Option<String>,
     pub ssh_password: Option<String>,
     pub ssh_key: Option<String>,
+    /// Commands Zedis may run on this server (comma or newline separated), empty means all.
+    pub allowed_commands: Option<String>,
+    /// Commands Zedis must never run on this server (comma or newline separated).
+    pub denied_commands: Option<String>,
+    /// Accent color of the server, one of [`SERVER_COLORS`], e.g. red for production.
+    pub color: Option<String>,
+    /// Short tag shown next to the server, e.g. `PROD`.
+    pub label: Option<String>,
 }
+
+/// Preset accent colors of a server, the first entry means no color.
+pub const SERVER_COLORS: [&str; 7] = ["", "red", "yellow", "green", "blue", "magenta", "cyan"];
+
+/// Per-server command allowlist/denylist, enforced before a command is sent.
+///
+/// Entries are either a command name (`FLUSHALL`) or a command with its
+/// subcommand (`CONFIG SET`). The denylist always wins over the allowlist.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandPolicy {
+    allowed: Vec<String>,
+    denied: Vec<String>,
+}
+
+impl CommandPolicy {
+    /// Parses a comma or newline separated command list into upper-cased entries.
+    pub fn parse_list(value: Option<&str>) -> Vec<String> {
+        value
+            .unwrap_or_default()
+            .split([',', '\n'])
+            .map(|item| item.split_whitespace().collect::<Vec<_>>().join(" ").to_uppercase())
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.allowed.is_empty() && self.denied.is_empty()
+    }
+    fn full_name(name: &str, sub_command: Option<&str>) -> (String, String) {
+        let name = name.to_uppercase();
+        let full_name = match sub_command {
+            Some(sub_command) => format!("{name} {}", sub_command.to_uppercase()),
+            None => name.clone(),
+        };
+        (name, full_name)
+    }
+    /// Returns the entry of a parsed list matching the command name or the command with its subcommand.
+    pub fn find_match(list: &[String], name: &str, sub_command: Option<&str>) -> Option<String> {
+        let (name, full_name) = Self::full_name(name, sub_command);
+        list.iter().find(|item| **item == name || **item == full_name).cloned()
+    }
+    /// Checks a command given its name and optional first argument.
+    ///
+    /// Returns the blocked command name if the policy forbids it.
+    pub fn check(&self, name: &str, sub_command: Option<&str>) -> Option<String> {
+        let (_, full_name) = Self::full_name(name, sub_command);
+        if Self::find_match(&self.denied, name, sub_command).is_some() {
+            return Some(full_name);
+        }
+        if !self.allowed.is_empty() && Self::find_match(&self.allowed, name, sub_command).is_none() {
+            return Some(full_name);
+        }
+        None
+    }
+}
+
 impl RedisServer {
     pub fn from_form_data(id: &str, data: &IndexMap<SharedString, SharedString>) -> Self {
         let get_str = |k: &str| data.get(k).map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
@@ -139,6 +202,21 @@ impl RedisServer {
             insecure: get_bool("insecure"),
             ssh_tunnel: get_bool("ssh_tunnel"),
             readonly: get_bool("readonly"),
+            allowed_commands: get_str("allowed_commands"),
+            denied_commands: get_str("denied_commands"),
+            color: get_str("color")
+                .and_then(|s| s.parse::<usize>().ok())
+                .and_then(|index| SERVER_COLORS.get(index))
+                .filter(|color| !color.is_empty())
+                .map(|color| color.to_string()),
+            label: get_str("label"),
+        }
+    }
+    /// Builds the command allowlist/denylist configured for this server.
+    pub fn command_policy(&self) -> CommandPolicy {
+        CommandPolicy {
+            allowed: CommandPolicy::parse_list(self.allowed_commands.as_deref()),
+            denied: CommandPolicy::parse_list(self.denied_commands.as_deref()),
         }
     }
     pub fn get_hash(&self, db: usize) -> u64 {
@@ -152,28 +230,34 @@ impl RedisServer {
     }
     /// Generates the connection URL based on host, port, and optional password.
     pub fn get_connection_url(&self) -> String {
+        self.to_redis_url(true)
+    }
+    /// Builds a `redis://` (or `rediss://`) URL, the inverse of the host parsing.
+    ///
+    /// The password is left out unless `include_password` is set, e.g. when sharing the connection.
+    pub fn to_redis_url(&self, include_password: bool) -> String {
         let tls = self.tls.unwrap_or(false);
         let scheme = if tls { "rediss" } else { "redis" };
-
-        let safe_pwd = self.password.as_deref().filter(|s| !s.trim().is_empty());
-        let safe_usr = self.username.as_deref().filter(|s| !s.trim().is_empty());
-
-        let url = match (safe_pwd, safe_usr) {
-            (Some(pwd), Some(username)) => {
-                let pwd_enc = utf8_percent_encode(pwd, NON_ALPHANUMERIC).to_string();
-                let username_enc = utf8_percent_encode(username, NON_ALPHANUMERIC).to_string();
-                format!("{scheme}://{username_enc}:{pwd_enc}@{}:{}", self.host, self.port)
-            }
-            (Some(pwd), None) => {
-                let pwd_enc = utf8_percent_encode(pwd, NON_ALPHANUMERIC).to_string();
-                format!("{scheme}://:{pwd_enc}@{}:{}", self.host, self.port)
-            }
-            _ => format!("{scheme}://{}:{}", self.host, self.port),
+        let username = self
+            .username
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| utf8_percent_encode(s, NON_ALPHANUMERIC).to_string());
+        let password = self
+            .password
+            .as_deref()
+            .filter(|s| include_password && !s.trim().is_empty())
+            .map(|s| utf8_percent_encode(s, NON_ALPHANUMERIC).to_string());
+        let auth = match (username, password) {
+            (Some(username), Some(password)) => format!("{username}:{password}@"),
+            (Some(username), None) => format!("{username}@"),
+            (None, Some(password)) => format!(":{password}@"),
+            (None, None) => String::new(),
         };
+        let url = format!("{scheme}://{auth}{}:{}", self.host, self.port);
         if tls && self.insecure.unwrap_or(false) {
             return format!("{url}/#insecure");
         }
-
         url
     }
     pub fn tls_certificates(&self) -> Option<TlsCertificates> {
@@ -295,6 +379,15 @@ pub async fn save_servers(mut servers: Vec<RedisServer>) -> Result<()> {
     Ok(())
 }
 
+/// Replaces the password of a server for the current session, without saving it to the file.
+pub fn set_server_password(id: &str, password: Option<String>) {
+    let mut configs = HashMap::clone(&SERVER_CONFIG_MAP.load());
+    if let Some(server) = configs.get_mut(id) {
+        server.password = password;
+    }
+    SERVER_CONFIG_MAP.store(Arc::new(configs));
+}
+
 /// Retrieves a single server configuration by name.
 pub fn get_server(id: &str) -> Result<RedisServer> {
     if let Some(server) = SERVER_CONFIG_MAP.load().get(id) {
@@ -306,3 +399,87 @@ pub fn get_server(id: &str) -> Result<RedisServer> {
     })?;
     Ok(config.clone())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_policy(allowed: &str, denied: &str) -> CommandPolicy {
+        CommandPolicy {
+            allowed: CommandPolicy::parse_list(Some(allowed)),
+            denied: CommandPolicy::parse_list(Some(denied)),
+        }
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            CommandPolicy::parse_list(Some("flushall, config  set\n\n keys ,")),
+            vec!["FLUSHALL", "CONFIG SET", "KEYS"]
+        );
+        assert!(CommandPolicy::parse_list(None).is_empty());
+        assert!(CommandPolicy::parse_list(Some(" , \n")).is_empty());
+    }
+
+    #[test]
+    fn test_find_match() {
+        let list = CommandPolicy::parse_list(Some("FLUSHALL, CONFIG SET"));
+        assert_eq!(
+            CommandPolicy::find_match(&list, "flushall", None),
+            Some("FLUSHALL".to_string())
+        );
+        assert_eq!(
+            CommandPolicy::find_match(&list, "Config", Some("set")),
+            Some("CONFIG SET".to_string())
+        );
+        assert_eq!(CommandPolicy::find_match(&list, "CONFIG", Some("GET")), None);
+        assert_eq!(CommandPolicy::find_match(&list, "CONFIG", None), None);
+    }
+
+    #[test]
+    fn test_check_case_insensitive() {
+        let policy = new_policy("", "flushall");
+        assert_eq!(policy.check("FlushAll", None), Some("FLUSHALL".to_string()));
+        assert_eq!(
+            policy.check("flushall", Some("async")),
+            Some("FLUSHALL ASYNC".to_string())
+        );
+        assert_eq!(policy.check("GET", Some("key")), None);
+    }
+
+    #[test]
+    fn test_check_subcommand() {
+        // A subcommand entry blocks only that subcommand
+        let policy = new_policy("", "CONFIG SET");
+        assert_eq!(policy.check("config", Some("set")), Some("CONFIG SET".to_string()));
+        assert_eq!(policy.check("config", Some("get")), None);
+        assert_eq!(policy.check("config", None), None);
+        // A command entry blocks every subcommand
+        let policy = new_policy("", "CONFIG");
+        assert_eq!(policy.check("config", Some("get")), Some("CONFIG GET".to_string()));
+        assert_eq!(policy.check("config", None), Some("CONFIG".to_string()));
+    }
+
+    #[test]
+    fn test_check_denylist_wins() {
+        let policy = new_policy("CONFIG, GET", "CONFIG SET");
+        assert_eq!(policy.check("CONFIG", Some("GET")), None);
+        assert_eq!(policy.check("CONFIG", Some("SET")), Some("CONFIG SET".to_string()));
+        assert_eq!(policy.check("GET", Some("key")), None);
+        // Commands missing from a non-empty allowlist are blocked
+        assert_eq!(policy.check("SET", Some("key")), Some("SET KEY".to_string()));
+    }
+
+    #[test]
+    fn test_check_empty_allowlist() {
+        let policy = new_policy("", "");
+        assert!(policy.is_empty());
+        assert_eq!(policy.check("FLUSHALL", None), None);
+        assert_eq!(policy.check("CONFIG", Some("SET")), None);
+        // An empty allowlist allows everything the denylist does not block
+        let policy = new_policy("", "KEYS");
+        assert!(!policy.is_empty());
+        assert_eq!(policy.check("SCAN", Some("0")), None);
+        assert_eq!(policy.check("KEYS", Some("*")), Some("KEYS *".to_string()));
+    }
+}
@@ -13,19 +13,26 @@
 // limitations under the License.
 
 use super::{
+    acl::{AclPermissions, load_acl_permissions},
     async_connection::{
-        RedisAsyncConn, get_redis_connection_timeout, get_redis_response_timeout, open_single_connection,
-        query_async_masters, query_async_masters_pipeline, remove_connection_from_pool,
+        RedisAsyncConn, get_redis_connection_timeout, get_redis_response_timeout, open_guarded_connection,
+        open_resp3_connection, open_single_connection, query_async_masters, query_async_masters_pipeline,
+        remove_connection_from_pool,
     },
     config::{RedisServer, get_server},
     ssh_cluster_connection::SshMultiplexedConnection,
 };
 use crate::helpers::TtlCache;
 use crate::{connection::async_connection::set_client_name, error::Error};
-use futures::future::try_join_all;
+use dashmap::DashMap;
+use futures::future::{join_all, try_join_all};
+use futures::stream::{self, StreamExt};
 use gpui::SharedString;
 use rand::Rng;
-use redis::{Cmd, FromRedisValue, InfoDict, ParsingError, Role, Value, aio::MultiplexedConnection, cluster, cmd};
+use redis::{
+    Cmd, FromRedisValue, InfoDict, ParsingError, ProtocolVersion, Role, Value, aio::MultiplexedConnection, cluster,
+    cluster_routing, cmd,
+};
 use regex::Regex;
 use semver::Version;
 use serde::{Deserialize, Serialize};
@@ -33,7 +40,7 @@ use std::{
     cmp::Reverse,
     collections::{HashMap, HashSet},
     sync::LazyLock,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tracing::{debug, error, info};
 
@@ -49,6 +56,16 @@ fn is_ignorable_server_error(msg: &str) -> bool {
     IGNORABLE_SERVER_ERROR.is_match(msg)
 }
 
+/// Version of a server whose INFO is denied (e.g. to a restricted ACL user) or can't be parsed.
+///
+/// Version gated features then assume a current server and let it reject what it lacks.
+const UNKNOWN_VERSION: Version = Version::new(0, 0, 0);
+
+/// Keys per pipeline of a per-key command, a page of keys is split into several pipelines
+const PER_KEY_PIPELINE_SIZE: usize = 500;
+/// Keys retried concurrently through the connection when their pipeline failed
+const PER_KEY_RETRY_CONCURRENCY: usize = 100;
+
 // Global singleton for ConnectionManager
 static CONNECTION_MANAGER: LazyLock<ConnectionManager> = LazyLock::new(ConnectionManager::new);
 
@@ -80,6 +97,61 @@ impl From<usize> for ServerType {
     }
 }
 
+/// The server implementation behind the Redis protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ServerFlavor {
+    #[default]
+    Redis,
+    Valkey,
+    KeyDB,
+    Dragonfly,
+}
+
+impl ServerFlavor {
+    /// Detects the flavor from the fields of `INFO server`.
+    ///
+    /// Valkey and Dragonfly report their own version fields, while KeyDB only
+    /// reveals itself through its executable path or KeyDB specific fields.
+    fn detect(info: &InfoDict) -> Self {
+        if info.contains_key("dragonfly_version") {
+            return Self::Dragonfly;
+        }
+        let server_name = info.get::<String>("server_name").unwrap_or_default().to_lowercase();
+        if info.contains_key("valkey_version") || server_name == "valkey" {
+            return Self::Valkey;
+        }
+        let executable = info.get::<String>("executable").unwrap_or_default().to_lowercase();
+        if server_name == "keydb" || executable.contains("keydb") || info.contains_key("server_threads") {
+            return Self::KeyDB;
+        }
+        Self::Redis
+    }
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Redis => "Redis",
+            Self::Valkey => "Valkey",
+            Self::KeyDB => "KeyDB",
+            Self::Dragonfly => "Dragonfly",
+        }
+    }
+    /// Commands (or subcommands) known to be missing on this flavor.
+    pub fn unsupported_commands(&self) -> &'static [&'static str] {
+        match self {
+            // Dragonfly has no LFU counters and no per-key encoding introspection
+            Self::Dragonfly => &["OBJECT FREQ", "OBJECT ENCODING"],
+            _ => &[],
+        }
+    }
+    /// Checks whether the command (e.g. `OBJECT FREQ`) is supported by this flavor.
+    pub fn supports_command(&self, command: &str) -> bool {
+        !self
+            .unsupported_commands()
+            .iter()
+            .any(|item| item.eq_ignore_ascii_case(command))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct KeyMemoryUsage {
     // key name
     pub key: SharedString,
@@ -91,6 +163,25 @@ pub struct KeyMemoryUsage {
     pub ttl: i64,
 }
 
+pub struct KeyFrequency {
+    // key name
+    pub key: SharedString,
+    // LFU access frequency counter (OBJECT FREQ)
+    pub freq: u64,
+    // key type
+    pub key_type: String,
+}
+
+#[derive(Clone)]
+pub struct DumpedKey {
+    // raw key name
+    pub key: Vec<u8>,
+    // remaining ttl in milliseconds, -1 for no expiry
+    pub pttl: i64,
+    // serialized value (DUMP)
+    pub data: Vec<u8>,
+}
+
 // Wrapper for the underlying Redis client
 #[derive(Clone)]
 enum RClient {
@@ -116,6 +207,8 @@ struct RedisNode {
     // connection_url: String,
     role: NodeRole,
     master_name: Option<String>,
+    /// Hash slot ranges served by the node (cluster masters only)
+    slots: Vec<(u16, u16)>,
 }
 
 impl RedisNode {
@@ -130,6 +223,68 @@ pub struct ClusterNodeInfo {
     pub ip: String,
     pub port: u16,
     pub role: NodeRole,
+    /// Hash slot ranges, e.g. `0-5460`; importing/migrating entries are skipped
+    pub slots: Vec<(u16, u16)>,
+}
+
+/// Number of hash slots of a Redis cluster
+pub const CLUSTER_SLOTS: u64 = 16384;
+
+/// Health of a cluster reported by `CLUSTER INFO`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClusterHealth {
+    /// `ok` or `fail`
+    pub state: SharedString,
+    /// Slots assigned to a node
+    pub slots_assigned: u64,
+    /// Slots whose node is considered failing by the majority
+    pub slots_fail: u64,
+    /// Slots whose node is possibly failing
+    pub slots_pfail: u64,
+    pub known_nodes: u64,
+}
+
+impl ClusterHealth {
+    /// Parses the `key:value` lines of `CLUSTER INFO`.
+    fn parse(raw: &str) -> Self {
+        let mut health = ClusterHealth::default();
+        for (key, value) in raw.lines().filter_map(|line| line.trim().split_once(':')) {
+            match key {
+                "cluster_state" => health.state = value.to_string().into(),
+                "cluster_slots_assigned" => health.slots_assigned = value.parse().unwrap_or(0),
+                "cluster_slots_fail" => health.slots_fail = value.parse().unwrap_or(0),
+                "cluster_slots_pfail" => health.slots_pfail = value.parse().unwrap_or(0),
+                "cluster_known_nodes" => health.known_nodes = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+        health
+    }
+    /// Whether every slot is served
+    pub fn is_ok(&self) -> bool {
+        self.state == "ok" && self.unassigned_slots() == 0 && self.slots_fail == 0
+    }
+    pub fn unassigned_slots(&self) -> u64 {
+        CLUSTER_SLOTS.saturating_sub(self.slots_assigned)
+    }
+}
+
+/// Round trip of a `PING` to a single node
+#[derive(Debug, Clone)]
+pub struct NodeLatency {
+    pub addr: SharedString,
+    pub is_master: bool,
+    /// `None` if the node did not answer
+    pub latency_ms: Option<u64>,
+}
+
+/// Reads a string reply (e.g. of TYPE), empty if it isn't a string.
+fn type_name(value: &Value) -> SharedString {
+    match value {
+        Value::SimpleString(s) => SharedString::from(s.clone()),
+        Value::BulkString(d) => SharedString::from(String::from_utf8_lossy(d).into_owned()),
+        _ => SharedString::default(),
+    }
 }
 
 /// Parses a Redis address string like "ip:port@cport" or just "ip:port".
@@ -188,12 +343,56 @@ fn parse_cluster_nodes(raw_data: &str) -> Result<Vec<ClusterNodeInfo>> {
             NodeRole::Unknown
         };
 
-        nodes.push(ClusterNodeInfo { ip, port, role });
+        let slots = parts[8..]
+            .iter()
+            .filter(|item| !item.starts_with('['))
+            .filter_map(|item| match item.split_once('-') {
+                Some((start, end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+                None => item.parse().ok().map(|slot| (slot, slot)),
+            })
+            .collect();
+
+        nodes.push(ClusterNodeInfo { ip, port, role, slots });
     }
 
     Ok(nodes)
 }
 
+/// Builds the client for the discovered nodes, cluster clients speak the given protocol.
+fn build_rclient(
+    server_id: &str,
+    nodes: &[RedisNode],
+    server_type: &ServerType,
+    protocol: ProtocolVersion,
+) -> Result<RClient> {
+    let Some(first_node) = nodes.first() else {
+        return Err(Error::Invalid {
+            message: "no nodes found".to_string(),
+        });
+    };
+    let client = match server_type {
+        ServerType::Cluster => {
+            let addrs: Vec<String> = nodes.iter().map(|n| n.server.get_connection_url()).collect();
+            let mut builder = cluster::ClusterClientBuilder::new(addrs).use_protocol(protocol);
+            if let Some(certificates) = first_node.server.tls_certificates() {
+                builder = builder.certs(certificates);
+            }
+            if first_node.server.insecure.unwrap_or(false) {
+                builder = builder.danger_accept_invalid_hostnames(true);
+            }
+            if first_node.server.is_ssh_tunnel() {
+                builder = builder.username(server_id);
+
+                RClient::SshCluster(builder.build()?)
+            } else {
+                RClient::Cluster(builder.build()?)
+            }
+        }
+        _ => RClient::Single(first_node.server.clone()),
+    };
+    Ok(client)
+}
+
 /// Establishes an asynchronous connection based on the client type.
 async fn get_async_connection(client: &RClient, db: usize, use_cache: bool) -> Result<RedisAsyncConn> {
     match client {
@@ -303,17 +502,18 @@ impl FromRedisValue for SlowLogEntry {
 #[derive(Clone)]
 pub struct RedisClient {
     access_mode: AccessMode,
+    acl_permissions: AclPermissions,
     db: usize,
     server_type: ServerType,
     nodes: Vec<RedisNode>,
     master_nodes: Vec<RedisNode>,
     version: Version,
-    is_valkey: bool,
+    flavor: ServerFlavor,
     connection: RedisAsyncConn,
 }
 #[derive(Debug, Clone, Default)]
 pub struct RedisClientDescription {
-    pub is_valkey: bool,
+    pub flavor: ServerFlavor,
     pub server_type: SharedString,
     pub master_nodes: SharedString,
     pub slave_nodes: SharedString,
@@ -325,12 +525,18 @@ impl RedisClient {
     pub fn version(&self) -> String {
         self.version.to_string()
     }
+    pub fn flavor(&self) -> ServerFlavor {
+        self.flavor
+    }
     pub fn supports_db_selection(&self) -> bool {
         self.server_type != ServerType::Cluster
     }
     pub fn access_mode(&self) -> AccessMode {
         self.access_mode
     }
+    pub fn acl_permissions(&self) -> AclPermissions {
+        self.acl_permissions.clone()
+    }
 
     /// Returns the list of master node server configurations.
     pub fn master_servers(&self) -> Vec<RedisServer> {
@@ -346,7 +552,7 @@ impl RedisClient {
             .map(|node| node.host_port().clone())
             .collect();
         RedisClientDescription {
-            is_valkey: self.is_valkey,
+            flavor: self.flavor,
             server_type: format!("{:?}", self.server_type).into(),
             master_nodes: master_nodes.join(",").into(),
             slave_nodes: slave_nodes.join(",").into(),
@@ -371,7 +577,7 @@ impl RedisClient {
     /// # Returns
     /// * `bool` - True if the client version is at least the given version, false otherwise.
     pub fn is_at_least_version(&self, version: &str) -> bool {
-        self.version >= Version::parse(version).unwrap_or(Version::new(0, 0, 0))
+        self.version == UNKNOWN_VERSION || self.version >= Version::parse(version).unwrap_or(UNKNOWN_VERSION)
     }
 
     /// Unlinks keys on all master nodes concurrently.
@@ -580,6 +786,49 @@ impl RedisClient {
         let (_, list): (_, Vec<u64>) = self.query_async_masters(vec![cmd("DBSIZE")]).await?;
         Ok(list.iter().sum())
     }
+    /// Reads `CLUSTER INFO` from the first master that answers, the others may be
+    /// the ones that are down.
+    pub async fn cluster_health(&self) -> Result<ClusterHealth> {
+        let mut last_error = None;
+        for server in self.master_servers() {
+            let result: Result<String> = async {
+                let mut conn = open_guarded_connection(&server, 0, true).await?;
+                let raw: String = cmd("CLUSTER").arg("INFO").query_async(&mut conn).await?;
+                Ok(raw)
+            }
+            .await;
+            match result {
+                Ok(raw) => return Ok(ClusterHealth::parse(&raw)),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| Error::Invalid {
+            message: "No master node".to_string(),
+        }))
+    }
+    /// Pings every master and replica on its own connection, so a single slow node
+    /// is not hidden by the aggregate latency.
+    pub async fn ping_nodes(&self) -> Vec<NodeLatency> {
+        let nodes = self
+            .nodes
+            .iter()
+            .filter(|node| matches!(node.role, NodeRole::Master | NodeRole::Slave));
+        join_all(nodes.map(|node| async move {
+            let start = Instant::now();
+            let result: Result<()> = async {
+                let mut conn = open_guarded_connection(&node.server, 0, true).await?;
+                let _: () = cmd("PING").query_async(&mut conn).await?;
+                Ok(())
+            }
+            .await;
+            NodeLatency {
+                addr: node.host_port().into(),
+                is_master: node.role == NodeRole::Master,
+                latency_ms: result.ok().map(|_| start.elapsed().as_millis() as u64),
+            }
+        }))
+        .await
+    }
     /// Pings the server to check connectivity.
     pub async fn ping(&self) -> Result<()> {
         let mut conn = self.connection.clone();
@@ -616,6 +865,13 @@ impl RedisClient {
                 keys.retain(|_| rng.random::<f32>() < ratio);
             }
         }
+        let keys_memory_usage = self.keys_memory_usage(keys_per_node).await?;
+
+        Ok((total_count as u64, cursors, keys_memory_usage))
+    }
+    /// Reads the type, memory usage and TTL of keys grouped by master node,
+    /// keys removed in the meantime are skipped.
+    async fn keys_memory_usage(&self, keys_per_node: Vec<Vec<SharedString>>) -> Result<Vec<KeyMemoryUsage>> {
         let capacity = keys_per_node.iter().map(|keys| keys.len()).sum();
         let master_addrs: Vec<_> = self.master_nodes.iter().map(|item| item.server.clone()).collect();
         let mut pipes: Vec<Option<redis::Pipeline>> = vec![None; master_addrs.len()];
@@ -679,7 +935,261 @@ impl RedisClient {
             }
         }
 
-        Ok((total_count as u64, cursors, keys_memory_usage))
+        Ok(keys_memory_usage)
+    }
+    /// Picks random keys with RANDOMKEY, spread over the master nodes, with their type, size and TTL.
+    ///
+    /// Keys picked twice are only returned once, so small databases may return fewer keys.
+    /// # Arguments
+    /// * `count` - The number of keys to sample.
+    pub async fn sample_random_keys(&self, count: usize) -> Result<Vec<KeyMemoryUsage>> {
+        let master_addrs: Vec<_> = self.master_nodes.iter().map(|item| item.server.clone()).collect();
+        let count_per_node = count.div_ceil(master_addrs.len().max(1));
+        let mut pipe = redis::pipe();
+        for _ in 0..count_per_node {
+            pipe.cmd("RANDOMKEY");
+        }
+        let pipes = vec![Some(pipe); master_addrs.len()];
+        let results_per_node = query_async_masters_pipeline(master_addrs, self.db, pipes).await?;
+        let keys_per_node: Vec<Vec<SharedString>> = results_per_node
+            .into_iter()
+            .map(|results| {
+                let mut seen = HashSet::new();
+                results
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        Value::BulkString(bytes) => {
+                            Some(SharedString::from(String::from_utf8_lossy(&bytes).to_string()))
+                        }
+                        _ => None,
+                    })
+                    .filter(|key| seen.insert(key.clone()))
+                    .collect()
+            })
+            .collect();
+        let mut keys_memory_usage = self.keys_memory_usage(keys_per_node).await?;
+        keys_memory_usage.truncate(count);
+        Ok(keys_memory_usage)
+    }
+    /// Samples keys via SCAN and reads their LFU access frequency (OBJECT FREQ).
+    ///
+    /// OBJECT FREQ is only available when `maxmemory-policy` is an LFU policy.
+    /// # Arguments
+    /// * `ratio` - The ratio of keys to sample.
+    /// * `count` - The count of keys to sample.
+    /// * `cursors` - The cursors to continue the scan from.
+    /// # Returns
+    /// * `(u64, Vec<u64>, Vec<KeyFrequency>)` - The scanned key count, the new cursors and the key frequencies.
+    pub async fn sample_scan_object_freq(
+        &self,
+        ratio: f32,
+        count: u64,
+        cursors: Option<Vec<u64>>,
+    ) -> Result<(u64, Vec<u64>, Vec<KeyFrequency>)> {
+        let (cursors, mut keys_per_node) = self.scan_nodes(cursors, "*", count).await?;
+
+        let total_count: usize = keys_per_node.iter().map(|keys| keys.len()).sum();
+
+        if ratio < 1.0 {
+            let mut rng = rand::rng();
+            for keys in keys_per_node.iter_mut() {
+                keys.retain(|_| rng.random::<f32>() < ratio);
+            }
+        }
+        let capacity = keys_per_node.iter().map(|keys| keys.len()).sum();
+        let master_addrs: Vec<_> = self.master_nodes.iter().map(|item| item.server.clone()).collect();
+        let mut pipes: Vec<Option<redis::Pipeline>> = vec![None; master_addrs.len()];
+        for (index, keys) in keys_per_node.iter().enumerate() {
+            if keys.is_empty() {
+                continue;
+            }
+            let mut pipe = redis::pipe();
+            // A deleted key or a non-LFU policy fails only its own command, not the whole sample
+            pipe.ignore_errors();
+            for key in keys {
+                pipe.cmd("TYPE")
+                    .arg(key.as_str())
+                    .cmd("OBJECT")
+                    .arg("FREQ")
+                    .arg(key.as_str());
+            }
+            pipes[index] = Some(pipe);
+        }
+
+        let results_per_node = query_async_masters_pipeline(master_addrs, self.db, pipes).await?;
+
+        let mut keys_frequency = Vec::with_capacity(capacity);
+        for (index, results) in results_per_node.into_iter().enumerate() {
+            let Some(results) = results else {
+                continue;
+            };
+            let keys = &keys_per_node[index];
+            for (i, chunk) in results.chunks_exact(2).enumerate() {
+                if i >= keys.len() {
+                    break;
+                }
+                let key_type = match &chunk[0] {
+                    Value::SimpleString(s) => s.clone(),
+                    Value::BulkString(d) => String::from_utf8_lossy(d).to_string(),
+                    _ => "unknown".to_string(),
+                };
+                // Keys deleted between SCAN and OBJECT FREQ return an error, skip them
+                let Value::Int(freq) = &chunk[1] else {
+                    continue;
+                };
+                keys_frequency.push(KeyFrequency {
+                    key: keys[i].clone(),
+                    freq: *freq as u64,
+                    key_type,
+                });
+            }
+        }
+
+        Ok((total_count as u64, cursors, keys_frequency))
+    }
+    /// Scans keys matching the pattern and fetches their TTLs with a pipeline per node.
+    /// # Returns
+    /// * `(u64, Vec<u64>, Vec<i64>)` - The scanned key count, the new cursors and the TTLs in seconds
+    ///   (`-1` for keys without expiry, keys deleted in between are skipped).
+    pub async fn sample_scan_ttl(
+        &self,
+        pattern: &str,
+        count: u64,
+        cursors: Option<Vec<u64>>,
+    ) -> Result<(u64, Vec<u64>, Vec<i64>)> {
+        let (cursors, keys_per_node) = self.scan_nodes(cursors, pattern, count).await?;
+        let total_count: usize = keys_per_node.iter().map(|keys| keys.len()).sum();
+        let master_addrs: Vec<_> = self.master_nodes.iter().map(|item| item.server.clone()).collect();
+        let mut pipes: Vec<Option<redis::Pipeline>> = vec![None; master_addrs.len()];
+        for (index, keys) in keys_per_node.iter().enumerate() {
+            if keys.is_empty() {
+                continue;
+            }
+            let mut pipe = redis::pipe();
+            for key in keys {
+                pipe.cmd("TTL").arg(key.as_str());
+            }
+            pipes[index] = Some(pipe);
+        }
+
+        let results_per_node = query_async_masters_pipeline(master_addrs, self.db, pipes).await?;
+        let ttls = results_per_node
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|value| match value {
+                Value::Int(ttl) if ttl != -2 => Some(ttl),
+                _ => None,
+            })
+            .collect();
+
+        Ok((total_count as u64, cursors, ttls))
+    }
+    /// Scans keys across all masters and serializes each with `PTTL` and `DUMP`.
+    ///
+    /// Keys are kept as raw bytes so binary names survive a backup.
+    /// # Arguments
+    /// * `count` - The count of keys to scan per node.
+    /// * `cursors` - The cursors to continue the scan from.
+    /// # Returns
+    /// * `(Vec<u64>, Vec<DumpedKey>)` - The new cursors and the dumped keys (deleted keys are skipped).
+    pub async fn dump_scan(&self, count: u64, cursors: Option<Vec<u64>>) -> Result<(Vec<u64>, Vec<DumpedKey>)> {
+        let first_scan = cursors.is_none();
+        let cursors = match cursors {
+            Some(cursors) => cursors,
+            None => vec![0; self.count_masters()?],
+        };
+        let cmds: Vec<Option<Cmd>> = cursors
+            .iter()
+            .map(|&cursor| {
+                (first_scan || cursor != 0).then(|| cmd("SCAN").cursor_arg(cursor).arg("COUNT").arg(count).clone())
+            })
+            .collect();
+        let values: Vec<Option<(u64, Vec<Vec<u8>>)>> = self.query_async_masters_with_option(cmds).await?;
+
+        let mut next_cursors = cursors;
+        let mut keys_per_node: Vec<Vec<Vec<u8>>> = vec![vec![]; next_cursors.len()];
+        for (index, result) in values.into_iter().enumerate() {
+            if let Some((cursor, keys)) = result {
+                next_cursors[index] = cursor;
+                keys_per_node[index] = keys;
+            }
+        }
+
+        let master_addrs: Vec<_> = self.master_nodes.iter().map(|item| item.server.clone()).collect();
+        let mut pipes: Vec<Option<redis::Pipeline>> = vec![None; master_addrs.len()];
+        for (index, keys) in keys_per_node.iter().enumerate() {
+            if keys.is_empty() {
+                continue;
+            }
+            let mut pipe = redis::pipe();
+            // A key deleted or not serializable fails only its own DUMP
+            pipe.ignore_errors();
+            for key in keys {
+                pipe.cmd("PTTL").arg(key).cmd("DUMP").arg(key);
+            }
+            pipes[index] = Some(pipe);
+        }
+        let results_per_node = query_async_masters_pipeline(master_addrs, self.db, pipes).await?;
+
+        let mut dumps = Vec::new();
+        for (keys, results) in keys_per_node.into_iter().zip(results_per_node) {
+            let Some(results) = results else {
+                continue;
+            };
+            for (key, pair) in keys.into_iter().zip(results.chunks(2)) {
+                let [Value::Int(pttl), Value::BulkString(data)] = pair else {
+                    // Deleted between SCAN and DUMP
+                    continue;
+                };
+                dumps.push(DumpedKey {
+                    key,
+                    pttl: *pttl,
+                    data: data.clone(),
+                });
+            }
+        }
+        Ok((next_cursors, dumps))
+    }
+    /// Restores dumped keys with `RESTORE`, keeping their remaining TTL.
+    ///
+    /// Existing keys are left untouched, a key that fails to restore doesn't stop the others.
+    /// # Returns
+    /// * `(usize, usize, Vec<String>)` - The count of restored keys, of skipped existing keys,
+    ///   and the failed keys with their error.
+    pub async fn restore_dumps(&self, dumps: &[DumpedKey]) -> Result<(usize, usize, Vec<String>)> {
+        let conn = self.connection();
+        let mut restored = 0;
+        let mut skipped = 0;
+        let mut failed = vec![];
+        for chunk in dumps.chunks(1000) {
+            let futures = chunk.iter().map(|dump| {
+                let mut conn_clone = conn.clone();
+                async move {
+                    let ttl = dump.pttl.max(0);
+                    let result: redis::RedisResult<()> = cmd("RESTORE")
+                        .arg(&dump.key)
+                        .arg(ttl)
+                        .arg(&dump.data)
+                        .query_async(&mut conn_clone)
+                        .await;
+                    match result {
+                        Ok(()) => Ok(true),
+                        Err(e) if e.code() == Some("BUSYKEY") => Ok(false),
+                        Err(e) => Err(format!("{}: {e}", String::from_utf8_lossy(&dump.key))),
+                    }
+                }
+            });
+            for result in join_all(futures).await {
+                match result {
+                    Ok(true) => restored += 1,
+                    Ok(false) => skipped += 1,
+                    Err(e) => failed.push(e),
+                }
+            }
+        }
+        Ok((restored, skipped, failed))
     }
     /// Initiates a SCAN operation across all masters.
     /// # Arguments
@@ -784,23 +1294,117 @@ impl RedisClient {
         for (idx, keys) in keys_per_node.into_iter().enumerate() {
             let types = type_results.get(idx).and_then(|r| r.as_ref());
             for (i, key) in keys.into_iter().enumerate() {
-                let key_type = types
-                    .and_then(|vals| vals.get(i))
-                    .map(|val| match val {
-                        Value::SimpleString(s) => SharedString::from(s.clone()),
-                        Value::BulkString(d) => SharedString::from(String::from_utf8_lossy(d).into_owned()),
-                        _ => SharedString::default(),
-                    })
-                    .unwrap_or_default();
+                let key_type = types.and_then(|vals| vals.get(i)).map(type_name).unwrap_or_default();
                 all_keys.push((key, key_type));
             }
         }
         Ok((new_cursors, all_keys))
     }
+    /// Returns the type of each key (e.g. `string`, empty if it couldn't be read).
+    pub async fn fetch_types(&self, keys: &[SharedString]) -> Result<Vec<SharedString>> {
+        self.query_per_key(&["TYPE"], keys).await
+    }
+    /// Returns the `OBJECT ENCODING` of each key (e.g. `listpack`, empty if it couldn't be read).
+    pub async fn fetch_encodings(&self, keys: &[SharedString]) -> Result<Vec<SharedString>> {
+        self.query_per_key(&["OBJECT", "ENCODING"], keys).await
+    }
+    /// Runs `command key` for each key and returns the string replies.
+    ///
+    /// The commands are batched into pipelines of up to `PER_KEY_PIPELINE_SIZE` keys per
+    /// master node instead of one round trip per key. In a cluster, keys are grouped by the
+    /// slot ranges discovered on connect. Keys of unknown slots, and keys whose command failed
+    /// (e.g. MOVED after a resharding), are retried concurrently through the cluster connection.
+    async fn query_per_key(&self, command: &[&str], keys: &[SharedString]) -> Result<Vec<SharedString>> {
+        let mut replies = vec![SharedString::default(); keys.len()];
+        if keys.is_empty() || self.master_nodes.is_empty() {
+            return Ok(replies);
+        }
+        let new_cmd = |key: &str| {
+            let mut command_with_key = cmd(command[0]);
+            command_with_key.arg(&command[1..]).arg(key);
+            command_with_key
+        };
+        let mut indexes_per_node: Vec<Vec<usize>> = vec![vec![]; self.master_nodes.len()];
+        let mut unrouted = vec![];
+        if self.is_cluster() {
+            for (index, key) in keys.iter().enumerate() {
+                let slot = cluster_routing::get_slot(key.as_bytes());
+                let node = self
+                    .master_nodes
+                    .iter()
+                    .position(|node| node.slots.iter().any(|(start, end)| (*start..=*end).contains(&slot)));
+                match node {
+                    Some(node) => indexes_per_node[node].push(index),
+                    None => unrouted.push(index),
+                }
+            }
+        } else {
+            indexes_per_node[0] = (0..keys.len()).collect();
+        }
+
+        // One pipeline per chunk of the keys of a node
+        let batches: Vec<(usize, &[usize])> = indexes_per_node
+            .iter()
+            .enumerate()
+            .flat_map(|(node, indexes)| indexes.chunks(PER_KEY_PIPELINE_SIZE).map(move |chunk| (node, chunk)))
+            .collect();
+        let master_addrs: Vec<_> = batches
+            .iter()
+            .map(|(node, _)| self.master_nodes[*node].server.clone())
+            .collect();
+        let pipes: Vec<Option<redis::Pipeline>> = batches
+            .iter()
+            .map(|(_, indexes)| {
+                let mut pipe = redis::pipe();
+                pipe.ignore_errors();
+                for index in indexes.iter() {
+                    pipe.add_command(new_cmd(keys[*index].as_str()));
+                }
+                Some(pipe)
+            })
+            .collect();
+        match query_async_masters_pipeline(master_addrs, self.db, pipes).await {
+            Ok(results) => {
+                for ((_, indexes), values) in batches.iter().zip(results) {
+                    let values = values.unwrap_or_default();
+                    for (i, index) in indexes.iter().enumerate() {
+                        match values.get(i) {
+                            Some(Value::ServerError(_)) | None => unrouted.push(*index),
+                            Some(value) => replies[*index] = type_name(value),
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                error!(error = %e, "query per key by node failed, retry through the connection");
+                unrouted.extend(indexes_per_node.iter().flatten());
+            }
+        }
+
+        let retried: Vec<(usize, Option<String>)> = stream::iter(unrouted)
+            .map(|index| {
+                let mut conn = self.connection.clone();
+                let command = new_cmd(keys[index].as_str());
+                async move {
+                    let reply: Option<String> = command.query_async(&mut conn).await.unwrap_or_default();
+                    (index, reply)
+                }
+            })
+            .buffer_unordered(PER_KEY_RETRY_CONCURRENCY)
+            .collect()
+            .await;
+        for (index, reply) in retried {
+            replies[index] = reply.unwrap_or_default().into();
+        }
+        Ok(replies)
+    }
 }
 
 pub struct ConnectionManager {
     clients: TtlCache<u64, RedisClient>,
+    resp3_connections: TtlCache<u64, RedisAsyncConn>,
+    /// Databases a client was created for, by server id, to drop them all when the server is edited
+    client_dbs: DashMap<String, HashSet<usize>>,
 }
 
 /// Detects the type of Redis server (Sentinel, Cluster, or Standalone).
@@ -883,6 +1487,8 @@ impl ConnectionManager {
     pub fn new() -> Self {
         Self {
             clients: TtlCache::new(Duration::from_secs(5 * 60)),
+            resp3_connections: TtlCache::new(Duration::from_secs(5 * 60)),
+            client_dbs: DashMap::new(),
         }
     }
     /// Discovers Redis nodes and server type based on initial configuration.
@@ -944,6 +1550,7 @@ impl ConnectionManager {
                         RedisNode {
                             server: tmp_config,
                             role: item.role.clone(),
+                            slots: item.slots.clone(),
                             ..Default::default()
                         }
                     })
@@ -1017,8 +1624,21 @@ impl ConnectionManager {
         };
         let key = config.get_hash(db);
         self.clients.remove(&key);
+        self.resp3_connections.remove(&key);
         remove_connection_from_pool(&config, db);
     }
+    /// Drops the cached clients and pooled connections of a server config,
+    /// e.g. when it is edited, so the next command connects with the new settings.
+    pub fn remove_server_clients(&self, config: &RedisServer) {
+        let Some((_, dbs)) = self.client_dbs.remove(&config.id) else {
+            return;
+        };
+        for db in dbs {
+            self.clients.remove(&config.get_hash(db));
+            self.resp3_connections.remove(&config.get_hash(db));
+            remove_connection_from_pool(config, db);
+        }
+    }
     pub async fn get_pubsub_connection(&self, server_id: &str) -> Result<redis::aio::PubSub> {
         let config = get_server(server_id)?;
         let url = config.get_connection_url();
@@ -1030,36 +1650,33 @@ impl ConnectionManager {
         let pubsub = client.get_async_pubsub().await?;
         Ok(pubsub)
     }
+    /// Returns the RESP3 connection used for protocol-level debugging.
+    ///
+    /// It is built from the resolved nodes of the shared client, so SSH tunnels, sentinel, cluster,
+    /// TLS and timeouts all apply, while the shared connection stays on RESP2 for typed replies.
+    pub async fn get_resp3_connection(&self, server_id: &str, db: usize) -> Result<RedisAsyncConn> {
+        let config = get_server(server_id)?;
+        let key = config.get_hash(db);
+        if let Some(conn) = self.resp3_connections.get(&key) {
+            debug!(server_id, db, "get resp3 connection from cache");
+            return Ok(conn);
+        }
+        let client = self.get_client(server_id, db).await?;
+        let conn = match build_rclient(server_id, &client.nodes, &client.server_type, ProtocolVersion::RESP3)? {
+            RClient::Single(server) => open_resp3_connection(&server, db).await?,
+            rclient => get_async_connection(&rclient, db, false)
+                .await?
+                .with_command_policy(config.command_policy()),
+        };
+        self.resp3_connections.insert(key, conn.clone());
+        Ok(conn)
+    }
     /// Retrieves or creates a RedisClient for the given configuration name without caching.
     pub async fn get_client_without_cache(&self, server_id: &str, db: usize) -> Result<RedisClient> {
         let config = get_server(server_id)?;
         let (nodes, server_type) = self.get_redis_nodes(server_id).await?;
         debug!(server_id, server_type = ?server_type, nodes = ?nodes, "get redis nodes");
-        let Some(first_node) = nodes.first() else {
-            return Err(Error::Invalid {
-                message: "no nodes found".to_string(),
-            });
-        };
-        let client = match server_type {
-            ServerType::Cluster => {
-                let addrs: Vec<String> = nodes.iter().map(|n| n.server.get_connection_url()).collect();
-                let mut builder = cluster::ClusterClientBuilder::new(addrs);
-                if let Some(certificates) = first_node.server.tls_certificates() {
-                    builder = builder.certs(certificates);
-                }
-                if first_node.server.insecure.unwrap_or(false) {
-                    builder = builder.danger_accept_invalid_hostnames(true);
-                }
-                if first_node.server.is_ssh_tunnel() {
-                    builder = builder.username(server_id);
-
-                    RClient::SshCluster(builder.build()?)
-                } else {
-                    RClient::Cluster(builder.build()?)
-                }
-            }
-            _ => RClient::Single(first_node.server.clone()),
-        };
+        let client = build_rclient(server_id, &nodes, &server_type, ProtocolVersion::RESP2)?;
         let master_nodes: Vec<RedisNode> = nodes
             .iter()
             .filter(|node| node.role == NodeRole::Master)
@@ -1075,53 +1692,75 @@ impl ConnectionManager {
         } else {
             AccessMode::ReadWrite
         };
+        let acl_permissions = load_acl_permissions(connection.clone(), &config.command_policy()).await;
         let mut client = RedisClient {
             db,
             access_mode,
+            acl_permissions,
             server_type: server_type.clone(),
             nodes,
             master_nodes,
-            version: Version::new(0, 0, 0),
-            is_valkey: false,
+            version: UNKNOWN_VERSION,
+            flavor: ServerFlavor::Redis,
             connection,
         };
         let mut conn = client.connection.clone();
-        let get_version = |info: InfoDict| -> (bool, Option<Version>) {
-            if let Some(v) = info.get::<String>("valkey_version") {
-                return (true, Version::parse(&v).ok());
+        let get_version = |info: InfoDict| -> (ServerFlavor, Option<Version>) {
+            let flavor = ServerFlavor::detect(&info);
+            if flavor == ServerFlavor::Valkey
+                && let Some(v) = info.get::<String>("valkey_version")
+            {
+                return (flavor, Version::parse(&v).ok());
             }
+            // Dragonfly reports the Redis version it is compatible with in redis_version
             if let Some(v) = info.get::<String>("redis_version") {
-                return (false, Version::parse(&v).ok());
+                return (flavor, Version::parse(&v).ok());
             }
-            (false, None)
+            (flavor, None)
         };
 
-        (client.is_valkey, client.version) = match server_type {
+        (client.flavor, client.version) = match server_type {
             ServerType::Cluster => {
-                let info: redis::Value = cmd("INFO").arg("server").query_async(&mut conn).await?;
+                let info: redis::Value = match cmd("INFO").arg("server").query_async(&mut conn).await {
+                    Ok(info) => info,
+                    Err(e) if is_ignorable_server_error(&e.to_string()) => {
+                        error!("get server version failed: {e:?}, assume the latest version");
+                        redis::Value::Nil
+                    }
+                    Err(e) => return Err(e.into()),
+                };
                 let mut version = None;
-                let mut is_valkey = false;
+                let mut flavor = ServerFlavor::Redis;
                 if let redis::Value::Map(items) = info {
                     for (_, node_info_val) in items {
                         if let Ok(info) = InfoDict::from_redis_value(node_info_val)
-                            && let (valkey, Some(v)) = get_version(info)
+                            && let (node_flavor, Some(v)) = get_version(info)
                         {
                             version = Some(v);
-                            is_valkey = valkey;
+                            flavor = node_flavor;
                             break;
                         }
                     }
                 }
-                (is_valkey, version.unwrap_or(Version::new(0, 0, 0)))
-            }
-            _ => {
-                let info: InfoDict = cmd("INFO").arg("server").query_async(&mut conn).await?;
-                let (is_valkey, version) = get_version(info);
-                (is_valkey, version.unwrap_or(Version::new(0, 0, 0)))
+                (flavor, version.unwrap_or(UNKNOWN_VERSION))
             }
+            _ => match cmd("INFO").arg("server").query_async::<InfoDict>(&mut conn).await {
+                Ok(info) => {
+                    let (flavor, version) = get_version(info);
+                    (flavor, version.unwrap_or(UNKNOWN_VERSION))
+                }
+                Err(e) if is_ignorable_server_error(&e.to_string()) => {
+                    error!("get server version failed: {e:?}, assume the latest version");
+                    (ServerFlavor::Redis, UNKNOWN_VERSION)
+                }
+                Err(e) => return Err(e.into()),
+            },
         };
 
-        debug!(server_id, version = client.version(), db, access_mode = ?client.access_mode(), "create redis client success");
+        // Setup commands above bypass the guard, everything issued afterwards is checked
+        client.connection = client.connection.with_command_policy(config.command_policy());
+
+        debug!(server_id, version = client.version(), flavor = client.flavor.as_str(), db, access_mode = ?client.access_mode(), "create redis client success");
         Ok(client)
     }
     /// Retrieves or creates a RedisClient for the given configuration name.
@@ -1135,6 +1774,7 @@ impl ConnectionManager {
         let client = self.get_client_without_cache(server_id, db).await?;
         // Cache the client
         self.clients.insert(key, client.clone());
+        self.client_dbs.entry(server_id.to_string()).or_default().insert(db);
         Ok(client)
     }
     /// Shorthand to get an async connection directly.
@@ -1151,5 +1791,12 @@ pub fn get_connection_manager() -> &'static ConnectionManager {
 
 /// Clears expired clients from the connection manager.
 pub fn clear_expired_clients() -> (usize, usize) {
+    CONNECTION_MANAGER.resp3_connections.clear_expired();
     CONNECTION_MANAGER.clients.clear_expired()
 }
+
+/// Drops all clients of the connection manager.
+pub fn clear_clients() -> usize {
+    CONNECTION_MANAGER.resp3_connections.clear();
+    CONNECTION_MANAGER.clients.clear()
+}
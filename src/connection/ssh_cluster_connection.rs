@@ -66,13 +66,16 @@ impl Connect for SshMultiplexedConnection {
             if tls {
                 config.tls = Some(true);
             }
-            let connection = open_single_ssh_tunnel_connection(&config).await.map_err(|e| {
-                (
-                    ErrorKind::InvalidClientConfig,
-                    "open_single_ssh_tunnel_connection",
-                    e.to_string(),
-                )
-            })?;
+            let protocol = connection_info.redis_settings().protocol();
+            let connection = open_single_ssh_tunnel_connection(&config, protocol)
+                .await
+                .map_err(|e| {
+                    (
+                        ErrorKind::InvalidClientConfig,
+                        "open_single_ssh_tunnel_connection",
+                        e.to_string(),
+                    )
+                })?;
 
             Ok(SshMultiplexedConnection { inner: connection })
         })
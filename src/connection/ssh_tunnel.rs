@@ -17,7 +17,7 @@ use super::config::RedisServer;
 use super::ssh_stream::SshRedisStream;
 use crate::error::Error;
 use crate::helpers::{TtlCache, get_home_dir, resolve_path};
-use redis::{RedisConnectionInfo, aio::MultiplexedConnection, cmd};
+use redis::{ProtocolVersion, RedisConnectionInfo, aio::MultiplexedConnection, cmd};
 use russh::client::AuthResult;
 use russh::client::{Handle, Handler};
 use russh::keys::agent::client::AgentClient;
@@ -448,11 +448,15 @@ fn build_tls_connector(config: &RedisServer) -> Result<TlsConnector> {
 /// # Arguments
 ///
 /// * `config` - Redis server configuration containing SSH and Redis connection details
+/// * `protocol` - Protocol the connection speaks, RESP3 sends `HELLO 3` with the credentials on connect
 ///
 /// # Returns
 ///
 /// A multiplexed Redis connection ready for use
-pub async fn open_single_ssh_tunnel_connection(config: &RedisServer) -> Result<MultiplexedConnection> {
+pub async fn open_single_ssh_tunnel_connection(
+    config: &RedisServer,
+    protocol: ProtocolVersion,
+) -> Result<MultiplexedConnection> {
     let ssh_addr = config.ssh_addr.clone().unwrap_or_default();
     let ssh_user = config.ssh_username.clone().unwrap_or_default();
     let ssh_key = config.ssh_key.clone().unwrap_or_default();
@@ -474,7 +478,15 @@ pub async fn open_single_ssh_tunnel_connection(config: &RedisServer) -> Result<M
             .await?;
         debug!(ssh_addr, ssh_user, host, port, "open direct tcpip success");
         let ssh_stream = SshRedisStream::new(channel.into_stream());
-        let info = RedisConnectionInfo::default();
+        let mut info = RedisConnectionInfo::default().set_protocol(protocol);
+        // HELLO 3 is refused before authentication, so RESP3 authenticates with it
+        let resp3 = protocol == ProtocolVersion::RESP3;
+        if resp3 && let Some(password) = &password {
+            info = info.set_password(password.clone());
+            if let Some(user) = &username {
+                info = info.set_username(user.clone());
+            }
+        }
         let conn_config = redis::AsyncConnectionConfig::new()
             .set_connection_timeout(Some(get_redis_connection_timeout()))
             .set_response_timeout(Some(get_redis_response_timeout()));
@@ -503,7 +515,7 @@ pub async fn open_single_ssh_tunnel_connection(config: &RedisServer) -> Result<M
             });
             conn
         };
-        if let Some(password) = password {
+        if !resp3 && let Some(password) = password {
             let mut auth_cmd = cmd("AUTH");
             if let Some(user) = username {
                 auth_cmd.arg(user);
@@ -521,3 +533,8 @@ pub async fn open_single_ssh_tunnel_connection(config: &RedisServer) -> Result<M
 pub fn clear_expired_ssh_sessions() -> (usize, usize) {
     SSH_SESSION.clear_expired()
 }
+
+/// Drops all SSH sessions of the cache.
+pub fn clear_ssh_sessions() -> usize {
+    SSH_SESSION.clear()
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use gpui::{Pixels, px};
+use std::time::Duration;
 
 pub const SIDEBAR_WIDTH: Pixels = px(80.0);
 pub const KEY_TREE_MIN_WIDTH: Pixels = px(275.0);
@@ -19,3 +20,9 @@ pub const KEY_TREE_MAX_WIDTH: Pixels = px(800.0);
 pub const KEY_TREE_KEYWORD_INPUT_HEIGHT: Pixels = px(40.0);
 pub const STATUS_BAR_HEIGHT: Pixels = px(35.0);
 pub const EDITOR_KEY_BAR_HEIGHT: Pixels = px(40.0);
+/// Tab order of the editor regions: key tree, then value editor, then status bar.
+pub const KEY_TREE_TAB_INDEX: isize = 1;
+pub const EDITOR_TAB_INDEX: isize = 2;
+pub const STATUS_BAR_TAB_INDEX: isize = 3;
+/// Delay before live filtering applies the keyword typed so far.
+pub const FILTER_DEBOUNCE: Duration = Duration::from_millis(250);
@@ -14,6 +14,7 @@
 
 use crate::error::Error;
 use crate::helpers::{get_or_create_config_dir, is_development};
+use ahash::AHashSet;
 use gpui::SharedString;
 use redb::{Database, TableDefinition};
 use std::sync::OnceLock;
@@ -68,8 +69,14 @@ pub fn init_database() -> Result<()> {
     Ok(())
 }
 
+/// Moves the keyword to the front of the history, most recent first.
+///
+/// Existing entries of the keyword are removed (ignoring surrounding whitespace
+/// stored by older versions), so re-searching a term never duplicates it.
 fn add_normalize_history(history: &mut Vec<SharedString>, keyword: SharedString, max: usize) {
-    history.retain(|x| *x != keyword);
+    let mut seen = AHashSet::with_capacity(history.len() + 1);
+    seen.insert(keyword.to_string());
+    history.retain(|x| seen.insert(x.trim().to_string()));
 
     history.insert(0, keyword);
 
@@ -77,3 +84,57 @@ fn add_normalize_history(history: &mut Vec<SharedString>, keyword: SharedString,
         history.truncate(max);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(items: &[&'static str]) -> Vec<SharedString> {
+        items.iter().map(|item| SharedString::from(*item)).collect()
+    }
+
+    #[test]
+    fn test_add_normalize_history_new_keyword() {
+        let mut items = history(&["b", "c"]);
+        add_normalize_history(&mut items, "a".into(), 10);
+        assert_eq!(items, history(&["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_add_normalize_history_moves_existing_to_front() {
+        let mut items = history(&["a", "b", "c"]);
+        add_normalize_history(&mut items, "c".into(), 10);
+        assert_eq!(items, history(&["c", "a", "b"]));
+
+        // re-adding the most recent keyword keeps a single entry
+        add_normalize_history(&mut items, "c".into(), 10);
+        assert_eq!(items, history(&["c", "a", "b"]));
+    }
+
+    #[test]
+    fn test_add_normalize_history_whitespace_variants() {
+        let mut items = history(&["a", " b ", "c", "b"]);
+        add_normalize_history(&mut items, "b".into(), 10);
+        assert_eq!(items, history(&["b", "a", "c"]));
+
+        // older entries that only differ by whitespace are collapsed
+        let mut items = history(&["a ", "a", " c"]);
+        add_normalize_history(&mut items, "d".into(), 10);
+        assert_eq!(items, history(&["d", "a ", " c"]));
+    }
+
+    #[test]
+    fn test_add_normalize_history_truncates_at_max() {
+        let mut items = history(&["a", "b", "c"]);
+        add_normalize_history(&mut items, "d".into(), 3);
+        assert_eq!(items, history(&["d", "a", "b"]));
+
+        // moving an existing keyword to the front never evicts another one
+        add_normalize_history(&mut items, "b".into(), 3);
+        assert_eq!(items, history(&["b", "d", "a"]));
+
+        let mut items = history(&["a", "b"]);
+        add_normalize_history(&mut items, "c".into(), 3);
+        assert_eq!(items, history(&["c", "a", "b"]));
+    }
+}
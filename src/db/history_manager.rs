@@ -56,8 +56,6 @@ impl HistoryManager {
             if !keyword.is_empty() {
                 add_normalize_history(&mut history, keyword.to_string().into(), self.max_history_size);
 
-                self.history_cache.insert(server_id.to_string(), history.clone());
-
                 let json_val = serde_json::to_string(&history)?;
                 table.insert(server_id, json_val.as_str())?;
             }
@@ -65,6 +63,8 @@ impl HistoryManager {
         };
 
         write_txn.commit()?;
+        // Only cache what was persisted, so a failed write can't leave the cache ahead of the table
+        self.history_cache.insert(server_id.to_string(), history.clone());
         Ok(history)
     }
 
@@ -101,9 +101,8 @@ impl HistoryManager {
                 Vec::new()
             };
             let len_before = history.len();
-            history.retain(|x| x.as_ref() != keyword);
+            history.retain(|x| x.trim() != keyword);
             if history.len() != len_before {
-                self.history_cache.insert(server_id.to_string(), history.clone());
                 let json_val = serde_json::to_string(&history)?;
                 table.insert(server_id, json_val.as_str())?;
             }
@@ -111,11 +110,11 @@ impl HistoryManager {
         };
 
         write_txn.commit()?;
+        self.history_cache.insert(server_id.to_string(), history.clone());
         Ok(history)
     }
 
     pub fn clear_history(&self, server_id: &str) -> Result<()> {
-        self.history_cache.remove(server_id);
         let db = get_database()?;
         let write_txn = db.begin_write()?;
         {
@@ -123,6 +122,7 @@ impl HistoryManager {
             table.remove(server_id)?;
         }
         write_txn.commit()?;
+        self.history_cache.remove(server_id);
         Ok(())
     }
 }
@@ -55,6 +55,16 @@ pub enum Error {
 
     #[snafu(display("Prost reflect decode error: {source}"))]
     ProstReflectDecode { source: prost_reflect::prost::DecodeError },
+
+    #[snafu(display("Key {key} is now a {actual}, the editor expects a {expected}"))]
+    TypeMismatch {
+        key: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[snafu(display("Cancelled"))]
+    Cancelled,
 }
 
 impl From<redis::RedisError> for Error {
@@ -152,3 +162,55 @@ impl From<prost_reflect::prost::DecodeError> for Error {
         Error::ProstReflectDecode { source }
     }
 }
+
+impl Error {
+    /// Returns the structured detail of the underlying error, e.g. the kind and code of a Redis error.
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Error::Redis { source } => {
+                let mut detail = format!("kind: {:?}", source.kind());
+                if let Some(code) = source.code() {
+                    detail.push_str(&format!(", code: {code}"));
+                }
+                if let Some(message) = source.detail() {
+                    detail.push_str(&format!(", detail: {message}"));
+                }
+                Some(detail)
+            }
+            Error::Invalid { .. } | Error::TypeMismatch { .. } | Error::Cancelled => None,
+            _ => Some(format!("{self:?}")),
+        }
+    }
+    /// Whether the task was cancelled by the user.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Error::Cancelled)
+    }
+    /// Whether the key changed its type on the server since its editor was opened.
+    pub fn is_type_mismatch(&self) -> bool {
+        matches!(self, Error::TypeMismatch { .. })
+    }
+    /// Whether the cluster can not serve the key, e.g. during a failover or with unassigned slots.
+    pub fn is_cluster_down(&self) -> bool {
+        match self {
+            Error::Redis { source } => source.code() == Some("CLUSTERDOWN"),
+            _ => false,
+        }
+    }
+    /// Whether the ACL user of the connection may not run the command.
+    pub fn is_no_permission(&self) -> bool {
+        match self {
+            Error::Redis { source } => source.code() == Some("NOPERM"),
+            _ => false,
+        }
+    }
+    /// Whether the server rejected the credentials, e.g. after its password was rotated.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Error::Redis { source } => {
+                source.kind() == redis::ErrorKind::AuthenticationFailed
+                    || matches!(source.code(), Some("NOAUTH" | "WRONGPASS"))
+            }
+            _ => false,
+        }
+    }
+}
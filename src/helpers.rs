@@ -28,7 +28,7 @@ pub use common::*;
 pub use font::*;
 pub use fs::*;
 pub use string::*;
-pub use time::{parse_duration, unix_ts, unix_ts_millis};
+pub use time::{format_timestamp_ms, parse_duration, timestamp_date, unix_ts, unix_ts_millis};
 pub use ttl_cache::*;
 pub use validate::*;
 pub fn is_development() -> bool {
@@ -34,6 +34,15 @@ pub enum EditorAction {
     AutoRefresh(u32),
 }
 
+/// Jumps to the Nth configured server (1-based, in sidebar order)
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+pub enum ServerAction {
+    Switch(usize),
+}
+
+/// Number of servers reachable with `cmd-1` to `cmd-9`
+pub const SERVER_HOT_KEYS: usize = 9;
+
 pub fn humanize_keystroke(keystroke: &str) -> String {
     let parts = keystroke.split('-');
     let mut display_text = String::new();
@@ -121,5 +130,14 @@ pub fn new_hot_keys() -> Vec<KeyBinding> {
         KeyBinding::new("cmd-t", EditorAction::UpdateTtl, None),
         KeyBinding::new("cmd-j", EditorAction::Cmd, None),
         KeyBinding::new("cmd-f", EditorAction::Search, None),
+        KeyBinding::new("cmd-1", ServerAction::Switch(1), None),
+        KeyBinding::new("cmd-2", ServerAction::Switch(2), None),
+        KeyBinding::new("cmd-3", ServerAction::Switch(3), None),
+        KeyBinding::new("cmd-4", ServerAction::Switch(4), None),
+        KeyBinding::new("cmd-5", ServerAction::Switch(5), None),
+        KeyBinding::new("cmd-6", ServerAction::Switch(6), None),
+        KeyBinding::new("cmd-7", ServerAction::Switch(7), None),
+        KeyBinding::new("cmd-8", ServerAction::Switch(8), None),
+        KeyBinding::new("cmd-9", ServerAction::Switch(9), None),
     ]
 }
@@ -15,7 +15,8 @@
 use crate::constants::KEY_TREE_MAX_WIDTH;
 use crate::constants::KEY_TREE_MIN_WIDTH;
 use crate::error::Error;
-use gpui::Pixels;
+use gpui::{App, Hsla, Pixels};
+use gpui_component::ActiveTheme;
 use ruzstd::decoding::StreamingDecoder;
 use std::io::Read;
 
@@ -35,3 +36,18 @@ pub fn decompress_zstd(bytes: &[u8]) -> Result<Vec<u8>> {
         .map_err(|e| Error::Invalid { message: e.to_string() })?;
     Ok(decompressed_vec)
 }
+
+/// Returns the theme color of a server accent color name, `None` for no or an unknown color.
+pub fn server_accent_color(color: &str, cx: &App) -> Option<Hsla> {
+    let theme = cx.theme();
+    let color = match color {
+        "red" => theme.red,
+        "yellow" => theme.yellow,
+        "green" => theme.green,
+        "blue" => theme.blue,
+        "magenta" => theme.magenta,
+        "cyan" => theme.cyan,
+        _ => return None,
+    };
+    Some(color)
+}
@@ -152,6 +152,12 @@ pub fn decrypt(cipher_text: &str) -> Result<String> {
     let data = BASE64
         .decode(cipher_text)
         .map_err(|e| Error::Invalid { message: e.to_string() })?;
+    // Too short to hold the nonce, e.g. a plain text value that is valid base64
+    if data.len() < 12 {
+        return Err(Error::Invalid {
+            message: "cipher text is too short".to_string(),
+        });
+    }
 
     // Initialize cipher with master key
     let cipher = Aes256Gcm::new(MASTER_KEY.into());
@@ -240,6 +246,53 @@ pub fn redis_value_to_string(v: &Value) -> String {
     }
 }
 
+/// Formats a reply with its RESP3 type annotation (e.g. `(map)`, `(double)`),
+/// instead of coercing everything to plain strings.
+pub fn redis_value_to_resp3_string(v: &Value) -> String {
+    let join = |items: &[Value]| {
+        items
+            .iter()
+            .map(redis_value_to_resp3_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let join_pairs = |items: &[(Value, Value)]| {
+        items
+            .iter()
+            .map(|(k, v)| {
+                format!(
+                    "{} => {}",
+                    redis_value_to_resp3_string(k),
+                    redis_value_to_resp3_string(v)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match v {
+        Value::Nil => "(null)".to_string(),
+        Value::Int(i) => format!("(integer) {i}"),
+        Value::SimpleString(s) => format!("(simple-string) {s}"),
+        Value::Okay => "(simple-string) OK".to_string(),
+        Value::Double(f) => format!("(double) {f}"),
+        Value::Boolean(b) => format!("(boolean) {b}"),
+        Value::BulkString(bytes) => format!("(blob-string) {:?}", String::from_utf8_lossy(bytes)),
+        Value::Array(items) => format!("(array) [{}]", join(items)),
+        Value::Set(items) => format!("(set) {{{}}}", join(items)),
+        Value::Map(items) => format!("(map) {{{}}}", join_pairs(items)),
+        Value::VerbatimString { format, text } => format!("(verbatim {format:?}) {text}"),
+        Value::Attribute { data, attributes } => format!(
+            "(attribute) {{{}}} {}",
+            join_pairs(attributes),
+            redis_value_to_resp3_string(data)
+        ),
+        Value::BigNumber(n) => format!("(big-number) {:?}", n),
+        Value::ServerError(e) => format!("(error) {}", e),
+        Value::Push { kind, data } => format!("(push {:?}) [{}]", kind, join(data)),
+        _ => "Unsupported".to_string(),
+    }
+}
+
 pub fn starts_with_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
     if haystack.len() < needle.len() {
         return false;
@@ -250,3 +303,78 @@ pub fn starts_with_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
         None => false,
     }
 }
+
+/// Escapes the glob metacharacters of a SCAN MATCH pattern (`*`, `?`, `[`, `]` and `\`),
+/// so the text is matched literally, e.g. a key named `user:*`.
+pub fn escape_glob(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Returns the SCAN MATCH pattern of the keys starting with the literal prefix.
+pub fn prefix_glob(prefix: &str) -> String {
+    format!("{}*", escape_glob(prefix))
+}
+
+/// Returns the SCAN MATCH pattern of the items containing the literal keyword.
+pub fn contains_glob(keyword: &str) -> String {
+    format!("*{}*", escape_glob(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_glob() {
+        assert_eq!(escape_glob("user:1"), "user:1");
+        assert_eq!(escape_glob("user:*"), "user:\\*");
+        assert_eq!(escape_glob("a?b"), "a\\?b");
+        assert_eq!(escape_glob("[abc]"), "\\[abc\\]");
+        assert_eq!(escape_glob("a\\b"), "a\\\\b");
+        assert_eq!(escape_glob("*?[]\\"), "\\*\\?\\[\\]\\\\");
+        assert_eq!(escape_glob(""), "");
+    }
+
+    #[test]
+    fn test_escape_glob_multibyte() {
+        assert_eq!(escape_glob("用户:*"), "用户:\\*");
+        assert_eq!(escape_glob("キー[1]"), "キー\\[1\\]");
+        assert_eq!(escape_glob("🔑?"), "🔑\\?");
+    }
+
+    #[test]
+    fn test_prefix_glob() {
+        assert_eq!(prefix_glob("user:"), "user:*");
+        assert_eq!(prefix_glob("user:*"), "user:\\**");
+        assert_eq!(prefix_glob("a[1]:"), "a\\[1\\]:*");
+        // a folder pattern is the folder with its separator as prefix
+        assert_eq!(prefix_glob(&format!("{}{}", "cache*", ":")), "cache\\*:*");
+        assert_eq!(prefix_glob(""), "*");
+    }
+
+    #[test]
+    fn test_contains_glob() {
+        assert_eq!(contains_glob("name"), "*name*");
+        assert_eq!(contains_glob("*"), "*\\**");
+        assert_eq!(contains_glob("a?"), "*a\\?*");
+        assert_eq!(contains_glob("x\\y"), "*x\\\\y*");
+        assert_eq!(contains_glob("名前"), "*名前*");
+    }
+
+    #[test]
+    fn test_decrypt() {
+        let cipher_text = encrypt("secret").unwrap();
+        assert_eq!(decrypt(&cipher_text).unwrap(), "secret");
+        // "password" is valid base64 but decodes to 6 bytes
+        assert!(decrypt("password").is_err());
+        assert!(decrypt("").is_err());
+        assert!(decrypt("not base64!").is_err());
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::error::Error;
-use chrono::Local;
+use chrono::{DateTime, Local, NaiveDate};
 use std::time::Duration;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -27,6 +27,27 @@ pub fn unix_ts_millis() -> i64 {
     Local::now().timestamp_millis()
 }
 
+/// Formats a Unix timestamp in milliseconds, in UTC or in the local timezone.
+pub fn format_timestamp_ms(ts_ms: i64, format: &str, utc: bool) -> Option<String> {
+    let dt = DateTime::from_timestamp_millis(ts_ms)?;
+    let value = if utc {
+        dt.format(format).to_string()
+    } else {
+        dt.with_timezone(&Local).format(format).to_string()
+    };
+    Some(value)
+}
+
+/// Returns the calendar date of a Unix timestamp in milliseconds, in UTC or in the local timezone.
+pub fn timestamp_date(ts_ms: i64, utc: bool) -> Option<NaiveDate> {
+    let dt = DateTime::from_timestamp_millis(ts_ms)?;
+    if utc {
+        Some(dt.date_naive())
+    } else {
+        Some(dt.with_timezone(&Local).date_naive())
+    }
+}
+
 /// Parse a duration string into a Duration.
 pub fn parse_duration(s: &str) -> Result<Duration> {
     if let Ok(secs) = s.parse::<u64>() {
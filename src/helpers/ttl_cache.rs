@@ -67,6 +67,12 @@ impl<K: Eq + Hash + Debug, V: Clone> TtlCache<K, V> {
     pub fn remove(&self, key: &K) {
         self.cache.remove(key);
     }
+    /// Removes all items, returns how many were removed.
+    pub fn clear(&self) -> usize {
+        let count = self.cache.len();
+        self.cache.clear();
+        count
+    }
     pub fn clear_expired(&self) -> (usize, usize) {
         let now = now_secs();
         let mut count = 0;
@@ -1,20 +1,28 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
-use crate::connection::{clear_expired_cache, get_servers};
+use crate::connection::{clear_expired_cache, close_all_connections, get_servers};
 use crate::constants::SIDEBAR_WIDTH;
 use crate::db::{ProtoManager, init_database};
 use crate::helpers::{
-    MemuAction, get_default_font_family, get_or_create_config_dir, is_app_store_build, is_development, new_hot_keys,
+    MemuAction, ServerAction, get_default_font_family, get_or_create_config_dir, is_app_store_build, is_development,
+    new_hot_keys,
 };
 use crate::states::{
-    FontSize, FontSizeAction, GlobalEvent, LocaleAction, NotificationCategory, Route, SettingsAction, ThemeAction,
-    ZedisAppState, ZedisGlobalStore, save_app_state, update_app_state_and_save,
+    FontSize, FontSizeAction, GlobalEvent, LocaleAction, NotificationAction, NotificationCategory, Route,
+    SettingsAction, ThemeAction, ZedisAppState, ZedisGlobalStore, flush_session_options, i18n_common, resolve_locale,
+    save_app_state, take_session_load_warnings, update_app_state_and_save,
 };
 use crate::views::{ZedisContent, ZedisSidebar, ZedisTitleBar, open_about_window};
 use gpui::{
-    App, Bounds, Entity, Menu, MenuItem, Pixels, Task, TitlebarOptions, Window, WindowAppearance, WindowBounds,
-    WindowOptions, div, prelude::*, px, size,
+    App, Bounds, ClipboardItem, Entity, Menu, MenuItem, Pixels, Task, TitlebarOptions, Window, WindowAppearance,
+    WindowBounds, WindowOptions, div, prelude::*, px, size,
+};
+use gpui_component::{
+    ActiveTheme, Root, Sizable, Theme, ThemeMode, WindowExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    notification::Notification,
+    v_flex,
 };
-use gpui_component::{ActiveTheme, Root, Theme, ThemeMode, WindowExt, h_flex, notification::Notification, v_flex};
 use std::{env, str::FromStr, time::Duration};
 use sys_locale::get_locale;
 use tracing::{Level, error, info};
@@ -43,6 +51,8 @@ mod views;
 pub struct Zedis {
     pending_notification: Option<Notification>,
     last_bounds: Bounds<Pixels>,
+    // the layout was reset and the window not moved since, its bounds must not be saved
+    layout_reset: bool,
     save_task: Option<Task<()>>,
     // views
     sidebar: Entity<ZedisSidebar>,
@@ -69,11 +79,36 @@ impl Zedis {
                 if let Some(title) = e.title.as_ref() {
                     notification = notification.title(title);
                 }
+                if let Some(details) = e.details.clone() {
+                    let label = i18n_common(cx, "copy_details");
+                    notification = notification.action(move |_, _, _| {
+                        let details = details.clone();
+                        Button::new("copy-notification-details")
+                            .outline()
+                            .xsmall()
+                            .label(label.clone())
+                            .on_click(move |_, _, cx| {
+                                cx.write_to_clipboard(ClipboardItem::new_string(details.to_string()));
+                            })
+                    });
+                }
                 this.pending_notification = Some(notification);
+            } else if let GlobalEvent::LayoutReset = event {
+                // a pending bounds save would write the reset bounds back
+                this.save_task = None;
+                this.layout_reset = true;
             }
             cx.notify();
         })
         .detach();
+        let session_warnings = take_session_load_warnings();
+        if !session_warnings.is_empty() {
+            let notification = NotificationAction::new_warning(i18n_common(cx, "session_config_invalid"))
+                .with_details(session_warnings.join("\n").into());
+            global_state.update(cx, |_state, cx| {
+                cx.emit(GlobalEvent::Notification(notification));
+            });
+        }
         cx.observe_window_appearance(window, |this, _window, cx| {
             if cx.global::<ZedisGlobalStore>().read(cx).theme().is_none() {
                 this.theme_update_task = Some(cx.spawn(async move |_this, cx| {
@@ -85,6 +120,34 @@ impl Zedis {
             }
         })
         .detach();
+        // Saves are spawned in the background, write the latest state before quitting so the
+        // last change (e.g. theme, locale or window bounds) is not lost, then close the connections
+        cx.on_app_quit(|this, cx| {
+            // the debounced bounds save is replaced by the save below
+            this.save_task = None;
+            let last_bounds = this.last_bounds;
+            let layout_reset = this.layout_reset;
+            let value = cx.global::<ZedisGlobalStore>().clone().update(cx, |state, _cx| {
+                if !layout_reset && last_bounds != Bounds::default() {
+                    state.set_bounds(last_bounds);
+                }
+                state.clone()
+            });
+            let task = cx.background_spawn(async move {
+                if let Err(e) = save_app_state(&value) {
+                    error!(error = %e, "save app state on quit fail");
+                }
+                if let Err(e) = flush_session_options() {
+                    error!(error = %e, "save session options on quit fail");
+                }
+                close_all_connections();
+            });
+            async move {
+                task.await;
+                info!("app state saved before quit");
+            }
+        })
+        .detach();
         let clear_expired_cache = Some(cx.spawn(async move |_this, cx| {
             loop {
                 cx.background_executor().timer(Duration::from_secs(30)).await;
@@ -102,10 +165,12 @@ impl Zedis {
             theme_update_task: None,
             _clear_expired_cache: clear_expired_cache,
             last_bounds: Bounds::default(),
+            layout_reset: false,
         }
     }
     fn persist_window_state(&mut self, new_bounds: Bounds<Pixels>, cx: &mut Context<Self>) {
         self.last_bounds = new_bounds;
+        self.layout_reset = false;
         let store = cx.global::<ZedisGlobalStore>().clone();
         let mut value = store.value(cx);
         value.set_bounds(new_bounds);
@@ -153,6 +218,7 @@ impl Render for Zedis {
         if let Some(font_size) = cx.global::<ZedisGlobalStore>().read(cx).font_size().to_pixels() {
             window.set_rem_size(font_size);
         }
+        let rtl = cx.global::<ZedisGlobalStore>().read(cx).is_rtl();
 
         let content = v_flex()
             .id(PKG_NAME)
@@ -164,6 +230,7 @@ impl Render for Zedis {
                     .id(PKG_NAME)
                     .bg(cx.theme().background)
                     .size_full()
+                    .when(rtl, |this| this.flex_row_reverse())
                     .child(div().w(SIDEBAR_WIDTH).flex_none().h_full().child(self.sidebar.clone()))
                     .child(self.content.clone())
                     .children(dialog_layer)
@@ -199,10 +266,7 @@ impl Render for Zedis {
             }))
             // Locale action handler - changes language and saves to disk
             .on_action(cx.listener(|_this, e: &LocaleAction, _window, cx| {
-                let locale = match e {
-                    LocaleAction::Zh => "zh",
-                    LocaleAction::En => "en",
-                };
+                let locale = resolve_locale(&e.locale);
 
                 // Save locale preference and refresh UI
                 update_app_state_and_save(cx, "save_locale", move |state, _cx| {
@@ -238,6 +302,28 @@ impl Render for Zedis {
                     });
                 }
             }))
+            // Server switch handler - jumps to the Nth server of the sidebar
+            .on_action(cx.listener(|_this, e: &ServerAction, _window, cx| {
+                let ServerAction::Switch(index) = *e;
+                let Some(server_id) = get_servers()
+                    .ok()
+                    .and_then(|servers| servers.get(index.saturating_sub(1)).map(|server| server.id.clone()))
+                else {
+                    return;
+                };
+                cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                    store.update(cx, |state, cx| {
+                        let route = state.server_route();
+                        let is_current =
+                            state.route() == route && state.selected_server().is_some_and(|(id, _)| *id == server_id);
+                        if is_current {
+                            return;
+                        }
+                        state.go_to(route, cx);
+                        state.set_selected_server((server_id, 0), cx);
+                    });
+                });
+            }))
     }
 }
 
@@ -13,16 +13,20 @@
 // limitations under the License.
 
 mod app;
+mod config_transfer;
 mod i18n;
 mod server;
 mod session;
 
 pub use app::*;
+pub use config_transfer::{ZedisConfigBundle, export_config, import_config};
+pub use i18n::i18n_about;
 pub use i18n::i18n_clients_manager;
 pub use i18n::i18n_monitor;
 pub use i18n::i18n_common;
 pub use i18n::i18n_editor;
 pub use i18n::i18n_hash_editor;
+pub use i18n::i18n_hot_keys;
 pub use i18n::i18n_key_tree;
 pub use i18n::i18n_kv_table;
 pub use i18n::i18n_list_editor;
@@ -36,13 +40,26 @@ pub use i18n::i18n_set_editor;
 pub use i18n::i18n_settings;
 pub use i18n::i18n_sidebar;
 pub use i18n::i18n_status_bar;
+pub use i18n::i18n_stream_editor;
+pub use i18n::i18n_ttl_histogram;
 pub use i18n::i18n_tray;
+pub use i18n::i18n_value_diff;
 pub use i18n::i18n_zset_editor;
 pub use server::ErrorMessage;
+pub use server::KeyTreeDelta;
+pub use server::ScanIteration;
 pub use server::ZedisServerState;
+pub use server::backup::{BackupKind, BackupProgress};
+pub use server::bulk::{BULK_PREVIEW_LIMIT, BulkPreview};
+pub use server::clients::ClientInfo;
+pub use server::diff::{DiffEntry, DiffKind, ValueDiff, diff_values, load_diff_value};
 pub use server::event::ServerEvent;
 pub use server::event::ServerTask;
-pub use server::stat::{RedisMetrics, get_metrics_cache};
-pub use server::string::detect_and_decode;
+pub use server::event::RunningTask;
+pub use server::hash::{HashFieldsError, parse_hash_fields};
+pub use server::stat::{MetricsAlert, MetricsSnapshot, RedisMetrics, ServerHealth, check_server_health, get_metrics_cache};
+pub use server::stream::{StreamGroup, StreamPendingEntries, StreamTrimPreview, StreamTrimStrategy};
+pub use server::string::{CounterValue, describe_hyperloglog, detect_and_decode};
+pub use server::zset::{ZADD_FLAG_NAMES, ZaddFlags};
 pub use server::value::*;
 pub use session::*;
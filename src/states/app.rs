@@ -13,20 +13,24 @@
 // limitations under the License.
 
 use crate::connection::{
-    RedisServer, get_servers, save_servers, set_redis_connection_timeout, set_redis_response_timeout,
+    CommandPolicy, DEFAULT_CLIENT_NAME_TEMPLATE, RedisServer, get_connection_manager, get_servers, save_servers,
+    set_client_name_template, set_command_log_enabled, set_redis_connection_timeout, set_redis_response_timeout,
 };
 use crate::constants::SIDEBAR_WIDTH;
 use crate::error::Error;
 use crate::helpers::{get_key_tree_widths, get_or_create_config_dir};
-use crate::states::i18n_common;
+use crate::states::{i18n_common, import_config};
 use chrono::Local;
 use gpui::{Action, App, AppContext, Bounds, Context, Entity, EventEmitter, Global, Pixels, SharedString};
 use gpui_component::{ThemeMode, dialog::DialogButtonProps};
+use parking_lot::Mutex;
+use rust_i18n::t;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::LazyLock;
 use std::time::Duration;
 use sys_locale::get_locale;
 use tracing::{error, info};
@@ -44,8 +48,11 @@ pub enum Route {
     Metrics,
     Slowlog,
     MemoryAnalysis,
+    HotKeys,
+    TtlHistogram,
     Clients,
     Monitor,
+    ValueDiff,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
@@ -65,6 +72,64 @@ impl FontSize {
     }
 }
 
+/// Clock format used for chart timestamps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ClockFormat {
+    /// Follow the system locale
+    #[default]
+    Auto,
+    Hour12,
+    Hour24,
+}
+
+/// Ordering applied automatically to the loaded rows of a collection type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum DefaultSort {
+    /// Keep the order returned by Redis
+    #[default]
+    None,
+    ValueAsc,
+    ValueDesc,
+    /// Highest score first, zset only
+    ScoreDesc,
+}
+
+impl DefaultSort {
+    pub const SET: [DefaultSort; 3] = [DefaultSort::None, DefaultSort::ValueAsc, DefaultSort::ValueDesc];
+    pub const ZSET: [DefaultSort; 4] = [
+        DefaultSort::None,
+        DefaultSort::ScoreDesc,
+        DefaultSort::ValueAsc,
+        DefaultSort::ValueDesc,
+    ];
+}
+
+/// Locales (or their language) that conventionally use a 12-hour clock.
+const TWELVE_HOUR_LOCALES: [&str; 12] = [
+    "en-us", "en-ca", "en-au", "en-nz", "en-in", "en-ph", "es-mx", "es-us", "zh-tw", "ko", "hi", "ar",
+];
+
+impl ClockFormat {
+    pub const ALL: [ClockFormat; 3] = [ClockFormat::Auto, ClockFormat::Hour12, ClockFormat::Hour24];
+    /// Whether timestamps use a 12-hour clock, resolving `Auto` from the system locale.
+    pub fn is_12_hour(self) -> bool {
+        match self {
+            ClockFormat::Hour12 => true,
+            ClockFormat::Hour24 => false,
+            ClockFormat::Auto => get_locale()
+                .map(|locale| {
+                    let locale = locale.replace('_', "-").to_lowercase();
+                    TWELVE_HOUR_LOCALES.iter().any(|item| locale.starts_with(item))
+                })
+                .unwrap_or(false),
+        }
+    }
+    /// Returns the chrono format of the time of day.
+    pub fn time_format(self) -> &'static str {
+        if self.is_12_hour() { "%I:%M:%S %p" } else { "%H:%M:%S" }
+    }
+}
+
 /// Theme selection actions for the settings menu
 #[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action)]
 pub enum ThemeAction {
@@ -76,13 +141,27 @@ pub enum ThemeAction {
     System,
 }
 
-/// Locale/language selection actions for the settings menu
-#[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action)]
-pub enum LocaleAction {
-    /// English language
-    En,
-    /// Chinese language
-    Zh,
+/// Supported locales as (code, native name), in menu order.
+///
+/// Adding a locale only requires `locales/<code>.toml` and an entry here.
+pub const LOCALES: &[(&str, &str)] = &[("en", "English"), ("zh", "中文"), ("ja", "日本語")];
+
+/// Locales written right-to-left, the layout is mirrored when one of them is selected.
+const RTL_LOCALES: &[&str] = &["ar", "fa", "he", "ur"];
+
+/// Returns the supported locale matching a language code, falling back to English.
+pub fn resolve_locale(lang: &str) -> &'static str {
+    LOCALES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(lang))
+        .map_or("en", |(code, _)| code)
+}
+
+/// Locale/language selection action for the settings menu
+#[derive(Clone, PartialEq, Debug, Deserialize, JsonSchema, Action)]
+pub struct LocaleAction {
+    /// Locale code, one of `LOCALES`
+    pub locale: SharedString,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug, Deserialize, JsonSchema, Action)]
@@ -99,6 +178,16 @@ pub enum SettingsAction {
 
 const LIGHT_THEME_MODE: &str = "light";
 const DARK_THEME_MODE: &str = "dark";
+/// Console commands that ask for confirmation before running, unless configured otherwise.
+/// `SCAN` only counts when it has no MATCH pattern.
+const DEFAULT_DANGEROUS_COMMANDS: &str =
+    "KEYS, SCAN, FLUSHALL, FLUSHDB, DEBUG, SHUTDOWN, CONFIG SET, SCRIPT FLUSH, FUNCTION FLUSH";
+/// Maximum decimal places of the metrics chart labels.
+const MAX_METRICS_PRECISION: usize = 4;
+/// Longest WAIT after a write, every other command of the server queues behind it.
+const MAX_WAIT_TIMEOUT_MS: u64 = 2_000;
+/// Automatic HSCAN/SSCAN rounds of a filter when the setting is unset.
+const DEFAULT_MAX_FILTER_SCAN_PAGES: usize = 20;
 
 fn get_or_create_server_config() -> Result<PathBuf> {
     let config_dir = get_or_create_config_dir()?;
@@ -126,6 +215,8 @@ pub struct NotificationAction {
     pub title: Option<SharedString>,
     pub category: NotificationCategory,
     pub message: SharedString,
+    /// Full report that can be copied from the notification
+    pub details: Option<SharedString>,
 }
 
 impl NotificationAction {
@@ -170,6 +261,12 @@ impl NotificationAction {
         self.title = Some(title);
         self
     }
+
+    /// Sets the copyable details for the notification
+    pub fn with_details(mut self, details: SharedString) -> Self {
+        self.details = Some(details);
+        self
+    }
 }
 
 pub enum GlobalEvent {
@@ -179,8 +276,12 @@ pub enum GlobalEvent {
     ServerSelected(SharedString, usize),
     /// Server list config has been modified (add/remove/edit).
     ServerListUpdated,
+    /// The config of an existing server has been edited, its connections were dropped.
+    ServerUpdated(SharedString),
     /// Route has been changed.
     RouteChanged(Route),
+    /// Persisted layout (window bounds, key tree width, column widths) has been reset.
+    LayoutReset,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -196,11 +297,55 @@ pub struct ZedisAppState {
     key_separator: Option<String>,
     auto_expand_threshold: Option<usize>,
     key_scan_count: Option<usize>,
+    /// Maximum automatic HSCAN/SSCAN rounds of a filter before it waits for "keep scanning"
+    max_filter_scan_pages: Option<usize>,
     max_truncate_length: Option<usize>,
     redis_connection_timeout: Option<Duration>,
     redis_response_timeout: Option<Duration>,
     selected_server: Option<(String, usize)>,
     tray_enabled: Option<bool>,
+    alert_hit_rate_below: Option<f64>,
+    alert_evictions_above: Option<f64>,
+    alert_memory_above: Option<f64>,
+    alert_notification_enabled: Option<bool>,
+    mini_metrics_enabled: Option<bool>,
+    /// Whether selecting a server lands on its metrics instead of the key editor
+    open_metrics_on_select: Option<bool>,
+    key_encoding_enabled: Option<bool>,
+    wait_replication_enabled: Option<bool>,
+    /// Whether writes check the TYPE of the key before running
+    verify_key_type: Option<bool>,
+    wait_replicas: Option<usize>,
+    wait_timeout_ms: Option<u64>,
+    dangerous_commands: Option<String>,
+    clock_format: Option<ClockFormat>,
+    metrics_show_date: Option<bool>,
+    metrics_precision: Option<usize>,
+    utc_time: Option<bool>,
+    scan_debug: Option<bool>,
+    command_log: Option<bool>,
+    /// Name template of the connections (CLIENT SETNAME), empty leaves them unnamed
+    client_name_template: Option<String>,
+    show_value_length: Option<bool>,
+    /// Mirrors the layout right-to-left whatever the locale
+    force_rtl: Option<bool>,
+    /// User resized column widths of the value tables, by key type and stable column id
+    column_widths: Option<HashMap<String, HashMap<String, f32>>>,
+    /// Sort applied to the loaded rows of the value tables, by key type
+    default_sorts: Option<HashMap<String, DefaultSort>>,
+}
+
+/// Thresholds evaluated against each metrics heartbeat sample.
+///
+/// A `None` threshold disables the corresponding check.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MetricsAlertThresholds {
+    /// Alert when the keyspace hit rate (%) drops below this value
+    pub hit_rate_below: Option<f64>,
+    /// Alert when evicted keys per second exceed this value
+    pub evictions_above: Option<f64>,
+    /// Alert when memory usage (% of maxmemory) exceeds this value
+    pub memory_above: Option<f64>,
 }
 
 impl EventEmitter<GlobalEvent> for ZedisAppState {}
@@ -234,7 +379,11 @@ impl ZedisGlobalStore {
 
 impl Global for ZedisGlobalStore {}
 
+/// Serializes writes of the app state file, so the save on quit waits for the running one
+static APP_STATE_SAVE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
 pub fn save_app_state(state: &ZedisAppState) -> Result<()> {
+    let _guard = APP_STATE_SAVE_LOCK.lock();
     let path = get_or_create_server_config()?;
     let value = toml::to_string(state)?;
     std::fs::write(path, value)?;
@@ -263,22 +412,37 @@ impl ZedisAppState {
                     // Already a simple language code like "en" or "zh"
                     locale.as_str()
                 };
-                state.locale = Some(lang.to_lowercase());
+                state.locale = Some(resolve_locale(lang).to_string());
             } else {
                 // Fallback to English if locale detection fails
                 state.locale = Some("en".to_string());
             }
         }
         state.route = Route::Home;
+        state.apply_runtime_settings();
 
-        if let Some(redis_connection_timeout) = state.redis_connection_timeout {
+        Ok(state)
+    }
+    /// Applies the settings read by the connection layer instead of the app state.
+    fn apply_runtime_settings(&self) {
+        if let Some(redis_connection_timeout) = self.redis_connection_timeout {
             set_redis_connection_timeout(redis_connection_timeout);
         }
-        if let Some(redis_response_timeout) = state.redis_response_timeout {
+        if let Some(redis_response_timeout) = self.redis_response_timeout {
             set_redis_response_timeout(redis_response_timeout);
         }
-
-        Ok(state)
+        set_command_log_enabled(self.command_log());
+        set_client_name_template(self.client_name_template());
+    }
+    /// Returns the settings without the state bound to this machine (window bounds,
+    /// selected server and route), for exporting them.
+    pub fn portable(&self) -> Self {
+        Self {
+            route: Route::default(),
+            bounds: None,
+            selected_server: None,
+            ..self.clone()
+        }
     }
     pub fn new() -> Self {
         Self { ..Default::default() }
@@ -363,10 +527,30 @@ impl ZedisAppState {
     pub fn locale(&self) -> &str {
         self.locale.as_deref().unwrap_or("en")
     }
+    /// Whether the layout is mirrored, forced by the setting or by a right-to-left locale.
+    pub fn is_rtl(&self) -> bool {
+        self.force_rtl() || RTL_LOCALES.contains(&self.locale())
+    }
+    pub fn force_rtl(&self) -> bool {
+        self.force_rtl.unwrap_or(false)
+    }
+    pub fn set_force_rtl(&mut self, enabled: bool) {
+        self.force_rtl = Some(enabled);
+    }
 
     pub fn set_bounds(&mut self, bounds: Bounds<Pixels>) {
         self.bounds = Some(bounds);
     }
+    /// Clears the persisted layout: window bounds, key tree width and value table column widths.
+    ///
+    /// The window keeps its current size, the default bounds are used on the next launch.
+    pub fn reset_layout(&mut self, cx: &mut Context<Self>) {
+        self.bounds = None;
+        self.key_tree_width = Pixels::default();
+        self.column_widths = None;
+        cx.emit(GlobalEvent::LayoutReset);
+        cx.notify();
+    }
     pub fn set_theme(&mut self, theme: Option<ThemeMode>) {
         match theme {
             Some(ThemeMode::Light) => {
@@ -415,6 +599,12 @@ impl ZedisAppState {
     pub fn set_key_scan_count(&mut self, key_scan_count: usize) {
         self.key_scan_count = Some(key_scan_count);
     }
+    pub fn max_filter_scan_pages(&self) -> usize {
+        self.max_filter_scan_pages.unwrap_or(DEFAULT_MAX_FILTER_SCAN_PAGES)
+    }
+    pub fn set_max_filter_scan_pages(&mut self, pages: usize) {
+        self.max_filter_scan_pages = Some(pages.max(1));
+    }
     pub fn auto_expand_threshold(&self) -> usize {
         self.auto_expand_threshold.unwrap_or(100)
     }
@@ -427,6 +617,185 @@ impl ZedisAppState {
     pub fn set_tray_enabled(&mut self, enabled: bool) {
         self.tray_enabled = Some(enabled);
     }
+    pub fn metrics_alert_thresholds(&self) -> MetricsAlertThresholds {
+        MetricsAlertThresholds {
+            hit_rate_below: self.alert_hit_rate_below,
+            evictions_above: self.alert_evictions_above,
+            memory_above: self.alert_memory_above,
+        }
+    }
+    pub fn set_alert_hit_rate_below(&mut self, value: Option<f64>) {
+        self.alert_hit_rate_below = value;
+    }
+    pub fn set_alert_evictions_above(&mut self, value: Option<f64>) {
+        self.alert_evictions_above = value;
+    }
+    pub fn set_alert_memory_above(&mut self, value: Option<f64>) {
+        self.alert_memory_above = value;
+    }
+    pub fn alert_notification_enabled(&self) -> bool {
+        self.alert_notification_enabled.unwrap_or(false)
+    }
+    pub fn set_alert_notification_enabled(&mut self, enabled: bool) {
+        self.alert_notification_enabled = Some(enabled);
+    }
+    pub fn mini_metrics_enabled(&self) -> bool {
+        self.mini_metrics_enabled.unwrap_or(true)
+    }
+    pub fn set_mini_metrics_enabled(&mut self, enabled: bool) {
+        self.mini_metrics_enabled = Some(enabled);
+    }
+    pub fn open_metrics_on_select(&self) -> bool {
+        self.open_metrics_on_select.unwrap_or(false)
+    }
+    pub fn set_open_metrics_on_select(&mut self, enabled: bool) {
+        self.open_metrics_on_select = Some(enabled);
+    }
+    /// Returns the route shown when a server is selected.
+    pub fn server_route(&self) -> Route {
+        if self.open_metrics_on_select() {
+            Route::Metrics
+        } else {
+            Route::Editor
+        }
+    }
+    /// Whether the key tree shows the OBJECT ENCODING of keys
+    pub fn key_encoding_enabled(&self) -> bool {
+        self.key_encoding_enabled.unwrap_or(false)
+    }
+    pub fn set_key_encoding_enabled(&mut self, enabled: bool) {
+        self.key_encoding_enabled = Some(enabled);
+    }
+    pub fn wait_replication_enabled(&self) -> bool {
+        self.wait_replication_enabled.unwrap_or(false)
+    }
+    pub fn set_wait_replication_enabled(&mut self, enabled: bool) {
+        self.wait_replication_enabled = Some(enabled);
+    }
+    /// Whether collection writes first check that the key still has the type of its open editor.
+    pub fn verify_key_type(&self) -> bool {
+        self.verify_key_type.unwrap_or(true)
+    }
+    pub fn set_verify_key_type(&mut self, enabled: bool) {
+        self.verify_key_type = Some(enabled);
+    }
+    pub fn wait_replicas(&self) -> usize {
+        self.wait_replicas.unwrap_or(1)
+    }
+    pub fn set_wait_replicas(&mut self, replicas: usize) {
+        self.wait_replicas = Some(replicas);
+    }
+    pub fn wait_timeout_ms(&self) -> u64 {
+        self.wait_timeout_ms.unwrap_or(1000).clamp(1, MAX_WAIT_TIMEOUT_MS)
+    }
+    /// Sets the WAIT timeout, at least 1ms as `WAIT n 0` blocks until the replicas acknowledge.
+    ///
+    /// Capped at `MAX_WAIT_TIMEOUT_MS`, WAIT holds the shared connection of the server.
+    pub fn set_wait_timeout_ms(&mut self, timeout: u64) {
+        self.wait_timeout_ms = Some(timeout.clamp(1, MAX_WAIT_TIMEOUT_MS));
+    }
+    pub fn clock_format(&self) -> ClockFormat {
+        self.clock_format.unwrap_or_default()
+    }
+    pub fn set_clock_format(&mut self, clock_format: ClockFormat) {
+        self.clock_format = Some(clock_format);
+    }
+    /// Whether metrics timestamps include the date when the history spans several days.
+    pub fn metrics_show_date(&self) -> bool {
+        self.metrics_show_date.unwrap_or(true)
+    }
+    pub fn set_metrics_show_date(&mut self, enabled: bool) {
+        self.metrics_show_date = Some(enabled);
+    }
+    /// Decimal places of the metrics chart labels, None keeps the per-chart defaults.
+    pub fn metrics_precision(&self) -> Option<usize> {
+        self.metrics_precision
+    }
+    pub fn set_metrics_precision(&mut self, precision: Option<usize>) {
+        self.metrics_precision = precision.map(|precision| precision.min(MAX_METRICS_PRECISION));
+    }
+    /// Whether timestamps (metrics, slow log, streams, ...) are shown in UTC instead of local time.
+    pub fn utc_time(&self) -> bool {
+        self.utc_time.unwrap_or(false)
+    }
+    pub fn set_utc_time(&mut self, enabled: bool) {
+        self.utc_time = Some(enabled);
+    }
+    /// Whether the key tree shows the SCAN debug panel (cursor, iterations, batch sizes).
+    pub fn scan_debug(&self) -> bool {
+        self.scan_debug.unwrap_or(false)
+    }
+    pub fn set_scan_debug(&mut self, enabled: bool) {
+        self.scan_debug = Some(enabled);
+    }
+    /// Whether every Redis command is logged with its duration (values redacted).
+    pub fn command_log(&self) -> bool {
+        self.command_log.unwrap_or(false)
+    }
+    pub fn set_command_log(&mut self, enabled: bool) {
+        set_command_log_enabled(enabled);
+        self.command_log = Some(enabled);
+    }
+    /// Whether collection tables show the byte length column of their values.
+    pub fn show_value_length(&self) -> bool {
+        self.show_value_length.unwrap_or(false)
+    }
+    pub fn set_show_value_length(&mut self, enabled: bool) {
+        self.show_value_length = Some(enabled);
+    }
+    /// Returns the user resized column widths of the value tables of a key type.
+    pub fn column_widths(&self, key_type: &str) -> Option<&HashMap<String, f32>> {
+        self.column_widths.as_ref()?.get(key_type)
+    }
+    /// Remembers the resized column widths of a key type, replacing the previous ones,
+    /// so the map never holds more than the columns of one table per key type.
+    pub fn set_column_widths(&mut self, key_type: String, widths: HashMap<String, f32>) {
+        self.column_widths.get_or_insert_default().insert(key_type, widths);
+    }
+    /// Returns the sort applied automatically to the loaded rows of a key type.
+    pub fn default_sort(&self, key_type: &str) -> DefaultSort {
+        self.default_sorts
+            .as_ref()
+            .and_then(|sorts| sorts.get(key_type).copied())
+            .unwrap_or_default()
+    }
+    pub fn set_default_sort(&mut self, key_type: String, sort: DefaultSort) {
+        self.default_sorts.get_or_insert_default().insert(key_type, sort);
+    }
+    /// Returns the comma separated list of commands that require confirmation in the console.
+    pub fn dangerous_commands(&self) -> &str {
+        self.dangerous_commands.as_deref().unwrap_or(DEFAULT_DANGEROUS_COMMANDS)
+    }
+    pub fn set_dangerous_commands(&mut self, commands: String) {
+        self.dangerous_commands = Some(commands);
+    }
+    pub fn client_name_template(&self) -> &str {
+        self.client_name_template
+            .as_deref()
+            .unwrap_or(DEFAULT_CLIENT_NAME_TEMPLATE)
+    }
+    /// Sets the name template of new connections, existing ones keep their name.
+    pub fn set_client_name_template(&mut self, template: String) {
+        set_client_name_template(&template);
+        self.client_name_template = Some(template);
+    }
+    /// Returns the matched entry if the command (split into parts) requires confirmation.
+    ///
+    /// Entries match the command name or the command with its subcommand (`CONFIG SET`).
+    pub fn dangerous_command(&self, parts: &[String]) -> Option<String> {
+        let name = parts.first()?;
+        // SCAN with a MATCH pattern is bounded enough to run without asking
+        if name.eq_ignore_ascii_case("SCAN") && parts.iter().skip(1).any(|arg| arg.eq_ignore_ascii_case("MATCH")) {
+            return None;
+        }
+        let list = CommandPolicy::parse_list(Some(self.dangerous_commands()));
+        CommandPolicy::find_match(&list, name, parts.get(1).map(String::as_str))
+    }
+    /// Returns `(numreplicas, timeout_ms)` for WAIT when write confirmation is enabled.
+    pub fn wait_replication(&self) -> Option<(usize, u64)> {
+        self.wait_replication_enabled()
+            .then(|| (self.wait_replicas(), self.wait_timeout_ms()))
+    }
     pub fn selected_server(&self) -> Option<&(String, usize)> {
         self.selected_server.as_ref()
     }
@@ -459,6 +828,59 @@ impl ZedisAppState {
         })
         .detach();
     }
+    /// Imports an exported config file: servers, session options and app settings.
+    ///
+    /// The window bounds, route and selected server of this machine are kept.
+    pub fn import_config_file(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        cx.spawn(async move |handle, cx| {
+            let result = cx.background_spawn(async move { import_config(&path).await }).await;
+            handle.update(cx, |this, cx| {
+                let (bundle, replaced) = match result {
+                    Ok(result) => result,
+                    Err(e) => {
+                        error!(error = %e, "Failed to import config");
+                        cx.emit(GlobalEvent::Notification(NotificationAction::new_error(
+                            e.to_string().into(),
+                        )));
+                        return;
+                    }
+                };
+                if let Some(app) = bundle.app {
+                    *this = Self {
+                        route: this.route,
+                        bounds: this.bounds,
+                        selected_server: this.selected_server.take(),
+                        ..app
+                    };
+                    this.apply_runtime_settings();
+                    let value = this.clone();
+                    cx.background_spawn(async move {
+                        if let Err(e) = save_app_state(&value) {
+                            error!(error = %e, "Failed to save imported settings");
+                        }
+                    })
+                    .detach();
+                }
+                let message = t!(
+                    "settings.import_config_success",
+                    servers = bundle.servers.len(),
+                    sessions = bundle.sessions.len(),
+                    locale = this.locale()
+                );
+                cx.emit(GlobalEvent::ServerListUpdated);
+                // The selected server reconnects with its imported config
+                for server_id in replaced {
+                    cx.emit(GlobalEvent::ServerUpdated(server_id.into()));
+                }
+                cx.emit(GlobalEvent::Notification(NotificationAction::new_success(
+                    message.into(),
+                )));
+                cx.refresh_windows();
+                cx.notify();
+            })
+        })
+        .detach();
+    }
     pub fn upsert_server(&mut self, mut server: RedisServer, cx: &mut Context<Self>) {
         if server.id.is_empty() {
             server.id = Uuid::now_v7().to_string();
@@ -472,15 +894,21 @@ impl ZedisAppState {
                     });
                 }
                 let mut servers = get_servers()?;
-                if let Some(existing_server) = servers.iter_mut().find(|s| s.id == server.id) {
-                    *existing_server = server;
+                let server_id = server.id.clone();
+                let previous = if let Some(existing_server) = servers.iter_mut().find(|s| s.id == server.id) {
+                    Some(std::mem::replace(existing_server, server))
                 } else {
                     servers.push(server);
-                }
+                    None
+                };
                 save_servers(servers.clone()).await?;
-                Ok(())
+                // Connections of the old config must not be reused
+                if let Some(previous) = &previous {
+                    get_connection_manager().remove_server_clients(previous);
+                }
+                Ok(previous.map(|_| server_id))
             });
-            let result: Result<()> = task.await;
+            let result: Result<Option<String>> = task.await;
 
             handle.update(cx, |_this, cx| {
                 if let Err(e) = &result {
@@ -491,6 +919,9 @@ impl ZedisAppState {
                     return;
                 }
                 cx.emit(GlobalEvent::ServerListUpdated);
+                if let Ok(Some(server_id)) = result {
+                    cx.emit(GlobalEvent::ServerUpdated(server_id.into()));
+                }
                 cx.notify();
             })
         })
@@ -547,3 +978,19 @@ pub fn dialog_button_props(cx: &App) -> DialogButtonProps {
         .cancel_text(i18n_common(cx, "cancel"))
         .ok_text(i18n_common(cx, "delete"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_rtl() {
+        let mut state = ZedisAppState::default();
+        assert!(!state.is_rtl());
+        state.locale = Some("he".to_string());
+        assert!(state.is_rtl());
+        state.locale = Some("en".to_string());
+        state.set_force_rtl(true);
+        assert!(state.is_rtl());
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{SessionOption, ZedisAppState, all_session_options, import_session_options};
+use crate::connection::{RedisServer, get_connection_manager, get_servers, save_servers};
+use crate::error::Error;
+use crate::helpers::{decrypt, encrypt};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Version of the exported file, bumped when the format changes incompatibly
+const CONFIG_BUNDLE_VERSION: u32 = 1;
+
+/// The whole Zedis setup in a single file, used to move it to another machine.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ZedisConfigBundle {
+    pub version: u32,
+    pub exported_at: Option<String>,
+    /// App settings, without the state bound to the exporting machine
+    pub app: Option<ZedisAppState>,
+    #[serde(default)]
+    pub servers: Vec<RedisServer>,
+    #[serde(default)]
+    pub sessions: Vec<SessionOption>,
+}
+
+/// Applies `f` to every secret of the server: password, ssh password and ssh key.
+fn map_secrets(server: &mut RedisServer, f: impl Fn(&String) -> Result<Option<String>>) -> Result<()> {
+    for secret in [&mut server.password, &mut server.ssh_password, &mut server.ssh_key] {
+        if let Some(value) = secret.as_ref() {
+            *secret = f(value)?;
+        }
+    }
+    Ok(())
+}
+
+/// Decrypts an imported secret, a secret that is not encrypted is kept as plain text.
+fn import_secret(value: &str) -> Result<Option<String>> {
+    Ok(Some(decrypt(value).unwrap_or_else(|_| value.to_string())))
+}
+
+/// Exports the servers, session options and app settings to `path`.
+///
+/// Secrets are left out unless `include_secrets` is set, in which case they are
+/// encrypted the same way as in the server config file.
+/// Returns the number of exported servers.
+pub fn export_config(path: &Path, app: &ZedisAppState, include_secrets: bool) -> Result<usize> {
+    let mut servers = get_servers()?;
+    for server in servers.iter_mut() {
+        if include_secrets {
+            map_secrets(server, |value| Ok(Some(encrypt(value)?)))?;
+        } else {
+            map_secrets(server, |_| Ok(None))?;
+        }
+    }
+    let count = servers.len();
+    let bundle = ZedisConfigBundle {
+        version: CONFIG_BUNDLE_VERSION,
+        exported_at: Some(Local::now().to_rfc3339()),
+        app: Some(app.portable()),
+        servers,
+        sessions: all_session_options()?,
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&bundle)?)?;
+    Ok(count)
+}
+
+/// Imports the servers and session options of an exported file.
+///
+/// Servers with the same id are replaced, keeping their current secrets if the
+/// file has none, other servers are added. The app settings are returned for
+/// the caller to apply, since they live in the global store, together with the
+/// ids of the replaced servers.
+pub async fn import_config(path: &Path) -> Result<(ZedisConfigBundle, Vec<String>)> {
+    let value = std::fs::read_to_string(path)?;
+    let bundle: ZedisConfigBundle = serde_json::from_str(&value)?;
+    if bundle.version > CONFIG_BUNDLE_VERSION {
+        return Err(Error::Invalid {
+            message: format!(
+                "Config file version {} is newer than the supported version {CONFIG_BUNDLE_VERSION}",
+                bundle.version
+            ),
+        });
+    }
+
+    let mut servers = get_servers()?;
+    let mut replaced = vec![];
+    for mut server in bundle.servers.iter().cloned() {
+        if server.id.is_empty() {
+            continue;
+        }
+        map_secrets(&mut server, |value| import_secret(value))?;
+        if let Some(existing) = servers.iter_mut().find(|item| item.id == server.id) {
+            server.password = server.password.or_else(|| existing.password.clone());
+            server.ssh_password = server.ssh_password.or_else(|| existing.ssh_password.clone());
+            server.ssh_key = server.ssh_key.or_else(|| existing.ssh_key.clone());
+            replaced.push(std::mem::replace(existing, server));
+        } else {
+            servers.push(server);
+        }
+    }
+    save_servers(servers).await?;
+    // Connections of the replaced configs must not be reused
+    for server in replaced.iter() {
+        get_connection_manager().remove_server_clients(server);
+    }
+
+    import_session_options(bundle.sessions.clone())?;
+    let replaced = replaced.into_iter().map(|server| server.id).collect();
+    Ok((bundle, replaced))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_plain_text_secrets() {
+        let mut server = RedisServer {
+            password: Some("password".to_string()),
+            ssh_password: Some("pwd".to_string()),
+            ssh_key: Some(encrypt("key").unwrap()),
+            ..Default::default()
+        };
+        map_secrets(&mut server, |value| import_secret(value)).unwrap();
+        assert_eq!(server.password.as_deref(), Some("password"));
+        assert_eq!(server.ssh_password.as_deref(), Some("pwd"));
+        assert_eq!(server.ssh_key.as_deref(), Some("key"));
+    }
+}
@@ -72,6 +72,11 @@ pub fn i18n_hash_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
     t!(format!("hash_editor.{key}"), locale = locale).into()
 }
 
+pub fn i18n_stream_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("stream_editor.{key}"), locale = locale).into()
+}
+
 pub fn i18n_settings<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("settings.{key}"), locale = locale).into()
@@ -116,3 +121,23 @@ pub fn i18n_memory_analysis<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("memory_analysis.{key}"), locale = locale).into()
 }
+
+pub fn i18n_hot_keys<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("hot_keys.{key}"), locale = locale).into()
+}
+
+pub fn i18n_ttl_histogram<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("ttl_histogram.{key}"), locale = locale).into()
+}
+
+pub fn i18n_value_diff<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("value_diff.{key}"), locale = locale).into()
+}
+
+pub fn i18n_about<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("about.{key}"), locale = locale).into()
+}
@@ -12,24 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::connection::{AccessMode, RedisClientDescription, SlowLogEntry, get_connection_manager, get_server};
+use crate::connection::{
+    AccessMode, AclPermissions, ClusterHealth, KeyMemoryUsage, NodeLatency, RedisClientDescription, SlowLogEntry,
+    get_connection_manager, get_server, set_server_password,
+};
 use crate::db::get_search_history_manager;
 use crate::error::Error;
-use crate::states::server::event::{ServerEvent, ServerTask};
-use crate::states::server::stat::{RedisInfo, get_metrics_cache};
-use crate::states::{QueryMode, get_session_option};
+use crate::helpers::{format_timestamp_ms, unix_ts_millis};
+use crate::states::server::event::{RunningTask, ServerEvent, ServerTask};
+use crate::states::server::stat::{MetricsAlert, RedisInfo, get_metrics_cache};
+use crate::states::{QueryMode, ZedisGlobalStore, get_session_option};
 use ahash::AHashMap;
 use ahash::AHashSet;
+use futures::future::{AbortHandle, Abortable};
+use gpui::App;
 use gpui::SharedString;
 use gpui::prelude::*;
 use parking_lot::RwLock;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info};
 use uuid::Uuid;
 use value::{KeyType, RedisValue, RedisValueData};
+use value_cache::ValueCache;
 
+pub mod backup;
+pub mod bulk;
+pub mod clients;
+pub mod diff;
 pub mod event;
 pub mod hash;
 pub mod key;
@@ -39,12 +50,29 @@ pub mod stat;
 pub mod stream;
 pub mod string;
 pub mod value;
+pub mod value_cache;
 pub mod zset;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
 // Constants for state management
 const MAX_ERROR_MESSAGES: usize = 10; // Maximum error messages to keep in memory
+const MAX_SCAN_ITERATIONS: usize = 500; // Maximum scan iterations kept for the debug panel
+const MAX_RECENT_KEYS: usize = 20; // Maximum recently viewed keys kept per server and db
+const MAX_AUTO_LOAD_DURATION: Duration = Duration::from_secs(2); // Time budget of automatic loading per user action
+
+/// A single SCAN round trip, recorded for the scan debug panel
+#[derive(Debug, Clone, Default)]
+pub struct ScanIteration {
+    /// Cursors returned by the iteration (one per node)
+    pub cursors: Vec<u64>,
+
+    /// Number of keys returned by the iteration
+    pub keys: usize,
+
+    /// Round trip time of the iteration
+    pub elapsed_ms: u64,
+}
 /// Error message with categorization and timestamp
 #[derive(Debug, Clone)]
 pub struct ErrorMessage {
@@ -53,6 +81,23 @@ pub struct ErrorMessage {
 
     /// Human-readable error message
     pub message: SharedString,
+
+    /// Multi-line report with the task, server, database, time and underlying error detail
+    pub details: SharedString,
+}
+
+/// Keys upserted by the latest key tree change, so the key tree view can apply
+/// them incrementally instead of rebuilding from every loaded key
+#[derive(Debug, Clone, Default)]
+pub struct KeyTreeDelta {
+    /// Key tree id before the change
+    pub from: SharedString,
+
+    /// Key tree id after the change
+    pub to: SharedString,
+
+    /// New keys and keys whose type was resolved
+    pub keys: Arc<Vec<(SharedString, KeyType)>>,
 }
 
 /// Redis server connection status
@@ -80,6 +125,28 @@ pub struct ZedisServerState {
     last_slow_logs_checked_at: i64,
     last_slow_log_count: usize,
     slow_logs: Vec<SlowLogEntry>,
+    /// Alerts active for the latest metrics sample
+    metrics_alerts: Vec<MetricsAlert>,
+    /// Random keys picked by the latest sampling, with their type, size and TTL
+    random_keys: Vec<KeyMemoryUsage>,
+    /// OBJECT ENCODING of the keys shown in the tree, fetched on demand
+    key_encodings: AHashMap<SharedString, SharedString>,
+    /// Dry run of the pending bulk operation
+    bulk_preview: Option<bulk::BulkPreview>,
+    /// Whether the bulk operation preview is being scanned
+    bulk_preview_loading: bool,
+    /// Whether random keys are being sampled
+    sampling_random_keys: bool,
+    /// Connections of the latest CLIENT LIST
+    clients: Vec<clients::ClientInfo>,
+    /// Whether the client list is being loaded
+    clients_loading: bool,
+    /// CLUSTER INFO while the cluster can not serve every slot
+    cluster_health: Option<ClusterHealth>,
+    /// Whether CLUSTER INFO is being read, concurrent CLUSTERDOWN errors wait for it
+    checking_cluster_health: bool,
+    /// Latency of every cluster node of the latest heartbeat
+    node_latencies: Vec<NodeLatency>,
 
     /// Whether the terminal is open
     terminal: bool,
@@ -90,6 +157,9 @@ pub struct ZedisServerState {
     /// Search history
     search_history: Vec<SharedString>,
 
+    /// Recently viewed keys of each server and db, newest first (kept across server switches)
+    recent_keys: AHashMap<(SharedString, usize), Vec<SharedString>>,
+
     /// Whether the server supports database selection
     supports_db_selection: bool,
 
@@ -99,12 +169,18 @@ pub struct ZedisServerState {
     /// Access mode
     access_mode: AccessMode,
 
+    /// Gated commands the ACL user may run, features that need a denied command are disabled
+    acl_permissions: AclPermissions,
+
     /// Query mode (All/Prefix/Exact) for key filtering
     query_mode: QueryMode,
 
     /// Whether to soft wrap the editor
     soft_wrap: bool,
 
+    /// Whether to show line numbers in the editor
+    line_numbers: bool,
+
     /// Current server status
     server_status: RedisServerStatus,
 
@@ -128,6 +204,39 @@ pub struct ZedisServerState {
     /// Value data for the currently selected key
     value: Option<RedisValue>,
 
+    /// Recently viewed values, so switching back to a key skips the first load
+    value_cache: ValueCache,
+    /// Fetch timestamp of the displayed value when it was served from the cache
+    value_cached_at: Option<i64>,
+    /// Unix timestamp of when the displayed value was fetched
+    value_fetched_at: i64,
+    /// Whether the displayed value was written to since it was fetched
+    value_dirty: bool,
+
+    /// Automatic load rounds of the current user action
+    value_auto_loads: usize,
+    /// When the current user action started loading
+    value_auto_load_started_at: Option<Instant>,
+    /// Whether automatic loading stopped at the round cap or time budget
+    value_scan_capped: bool,
+
+    /// Hash fields whose lazily loaded value is queued or being fetched
+    hash_value_requests: AHashSet<SharedString>,
+    /// Hash fields queued for the next HMGET batch
+    hash_value_queue: Vec<SharedString>,
+    /// Hash fields whose value failed to load, not requested again until the value is reloaded
+    hash_value_failed: AHashSet<SharedString>,
+
+    /// Whether new entries of the selected stream are being followed
+    stream_following: bool,
+    /// Generation of the stream follow loop, bumped on every start/stop
+    stream_follow_seq: u64,
+
+    /// Progress of the running database export or import
+    backup_progress: Option<backup::BackupProgress>,
+    /// Generation of the backup loop, bumped on every start/cancel
+    backup_seq: u64,
+
     // ===== Key scanning state =====
     /// Search keyword for filtering keys
     keyword: SharedString,
@@ -141,12 +250,24 @@ pub struct ZedisServerState {
     /// Whether the current scan has completed
     scan_completed: bool,
 
+    /// Whether the keys come from a pasted/loaded key list instead of SCAN
+    key_list_loaded: bool,
+
     /// Number of scan iterations performed
     scan_times: usize,
 
+    /// Recent SCAN round trips of the current scan (for the debug panel)
+    scan_iterations: Vec<ScanIteration>,
+
+    /// Total SCAN round trips of the current scan
+    scan_iteration_count: usize,
+
     /// Unique ID for current key tree (changes when keys are reloaded)
     key_tree_id: SharedString,
 
+    /// Keys changed by the latest key tree id change, if it only upserted keys
+    key_tree_delta: Option<KeyTreeDelta>,
+
     /// Set of prefixes that have been scanned (for lazy loading folders)
     loaded_prefixes: AHashSet<SharedString>,
 
@@ -156,6 +277,14 @@ pub struct ZedisServerState {
     // ===== Error tracking =====
     /// Recent error messages (limited to MAX_ERROR_MESSAGES)
     error_messages: Arc<RwLock<Vec<ErrorMessage>>>,
+    /// Whether the server rejected the stored credentials, pausing requests until re-authenticated
+    auth_required: bool,
+
+    // ===== Background tasks =====
+    /// Spawned tasks that have not finished yet, oldest first
+    running_tasks: Vec<RunningTask>,
+    /// Id of the latest spawned task
+    task_seq: u64,
 }
 
 impl ZedisServerState {
@@ -174,8 +303,12 @@ impl ZedisServerState {
         self.key_tree_id = Uuid::now_v7().to_string().into();
         self.scanning = false;
         self.scan_completed = false;
+        self.key_list_loaded = false;
         self.scan_times = 0;
+        self.scan_iterations.clear();
+        self.scan_iteration_count = 0;
         self.loaded_prefixes.clear();
+        self.key_encodings.clear();
         cx.emit(ServerEvent::KeyScanReset);
         cx.emit(ServerEvent::KeyTreeUpdated);
     }
@@ -197,6 +330,23 @@ impl ZedisServerState {
         self.last_slow_logs_checked_at = 0;
         self.last_slow_log_count = 0;
         self.slow_logs.clear();
+        self.metrics_alerts.clear();
+        self.random_keys.clear();
+        self.sampling_random_keys = false;
+        self.clients.clear();
+        self.clients_loading = false;
+        self.bulk_preview = None;
+        self.bulk_preview_loading = false;
+        self.cluster_health = None;
+        self.checking_cluster_health = false;
+        self.node_latencies.clear();
+        self.value_cache.clear();
+        self.value_cached_at = None;
+        self.auth_required = false;
+        self.acl_permissions = AclPermissions::default();
+        // Stop a running export/import of the previous server
+        self.backup_progress = None;
+        self.backup_seq += 1;
     }
 
     /// Add new keys with their types to the key map (deduplicating automatically)
@@ -205,33 +355,49 @@ impl ZedisServerState {
     fn extend_keys(&mut self, keys: Vec<(SharedString, SharedString)>) {
         self.keys.reserve(keys.len());
         let mut insert_count = 0;
+        let mut changed_keys = Vec::with_capacity(keys.len());
 
         for (key, key_type) in keys {
             let kt = KeyType::from(key_type.as_ref());
+            let changed_key = key.clone();
             self.keys
                 .entry(key)
                 .and_modify(|existing| {
                     if *existing == KeyType::Unknown && kt != KeyType::Unknown {
                         *existing = kt;
+                        changed_keys.push((changed_key.clone(), kt));
                     }
                 })
                 .or_insert_with(|| {
                     insert_count += 1;
+                    changed_keys.push((changed_key.clone(), kt));
                     kt
                 });
         }
 
         // Update tree ID only if new keys were added
         if insert_count != 0 {
-            self.key_tree_id = Uuid::now_v7().to_string().into();
+            self.upsert_key_tree(changed_keys);
         }
     }
 
+    /// Generates a new tree ID for keys that were only added or had their type resolved,
+    /// recording them so the key tree can update incrementally
+    fn upsert_key_tree(&mut self, keys: Vec<(SharedString, KeyType)>) {
+        let from = std::mem::take(&mut self.key_tree_id);
+        self.key_tree_id = Uuid::now_v7().to_string().into();
+        self.key_tree_delta = Some(KeyTreeDelta {
+            from,
+            to: self.key_tree_id.clone(),
+            keys: Arc::new(keys),
+        });
+    }
+
     /// Add an error message to the history and emit error event
     ///
     /// Maintains a rolling window of MAX_ERROR_MESSAGES most recent errors.
     /// Includes server_id and db context for easier debugging.
-    fn add_error_message(&mut self, category: String, message: String, cx: &mut Context<Self>) {
+    fn add_error_message(&mut self, category: String, message: String, detail: Option<String>, cx: &mut Context<Self>) {
         let mut guard = self.error_messages.write();
 
         // Remove oldest error if at capacity
@@ -246,22 +412,40 @@ impl ZedisServerState {
             format!("[{category}] [{server_name}:{}] {message}", self.db).into()
         };
 
+        let mut details = vec![format!("Task: {category}")];
+        if server_name.is_empty() {
+            details.push(format!("Server: {}", self.server_id));
+        } else {
+            details.push(format!("Server: {server_name} ({})", self.server_id));
+        }
+        details.push(format!("Database: {}", self.db));
+        if let Some(time) = format_timestamp_ms(unix_ts_millis(), "%Y-%m-%d %H:%M:%S %:z", false) {
+            details.push(format!("Time: {time}"));
+        }
+        details.push(format!("Error: {message}"));
+        if let Some(detail) = detail {
+            details.push(format!("Detail: {detail}"));
+        }
+
         let info = ErrorMessage {
             category: category.into(),
             message: context_message,
+            details: details.join("\n").into(),
         };
         guard.push(info.clone());
-        self.emit_error_notification(info.message.clone(), cx);
+        self.emit_error_notification_with_details(info.message.clone(), info.details.clone(), cx);
         cx.emit(ServerEvent::ErrorOccurred(info));
     }
     /// Spawn an async background task with error handling
     ///
     /// This is the core async task dispatcher that:
-    /// 1. Emits a Spawn event for UI feedback
+    /// 1. Emits a Spawn event for UI feedback and tracks the task as running
     /// 2. Runs the task in a background thread pool
     /// 3. Captures errors and adds them to error history
     /// 4. Calls the callback with the result
     ///
+    /// A cancelled task calls the callback with [`Error::Cancelled`], so it resets its state like on failure.
+    ///
     /// # Type Parameters
     /// * `T` - The success return type of the task
     /// * `Fut` - The future type returned by the task closure
@@ -283,17 +467,37 @@ impl ZedisServerState {
     {
         cx.emit(ServerEvent::TaskStarted(name.clone()));
         debug!(name = name.as_str(), "Spawning background task");
+        // The written key is captured now, the selection may change before the write finishes
+        let written_key = name.is_write().then(|| (self.db, self.key.clone()));
+        if let Some((db, key)) = written_key.as_ref() {
+            self.invalidate_written_values(&name, *db, key.as_ref());
+        }
         let server_id = self.server_id.clone();
         let start = Instant::now();
+        self.task_seq += 1;
+        let task_id = self.task_seq;
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        self.running_tasks
+            .push(RunningTask::new(task_id, name.clone(), abort_handle));
 
         cx.spawn(async move |handle, cx| {
             // Run task in background executor (thread pool)
-            let task = cx.background_spawn(async move { task().await });
+            let task = cx.background_spawn(async move {
+                Abortable::new(task(), abort_registration)
+                    .await
+                    .unwrap_or(Err(Error::Cancelled))
+            });
             let result: Result<T> = task.await;
 
             // Update state with result on main thread
             handle.update(cx, move |this, cx| {
-                if let Err(e) = &result {
+                this.running_tasks.retain(|item| item.id != task_id);
+                cx.emit(ServerEvent::TaskFinished(name.clone()));
+                if let Err(e) = &result
+                    && e.is_cancelled()
+                {
+                    info!(task = name.as_str(), server_id = server_id.as_str(), "Task cancelled");
+                } else if let Err(e) = &result {
                     error!(
                         task = name.as_str(),
                         server_id = server_id.as_str(),
@@ -302,11 +506,41 @@ impl ZedisServerState {
                     );
                     // only add error message if the server id is the same as the current server id
                     // ignore refresh redis info error
-                    if this.server_id == server_id && name != ServerTask::RefreshRedisInfo {
-                        this.add_error_message(name.as_str().to_string(), e.to_string(), cx);
+                    if this.server_id == server_id && e.is_auth_error() {
+                        this.require_auth(cx);
+                    } else if this.server_id == server_id && e.is_cluster_down() {
+                        // one banner for the whole cluster instead of an error per operation
+                        if this.cluster_health.is_none() && !this.checking_cluster_health {
+                            this.add_error_message(name.as_str().to_string(), e.to_string(), e.detail(), cx);
+                        }
+                        if name != ServerTask::CheckClusterHealth {
+                            this.check_cluster_health(cx);
+                        }
+                    } else if this.server_id == server_id
+                        && let Error::TypeMismatch { key, .. } = e
+                    {
+                        // ask to reload the value instead of reporting a confusing write error
+                        cx.emit(ServerEvent::KeyTypeChanged(key.clone().into()));
+                    } else if this.server_id == server_id && name != ServerTask::RefreshRedisInfo {
+                        this.add_error_message(name.as_str().to_string(), e.to_string(), e.detail(), cx);
                     }
                 }
+                let succeeded = result.is_ok();
+                // A value of the key loaded while the write was running may already be cached
+                if let Some((db, key)) = written_key.as_ref()
+                    && this.server_id == server_id
+                {
+                    this.invalidate_written_values(&name, *db, key.as_ref());
+                }
                 callback(this, result, cx);
+                // Confirm that the write reached the replicas when enabled
+                if succeeded
+                    && name.is_write()
+                    && this.server_id == server_id
+                    && let Some((replicas, timeout)) = cx.global::<ZedisGlobalStore>().read(cx).wait_replication()
+                {
+                    this.wait_replication(replicas, timeout, cx);
+                }
                 let latency = start.elapsed();
                 if name != ServerTask::RefreshRedisInfo {
                     info!(
@@ -321,6 +555,46 @@ impl ZedisServerState {
         .detach();
     }
 
+    /// Pauses requests and asks for a new password once the server rejects the stored credentials,
+    /// instead of reconnecting with the same password over and over.
+    fn require_auth(&mut self, cx: &mut Context<Self>) {
+        if self.auth_required {
+            return;
+        }
+        self.auth_required = true;
+        get_connection_manager().remove_client(&self.server_id, self.db);
+        cx.emit(ServerEvent::AuthRequired(self.server_id.clone()));
+    }
+    /// Reconnects to the current server with a new password.
+    ///
+    /// The password replaces the stored one for this session, and is also saved to the
+    /// server config when `save` is set.
+    pub fn reauthenticate(&mut self, password: String, save: bool, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        if server_id.is_empty() {
+            return;
+        }
+        let db = self.db;
+        let password = Some(password).filter(|password| !password.is_empty());
+        // The client cache is keyed by a hash of the config, so drop it before the password changes
+        get_connection_manager().remove_client(&server_id, db);
+        set_server_password(&server_id, password);
+        if save && let Ok(server) = get_server(&server_id) {
+            info!(server_id = server_id.as_str(), "Saving the new password");
+            // Saving emits `ServerUpdated`, which reconnects the server with the new config
+            cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                state.upsert_server(server, cx);
+            });
+            return;
+        }
+        info!(server_id = server_id.as_str(), "Reconnecting with a new password");
+        self.reset(cx);
+        self.select(server_id, db, cx);
+    }
+    pub fn auth_required(&self) -> bool {
+        self.auth_required
+    }
+
     fn try_get_mut_key_value(&mut self) -> Option<(SharedString, &mut RedisValue)> {
         let key = self.key.as_ref().filter(|k| !k.is_empty())?.clone();
         let value = self.value.as_mut()?;
@@ -351,6 +625,88 @@ impl ZedisServerState {
         &self.key_tree_id
     }
 
+    /// Fetch timestamp of the displayed value if it was served from the value cache
+    pub fn value_cached_at(&self) -> Option<i64> {
+        self.value_cached_at
+    }
+
+    /// Whether automatic loading stopped at the round cap or time budget before a page was filled.
+    pub fn value_scan_capped(&self) -> bool {
+        self.value_scan_capped
+    }
+
+    /// Starts counting automatic load rounds for a new user action.
+    fn reset_auto_load(&mut self) {
+        self.value_auto_loads = 0;
+        self.value_auto_load_started_at = Some(Instant::now());
+        self.value_scan_capped = false;
+    }
+
+    /// Counts an automatic load round, returning false once the cap or time budget is reached.
+    ///
+    /// Some proxies and forks ignore the COUNT hint and return tiny batches, and
+    /// filtering a huge sparse collection may take many rounds, so the auto-load
+    /// loops must not spin unbounded. The round cap is the `max_filter_scan_pages` setting.
+    fn next_auto_load(&mut self, cx: &App) -> bool {
+        self.value_auto_loads += 1;
+        let max_rounds = cx.global::<ZedisGlobalStore>().read(cx).max_filter_scan_pages();
+        let over_budget = self
+            .value_auto_load_started_at
+            .is_some_and(|started_at| started_at.elapsed() >= MAX_AUTO_LOAD_DURATION);
+        if self.value_auto_loads >= max_rounds || over_budget {
+            self.value_scan_capped = true;
+            return false;
+        }
+        true
+    }
+
+    /// Drops every cached value, e.g. after commands with unknown targets ran in the console
+    pub fn invalidate_value_cache(&mut self) {
+        self.value_cache.clear();
+    }
+
+    /// Drops cached values a write task on `key` (the key selected when it was spawned) may change
+    fn invalidate_written_values(&mut self, task: &ServerTask, db: usize, key: Option<&SharedString>) {
+        match task {
+            // Deletions may target keys other than the displayed one
+            ServerTask::DeleteKey | ServerTask::DeleteKeys => self.value_cache.clear(),
+            // A console transaction may write any key, including the displayed one
+            ServerTask::ExecTransaction => {
+                self.value_cache.clear();
+                if self.db == db {
+                    self.value_dirty = true;
+                }
+            }
+            _ => {
+                if let Some(key) = key {
+                    self.value_cache.remove(db, key);
+                }
+                if self.db == db && self.key.as_ref() == key {
+                    self.value_dirty = true;
+                }
+            }
+        }
+    }
+
+    /// Caches the displayed value before another key is selected, unless it was written to
+    fn cache_current_value(&mut self) {
+        if self.value_dirty {
+            return;
+        }
+        if let (Some(key), Some(value)) = (self.key.clone(), self.value.as_ref())
+            && !key.is_empty()
+            && !value.is_busy()
+        {
+            let cached_at = self.value_cached_at.unwrap_or(self.value_fetched_at);
+            self.value_cache.insert(self.db, key, value, cached_at);
+        }
+    }
+
+    /// Get the keys changed by the latest key tree update, if it only upserted keys
+    pub fn key_tree_delta(&self) -> Option<&KeyTreeDelta> {
+        self.key_tree_delta.as_ref()
+    }
+
     /// Get the search history
     pub fn search_history(&self) -> Vec<SharedString> {
         self.search_history.clone()
@@ -360,6 +716,84 @@ impl ZedisServerState {
         self.search_history.clear();
     }
 
+    /// Get the dry run of the pending bulk operation
+    pub fn bulk_preview(&self) -> Option<&bulk::BulkPreview> {
+        self.bulk_preview.as_ref()
+    }
+    /// Get whether the bulk operation preview is being scanned
+    pub fn bulk_preview_loading(&self) -> bool {
+        self.bulk_preview_loading
+    }
+
+    /// Get the OBJECT ENCODING of the keys fetched so far
+    pub fn key_encodings(&self) -> &AHashMap<SharedString, SharedString> {
+        &self.key_encodings
+    }
+
+    /// Get the recently viewed keys of the current server and db, newest first
+    pub fn recent_keys(&self) -> Vec<SharedString> {
+        self.recent_keys
+            .get(&(self.server_id.clone(), self.db))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Moves the key to the front of the recently viewed keys
+    fn record_recent_key(&mut self, key: SharedString) {
+        let keys = self.recent_keys.entry((self.server_id.clone(), self.db)).or_default();
+        keys.retain(|item| item != &key);
+        keys.insert(0, key);
+        keys.truncate(MAX_RECENT_KEYS);
+    }
+
+    pub fn clear_recent_keys(&mut self, _cx: &mut Context<Self>) {
+        self.recent_keys.remove(&(self.server_id.clone(), self.db));
+    }
+
+    /// Get the connections of the latest CLIENT LIST
+    pub fn clients(&self) -> &[clients::ClientInfo] {
+        &self.clients
+    }
+    /// Get whether the client list is being loaded
+    pub fn clients_loading(&self) -> bool {
+        self.clients_loading
+    }
+    /// Get the latency of every cluster node, empty for a single server
+    pub fn node_latencies(&self) -> &[NodeLatency] {
+        &self.node_latencies
+    }
+    /// Get the cluster health, only set while the cluster is down
+    pub fn cluster_health(&self) -> Option<&ClusterHealth> {
+        self.cluster_health.as_ref()
+    }
+    /// Get the spawned tasks that have not finished yet, oldest first
+    pub fn running_tasks(&self) -> &[RunningTask] {
+        &self.running_tasks
+    }
+    /// Cancels a running task, writes can not be cancelled.
+    pub fn cancel_task(&mut self, id: u64) {
+        if let Some(item) = self
+            .running_tasks
+            .iter()
+            .find(|item| item.id == id && item.task.is_cancellable())
+        {
+            item.abort();
+        }
+    }
+    /// Get the random keys picked by the latest sampling
+    pub fn random_keys(&self) -> &[KeyMemoryUsage] {
+        &self.random_keys
+    }
+    /// Get whether random keys are being sampled
+    pub fn sampling_random_keys(&self) -> bool {
+        self.sampling_random_keys
+    }
+
+    /// Get the commands the ACL user of the connection may run
+    pub fn acl_permissions(&self) -> &AclPermissions {
+        &self.acl_permissions
+    }
+
     /// Get whether the server is readonly
     pub fn readonly(&self) -> bool {
         matches!(self.access_mode, AccessMode::StrictReadOnly | AccessMode::SafeMode)
@@ -369,6 +803,7 @@ impl ZedisServerState {
             self.add_error_message(
                 "toggle_readonly".to_string(),
                 "Strict read-only mode, cannot be toggled".to_string(),
+                None,
                 cx,
             );
             return;
@@ -390,6 +825,11 @@ impl ZedisServerState {
         self.soft_wrap = soft_wrap;
         cx.emit(ServerEvent::SoftWrapToggled(self.soft_wrap));
     }
+    /// Set whether to show line numbers in the editor
+    pub fn set_line_numbers(&mut self, line_numbers: bool, cx: &mut Context<Self>) {
+        self.line_numbers = line_numbers;
+        cx.emit(ServerEvent::LineNumbersToggled(self.line_numbers));
+    }
     /// Get the current query mode (All/Prefix/Exact)
     pub fn query_mode(&self) -> QueryMode {
         self.query_mode
@@ -400,6 +840,11 @@ impl ZedisServerState {
         self.scan_completed
     }
 
+    /// Check if the keys come from a loaded key list
+    pub fn key_list_loaded(&self) -> bool {
+        self.key_list_loaded
+    }
+
     /// Check if a scan is currently in progress
     pub fn scanning(&self) -> bool {
         self.scanning
@@ -415,6 +860,34 @@ impl ZedisServerState {
         self.keys.len()
     }
 
+    /// Get the cursors of the next SCAN iteration (`None` when not scanning or completed)
+    pub fn scan_cursors(&self) -> Option<&[u64]> {
+        self.cursors.as_deref()
+    }
+
+    /// Get the recent SCAN round trips of the current scan
+    pub fn scan_iterations(&self) -> &[ScanIteration] {
+        &self.scan_iterations
+    }
+
+    /// Get the total number of SCAN round trips of the current scan
+    pub fn scan_iteration_count(&self) -> usize {
+        self.scan_iteration_count
+    }
+
+    /// Records a SCAN round trip, keeping only the most recent ones
+    fn record_scan_iteration(&mut self, iteration: ScanIteration) {
+        if self.scan_iterations.len() >= MAX_SCAN_ITERATIONS {
+            self.scan_iterations.remove(0);
+        }
+        self.scan_iterations.push(iteration);
+        self.scan_iteration_count += 1;
+    }
+
+    /// Get the metrics alert thresholds crossed by the latest heartbeat
+    pub fn metrics_alerts(&self) -> &[MetricsAlert] {
+        &self.metrics_alerts
+    }
     /// Get the last measured latency to the server
     pub fn redis_info(&self) -> Option<&RedisInfo> {
         self.redis_info.as_ref()
@@ -437,12 +910,53 @@ impl ZedisServerState {
     pub fn nodes_description(&self) -> Arc<RedisClientDescription> {
         self.nodes_description.clone()
     }
+    /// Whether the server can report per-key encodings (OBJECT ENCODING).
+    pub fn supports_key_encoding(&self) -> bool {
+        self.nodes_description.flavor.supports_command("OBJECT ENCODING")
+    }
 
     /// Get the Redis server version string
     pub fn version(&self) -> &str {
         &self.version
     }
 
+    /// Builds a plain text summary of the connection for bug reports.
+    ///
+    /// Only server facts from INFO are included, the host and credentials are never part of it.
+    pub fn connection_summary(&self) -> String {
+        let format_bytes =
+            |bytes: u64| humansize::format_size(bytes, humansize::FormatSizeOptions::default().decimal_places(1));
+        let description = self.nodes_description();
+        let (master_nodes, nodes) = self.nodes();
+        let mut lines = vec![
+            format!("Zedis: v{}", env!("CARGO_PKG_VERSION")),
+            format!("Server: {} v{}", description.flavor.as_str(), self.version),
+            format!("Mode: {}", description.server_type),
+            format!("Nodes: {master_nodes} master / {nodes} total"),
+        ];
+        if let Some(info) = self.redis_info() {
+            let meta = &info.meta;
+            let maxmemory = if meta.maxmemory > 0 {
+                format_bytes(meta.maxmemory)
+            } else {
+                "unlimited".to_string()
+            };
+            lines.extend([
+                format!("OS: {}", meta.os),
+                format!("Role: {}", meta.role),
+                format!(
+                    "Uptime: {}",
+                    humantime::format_duration(std::time::Duration::from_secs(meta.uptime_in_seconds))
+                ),
+                format!("Maxmemory: {maxmemory} ({})", meta.maxmemory_policy),
+                format!("Used memory: {}", format_bytes(info.metrics.used_memory)),
+            ]);
+        }
+        if let Some(dbsize) = self.dbsize {
+            lines.push(format!("DB{} keys: {dbsize}", self.db));
+        }
+        lines.join("\n")
+    }
     /// Get the currently selected server id
     pub fn server_id(&self) -> &str {
         &self.server_id
@@ -461,6 +975,11 @@ impl ZedisServerState {
         self.soft_wrap
     }
 
+    /// Get whether to show line numbers in the editor
+    pub fn line_numbers(&self) -> bool {
+        self.line_numbers
+    }
+
     /// Get the currently selected key name
     pub fn key(&self) -> Option<SharedString> {
         self.key.clone()
@@ -479,6 +998,21 @@ impl ZedisServerState {
         self.search_history = history;
     }
 
+    /// Reconnect to the selected server, e.g. after its config was edited
+    ///
+    /// The clients of the old config are already dropped, so the metadata is
+    /// loaded again from a new connection.
+    pub fn reconnect(&mut self, cx: &mut Context<Self>) {
+        if self.server_id.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        // Forget the selection so that `select` doesn't skip the same server
+        self.server_id = SharedString::default();
+        self.select(server_id, db, cx);
+    }
+
     /// Select and connect to a Redis server
     ///
     /// This initiates a connection and loads server metadata:
@@ -501,7 +1035,7 @@ impl ZedisServerState {
             self.server_id = server_id.clone();
             self.db = db;
 
-            let (query_mode, soft_wrap) = get_session_option(&server_id)
+            let (query_mode, soft_wrap, line_numbers) = get_session_option(&server_id)
                 .map(|option| {
                     let mode = option
                         .query_mode
@@ -510,13 +1044,15 @@ impl ZedisServerState {
                         .unwrap_or_default();
 
                     let wrap = option.soft_wrap.unwrap_or(true);
+                    let line_numbers = option.line_numbers.unwrap_or(true);
 
                     // 返回一个元组，包含所有需要更新的值
-                    (mode, wrap)
+                    (mode, wrap, line_numbers)
                 })
-                .unwrap_or((QueryMode::All, true));
+                .unwrap_or((QueryMode::All, true, true));
             self.query_mode = query_mode;
             self.soft_wrap = soft_wrap;
+            self.line_numbers = line_numbers;
 
             debug!(server_id = self.server_id.as_str(), "Selecting server");
             let search_history_manager = get_search_history_manager();
@@ -545,12 +1081,18 @@ impl ZedisServerState {
                     let client = get_connection_manager().get_client(&server_id_clone, db).await?;
 
                     // Gather server metadata
-                    let dbsize = client.dbsize().await?;
+                    // restricted users may not run DBSIZE, the key count is then unknown
+                    let dbsize = match client.dbsize().await {
+                        Ok(dbsize) => Some(dbsize),
+                        Err(e) if e.is_no_permission() => None,
+                        Err(e) => return Err(e),
+                    };
                     let version = client.version().to_string();
                     let nodes = client.nodes();
                     let nodes_description = client.nodes_description();
                     let supports_db_selection = client.supports_db_selection();
                     let access_mode = client.access_mode();
+                    let acl_permissions = client.acl_permissions();
                     Ok((
                         dbsize,
                         nodes,
@@ -558,6 +1100,7 @@ impl ZedisServerState {
                         version,
                         supports_db_selection,
                         access_mode,
+                        acl_permissions,
                     ))
                 },
                 move |this, result, cx| {
@@ -567,14 +1110,23 @@ impl ZedisServerState {
                     }
 
                     // Update metadata if successful
-                    if let Ok((dbsize, nodes, nodes_description, version, supports_db_selection, access_mode)) = result
+                    if let Ok((
+                        dbsize,
+                        nodes,
+                        nodes_description,
+                        version,
+                        supports_db_selection,
+                        access_mode,
+                        acl_permissions,
+                    )) = result
                     {
-                        this.dbsize = Some(dbsize);
+                        this.dbsize = dbsize;
                         this.nodes = nodes;
                         this.nodes_description = Arc::new(nodes_description);
                         this.version = version.into();
                         this.supports_db_selection = supports_db_selection;
                         this.access_mode = access_mode;
+                        this.acl_permissions = acl_permissions;
                     };
 
                     let server_id = this.server_id.clone();
@@ -0,0 +1,394 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Whole-database backup and restore.
+//!
+//! Export SCANs every key, serializes it with `DUMP` and its `PTTL`, and
+//! appends the records to an archive file. Import reads the archive back
+//! and `RESTORE`s each key. Unlike `BGSAVE`, no server filesystem access is needed.
+
+use super::{ServerEvent, ZedisServerState};
+use crate::{
+    connection::{DumpedKey, get_connection_manager},
+    error::Error,
+    states::ZedisGlobalStore,
+};
+use gpui::{SharedString, prelude::*};
+use rust_i18n::t;
+use smol::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use tracing::{error, info};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Header of the archive file, bumped when the record layout changes
+const BACKUP_MAGIC: &[u8] = b"ZEDISDB1";
+/// Keys scanned per node and batch on export
+const EXPORT_SCAN_COUNT: u64 = 500;
+/// Keys restored per batch on import, the archive is read one batch at a time
+const IMPORT_BATCH_SIZE: usize = 500;
+/// Failed keys listed in the import report, the others are only counted
+const IMPORT_FAILED_REPORT_LIMIT: usize = 100;
+/// Largest key length accepted from an archive
+const MAX_BACKUP_KEY_LEN: usize = 1024 * 1024;
+/// Largest dumped value accepted from an archive, Redis's own bulk string limit
+const MAX_BACKUP_DATA_LEN: usize = 512 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackupKind {
+    Export,
+    Import,
+}
+
+/// Progress of the running export or import.
+#[derive(Debug, Clone)]
+pub struct BackupProgress {
+    pub kind: BackupKind,
+    /// Keys written to or restored from the archive so far
+    pub processed: usize,
+    /// Expected key count, the db size on export
+    pub total: Option<u64>,
+}
+
+/// Appends one record: key length, key, pttl, data length, data (big-endian).
+fn encode_dump(buf: &mut Vec<u8>, dump: &DumpedKey) {
+    buf.extend_from_slice(&(dump.key.len() as u32).to_be_bytes());
+    buf.extend_from_slice(&dump.key);
+    buf.extend_from_slice(&dump.pttl.to_be_bytes());
+    buf.extend_from_slice(&(dump.data.len() as u32).to_be_bytes());
+    buf.extend_from_slice(&dump.data);
+}
+
+fn invalid_backup_file() -> Error {
+    Error::Invalid {
+        message: "Invalid backup file".to_string(),
+    }
+}
+
+/// Fills `buf` from the reader, false if the file ended before the first byte.
+async fn read_full<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            if filled == 0 {
+                return Ok(false);
+            }
+            return Err(invalid_backup_file());
+        }
+        filled += n;
+    }
+    Ok(true)
+}
+
+async fn read_u32<R: AsyncRead + Unpin>(reader: &mut R) -> Result<usize> {
+    let mut buf = [0; 4];
+    if !read_full(reader, &mut buf).await? {
+        return Err(invalid_backup_file());
+    }
+    Ok(u32::from_be_bytes(buf) as usize)
+}
+
+/// Reads `len` bytes, a length above `max` is rejected before allocating.
+async fn read_vec<R: AsyncRead + Unpin>(reader: &mut R, len: usize, max: usize) -> Result<Vec<u8>> {
+    if len > max {
+        return Err(invalid_backup_file());
+    }
+    let mut buf = vec![0; len];
+    if len > 0 && !read_full(reader, &mut buf).await? {
+        return Err(invalid_backup_file());
+    }
+    Ok(buf)
+}
+
+/// Checks the header of an archive file.
+async fn read_magic<R: AsyncRead + Unpin>(reader: &mut R) -> Result<()> {
+    let mut magic = [0; BACKUP_MAGIC.len()];
+    if !read_full(reader, &mut magic).await? || magic != BACKUP_MAGIC {
+        return Err(invalid_backup_file());
+    }
+    Ok(())
+}
+
+/// Reads the next record of an archive file, `None` at its end.
+async fn read_dump<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<DumpedKey>> {
+    let mut key_len = [0; 4];
+    if !read_full(reader, &mut key_len).await? {
+        return Ok(None);
+    }
+    let key = read_vec(reader, u32::from_be_bytes(key_len) as usize, MAX_BACKUP_KEY_LEN).await?;
+    let mut pttl = [0; 8];
+    if !read_full(reader, &mut pttl).await? {
+        return Err(invalid_backup_file());
+    }
+    let data_len = read_u32(reader).await?;
+    let data = read_vec(reader, data_len, MAX_BACKUP_DATA_LEN).await?;
+    Ok(Some(DumpedKey {
+        key,
+        pttl: i64::from_be_bytes(pttl),
+        data,
+    }))
+}
+
+/// The archive is written next to the target and renamed once complete.
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+impl ZedisServerState {
+    pub fn backup_progress(&self) -> Option<&BackupProgress> {
+        self.backup_progress.as_ref()
+    }
+
+    /// Stops the running export or import after its current batch.
+    pub fn cancel_backup(&mut self, cx: &mut Context<Self>) {
+        if self.backup_progress.take().is_none() {
+            return;
+        }
+        self.backup_seq += 1;
+        cx.emit(ServerEvent::BackupProgressUpdated);
+        cx.notify();
+    }
+
+    /// Bumps the progress of the backup with generation `seq`, false if it was cancelled.
+    fn update_backup_progress(&mut self, seq: u64, processed: usize, cx: &mut Context<Self>) -> bool {
+        if self.backup_seq != seq {
+            return false;
+        }
+        let Some(progress) = self.backup_progress.as_mut() else {
+            return false;
+        };
+        progress.processed += processed;
+        cx.emit(ServerEvent::BackupProgressUpdated);
+        cx.notify();
+        true
+    }
+
+    fn finish_backup(&mut self, seq: u64, message: Result<SharedString>, cx: &mut Context<Self>) {
+        if self.backup_seq != seq {
+            return;
+        }
+        self.backup_progress = None;
+        cx.emit(ServerEvent::BackupProgressUpdated);
+        match message {
+            Ok(message) => self.emit_success_notification(message, "BACKUP".into(), cx),
+            Err(e) => {
+                error!(error = %e, "Database backup failed");
+                self.emit_error_notification(e.to_string().into(), cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Exports every key of the current database to an archive file at `path`.
+    ///
+    /// Keys are scanned in batches, each dumped with its remaining TTL.
+    /// Cancelling leaves no file behind.
+    pub fn export_database(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        if self.backup_progress.is_some() {
+            return;
+        }
+        self.backup_seq += 1;
+        let seq = self.backup_seq;
+        self.backup_progress = Some(BackupProgress {
+            kind: BackupKind::Export,
+            processed: 0,
+            total: self.dbsize,
+        });
+        cx.emit(ServerEvent::BackupProgressUpdated);
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        info!(server_id = server_id.as_str(), path = %path.display(), "Export database");
+
+        cx.spawn(async move |handle, cx| {
+            let part_path = partial_path(&path);
+            let result = async {
+                let file = smol::fs::File::create(&part_path).await?;
+                let mut writer = BufWriter::new(file);
+                writer.write_all(BACKUP_MAGIC).await?;
+                let mut cursors: Option<Vec<u64>> = None;
+                let mut exported = 0;
+                loop {
+                    let (new_cursors, dumps) = cx
+                        .background_spawn({
+                            let server_id = server_id.clone();
+                            let cursors = cursors.clone();
+                            async move {
+                                let client = get_connection_manager().get_client(&server_id, db).await?;
+                                client.dump_scan(EXPORT_SCAN_COUNT, cursors).await
+                            }
+                        })
+                        .await?;
+                    let mut buf = Vec::new();
+                    for dump in &dumps {
+                        encode_dump(&mut buf, dump);
+                    }
+                    writer.write_all(&buf).await?;
+                    exported += dumps.len();
+
+                    let running = handle
+                        .update(cx, |this, cx| this.update_backup_progress(seq, dumps.len(), cx))
+                        .unwrap_or(false);
+                    if !running {
+                        return Ok(None);
+                    }
+                    if new_cursors.iter().all(|cursor| *cursor == 0) {
+                        break;
+                    }
+                    cursors = Some(new_cursors);
+                }
+                writer.flush().await?;
+                smol::fs::rename(&part_path, &path).await?;
+                Ok::<Option<usize>, Error>(Some(exported))
+            }
+            .await;
+
+            let message = match result {
+                Ok(Some(count)) => Ok(t!(
+                    "key_tree.export_database_success",
+                    count = count,
+                    path = path.display().to_string(),
+                    locale = &locale
+                )
+                .into()),
+                // Cancelled
+                Ok(None) => {
+                    let _ = smol::fs::remove_file(&part_path).await;
+                    return;
+                }
+                Err(e) => {
+                    let _ = smol::fs::remove_file(&part_path).await;
+                    Err(e)
+                }
+            };
+            let _ = handle.update(cx, |this, cx| {
+                this.finish_backup(seq, message, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// Restores every key of the archive file at `path` into the current database.
+    ///
+    /// The archive is streamed and restored in bounded batches. Keys that already exist are
+    /// skipped and counted, keys that fail are reported, then the key tree is rescanned.
+    pub fn import_database(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        if self.backup_progress.is_some() || self.readonly() {
+            return;
+        }
+        self.backup_seq += 1;
+        let seq = self.backup_seq;
+        self.backup_progress = Some(BackupProgress {
+            kind: BackupKind::Import,
+            processed: 0,
+            total: None,
+        });
+        cx.emit(ServerEvent::BackupProgressUpdated);
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        info!(server_id = server_id.as_str(), path = %path.display(), "Import database");
+
+        cx.spawn(async move |handle, cx| {
+            let result = async {
+                let file = smol::fs::File::open(&path).await?;
+                let mut reader = BufReader::new(file);
+                read_magic(&mut reader).await?;
+
+                let mut restored = 0;
+                let mut skipped = 0;
+                let mut failed_count = 0;
+                let mut failed = vec![];
+                loop {
+                    let mut batch = Vec::with_capacity(IMPORT_BATCH_SIZE);
+                    while batch.len() < IMPORT_BATCH_SIZE
+                        && let Some(dump) = read_dump(&mut reader).await?
+                    {
+                        batch.push(dump);
+                    }
+                    let count = batch.len();
+                    if count == 0 {
+                        break;
+                    }
+                    let (batch_restored, batch_skipped, batch_failed) = cx
+                        .background_spawn({
+                            let server_id = server_id.clone();
+                            async move {
+                                let client = get_connection_manager().get_client(&server_id, db).await?;
+                                client.restore_dumps(&batch).await
+                            }
+                        })
+                        .await?;
+                    restored += batch_restored;
+                    skipped += batch_skipped;
+                    failed_count += batch_failed.len();
+                    let room = IMPORT_FAILED_REPORT_LIMIT.saturating_sub(failed.len());
+                    failed.extend(batch_failed.into_iter().take(room));
+                    let running = handle
+                        .update(cx, |this, cx| this.update_backup_progress(seq, count, cx))
+                        .unwrap_or(false);
+                    if !running {
+                        return Ok(None);
+                    }
+                    if count < IMPORT_BATCH_SIZE {
+                        break;
+                    }
+                }
+                Ok::<Option<(usize, usize, usize, Vec<String>)>, Error>(Some((restored, skipped, failed_count, failed)))
+            }
+            .await;
+
+            let (message, failed_count, failed) = match result {
+                Ok(Some((restored, skipped, failed_count, failed))) => (
+                    Ok(t!(
+                        "key_tree.import_database_success",
+                        restored = restored,
+                        skipped = skipped,
+                        locale = &locale
+                    )
+                    .into()),
+                    failed_count,
+                    failed,
+                ),
+                Ok(None) => return,
+                Err(e) => (Err(e), 0, vec![]),
+            };
+            let _ = handle.update(cx, |this, cx| {
+                let succeeded = message.is_ok();
+                this.finish_backup(seq, message, cx);
+                if failed_count > 0 {
+                    error!(count = failed_count, "Some keys failed to restore");
+                    let message = t!(
+                        "key_tree.import_database_failed",
+                        count = failed_count,
+                        locale = &locale
+                    );
+                    let details = failed.join("\n");
+                    this.emit_error_notification_with_details(message.into(), details.into(), cx);
+                }
+                if succeeded {
+                    this.scan(this.keyword.clone(), cx);
+                }
+            });
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ServerEvent, ServerTask, ZedisServerState};
+use crate::connection::get_connection_manager;
+use gpui::{Context, SharedString};
+
+/// SCAN COUNT of pattern based bulk operations
+pub(super) const BULK_SCAN_COUNT: u64 = 10_000;
+/// SCAN round trips of pattern based bulk operations, the preview stops at the same point
+pub(super) const BULK_SCAN_ROUNDS: usize = 20;
+/// Sample keys shown before a bulk operation is confirmed
+pub const BULK_PREVIEW_LIMIT: usize = 50;
+
+/// Dry run of a pattern based bulk operation.
+#[derive(Clone, Debug, Default)]
+pub struct BulkPreview {
+    pub pattern: SharedString,
+    /// The first matching keys
+    pub keys: Vec<SharedString>,
+    /// Number of matching keys the operation will reach
+    pub count: usize,
+    /// Whether the scan finished, otherwise more keys may match than are affected
+    pub complete: bool,
+}
+
+impl ZedisServerState {
+    /// Scans the keys matching the pattern exactly like the bulk operations do,
+    /// keeping the first `limit` keys and the total count.
+    pub fn preview_matching(&mut self, pattern: SharedString, limit: usize, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.bulk_preview = None;
+        self.bulk_preview_loading = true;
+        cx.notify();
+        self.spawn(
+            ServerTask::PreviewMatching,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                let mut preview = BulkPreview {
+                    pattern: pattern.clone(),
+                    ..Default::default()
+                };
+                let mut cursors: Option<Vec<u64>> = None;
+                for _ in 0..BULK_SCAN_ROUNDS {
+                    let (new_cursors, keys_per_node) = client.scan_nodes(cursors, &pattern, BULK_SCAN_COUNT).await?;
+                    for key in keys_per_node.into_iter().flatten() {
+                        if preview.keys.len() < limit {
+                            preview.keys.push(key);
+                        }
+                        preview.count += 1;
+                    }
+                    if new_cursors.iter().sum::<u64>() == 0 {
+                        preview.complete = true;
+                        break;
+                    }
+                    cursors = Some(new_cursors);
+                }
+                preview.keys.sort_unstable();
+                Ok((server_id, preview))
+            },
+            move |this, result, cx| {
+                this.bulk_preview_loading = false;
+                if let Ok((server_id, preview)) = result
+                    && this.server_id == server_id
+                {
+                    this.bulk_preview = Some(preview);
+                }
+                cx.emit(ServerEvent::BulkPreviewLoaded);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
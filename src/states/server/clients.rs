@@ -0,0 +1,153 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ServerEvent, ServerTask, ZedisServerState};
+use crate::connection::{RedisServer, get_connection_manager, open_guarded_connection};
+use crate::states::ZedisGlobalStore;
+use gpui::{Context, SharedString};
+use redis::cmd;
+use rust_i18n::t;
+
+/// A connection of `CLIENT LIST`.
+#[derive(Clone, Debug)]
+pub struct ClientInfo {
+    pub id: u64,
+    pub addr: SharedString,
+    pub name: SharedString,
+    /// Connection age in seconds
+    pub age: u64,
+    /// Idle time in seconds
+    pub idle: u64,
+    pub db: SharedString,
+    pub flags: SharedString,
+    pub command: SharedString,
+    /// The node the client is connected to, CLIENT KILL must be sent there
+    pub node: RedisServer,
+}
+
+impl ClientInfo {
+    /// Whether the connection is a replica or master link, which should not be killed
+    pub fn is_replication(&self) -> bool {
+        self.flags.contains('S') || self.flags.contains('M')
+    }
+}
+
+/// Parses the raw `CLIENT LIST` output (one line per client).
+fn parse_client_list(raw: &str, node: &RedisServer) -> Vec<ClientInfo> {
+    raw.lines()
+        .filter_map(|line| {
+            let mut id = None;
+            let mut client = ClientInfo {
+                id: 0,
+                addr: SharedString::default(),
+                name: SharedString::default(),
+                age: 0,
+                idle: 0,
+                db: SharedString::default(),
+                flags: SharedString::default(),
+                command: SharedString::default(),
+                node: node.clone(),
+            };
+            for (key, value) in line.split_whitespace().filter_map(|part| part.split_once('=')) {
+                match key {
+                    "id" => id = value.parse().ok(),
+                    "addr" => client.addr = value.to_string().into(),
+                    "name" => client.name = value.to_string().into(),
+                    "age" => client.age = value.parse().unwrap_or(0),
+                    "idle" => client.idle = value.parse().unwrap_or(0),
+                    "db" => client.db = value.to_string().into(),
+                    "flags" => client.flags = value.to_string().into(),
+                    "cmd" => client.command = value.to_string().into(),
+                    _ => {}
+                }
+            }
+            client.id = id?;
+            Some(client)
+        })
+        .collect()
+}
+
+impl ZedisServerState {
+    /// Loads the connections of every master node, longest connected first.
+    pub fn fetch_clients(&mut self, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        if server_id.is_empty() {
+            return;
+        }
+        let db = self.db;
+        self.clients_loading = true;
+        cx.notify();
+        self.spawn(
+            ServerTask::FetchClients,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                let (addrs, results): (Vec<RedisServer>, Vec<String>) = client
+                    .query_async_masters(vec![cmd("CLIENT").arg("LIST").clone()])
+                    .await?;
+                let mut clients: Vec<ClientInfo> = addrs
+                    .iter()
+                    .zip(results.iter())
+                    .flat_map(|(node, raw)| parse_client_list(raw, node))
+                    .collect();
+                clients.sort_by(|a, b| b.age.cmp(&a.age));
+                Ok((server_id, clients))
+            },
+            move |this, result, cx| {
+                this.clients_loading = false;
+                if let Ok((server_id, clients)) = result
+                    && this.server_id == server_id
+                {
+                    this.clients = clients;
+                }
+                cx.emit(ServerEvent::ClientsLoaded);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Closes a connection with `CLIENT KILL ID` on its node, then reloads the list.
+    pub fn kill_client(&mut self, client: ClientInfo, cx: &mut Context<Self>) {
+        let db = self.db;
+        let id = client.id;
+        self.spawn(
+            ServerTask::KillClient,
+            move || async move {
+                let mut conn = open_guarded_connection(&client.node, db, true).await?;
+                let _: () = cmd("CLIENT")
+                    .arg("KILL")
+                    .arg("ID")
+                    .arg(id)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(client.addr)
+            },
+            move |this, result, cx| {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                match result {
+                    Ok(addr) => {
+                        let message = t!("clients_manager.kill_success", addr = addr, locale = locale);
+                        this.emit_success_notification(message.into(), "CLIENT KILL".into(), cx);
+                        this.fetch_clients(cx);
+                    }
+                    Err(e) => {
+                        let message = t!("clients_manager.kill_failed", error = e.to_string(), locale = locale);
+                        this.emit_error_notification(message.into(), cx);
+                    }
+                }
+            },
+            cx,
+        );
+    }
+}
@@ -0,0 +1,377 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structural diff of two Redis values.
+//!
+//! Values are loaded in full (up to `MAX_DIFF_ITEMS` elements) from any server and db,
+//! then compared field by field for hashes, member by member for sets and sorted sets,
+//! entry by entry for streams, and position by position for lists and string lines.
+
+use super::{
+    KeyType, RedisValueData,
+    string::get_redis_bytes_value,
+    value::{RedisHashValue, RedisListValue, RedisSetValue, RedisStreamValue, RedisValue, RedisZsetValue},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+};
+use gpui::SharedString;
+use redis::cmd;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Maximum number of elements loaded per value for a diff
+const MAX_DIFF_ITEMS: usize = 10_000;
+/// COUNT hint of the scans used to load a value
+const SCAN_COUNT: usize = 1_000;
+
+/// Change of a single field, member or position between the left and right value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffKind {
+    Unchanged,
+    Changed,
+    /// Only in the right value
+    Added,
+    /// Only in the left value
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    /// Field, member, entry id or position (1-based) of the entry
+    pub name: SharedString,
+    pub left: Option<SharedString>,
+    pub right: Option<SharedString>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueDiff {
+    pub left_type: KeyType,
+    pub right_type: KeyType,
+    /// The values have different types, so no entries are compared
+    pub type_mismatch: bool,
+    /// At least one value has more than `MAX_DIFF_ITEMS` elements and was compared partially
+    pub truncated: bool,
+    pub entries: Vec<DiffEntry>,
+}
+
+impl ValueDiff {
+    /// Returns the number of entries of the given kind
+    pub fn count(&self, kind: DiffKind) -> usize {
+        self.entries.iter().filter(|entry| entry.kind == kind).count()
+    }
+
+    /// Returns true if both values have the same type and content
+    pub fn is_identical(&self) -> bool {
+        !self.type_mismatch && self.entries.iter().all(|entry| entry.kind == DiffKind::Unchanged)
+    }
+}
+
+/// How the entries of a value are matched against the other value
+#[derive(Clone, Copy, PartialEq)]
+enum DiffMode {
+    /// Matched by field, member or entry id
+    Keyed,
+    /// Matched by position
+    Indexed,
+}
+
+/// Flattens a value into comparable (name, content) pairs.
+fn diff_items(value: &RedisValue) -> (DiffMode, Vec<(SharedString, SharedString)>) {
+    let indexed = |values: Vec<SharedString>| -> Vec<(SharedString, SharedString)> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| ((index + 1).to_string().into(), value))
+            .collect()
+    };
+    match value.data.as_ref() {
+        Some(RedisValueData::Bytes(data)) => {
+            let text = data
+                .text
+                .clone()
+                .unwrap_or_else(|| String::from_utf8_lossy(&data.bytes).to_string().into());
+            (
+                DiffMode::Indexed,
+                indexed(text.lines().map(|line| line.to_string().into()).collect()),
+            )
+        }
+        Some(RedisValueData::List(data)) => (DiffMode::Indexed, indexed(data.values.clone())),
+        Some(RedisValueData::Set(data)) => (
+            DiffMode::Keyed,
+            data.values
+                .iter()
+                .map(|member| (member.clone(), SharedString::default()))
+                .collect(),
+        ),
+        Some(RedisValueData::Zset(data)) => (
+            DiffMode::Keyed,
+            data.values
+                .iter()
+                .map(|(member, score)| (member.clone(), score.to_string().into()))
+                .collect(),
+        ),
+        Some(RedisValueData::Hash(data)) => (DiffMode::Keyed, data.values.clone()),
+        Some(RedisValueData::Stream(data)) => (
+            DiffMode::Keyed,
+            data.values
+                .iter()
+                .map(|(id, fields)| {
+                    let content = fields
+                        .iter()
+                        .map(|(field, value)| format!("{field}={value}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    (id.clone(), content.into())
+                })
+                .collect(),
+        ),
+        None => {
+            let mode = if matches!(value.key_type, KeyType::String | KeyType::List) {
+                DiffMode::Indexed
+            } else {
+                DiffMode::Keyed
+            };
+            (mode, vec![])
+        }
+    }
+}
+
+/// Returns true if all elements of the value were loaded
+fn is_complete(value: &RedisValue) -> bool {
+    match value.data.as_ref() {
+        Some(RedisValueData::List(data)) => data.values.len() >= data.size,
+        Some(RedisValueData::Set(data)) => data.done,
+        Some(RedisValueData::Zset(data)) => data.done,
+        // Values scanned with NOVALUES may not be loaded yet
+        Some(RedisValueData::Hash(data)) => data.done && data.pending.is_empty(),
+        Some(RedisValueData::Stream(data)) => data.done,
+        _ => true,
+    }
+}
+
+fn diff_entry(name: SharedString, left: Option<SharedString>, right: Option<SharedString>) -> DiffEntry {
+    let kind = match (&left, &right) {
+        (Some(left), Some(right)) if left == right => DiffKind::Unchanged,
+        (Some(_), Some(_)) => DiffKind::Changed,
+        (None, _) => DiffKind::Added,
+        (_, None) => DiffKind::Removed,
+    };
+    DiffEntry {
+        kind,
+        name,
+        left,
+        right,
+    }
+}
+
+/// Compares two values structurally.
+///
+/// A missing key (`KeyType::Unknown`) compares as an empty value of the other type,
+/// so all entries of the other value are reported as added or removed.
+pub fn diff_values(a: &RedisValue, b: &RedisValue) -> ValueDiff {
+    let mut diff = ValueDiff {
+        left_type: a.key_type,
+        right_type: b.key_type,
+        truncated: !is_complete(a) || !is_complete(b),
+        ..Default::default()
+    };
+    if a.key_type != KeyType::Unknown && b.key_type != KeyType::Unknown && a.key_type != b.key_type {
+        diff.type_mismatch = true;
+        return diff;
+    }
+    let (left_mode, left) = diff_items(a);
+    let (right_mode, right) = diff_items(b);
+    let mode = if a.key_type == KeyType::Unknown {
+        right_mode
+    } else {
+        left_mode
+    };
+
+    diff.entries = match mode {
+        DiffMode::Indexed => {
+            let count = left.len().max(right.len());
+            let mut left = left.into_iter();
+            let mut right = right.into_iter();
+            (0..count)
+                .map(|index| {
+                    let left = left.next().map(|(_, value)| value);
+                    let right = right.next().map(|(_, value)| value);
+                    diff_entry((index + 1).to_string().into(), left, right)
+                })
+                .collect()
+        }
+        DiffMode::Keyed => {
+            let mut items: BTreeMap<SharedString, (Option<SharedString>, Option<SharedString>)> = BTreeMap::new();
+            for (name, value) in left {
+                items.entry(name).or_default().0 = Some(value);
+            }
+            for (name, value) in right {
+                items.entry(name).or_default().1 = Some(value);
+            }
+            items
+                .into_iter()
+                .map(|(name, (left, right))| diff_entry(name, left, right))
+                .collect()
+        }
+    };
+    diff
+}
+
+fn lossy(value: &[u8]) -> SharedString {
+    String::from_utf8_lossy(value).to_string().into()
+}
+
+async fn load_hash(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisHashValue> {
+    let mut hash = RedisHashValue::default();
+    loop {
+        let (cursor, values): (u64, Vec<(Vec<u8>, Vec<u8>)>) = cmd("HSCAN")
+            .arg(key)
+            .arg(hash.cursor)
+            .arg("COUNT")
+            .arg(SCAN_COUNT)
+            .query_async(conn)
+            .await?;
+        hash.append_scanned(
+            values
+                .iter()
+                .map(|(field, value)| (lossy(field), lossy(value)))
+                .collect(),
+        );
+        hash.cursor = cursor;
+        hash.done = cursor == 0;
+        if hash.done || hash.values.len() >= MAX_DIFF_ITEMS {
+            break;
+        }
+    }
+    hash.size = hash.values.len();
+    Ok(hash)
+}
+
+async fn load_set(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisSetValue> {
+    let mut set = RedisSetValue::default();
+    loop {
+        let (cursor, values): (u64, Vec<Vec<u8>>) = cmd("SSCAN")
+            .arg(key)
+            .arg(set.cursor)
+            .arg("COUNT")
+            .arg(SCAN_COUNT)
+            .query_async(conn)
+            .await?;
+        set.append_scanned(values.iter().map(|value| lossy(value)).collect());
+        set.cursor = cursor;
+        set.done = cursor == 0;
+        if set.done || set.values.len() >= MAX_DIFF_ITEMS {
+            break;
+        }
+    }
+    set.size = set.values.len();
+    Ok(set)
+}
+
+async fn load_list(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisListValue> {
+    let size: usize = cmd("LLEN").arg(key).query_async(conn).await?;
+    let values: Vec<Vec<u8>> = cmd("LRANGE")
+        .arg(key)
+        .arg(0)
+        .arg(MAX_DIFF_ITEMS - 1)
+        .query_async(conn)
+        .await?;
+    Ok(RedisListValue {
+        size,
+        values: values.iter().map(|value| lossy(value)).collect(),
+        ..Default::default()
+    })
+}
+
+async fn load_zset(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisZsetValue> {
+    let size: usize = cmd("ZCARD").arg(key).query_async(conn).await?;
+    let values: Vec<(Vec<u8>, f64)> = cmd("ZRANGE")
+        .arg(key)
+        .arg(0)
+        .arg(MAX_DIFF_ITEMS - 1)
+        .arg("WITHSCORES")
+        .query_async(conn)
+        .await?;
+    Ok(RedisZsetValue {
+        size,
+        done: values.len() >= size,
+        values: values.iter().map(|(member, score)| (lossy(member), *score)).collect(),
+        ..Default::default()
+    })
+}
+
+async fn load_stream(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisStreamValue> {
+    let size: usize = cmd("XLEN").arg(key).query_async(conn).await?;
+    let entries: Vec<(String, Vec<String>)> = cmd("XRANGE")
+        .arg(key)
+        .arg("-")
+        .arg("+")
+        .arg("COUNT")
+        .arg(MAX_DIFF_ITEMS)
+        .query_async(conn)
+        .await?;
+    let values: Vec<_> = entries
+        .into_iter()
+        .map(|(id, flat_fields)| {
+            let fields = flat_fields
+                .chunks_exact(2)
+                .map(|pair| (pair[0].clone().into(), pair[1].clone().into()))
+                .collect();
+            (id.into(), fields)
+        })
+        .collect();
+    Ok(RedisStreamValue {
+        size,
+        done: values.len() >= size,
+        values,
+        ..Default::default()
+    })
+}
+
+/// Loads a value for a diff, up to `MAX_DIFF_ITEMS` elements.
+///
+/// A missing key is returned as a value of `KeyType::Unknown` without data.
+pub async fn load_diff_value(server_id: &str, db: usize, key: &str) -> Result<RedisValue> {
+    let mut conn = get_connection_manager().get_connection(server_id, db).await?;
+    let key_type: String = cmd("TYPE").arg(key).query_async(&mut conn).await?;
+    let key_type = KeyType::from(key_type.as_str());
+    let data = match key_type {
+        KeyType::Unknown => None,
+        KeyType::String => {
+            let mut data = get_redis_bytes_value(&mut conn, key).await?;
+            data.text = Some(lossy(&data.bytes));
+            Some(RedisValueData::Bytes(Arc::new(data)))
+        }
+        KeyType::List => Some(RedisValueData::List(Arc::new(load_list(&mut conn, key).await?))),
+        KeyType::Set => Some(RedisValueData::Set(Arc::new(load_set(&mut conn, key).await?))),
+        KeyType::Zset => Some(RedisValueData::Zset(Arc::new(load_zset(&mut conn, key).await?))),
+        KeyType::Hash => Some(RedisValueData::Hash(Arc::new(load_hash(&mut conn, key).await?))),
+        KeyType::Stream => Some(RedisValueData::Stream(Arc::new(load_stream(&mut conn, key).await?))),
+        _ => {
+            return Err(Error::Invalid {
+                message: format!("unsupported key type: {}", key_type.as_str()),
+            });
+        }
+    };
+    Ok(RedisValue {
+        key_type,
+        data,
+        ..Default::default()
+    })
+}
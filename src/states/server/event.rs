@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use crate::helpers::EditorAction;
+use crate::states::server::stream::{StreamGroup, StreamPendingEntries, StreamTrimPreview};
 use crate::states::{ErrorMessage, GlobalEvent, NotificationAction, ZedisGlobalStore, ZedisServerState};
+use futures::future::AbortHandle;
 use gpui::prelude::*;
 use gpui::{EventEmitter, SharedString};
+use std::time::Instant;
 
 /// Background task types for Redis operations
 ///
@@ -34,6 +37,22 @@ pub enum ServerTask {
     /// Fill in key types for unknown keys
     FillKeyTypes,
 
+    /// Pick random keys to inspect an unknown keyspace
+    SampleRandomKeys,
+
+    /// Read OBJECT ENCODING of the keys shown in the tree
+    FetchKeyEncodings,
+
+    /// Dry run SCAN of a pattern based bulk operation
+    PreviewMatching,
+
+    /// Load the connections of CLIENT LIST
+    FetchClients,
+    /// Close a connection with CLIENT KILL
+    KillClient,
+    /// Read CLUSTER INFO after a CLUSTERDOWN error
+    CheckClusterHealth,
+
     /// Load value data for a selected key
     Selectkey,
 
@@ -68,6 +87,8 @@ pub enum ServerTask {
 
     /// Load more items
     LoadMoreValue,
+    /// Load the values of hash fields scanned without them
+    LoadHashValues,
 
     /// Add a value to a set
     AddSetValue,
@@ -87,17 +108,41 @@ pub enum ServerTask {
     UpdateHashField,
     /// Remove a value from a hash
     RemoveHashField,
+    /// Set and remove several hash fields at once
+    ApplyHashChanges,
 
     /// Add a stream entry
     AddStreamEntry,
     /// Remove a stream entry
     RemoveStreamEntry,
+    /// List the consumer groups of a stream (XINFO GROUPS)
+    LoadStreamGroups,
+    /// List the pending entries of a consumer group (XPENDING)
+    LoadStreamPending,
+    /// Acknowledge pending stream entries of a consumer group
+    AckStreamEntries,
+    /// Claim pending stream entries for another consumer
+    ClaimStreamEntries,
+    /// Count the entries a stream trim would remove
+    PreviewStreamTrim,
+    /// Trim a stream by MAXLEN or MINID (XTRIM)
+    TrimStream,
+    /// Load the newest stream entries (XREVRANGE) before following or jumping to them
+    LoadStreamTail,
 
     /// Save edited value back to Redis
     SaveValue,
+    /// Increment a numeric string with INCRBY / INCRBYFLOAT
+    IncrStringValue,
 
     /// Publish a message to a channel
     PublishMessage,
+
+    /// Execute queued commands atomically (MULTI/EXEC)
+    ExecTransaction,
+
+    /// Wait for replicas to acknowledge previous writes (WAIT)
+    WaitReplication,
 }
 
 impl ServerTask {
@@ -108,6 +153,12 @@ impl ServerTask {
             ServerTask::AutoRefresh => "auto_refresh",
             ServerTask::SelectServer => "select_server",
             ServerTask::FillKeyTypes => "fill_key_types",
+            ServerTask::SampleRandomKeys => "sample_random_keys",
+            ServerTask::FetchKeyEncodings => "fetch_key_encodings",
+            ServerTask::PreviewMatching => "preview_matching",
+            ServerTask::FetchClients => "fetch_clients",
+            ServerTask::KillClient => "kill_client",
+            ServerTask::CheckClusterHealth => "check_cluster_health",
             ServerTask::Selectkey => "select_key",
             ServerTask::DeleteKey => "delete_key",
             ServerTask::ReloadValue => "reload_value",
@@ -119,7 +170,9 @@ impl ServerTask {
             ServerTask::RemoveListValue => "remove_list_value",
             ServerTask::UpdateListValue => "update_list_value",
             ServerTask::LoadMoreValue => "load_more_value",
+            ServerTask::LoadHashValues => "load_hash_values",
             ServerTask::SaveValue => "save_value",
+            ServerTask::IncrStringValue => "incr_string_value",
             ServerTask::PushListValue => "push_list_value",
             ServerTask::AddSetValue => "add_set_value",
             ServerTask::UpdateSetValue => "update_set_value",
@@ -129,11 +182,82 @@ impl ServerTask {
             ServerTask::AddHashField => "add_hash_field",
             ServerTask::UpdateHashField => "update_hash_field",
             ServerTask::RemoveHashField => "remove_hash_field",
+            ServerTask::ApplyHashChanges => "apply_hash_changes",
             ServerTask::AddStreamEntry => "add_stream_entry",
             ServerTask::RemoveStreamEntry => "remove_stream_entry",
+            ServerTask::LoadStreamGroups => "load_stream_groups",
+            ServerTask::LoadStreamPending => "load_stream_pending",
+            ServerTask::AckStreamEntries => "ack_stream_entries",
+            ServerTask::ClaimStreamEntries => "claim_stream_entries",
+            ServerTask::PreviewStreamTrim => "preview_stream_trim",
+            ServerTask::TrimStream => "trim_stream",
+            ServerTask::LoadStreamTail => "load_stream_tail",
             ServerTask::PublishMessage => "publish_message",
+            ServerTask::ExecTransaction => "exec_transaction",
+            ServerTask::WaitReplication => "wait_replication",
         }
     }
+    /// Whether the task mutates data, so its writes can be confirmed with WAIT.
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            ServerTask::DeleteKey
+                | ServerTask::DeleteKeys
+                | ServerTask::AddKey
+                | ServerTask::UpdateKeyTtl
+                | ServerTask::RemoveListValue
+                | ServerTask::UpdateListValue
+                | ServerTask::PushListValue
+                | ServerTask::AddSetValue
+                | ServerTask::UpdateSetValue
+                | ServerTask::RemoveSetValue
+                | ServerTask::AddZsetValue
+                | ServerTask::RemoveZsetValue
+                | ServerTask::AddHashField
+                | ServerTask::UpdateHashField
+                | ServerTask::RemoveHashField
+                | ServerTask::ApplyHashChanges
+                | ServerTask::AddStreamEntry
+                | ServerTask::RemoveStreamEntry
+                | ServerTask::AckStreamEntries
+                | ServerTask::ClaimStreamEntries
+                | ServerTask::TrimStream
+                | ServerTask::SaveValue
+                | ServerTask::IncrStringValue
+                | ServerTask::ExecTransaction
+        )
+    }
+    /// Whether the task may be cancelled, writes are not as they may already be applied.
+    pub fn is_cancellable(&self) -> bool {
+        !self.is_write()
+            && !matches!(
+                self,
+                ServerTask::SelectServer | ServerTask::PublishMessage | ServerTask::KillClient
+            )
+    }
+}
+
+/// A background task that has not finished yet
+#[derive(Clone, Debug)]
+pub struct RunningTask {
+    pub id: u64,
+    pub task: ServerTask,
+    pub started_at: Instant,
+    abort_handle: AbortHandle,
+}
+
+impl RunningTask {
+    pub(crate) fn new(id: u64, task: ServerTask, abort_handle: AbortHandle) -> Self {
+        Self {
+            id,
+            task,
+            started_at: Instant::now(),
+            abort_handle,
+        }
+    }
+    pub(crate) fn abort(&self) {
+        self.abort_handle.abort();
+    }
 }
 
 /// Events emitted by server state for reactive UI updates
@@ -141,6 +265,8 @@ impl ServerTask {
 pub enum ServerEvent {
     /// A new background task has started.
     TaskStarted(ServerTask),
+    /// A background task has finished, failed or was cancelled.
+    TaskFinished(ServerTask),
 
     /// Terminal toggled
     TerminalToggled(bool),
@@ -157,6 +283,14 @@ pub enum ServerEvent {
     KeyScanFinished,
     /// Key collapse all
     KeyCollapseAll,
+    /// Random keys have been sampled (or sampling failed)
+    RandomKeysSampled,
+    /// The OBJECT ENCODING of keys has been loaded
+    KeyEncodingsLoaded,
+    /// The dry run of a bulk operation has been scanned (or scanning failed)
+    BulkPreviewLoaded,
+    /// The client list has been loaded (or loading failed)
+    ClientsLoaded,
 
     /// Key tree has been updated
     KeyTreeUpdated,
@@ -171,8 +305,20 @@ pub enum ServerEvent {
     ValuePaginationStarted,
     /// Load more value
     ValuePaginationFinished,
+    /// Lazily loaded values of the shown rows arrived
+    ValueCellsLoaded,
     /// Add a value to a set、list、hash、zset
     ValueAdded,
+    /// New entries were appended to the followed stream
+    StreamFollowed,
+    /// The entries a stream trim would remove have been counted
+    StreamTrimPreviewed(StreamTrimPreview),
+    /// The consumer groups of the stream have been listed
+    StreamGroupsLoaded(Vec<StreamGroup>),
+    /// The pending entries of a consumer group have been listed
+    StreamPendingLoaded(StreamPendingEntries),
+    /// Database export/import progressed, finished or was cancelled
+    BackupProgressUpdated,
 
     /// User selected a different server
     ServerSelected(SharedString),
@@ -180,14 +326,26 @@ pub enum ServerEvent {
     ServerInfoUpdated,
     /// Periodic redis info updated.
     ServerRedisInfoUpdated,
+    /// Active metrics alerts changed (thresholds crossed or recovered).
+    MetricsAlertsUpdated,
+    /// The cluster went down or recovered.
+    ClusterHealthUpdated,
 
     /// Soft wrap changed
     SoftWrapToggled(bool),
+    /// Line numbers of the editor shown or hidden
+    LineNumbersToggled(bool),
     /// An error occurred.
     ErrorOccurred(ErrorMessage),
+    /// The server rejected the stored credentials (NOAUTH / WRONGPASS).
+    AuthRequired(SharedString),
+    /// A write was refused because the key now holds another type than its open editor.
+    KeyTypeChanged(SharedString),
 
     /// A pubsub message was published
     PubsubMessagePublished,
+    /// A MULTI/EXEC transaction finished, with one reply per queued command
+    TransactionExecuted(Vec<SharedString>),
 
     /// Trigger Action
     EditionActionTriggered(EditorAction),
@@ -230,4 +388,17 @@ impl ZedisServerState {
             cx.emit(GlobalEvent::Notification(NotificationAction::new_error(message)));
         });
     }
+    /// Emits an error notification carrying a copyable report of the failure.
+    pub fn emit_error_notification_with_details(
+        &self,
+        message: SharedString,
+        details: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        cx.global::<ZedisGlobalStore>().clone().update(cx, |_state, cx| {
+            cx.emit(GlobalEvent::Notification(
+                NotificationAction::new_error(message).with_details(details),
+            ));
+        });
+    }
 }
@@ -16,29 +16,105 @@
 //!
 //! This module provides functionality for managing Redis HASH operations including:
 //! - Loading HASH field-value pairs with pagination support via HSCAN
+//! - Scanning only the field names of wide HASHes (`HSCAN ... NOVALUES`, Redis 7.4+)
+//!   and loading the values of the shown rows with HMGET
+//! - Loading each value of HASHes with very large values on its own with HGET
 //! - Adding/updating fields in a HASH (HSET)
 //! - Removing fields from a HASH (HDEL)
+//! - Setting and removing several fields at once in a MULTI/EXEC pipeline
 //! - Filtering HASH fields with pattern matching
 //! - Efficient incremental loading for large HASHes
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
-    value::{RedisHashValue, RedisValue, RedisValueStatus},
+    value::{RedisHashValue, RedisValue, RedisValueStatus, ensure_key_type},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, i18n_hash_editor},
+    helpers::contains_glob,
+    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, ZedisGlobalStore, i18n_hash_editor},
 };
 use gpui::{SharedString, prelude::*};
-use redis::cmd;
+use redis::{cmd, pipe};
+use rust_i18n::t;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::warn;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Type alias for HSCAN result: (cursor, vec of (field, value) pairs as bytes)
 type HashScanValue = (u64, Vec<(Vec<u8>, Vec<u8>)>);
 
+/// Hashes with at least this many fields only scan field names when NOVALUES is supported
+const NOVALUES_MIN_FIELDS: usize = 1000;
+/// Hashes whose average value is at least this large load each value when its row is shown
+const LAZY_VALUE_MIN_BYTES: u64 = 256 * 1024;
+/// Without NOVALUES, lazily loaded hashes list their fields with HKEYS up to this many fields
+const HKEYS_MAX_FIELDS: usize = 1000;
+
+/// A page of HSCAN results
+enum HashScanPage {
+    Values(Vec<(SharedString, SharedString)>),
+    /// Field names scanned with NOVALUES
+    Fields(Vec<SharedString>),
+}
+
+/// Why pasted hash fields could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum HashFieldsError {
+    /// The text looks like a JSON object but is not a valid one
+    Json(String),
+    /// 1-based numbers of the lines that are not `field=value`
+    Lines(Vec<usize>),
+}
+
+/// Parses pasted hash fields, either a JSON object or `field=value` lines.
+///
+/// Empty lines and lines starting with `#` are skipped. Field names are trimmed,
+/// values are kept as typed so intentional whitespace survives. Non-string JSON
+/// values are stored as their JSON text.
+pub fn parse_hash_fields(text: &str) -> Result<Vec<(SharedString, SharedString)>, HashFieldsError> {
+    let trimmed = text.trim();
+    if trimmed.starts_with('{') {
+        let object: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(trimmed).map_err(|e| HashFieldsError::Json(e.to_string()))?;
+        return Ok(object
+            .into_iter()
+            .map(|(field, value)| {
+                let value = match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                (field.into(), value.into())
+            })
+            .collect());
+    }
+    let mut fields = vec![];
+    let mut invalid_lines = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let content = line.trim();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        match line.split_once('=') {
+            Some((field, value)) if !field.trim().is_empty() => {
+                fields.push((field.trim().to_string().into(), value.to_string().into()));
+            }
+            _ => invalid_lines.push(index + 1),
+        }
+    }
+    if !invalid_lines.is_empty() {
+        return Err(HashFieldsError::Lines(invalid_lines));
+    }
+    Ok(fields)
+}
+
+fn hash_scan_pattern(keyword: Option<&SharedString>) -> String {
+    keyword.map(|kw| contains_glob(kw)).unwrap_or_else(|| "*".to_string())
+}
+
 /// Retrieves HASH field-value pairs using Redis HSCAN command for cursor-based pagination.
 ///
 /// # Arguments
@@ -58,10 +134,7 @@ async fn get_redis_hash_value(
     count: usize,
 ) -> Result<(u64, Vec<(SharedString, SharedString)>)> {
     // Build pattern: wrap keyword with wildcards or match all fields
-    let pattern = keyword
-        .as_ref()
-        .map(|kw| format!("*{}*", kw))
-        .unwrap_or_else(|| "*".to_string());
+    let pattern = hash_scan_pattern(keyword.as_ref());
 
     // Execute HSCAN with MATCH and COUNT options
     let (next_cursor, raw_values): HashScanValue = cmd("HSCAN")
@@ -93,42 +166,176 @@ async fn get_redis_hash_value(
     Ok((next_cursor, values))
 }
 
+/// Retrieves HASH field names only, using `HSCAN ... NOVALUES` (Redis 7.4+).
+async fn get_redis_hash_fields(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    keyword: Option<&SharedString>,
+    cursor: u64,
+    count: usize,
+) -> Result<(u64, Vec<SharedString>)> {
+    let (next_cursor, raw_fields): (u64, Vec<Vec<u8>>) = cmd("HSCAN")
+        .arg(key)
+        .arg(cursor)
+        .arg("MATCH")
+        .arg(hash_scan_pattern(keyword))
+        .arg("COUNT")
+        .arg(count)
+        .arg("NOVALUES")
+        .query_async(conn)
+        .await?;
+    let fields = raw_fields
+        .iter()
+        .map(|field| SharedString::new(String::from_utf8_lossy(field)))
+        .collect();
+    Ok((next_cursor, fields))
+}
+
+/// Scans the next page, with field names only when `novalues` is set.
+///
+/// Falls back to scanning the values if the server rejects NOVALUES,
+/// e.g. a compatible server reporting 7.4 without supporting it.
+async fn scan_hash_page(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    keyword: Option<SharedString>,
+    cursor: u64,
+    count: usize,
+    novalues: bool,
+) -> Result<(u64, HashScanPage)> {
+    if novalues {
+        match get_redis_hash_fields(conn, key, keyword.as_ref(), cursor, count).await {
+            Ok((next_cursor, fields)) => return Ok((next_cursor, HashScanPage::Fields(fields))),
+            Err(e) => warn!(error = %e, "HSCAN NOVALUES failed, fall back to scanning values"),
+        }
+    }
+    let (next_cursor, values) = get_redis_hash_value(conn, key, keyword, cursor, count).await?;
+    Ok((next_cursor, HashScanPage::Values(values)))
+}
+
+/// Lists the field names with HKEYS, for servers without NOVALUES.
+///
+/// Only used for hashes with few fields, the keyword is matched as a substring.
+async fn list_hash_fields(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    keyword: Option<&SharedString>,
+) -> Result<Vec<SharedString>> {
+    let raw_fields: Vec<Vec<u8>> = cmd("HKEYS").arg(key).query_async(conn).await?;
+    let fields = raw_fields
+        .iter()
+        .map(|field| SharedString::new(String::from_utf8_lossy(field)))
+        .filter(|field| keyword.is_none_or(|keyword| field.contains(keyword.as_str())))
+        .collect();
+    Ok(fields)
+}
+
+/// Returns the average value size, estimated with a sampled `MEMORY USAGE` (Redis 4.0+).
+async fn sample_hash_value_size(conn: &mut RedisAsyncConn, key: &str, size: usize) -> Option<u64> {
+    if size == 0 {
+        return None;
+    }
+    // MEMORY may be renamed or restricted, the estimate is best-effort
+    let usage: u64 = cmd("MEMORY")
+        .arg("USAGE")
+        .arg(key)
+        .arg("SAMPLES")
+        .arg(5)
+        .query_async(conn)
+        .await
+        .ok()?;
+    Some(usage / size as u64)
+}
+
+impl RedisHashValue {
+    /// Appends a scanned page, turning NOVALUES off if the server fell back to values.
+    fn append_page(&mut self, page: HashScanPage) {
+        match page {
+            HashScanPage::Values(values) => {
+                self.novalues = false;
+                self.append_scanned(values);
+            }
+            HashScanPage::Fields(fields) => self.append_scanned_fields(fields),
+        }
+    }
+}
+
 /// Performs initial load of a Redis HASH value.
 ///
 /// Fetches the total number of fields (HLEN) and loads the first batch of field-value
 /// pairs (up to 100). This is called when a HASH key is first opened in the editor.
 ///
+/// Wide HASHes only scan field names when the server supports NOVALUES.
+/// HASHes with very large values only list field names too, each value is
+/// then loaded on its own when its row is shown.
+///
 /// # Arguments
 /// * `conn` - Redis async connection
 /// * `key` - The HASH key to load
+/// * `novalues_supported` - Whether the server supports `HSCAN ... NOVALUES` (7.4+)
+/// * `memory_usage_supported` - Whether the server supports `MEMORY USAGE` (4.0+)
 ///
 /// # Returns
 /// A `RedisValue` containing HASH metadata and initial field-value pairs
-pub(crate) async fn first_load_hash_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisValue> {
+pub(crate) async fn first_load_hash_value(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    novalues_supported: bool,
+    memory_usage_supported: bool,
+) -> Result<RedisValue> {
     // Get total number of fields in the HASH
     let size: usize = cmd("HLEN").arg(key).query_async(conn).await?;
+    let large_values = if memory_usage_supported {
+        sample_hash_value_size(conn, key, size)
+            .await
+            .is_some_and(|value_size| value_size >= LAZY_VALUE_MIN_BYTES)
+    } else {
+        false
+    };
+    let lazy_values = large_values && (novalues_supported || size <= HKEYS_MAX_FIELDS);
+    let novalues = novalues_supported && (size >= NOVALUES_MIN_FIELDS || lazy_values);
+
+    if lazy_values && !novalues {
+        let mut hash = RedisHashValue {
+            size,
+            done: true,
+            lazy_values,
+            ..Default::default()
+        };
+        hash.append_scanned_fields(list_hash_fields(conn, key, None).await?);
+        return Ok(RedisValue {
+            key_type: KeyType::Hash,
+            data: Some(RedisValueData::Hash(Arc::new(hash))),
+            ..Default::default()
+        });
+    }
 
     // Load first batch of field-value pairs (up to 100)
-    let (cursor, values) = get_redis_hash_value(conn, key, None, 0, 100).await?;
+    let (cursor, page) = scan_hash_page(conn, key, None, 0, 100, novalues).await?;
 
     // If cursor is 0, all values have been loaded in one iteration
     let done = cursor == 0;
 
+    let mut hash = RedisHashValue {
+        cursor,
+        size,
+        done,
+        novalues,
+        lazy_values,
+        ..Default::default()
+    };
+    hash.append_page(page);
+
     Ok(RedisValue {
         key_type: KeyType::Hash,
-        data: Some(RedisValueData::Hash(Arc::new(RedisHashValue {
-            cursor,
-            size,
-            values,
-            done,
-            ..Default::default()
-        }))),
+        data: Some(RedisValueData::Hash(Arc::new(hash))),
         ..Default::default()
     })
 }
 impl ZedisServerState {
     /// A generic helper for Redis Hash operations (Add, Remove, Update).
     /// Handles status switching, optimistic UI updates, and background task execution.
+    /// `rollback` reverts the optimistic update if the Redis command fails.
     fn exec_hash_op<F, Fut, R>(
         &mut self,
         task: ServerTask,
@@ -136,6 +343,7 @@ impl ZedisServerState {
         optimistic_update: impl FnOnce(&mut RedisHashValue),
         redis_op: F,
         on_success: impl FnOnce(&mut Self, R, &mut Context<Self>) + Send + 'static,
+        rollback: impl FnOnce(&mut RedisHashValue) + Send + 'static,
     ) where
         F: FnOnce(String, RedisAsyncConn) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = Result<R>> + Send,
@@ -145,6 +353,7 @@ impl ZedisServerState {
             return;
         };
         let key_str = key.to_string();
+        let rollback_key = key.clone();
         value.status = RedisValueStatus::Updating;
 
         // Step 1: Perform local optimistic update
@@ -156,12 +365,17 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
+        let verify_type = cx.global::<ZedisGlobalStore>().read(cx).verify_key_type();
 
         // Step 2: Spawn background task
         self.spawn(
             task,
             move || async move {
-                let conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Refuse to write with the commands of another type, the editor asks to reload instead
+                if verify_type {
+                    ensure_key_type(&mut conn, &key_str, KeyType::Hash).await?;
+                }
                 redis_op(key_str, conn).await
             },
             move |this, result, cx| {
@@ -171,7 +385,19 @@ impl ZedisServerState {
 
                 match result {
                     Ok(data) => on_success(this, data, cx),
-                    Err(e) => this.emit_error_notification(e.to_string().into(), cx),
+                    Err(e) => {
+                        // Revert the optimistic update if the key is still selected
+                        if this.key.as_ref() == Some(&rollback_key)
+                            && let Some(RedisValueData::Hash(hash_data)) =
+                                this.value.as_mut().and_then(|v| v.data.as_mut())
+                        {
+                            rollback(Arc::make_mut(hash_data));
+                            cx.emit(ServerEvent::ValueUpdated);
+                        }
+                        if !e.is_type_mismatch() {
+                            this.emit_error_notification(e.to_string().into(), cx);
+                        }
+                    }
                 }
                 cx.notify();
             },
@@ -180,9 +406,9 @@ impl ZedisServerState {
     }
     /// Adds a field-value pair in the Redis HASH.
     ///
-    /// Uses HSET command which creates a new field or updates an existing one.
-    /// Updates the UI state and shows appropriate notifications based on whether
-    /// it was a new field (count=1) or an update (count=0).
+    /// Uses HSETNX command which only creates the field if it doesn't already exist.
+    /// Updates the UI state for a new field (count=1) and warns that the field
+    /// already exists otherwise (count=0), like the set and zset editors.
     ///
     /// # Arguments
     /// * `field` - The field name to add
@@ -197,7 +423,8 @@ impl ZedisServerState {
             cx,
             |_| {}, // Wait for server confirmation to avoid duplicate UI entries during scan
             move |key, mut conn| async move {
-                let count: usize = cmd("HSET")
+                // HSETNX checks the existence and adds in one step, an existing field is reported, not overwritten
+                let count: usize = cmd("HSETNX")
                     .arg(&key)
                     .arg(field.as_str())
                     .arg(value.as_str())
@@ -206,11 +433,14 @@ impl ZedisServerState {
                 Ok(count)
             },
             move |this, count, cx| {
-                if let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) {
+                if count == 0 {
+                    this.emit_warning_notification(i18n_hash_editor(cx, "add_value_exists_tips"), cx);
+                } else if let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
                     let hash = Arc::make_mut(hash_data);
                     hash.size += count;
                     // Optimistically append if we are at the end of the scan
-                    if hash.done && !hash.values.iter().any(|(f, _)| f == &field_clone) {
+                    if hash.done && hash.seen.insert(field_clone.clone()) {
                         hash.values.push((field_clone, value_clone));
                     }
                     if hash.size > SUCCESS_NOTIFY_THRESHOLD {
@@ -223,6 +453,7 @@ impl ZedisServerState {
                 }
                 cx.emit(ServerEvent::ValueAdded);
             },
+            |_| {},
         );
     }
     /// Updates a field-value pair in the Redis HASH.
@@ -244,7 +475,14 @@ impl ZedisServerState {
         let old_field_clone = old_field.clone();
         let new_field_clone = new_field.clone();
         let new_value_clone = new_value.clone();
+        let rollback_field = new_field.clone();
         let is_rename = old_field != new_field;
+        // Original entry, restored if the update fails
+        let original = self
+            .value
+            .as_ref()
+            .and_then(|v| v.hash_value())
+            .and_then(|hash| hash.values.iter().find(|(f, _)| f == &old_field).cloned());
 
         self.exec_hash_op(
             ServerTask::UpdateHashField,
@@ -252,6 +490,9 @@ impl ZedisServerState {
             move |hash| {
                 // Optimistic UI update: Replace old entry with new entry
                 if let Some(pos) = hash.values.iter().position(|(f, _)| f == &old_field_clone) {
+                    hash.seen.remove(&old_field_clone);
+                    hash.pending.remove(&old_field_clone);
+                    hash.seen.insert(new_field_clone.clone());
                     hash.values[pos] = (new_field_clone, new_value_clone);
                 }
             },
@@ -283,9 +524,117 @@ impl ZedisServerState {
                 this.emit_info_notification(i18n_hash_editor(cx, "update_exist_field_value_success_tips"), cx);
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            move |hash| {
+                if let Some(original) = original
+                    && let Some(pos) = hash.values.iter().position(|(f, _)| f == &rollback_field)
+                {
+                    hash.seen.remove(&rollback_field);
+                    hash.seen.insert(original.0.clone());
+                    hash.values[pos] = original;
+                }
+            },
         );
         // self.add_or_update_hash_value(new_field, new_value, cx);
     }
+    /// Sets and removes several HASH fields in one atomic round trip.
+    ///
+    /// Sets are sent as a single `HSET key f1 v1 f2 v2 ...` and removals as a single
+    /// `HDEL`, wrapped in MULTI/EXEC so other clients never see a half-applied edit.
+    ///
+    /// # Arguments
+    /// * `changes` - Field and its new value, `None` removes the field
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn apply_hash_changes(&mut self, changes: Vec<(SharedString, Option<SharedString>)>, cx: &mut Context<Self>) {
+        let (sets, removes): (Vec<_>, Vec<_>) = changes.into_iter().partition(|(_, value)| value.is_some());
+        let sets: Vec<(SharedString, SharedString)> = sets
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+            .collect();
+        let removes: Vec<SharedString> = removes.into_iter().map(|(field, _)| field).collect();
+        if sets.is_empty() && removes.is_empty() {
+            return;
+        }
+        // Loaded entries, restored if the commit fails
+        let original = self
+            .value
+            .as_ref()
+            .and_then(|v| v.hash_value())
+            .map(|hash| (hash.values.clone(), hash.seen.clone()));
+        let local_sets = sets.clone();
+        let local_removes = removes.clone();
+        let set_count = sets.len();
+        let remove_count = removes.len();
+
+        self.exec_hash_op(
+            ServerTask::ApplyHashChanges,
+            cx,
+            move |hash| {
+                for field in &local_removes {
+                    hash.seen.remove(field);
+                    hash.pending.remove(field);
+                    hash.values.retain(|(f, _)| f != field);
+                }
+                for (field, value) in local_sets {
+                    hash.pending.remove(&field);
+                    if let Some(entry) = hash.values.iter_mut().find(|(f, _)| f == &field) {
+                        entry.1 = value;
+                    } else if hash.done && hash.seen.insert(field.clone()) {
+                        // Only append when the whole hash is loaded, otherwise a later scan page may contain it
+                        hash.values.push((field, value));
+                    }
+                }
+            },
+            move |key, mut conn| async move {
+                let mut pipeline = pipe();
+                pipeline.atomic();
+                if !sets.is_empty() {
+                    let hset = pipeline.cmd("HSET").arg(&key);
+                    for (field, value) in &sets {
+                        hset.arg(field.as_str()).arg(value.as_str());
+                    }
+                }
+                if !removes.is_empty() {
+                    let hdel = pipeline.cmd("HDEL").arg(&key);
+                    for field in &removes {
+                        hdel.arg(field.as_str());
+                    }
+                }
+                let counts: Vec<usize> = pipeline.query_async(&mut conn).await?;
+                let added = if sets.is_empty() {
+                    0
+                } else {
+                    counts.first().copied().unwrap_or(0)
+                };
+                let removed = if removes.is_empty() {
+                    0
+                } else {
+                    counts.last().copied().unwrap_or(0)
+                };
+                Ok((added, removed))
+            },
+            move |this, (added, removed), cx| {
+                if let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) {
+                    let hash = Arc::make_mut(hash_data);
+                    hash.size = (hash.size + added).saturating_sub(removed);
+                }
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                let message = t!(
+                    "hash_editor.batch_edit_success",
+                    set = set_count,
+                    removed = remove_count,
+                    locale = locale
+                );
+                this.emit_info_notification(message.into(), cx);
+                cx.emit(ServerEvent::ValueUpdated);
+            },
+            move |hash| {
+                if let Some((values, seen)) = original {
+                    hash.values = values;
+                    hash.seen = seen;
+                }
+            },
+        );
+    }
     /// Applies a filter to HASH fields by resetting the scan state with a keyword.
     ///
     /// Creates a new HASH value state with the filter keyword and triggers a load.
@@ -306,6 +655,8 @@ impl ZedisServerState {
         let new_hash = RedisHashValue {
             keyword: Some(keyword),
             size: hash.size,
+            novalues: hash.novalues,
+            lazy_values: hash.lazy_values,
             ..Default::default()
         };
         value.data = Some(RedisValueData::Hash(Arc::new(new_hash)));
@@ -323,11 +674,20 @@ impl ZedisServerState {
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn remove_hash_value(&mut self, remove_field: SharedString, cx: &mut Context<Self>) {
         let remove_field_clone = remove_field.clone();
+        // Removed entry and its position, restored if the removal fails
+        let removed = self.value.as_ref().and_then(|v| v.hash_value()).and_then(|hash| {
+            hash.values
+                .iter()
+                .position(|(f, _)| f == &remove_field)
+                .map(|pos| (pos, hash.values[pos].clone()))
+        });
         self.exec_hash_op(
             ServerTask::RemoveHashField,
             cx,
             move |hash| {
                 hash.size = hash.size.saturating_sub(1);
+                hash.seen.remove(&remove_field_clone);
+                hash.pending.remove(&remove_field_clone);
                 hash.values.retain(|(f, _)| f != &remove_field_clone);
             },
             move |key, mut conn| async move {
@@ -341,6 +701,14 @@ impl ZedisServerState {
             |_, _, cx| {
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            move |hash| {
+                hash.size += 1;
+                if let Some((pos, entry)) = removed
+                    && hash.seen.insert(entry.0.clone())
+                {
+                    hash.values.insert(pos.min(hash.values.len()), entry);
+                }
+            },
         );
     }
     /// Loads the next batch of HASH field-value pairs using cursor-based pagination.
@@ -351,20 +719,32 @@ impl ZedisServerState {
     /// # Arguments
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn load_more_hash_value(&mut self, cx: &mut Context<Self>) {
+        self.fetch_more_hash_value(true, cx);
+    }
+
+    /// Fetches the next HSCAN batch, automatically continuing until enough items are loaded.
+    ///
+    /// The automatic rounds are bounded by count and time per user action, see `next_auto_load`.
+    /// A `user_action` starts a new budget, unless a load is already in flight.
+    fn fetch_more_hash_value(&mut self, user_action: bool, cx: &mut Context<Self>) {
+        // Skip while a load or write is in flight, overlapping loads would duplicate entries
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
 
-        // Update UI to show loading state
-        value.status = RedisValueStatus::Loading;
-        cx.notify();
-
         // Extract current cursor and filter keyword from HASH state
-        let (cursor, keyword) = match value.hash_value() {
-            Some(hash) => (hash.cursor, hash.keyword.clone()),
+        let (cursor, keyword, novalues, lazy_values) = match value.hash_value() {
+            Some(hash) => (hash.cursor, hash.keyword.clone(), hash.novalues, hash.lazy_values),
             None => return,
         };
 
+        // Update UI to show loading state
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+        if user_action {
+            self.reset_auto_load();
+        }
+
         let server_id = self.server_id.clone();
         let db = self.db;
         cx.emit(ServerEvent::ValuePaginationStarted);
@@ -378,12 +758,17 @@ impl ZedisServerState {
                 // Use larger batch size when filtering to reduce round trips
                 let count = if keyword.is_some() { 1000 } else { 100 };
 
-                get_redis_hash_value(&mut conn, &key, keyword, cursor, count).await
+                // Servers without NOVALUES list the few fields of lazily loaded hashes at once
+                if lazy_values && !novalues {
+                    let fields = list_hash_fields(&mut conn, &key, keyword.as_ref()).await?;
+                    return Ok((0, HashScanPage::Fields(fields)));
+                }
+                scan_hash_page(&mut conn, &key, keyword, cursor, count, novalues).await
             },
             // UI callback: merge results into local state
             move |this, result, cx| {
                 let mut should_load_more = false;
-                if let Ok((new_cursor, new_values)) = result
+                if let Ok((new_cursor, page)) = result
                     && let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
                     let hash = Arc::make_mut(hash_data);
@@ -394,10 +779,8 @@ impl ZedisServerState {
                         hash.done = true;
                     }
 
-                    // Append new field-value pairs to existing list
-                    if !new_values.is_empty() {
-                        hash.values.extend(new_values);
-                    }
+                    // Append new field-value pairs to existing list, skipping duplicates
+                    hash.append_page(page);
                     if !hash.done && hash.values.len() < 50 {
                         should_load_more = true;
                     }
@@ -410,11 +793,219 @@ impl ZedisServerState {
                     value.status = RedisValueStatus::Idle;
                 }
                 cx.notify();
-                if should_load_more {
-                    this.load_more_hash_value(cx);
+                if should_load_more && this.next_auto_load(cx) {
+                    this.fetch_more_hash_value(false, cx);
                 }
             },
             cx,
         );
     }
+    /// Queues loading the value of a field scanned with NOVALUES.
+    ///
+    /// The fields requested while rendering are batched into a single HMGET.
+    /// Returns false for a field that failed to load; it is not requested again until the value is reloaded.
+    pub fn request_hash_value(&mut self, field: SharedString, cx: &mut Context<Self>) -> bool {
+        if self.hash_value_failed.contains(&field) {
+            return false;
+        }
+        if !self.hash_value_requests.insert(field.clone()) {
+            return true;
+        }
+        let lazy_values = self
+            .value
+            .as_ref()
+            .and_then(|value| value.hash_value())
+            .is_some_and(|hash| hash.lazy_values);
+        // Large values are loaded one by one, so one big value never delays the others
+        if lazy_values {
+            self.fetch_hash_field(field, cx);
+            return true;
+        }
+        self.hash_value_queue.push(field);
+        // The first queued field schedules the batch
+        if self.hash_value_queue.len() == 1 {
+            cx.spawn(async move |handle, cx| {
+                let _ = handle.update(cx, |this, cx| {
+                    this.load_hash_values(cx);
+                });
+            })
+            .detach();
+        }
+        true
+    }
+    /// Loads the queued field values with HMGET and fills them in.
+    fn load_hash_values(&mut self, cx: &mut Context<Self>) {
+        let fields = std::mem::take(&mut self.hash_value_queue);
+        let Some(key) = self.key.clone() else {
+            self.hash_value_requests.clear();
+            return;
+        };
+        if fields.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let batch = fields.clone();
+        self.spawn(
+            ServerTask::LoadHashValues,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut hmget = cmd("HMGET");
+                hmget.arg(key.as_str());
+                for field in &batch {
+                    hmget.arg(field.as_str());
+                }
+                let values: Vec<Option<Vec<u8>>> = hmget.query_async(&mut conn).await?;
+                Ok((key, values))
+            },
+            move |this, result, cx| {
+                for field in &fields {
+                    this.hash_value_requests.remove(field);
+                }
+                let Ok((key, values)) = result else {
+                    // Rendering would request the fields again on every frame
+                    this.hash_value_failed.extend(fields);
+                    cx.notify();
+                    return;
+                };
+                if this.key.as_ref() != Some(&key) {
+                    return;
+                }
+                let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) else {
+                    return;
+                };
+                let hash = Arc::make_mut(hash_data);
+                let mut loaded: HashMap<SharedString, SharedString> = fields
+                    .into_iter()
+                    .zip(values)
+                    .filter(|(field, _)| hash.pending.contains(field))
+                    .map(|(field, value)| {
+                        // A field deleted in the meantime has no value
+                        let value = value.map(|value| String::from_utf8_lossy(&value).to_string());
+                        (field, value.unwrap_or_default().into())
+                    })
+                    .collect();
+                for (field, value) in hash.values.iter_mut() {
+                    if let Some(loaded_value) = loaded.remove(field) {
+                        hash.pending.remove(field);
+                        *value = loaded_value;
+                    }
+                }
+                cx.emit(ServerEvent::ValueCellsLoaded);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Loads the value of one field with HGET and caches it in the loaded hash.
+    pub fn fetch_hash_field(&mut self, field: SharedString, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            self.hash_value_requests.remove(&field);
+            return;
+        };
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let request_field = field.clone();
+        self.spawn(
+            ServerTask::LoadHashValues,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let value: Option<Vec<u8>> = cmd("HGET")
+                    .arg(key.as_str())
+                    .arg(request_field.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok((key, value))
+            },
+            move |this, result, cx| {
+                this.hash_value_requests.remove(&field);
+                let Ok((key, value)) = result else {
+                    this.hash_value_failed.insert(field);
+                    cx.notify();
+                    return;
+                };
+                if this.key.as_ref() != Some(&key) {
+                    return;
+                }
+                let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) else {
+                    return;
+                };
+                if !hash_data.pending.contains(&field) {
+                    return;
+                }
+                let hash = Arc::make_mut(hash_data);
+                hash.pending.remove(&field);
+                if let Some(entry) = hash.values.iter_mut().find(|(f, _)| f == &field) {
+                    // A field deleted in the meantime has no value
+                    entry.1 = value
+                        .map(|value| String::from_utf8_lossy(&value).to_string())
+                        .unwrap_or_default()
+                        .into();
+                }
+                cx.emit(ServerEvent::ValueCellsLoaded);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(fields: &[(&str, &str)]) -> Vec<(SharedString, SharedString)> {
+        fields
+            .iter()
+            .map(|(field, value)| (field.to_string().into(), value.to_string().into()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_hash_fields_json() {
+        assert_eq!(
+            parse_hash_fields(r#"{"name": "zedis", "port": 6379, "tags": ["a"]}"#),
+            Ok(pairs(&[("name", "zedis"), ("port", "6379"), ("tags", r#"["a"]"#)]))
+        );
+        assert_eq!(parse_hash_fields("  {}  "), Ok(vec![]));
+        assert!(matches!(
+            parse_hash_fields(r#"{"name": }"#),
+            Err(HashFieldsError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_hash_fields_lines() {
+        assert_eq!(
+            parse_hash_fields("name=zedis\nurl=redis://host/?a=1&b=2\nempty="),
+            Ok(pairs(&[
+                ("name", "zedis"),
+                ("url", "redis://host/?a=1&b=2"),
+                ("empty", "")
+            ]))
+        );
+        // Field names are trimmed, values keep their whitespace
+        assert_eq!(
+            parse_hash_fields("  name = zedis \r\npadded=  two  "),
+            Ok(pairs(&[("name", " zedis "), ("padded", "  two  ")]))
+        );
+    }
+
+    #[test]
+    fn test_parse_hash_fields_comments() {
+        assert_eq!(
+            parse_hash_fields("# exported fields\n\nname=zedis\n  # note=skipped\ncolor=#fff"),
+            Ok(pairs(&[("name", "zedis"), ("color", "#fff")]))
+        );
+        assert_eq!(parse_hash_fields("# only a comment\n\n"), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_hash_fields_invalid_lines() {
+        // Line numbers are 1-based and count the skipped comment and empty lines
+        assert_eq!(
+            parse_hash_fields("# header\nname=zedis\n\nmissing separator\n=no field\nport=6379"),
+            Err(HashFieldsError::Lines(vec![4, 5]))
+        );
+    }
 }
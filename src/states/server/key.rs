@@ -13,31 +13,36 @@
 // limitations under the License.
 
 use super::{
-    ServerEvent, ServerTask, ZedisServerState,
+    ScanIteration, ServerEvent, ServerTask, ZedisServerState,
+    bulk::{BULK_SCAN_COUNT, BULK_SCAN_ROUNDS},
     hash::first_load_hash_value,
     list::first_load_list_value,
     set::first_load_set_value,
     stream::first_load_stream_value,
-    string::get_redis_bytes_value,
+    string::get_redis_bytes_head_value,
     value::{KeyType, RedisValue, RedisValueData, RedisValueStatus, SortOrder},
     zset::first_load_zset_value,
 };
-use crate::states::{QueryMode, ZedisGlobalStore};
+use crate::states::{DefaultSort, QueryMode, ZedisGlobalStore};
 use crate::{
     connection::get_connection_manager,
     error::Error,
-    helpers::{parse_duration, unix_ts},
+    helpers::{parse_duration, prefix_glob, redis_value_to_string, unix_ts},
 };
 use ahash::AHashSet;
-use futures::stream::{self, StreamExt};
 use gpui::{SharedString, prelude::*};
-use redis::{cmd, pipe};
+use redis::{RedisResult, cmd, pipe};
+use rust_i18n::t;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::debug;
 use uuid::Uuid;
 
 const DEFAULT_SCAN_RESULT_MAX: usize = 3_000;
+/// Keys of a single OBJECT ENCODING fetch, the tree asks again as folders are expanded
+const MAX_KEY_ENCODINGS_PER_FETCH: usize = 1_000;
+/// Extra time given to WAIT over its own timeout before the client gives up on the reply.
+const WAIT_CLIENT_GRACE: Duration = Duration::from_secs(1);
 
 impl ZedisServerState {
     /// Fills the type of keys that are currently loaded but have an unknown type.
@@ -87,52 +92,21 @@ impl ZedisServerState {
             ServerTask::FillKeyTypes,
             move || async move {
                 let client = get_connection_manager().get_client(&server_id, db).await?;
-                let mut types = Vec::with_capacity(keys.len());
-                if client.is_cluster() {
-                    // Cluster mode: keys may be on different nodes, use concurrent requests
-                    let conn = client.connection().clone();
-                    let results: Vec<(SharedString, String)> = stream::iter(keys.iter().cloned())
-                        .map(|key| {
-                            let mut conn_clone = conn.clone();
-                            async move {
-                                let t: String = cmd("TYPE")
-                                    .arg(key.as_str())
-                                    .query_async(&mut conn_clone)
-                                    .await
-                                    .unwrap_or_default();
-                                (key, t)
-                            }
-                        })
-                        .buffer_unordered(100)
-                        .collect()
-                        .await;
-                    types = results;
-                } else {
-                    // Non-cluster: use pipeline to batch TYPE commands, reducing RTT
-                    let mut conn = client.connection().clone();
-                    for chunk in keys.chunks(500) {
-                        let mut pipeline = pipe();
-                        for key in chunk {
-                            pipeline.cmd("TYPE").arg(key.as_str());
-                        }
-                        let results: Vec<String> = pipeline.query_async(&mut conn).await?;
-                        for (key, t) in chunk.iter().zip(results) {
-                            types.push((key.clone(), t));
-                        }
-                    }
-                }
-                Ok(types)
+                let types = client.fetch_types(&keys).await?;
+                Ok(keys.into_iter().zip(types).collect::<Vec<_>>())
             },
             move |this, result, cx| {
                 if let Ok(types) = result {
                     // Update local state with fetched types
+                    let mut changed_keys = Vec::with_capacity(types.len());
                     for (key, value) in types {
                         if let Some(k) = this.keys.get_mut(&key) {
                             *k = KeyType::from(value.as_str());
+                            changed_keys.push((key, *k));
                         }
                     }
                     // Trigger UI update by changing the tree ID
-                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                    this.upsert_key_tree(changed_keys);
                 }
                 cx.notify();
             },
@@ -171,16 +145,23 @@ impl ZedisServerState {
                 } else {
                     key_scan_count
                 };
-                if let Some(cursors) = cursors {
-                    client.scan(Some(cursors), &pattern, count).await
+                let start = Instant::now();
+                let (cursors, keys) = if let Some(cursors) = cursors {
+                    client.scan(Some(cursors), &pattern, count).await?
                 } else {
-                    client.first_scan(&pattern, count).await
-                }
+                    client.first_scan(&pattern, count).await?
+                };
+                Ok((cursors, keys, start.elapsed()))
             },
             move |this, result, cx| {
                 match result {
-                    Ok((cursors, keys)) => {
+                    Ok((cursors, keys, elapsed)) => {
                         debug!("cursors: {cursors:?}, keys count: {}", keys.len());
+                        this.record_scan_iteration(ScanIteration {
+                            cursors: cursors.clone(),
+                            keys: keys.len(),
+                            elapsed_ms: elapsed.as_millis() as u64,
+                        });
                         // Check if scan is complete (all cursors returned to 0)
                         if cursors.iter().sum::<u64>() == 0 {
                             this.scan_completed = true;
@@ -217,6 +198,10 @@ impl ZedisServerState {
         );
     }
     pub fn handle_auto_refresh(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        // a loaded key list is not rescanned
+        if self.key_list_loaded {
+            return;
+        }
         if self.query_mode == QueryMode::Exact {
             self.select_key(keyword, cx);
             return;
@@ -226,7 +211,7 @@ impl ZedisServerState {
                 self.select_key(keyword, cx);
                 return;
             }
-            QueryMode::Prefix => format!("{keyword}*"),
+            QueryMode::Prefix => prefix_glob(&keyword),
             _ => format!("*{keyword}*"),
         };
         let server_id = self.server_id.clone();
@@ -344,7 +329,8 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
-        let pattern = format!("{}*", prefix);
+        // the prefix is a literal key prefix, not a glob
+        let pattern = prefix_glob(&prefix);
         let key_scan_count = cx.global::<ZedisGlobalStore>().read(cx).key_scan_count() as u64;
         self.spawn(
             ServerTask::ScanPrefix,
@@ -397,14 +383,76 @@ impl ZedisServerState {
         );
     }
 
+    /// Fetches the OBJECT ENCODING of the keys that don't have one yet, pipelined per node.
+    pub fn fetch_key_encodings(&mut self, keys: Vec<SharedString>, cx: &mut Context<Self>) {
+        if !self.supports_key_encoding() {
+            return;
+        }
+        let keys: Vec<SharedString> = keys
+            .into_iter()
+            .filter(|key| !self.key_encodings.contains_key(key))
+            .take(MAX_KEY_ENCODINGS_PER_FETCH)
+            .collect();
+        if keys.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.spawn(
+            ServerTask::FetchKeyEncodings,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                let encodings = client.fetch_encodings(&keys).await?;
+                Ok((server_id, keys.into_iter().zip(encodings).collect::<Vec<_>>()))
+            },
+            move |this, result, cx| {
+                if let Ok((server_id, encodings)) = result
+                    && this.server_id == server_id
+                {
+                    this.key_encodings.extend(encodings);
+                    cx.emit(ServerEvent::KeyEncodingsLoaded);
+                }
+            },
+            cx,
+        );
+    }
+
+    /// Shows exactly the keys of an external list (e.g. `redis-cli --scan` output)
+    /// without scanning the database.
+    ///
+    /// The scan is marked as completed, so expanding a folder only fetches the
+    /// types of its keys instead of scanning the prefix.
+    pub fn load_key_list(&mut self, keys: Vec<String>, cx: &mut Context<Self>) {
+        self.reset_scan(cx);
+        let keys: Vec<(SharedString, SharedString)> = keys
+            .into_iter()
+            .map(|key| key.trim_end_matches(['\r', '\n']).to_string())
+            .filter(|key| !key.is_empty())
+            .map(|key| (key.into(), SharedString::default()))
+            .collect();
+        debug!(count = keys.len(), "load key list");
+        self.scan_completed = true;
+        self.key_list_loaded = true;
+        self.extend_keys(keys);
+        cx.emit(ServerEvent::KeyScanFinished);
+        cx.emit(ServerEvent::KeyTreeUpdated);
+        cx.notify();
+        self.fill_key_types(None, cx);
+    }
+
     fn get_value(&mut self, key: SharedString, task: ServerTask, cx: &mut Context<Self>) {
         if key.is_empty() {
             return;
         }
+        self.hash_value_failed.clear();
         let server_id = self.server_id.clone();
         let db = self.db;
         let current_key = key.clone();
-        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let max_truncate_length = store.max_truncate_length();
+        let set_sort = store.default_sort(KeyType::Set.as_str());
+        let zset_sort = store.default_sort(KeyType::Zset.as_str());
+        let config_allowed = self.acl_permissions.allows("CONFIG GET");
 
         self.spawn(
             task,
@@ -433,7 +481,7 @@ impl ZedisServerState {
                 let key_type = KeyType::from(t.as_str());
                 let mut redis_value = match key_type {
                     KeyType::String => {
-                        let mut data = get_redis_bytes_value(&mut conn, &key).await?;
+                        let mut data = get_redis_bytes_head_value(&mut conn, &key).await?;
                         data.detect_and_update(server_id.as_str(), key.as_str(), max_truncate_length);
                         Ok(RedisValue {
                             key_type: KeyType::String,
@@ -443,16 +491,67 @@ impl ZedisServerState {
                     }
                     KeyType::List => first_load_list_value(&mut conn, &key).await,
                     KeyType::Set => first_load_set_value(&mut conn, &key).await,
-                    KeyType::Zset => first_load_zset_value(&mut conn, &key, SortOrder::Asc).await,
-                    KeyType::Hash => first_load_hash_value(&mut conn, &key).await,
+                    KeyType::Zset => {
+                        // Leaderboards load the highest scores first instead of sorting the first page
+                        let sort_order = if zset_sort == DefaultSort::ScoreDesc {
+                            SortOrder::Desc
+                        } else {
+                            SortOrder::Asc
+                        };
+                        first_load_zset_value(&mut conn, &key, sort_order).await
+                    }
+                    KeyType::Hash => {
+                        first_load_hash_value(
+                            &mut conn,
+                            &key,
+                            client.is_at_least_version("7.4.0"),
+                            client.is_at_least_version("4.0.0"),
+                        )
+                        .await
+                    }
                     KeyType::Stream => first_load_stream_value(&mut conn, &key).await,
                     _ => Err(Error::Invalid {
                         message: "unsupported key type".to_string(),
                     }),
                 }?;
+                match key_type {
+                    KeyType::Set => redis_value.apply_default_sort(set_sort),
+                    KeyType::Zset => redis_value.apply_default_sort(zset_sort),
+                    _ => {}
+                }
                 if let Ok(memory_usage) = client.memory_usage(key.as_str(), key_type.as_str()).await {
                     redis_value.size = memory_usage;
                 }
+                // OBJECT/CONFIG may be renamed or restricted, so encoding info is best-effort
+                if client.flavor().supports_command("OBJECT ENCODING")
+                    && let Ok(encoding) = cmd("OBJECT")
+                        .arg("ENCODING")
+                        .arg(key.as_str())
+                        .query_async::<String>(&mut conn)
+                        .await
+                {
+                    redis_value.encoding = Some(encoding.into());
+                }
+                if config_allowed
+                    && redis_value.is_encoding_converted()
+                    && let Some(pattern) = key_type.encoding_config_pattern()
+                    && let Ok(items) = cmd("CONFIG")
+                        .arg("GET")
+                        .arg(pattern)
+                        .query_async::<Vec<String>>(&mut conn)
+                        .await
+                {
+                    let mut configs: Vec<(SharedString, SharedString)> = items
+                        .chunks_exact(2)
+                        .map(|pair| (pair[0].clone().into(), pair[1].clone().into()))
+                        .collect();
+                    // Redis 7 reports the legacy ziplist names as aliases of the listpack ones
+                    if configs.iter().any(|(name, _)| name.contains("listpack")) {
+                        configs.retain(|(name, _)| !name.contains("ziplist"));
+                    }
+                    configs.sort_by(|a, b| a.0.cmp(&b.0));
+                    redis_value.encoding_configs = configs;
+                }
                 redis_value.expire_at = expire_at;
                 Ok(redis_value)
             },
@@ -462,7 +561,11 @@ impl ZedisServerState {
                 }
                 match result {
                     Ok(value) => {
+                        this.value_cached_at = None;
+                        this.value_fetched_at = unix_ts();
+                        this.value_dirty = false;
                         if this.value.as_ref() == Some(&value) {
+                            cx.notify();
                             return;
                         }
                         if !value.is_expired() {
@@ -536,13 +639,132 @@ impl ZedisServerState {
         );
     }
 
+    /// Executes the queued commands atomically via MULTI/EXEC.
+    ///
+    /// Each command is a list of arguments starting with the command name.
+    /// Emits `TransactionExecuted` with one formatted reply per command,
+    /// or a single error line if the transaction was aborted.
+    pub fn exec_transaction(&mut self, commands: Vec<Vec<String>>, cx: &mut Context<Self>) {
+        if commands.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.spawn(
+            ServerTask::ExecTransaction,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut tx = pipe();
+                // Every queued command has been applied once EXEC runs, so keep the replies after a failed one
+                tx.atomic().ignore_errors();
+                for parts in commands {
+                    let Some((name, args)) = parts.split_first() else {
+                        continue;
+                    };
+                    tx.cmd(name).arg(args);
+                }
+                let values: Vec<RedisResult<redis::Value>> = tx.query_async(&mut conn).await?;
+                Ok(values)
+            },
+            |_this, result, cx| {
+                let replies = match result {
+                    Ok(values) => values
+                        .iter()
+                        .map(|value| match value {
+                            Ok(value) => redis_value_to_string(value).into(),
+                            Err(e) => format!("(error) {e}").into(),
+                        })
+                        .collect(),
+                    Err(e) => vec![format!("(error) {e}").into()],
+                };
+                cx.emit(ServerEvent::TransactionExecuted(replies));
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Blocks until `replicas` replicas acknowledged the previous writes or `timeout` (ms) elapses.
+    ///
+    /// Runs `WAIT numreplicas timeout` and reports the number of acknowledging replicas,
+    /// warning when fewer replicas than requested confirmed the write. WAIT only covers the
+    /// writes of its own connection, so it runs on the shared one and stalls it meanwhile;
+    /// the timeout setting is capped low for that reason.
+    pub fn wait_replication(&mut self, replicas: usize, timeout: u64, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.spawn(
+            ServerTask::WaitReplication,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let wait = async {
+                    let acked: usize = cmd("WAIT").arg(replicas).arg(timeout).query_async(&mut conn).await?;
+                    Ok::<_, Error>(acked)
+                };
+                // Don't rely on the server honoring the timeout, the connection is shared by every command
+                let deadline = async {
+                    smol::Timer::after(Duration::from_millis(timeout) + WAIT_CLIENT_GRACE).await;
+                    Err(Error::Invalid {
+                        message: format!("WAIT did not return within {timeout}ms"),
+                    })
+                };
+                smol::future::or(wait, deadline).await
+            },
+            move |this, result, cx| {
+                let Ok(acked) = result else {
+                    return;
+                };
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                if acked >= replicas {
+                    let message = t!(
+                        "editor.wait_replicated",
+                        acked = acked,
+                        replicas = replicas,
+                        locale = locale
+                    );
+                    this.emit_info_notification(message.into(), cx);
+                } else {
+                    let message = t!(
+                        "editor.wait_not_replicated",
+                        acked = acked,
+                        replicas = replicas,
+                        timeout = timeout,
+                        locale = locale
+                    );
+                    this.emit_warning_notification(message.into(), cx);
+                }
+            },
+            cx,
+        );
+    }
+
     /// Selects a key and fetches its details (Type, TTL, Value).
     pub fn select_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+        self.stop_stream_follow(cx);
+        // Selecting the displayed key again fetches it fresh
+        let reselected = self.key.as_ref() == Some(&key);
+        if !reselected {
+            self.cache_current_value();
+        }
         self.key = Some(key.clone());
         if key.is_empty() {
             return;
         }
+        self.record_recent_key(key.clone());
         self.terminal = false;
+        self.reset_auto_load();
+        if !reselected && let Some((value, cached_at)) = self.value_cache.get(self.db, &key) {
+            debug!(key = key.as_str(), cached_at, "Value served from cache");
+            self.keys.entry(key.clone()).or_insert(value.key_type);
+            self.value = Some(value);
+            self.value_cached_at = Some(cached_at);
+            self.hash_value_failed.clear();
+            self.value_dirty = false;
+            cx.emit(ServerEvent::KeySelected(key));
+            cx.emit(ServerEvent::ValueLoaded);
+            cx.notify();
+            return;
+        }
         // only set loading status if the value exists for better performance
         // prevent editor flickering
         if let Some(value) = self.value.as_mut() {
@@ -590,20 +812,25 @@ impl ZedisServerState {
         );
     }
 
+    /// Returns the SCAN pattern of the keys under a folder of the tree.
+    pub fn folder_pattern(&self, folder: &str, cx: &Context<Self>) -> SharedString {
+        let separator = cx.global::<ZedisGlobalStore>().read(cx).key_separator();
+        prefix_glob(&format!("{folder}{separator}")).into()
+    }
+
     pub fn delete_folder(&mut self, folder: SharedString, cx: &mut Context<Self>) {
         let server_id = self.server_id.clone();
         let db = self.db;
-        let separator = cx.global::<ZedisGlobalStore>().value(cx).key_separator().to_string();
+        let pattern = self.folder_pattern(&folder, cx);
+        let separator = cx.global::<ZedisGlobalStore>().read(cx).key_separator();
         let prefix = format!("{folder}{separator}");
-        let pattern = format!("{prefix}*");
         self.spawn(
             ServerTask::DeleteKeys,
             move || async move {
                 let client = get_connection_manager().get_client(&server_id, db).await?;
-                let count = 10_000;
                 let mut cursors: Option<Vec<u64>> = None;
-                for _ in 0..20 {
-                    let (new_cursors, keys_per_node) = client.scan_nodes(cursors, &pattern, count).await?;
+                for _ in 0..BULK_SCAN_ROUNDS {
+                    let (new_cursors, keys_per_node) = client.scan_nodes(cursors, &pattern, BULK_SCAN_COUNT).await?;
                     client.unlike_keys(keys_per_node).await?;
 
                     if new_cursors.iter().sum::<u64>() == 0 {
@@ -784,4 +1011,36 @@ impl ZedisServerState {
             cx,
         );
     }
+
+    /// Picks `count` random keys with their type, size and TTL, to get a feel
+    /// of an unfamiliar keyspace without scanning all of it.
+    pub fn sample_random_keys(&mut self, count: usize, cx: &mut Context<Self>) {
+        if self.sampling_random_keys {
+            return;
+        }
+        self.sampling_random_keys = true;
+        cx.notify();
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_server_id = server_id.clone();
+        self.spawn(
+            ServerTask::SampleRandomKeys,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                client.sample_random_keys(count).await
+            },
+            move |this, result, cx| {
+                if this.server_id != current_server_id {
+                    return;
+                }
+                this.sampling_random_keys = false;
+                if let Ok(keys) = result {
+                    this.random_keys = keys;
+                }
+                cx.emit(ServerEvent::RandomKeysSampled);
+                cx.notify();
+            },
+            cx,
+        );
+    }
 }
@@ -14,12 +14,12 @@
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
-    value::{RedisListValue, RedisValue, RedisValueStatus},
+    value::{RedisListValue, RedisValue, RedisValueStatus, ensure_key_type},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::ServerEvent,
+    states::{ServerEvent, ZedisGlobalStore},
 };
 use gpui::{SharedString, prelude::*};
 use redis::{cmd, pipe};
@@ -100,12 +100,17 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
+        let verify_type = cx.global::<ZedisGlobalStore>().read(cx).verify_key_type();
 
         // Step 2: Spawn background task for Redis operation
         self.spawn(
             task,
             move || async move {
-                let conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Refuse to write with the commands of another type, the editor asks to reload instead
+                if verify_type {
+                    ensure_key_type(&mut conn, &key_str, KeyType::List).await?;
+                }
                 // Pass conn directly; 'mut' is handled inside the closure implementation
                 redis_op(key_str, conn).await?;
                 Ok(())
@@ -14,12 +14,13 @@
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
-    value::{RedisSetValue, RedisValue, RedisValueStatus},
+    value::{RedisSetValue, RedisValue, RedisValueStatus, ensure_key_type},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, i18n_set_editor},
+    helpers::contains_glob,
+    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, ZedisGlobalStore, i18n_set_editor},
 };
 use gpui::{SharedString, prelude::*};
 use redis::cmd;
@@ -45,10 +46,10 @@ async fn get_redis_set_value(
     cursor: u64,
     count: usize,
 ) -> Result<(u64, Vec<SharedString>)> {
-    // Build pattern: wrap keyword with wildcards or match all
+    // Build pattern: wrap the literal keyword with wildcards or match all
     let pattern = keyword
         .as_ref()
-        .map(|kw| format!("*{}*", kw))
+        .map(|kw| contains_glob(kw))
         .unwrap_or_else(|| "*".to_string());
 
     // Execute SSCAN with MATCH and COUNT options
@@ -97,15 +98,17 @@ pub(crate) async fn first_load_set_value(conn: &mut RedisAsyncConn, key: &str) -
     // If cursor is 0, all values have been loaded in one iteration
     let done = cursor == 0;
 
+    let mut set = RedisSetValue {
+        cursor,
+        size,
+        done,
+        ..Default::default()
+    };
+    set.append_scanned(values);
+
     Ok(RedisValue {
         key_type: KeyType::Set,
-        data: Some(RedisValueData::Set(Arc::new(RedisSetValue {
-            cursor,
-            size,
-            values,
-            done,
-            ..Default::default()
-        }))),
+        data: Some(RedisValueData::Set(Arc::new(set))),
         ..Default::default()
     })
 }
@@ -113,6 +116,7 @@ pub(crate) async fn first_load_set_value(conn: &mut RedisAsyncConn, key: &str) -
 impl ZedisServerState {
     /// A generic helper for Redis Set operations (Add, Remove, Update).
     /// Handles status switching, optimistic UI updates, and background task execution.
+    /// `rollback` reverts the optimistic update if the Redis command fails.
     fn exec_set_op<F, Fut, R>(
         &mut self,
         task: ServerTask,
@@ -120,6 +124,7 @@ impl ZedisServerState {
         optimistic_update: impl FnOnce(&mut RedisSetValue),
         redis_op: F,
         on_success: impl FnOnce(&mut Self, R, &mut Context<Self>) + Send + 'static,
+        rollback: impl FnOnce(&mut RedisSetValue) + Send + 'static,
     ) where
         F: FnOnce(String, RedisAsyncConn) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = Result<R>> + Send,
@@ -129,6 +134,7 @@ impl ZedisServerState {
             return;
         };
         let key_str = key.to_string();
+        let rollback_key = key.clone();
         value.status = RedisValueStatus::Updating;
 
         // Step 1: Perform local optimistic update
@@ -140,12 +146,17 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
+        let verify_type = cx.global::<ZedisGlobalStore>().read(cx).verify_key_type();
 
         // Step 2: Spawn background task
         self.spawn(
             task,
             move || async move {
-                let conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Refuse to write with the commands of another type, the editor asks to reload instead
+                if verify_type {
+                    ensure_key_type(&mut conn, &key_str, KeyType::Set).await?;
+                }
                 redis_op(key_str, conn).await
             },
             move |this, result, cx| {
@@ -156,8 +167,17 @@ impl ZedisServerState {
                 match result {
                     Ok(data) => on_success(this, data, cx),
                     Err(e) => {
-                        // Handle error (e.g., show notification and potentially rollback if needed)
-                        this.emit_error_notification(e.to_string().into(), cx);
+                        // Revert the optimistic update if the key is still selected
+                        if this.key.as_ref() == Some(&rollback_key)
+                            && let Some(RedisValueData::Set(set_data)) =
+                                this.value.as_mut().and_then(|v| v.data.as_mut())
+                        {
+                            rollback(Arc::make_mut(set_data));
+                            cx.emit(ServerEvent::ValueUpdated);
+                        }
+                        if !e.is_type_mismatch() {
+                            this.emit_error_notification(e.to_string().into(), cx);
+                        }
                     }
                 }
                 cx.notify();
@@ -168,12 +188,16 @@ impl ZedisServerState {
     pub fn update_set_value(&mut self, old_value: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
         let old_value_clone = old_value.clone();
         let new_value_clone = new_value.clone();
+        let rollback_old_value = old_value.clone();
+        let rollback_new_value = new_value.clone();
 
         self.exec_set_op(
             ServerTask::UpdateSetValue,
             cx,
             move |set| {
                 if let Some(pos) = set.values.iter().position(|v| v == &old_value_clone) {
+                    set.seen.remove(&old_value_clone);
+                    set.seen.insert(new_value_clone.clone());
                     set.values[pos] = new_value_clone;
                 }
             },
@@ -193,6 +217,13 @@ impl ZedisServerState {
             |_, _, cx| {
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            move |set| {
+                if let Some(pos) = set.values.iter().position(|v| v == &rollback_new_value) {
+                    set.seen.remove(&rollback_new_value);
+                    set.seen.insert(rollback_old_value.clone());
+                    set.values[pos] = rollback_old_value;
+                }
+            },
         );
     }
     /// Adds a new member to the Redis SET.
@@ -225,7 +256,7 @@ impl ZedisServerState {
                     let set = Arc::make_mut(set_data);
                     set.size += count;
                     // Only append to UI if scan is complete to maintain consistency
-                    if set.done && !set.values.contains(&val_clone) {
+                    if set.done && set.seen.insert(val_clone.clone()) {
                         set.values.push(val_clone);
                     }
                     if set.size > SUCCESS_NOTIFY_THRESHOLD {
@@ -238,6 +269,7 @@ impl ZedisServerState {
                 }
                 cx.emit(ServerEvent::ValueAdded);
             },
+            |_| {},
         );
     }
     /// Applies a filter to SET members by resetting the scan state with a keyword.
@@ -267,6 +299,18 @@ impl ZedisServerState {
         // Trigger load with the new filter
         self.load_more_set_value(cx);
     }
+    /// Sorts the loaded SET members alphabetically (client-side only).
+    pub fn sort_set_value(&mut self, descending: bool, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(RedisValueData::Set(set_data)) = value.data.as_mut() else {
+            return;
+        };
+        Arc::make_mut(set_data).sort_values(descending);
+        cx.emit(ServerEvent::ValueUpdated);
+        cx.notify();
+    }
     /// Loads the next batch of SET members using cursor-based pagination.
     ///
     /// Uses SSCAN to incrementally load members without blocking on large SETs.
@@ -276,20 +320,32 @@ impl ZedisServerState {
     /// # Arguments
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn load_more_set_value(&mut self, cx: &mut Context<Self>) {
+        self.fetch_more_set_value(true, cx);
+    }
+
+    /// Fetches the next SSCAN batch, automatically continuing until enough items are loaded.
+    ///
+    /// The automatic rounds are bounded by count and time per user action, see `next_auto_load`.
+    /// A `user_action` starts a new budget, unless a load is already in flight.
+    fn fetch_more_set_value(&mut self, user_action: bool, cx: &mut Context<Self>) {
+        // Skip while a load or write is in flight, overlapping loads would duplicate entries
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
 
-        // Update UI to show loading state
-        value.status = RedisValueStatus::Loading;
-        cx.notify();
-
         // Extract current cursor and filter keyword from SET state
         let (cursor, keyword) = match value.set_value() {
             Some(set) => (set.cursor, set.keyword.clone()),
             None => return,
         };
 
+        // Update UI to show loading state
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+        if user_action {
+            self.reset_auto_load();
+        }
+
         let server_id = self.server_id.clone();
         let db = self.db;
         cx.emit(ServerEvent::ValuePaginationStarted);
@@ -310,6 +366,10 @@ impl ZedisServerState {
             // UI callback: merge results and handle auto-loading for filters
             move |this, result, cx| {
                 let mut should_load_more = false;
+                let default_sort = cx
+                    .global::<ZedisGlobalStore>()
+                    .read(cx)
+                    .default_sort(KeyType::Set.as_str());
 
                 if let Ok((new_cursor, new_values)) = result
                     && let Some(RedisValueData::Set(set_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
@@ -322,10 +382,9 @@ impl ZedisServerState {
                         set.done = true;
                     }
 
-                    // Append new members to existing list
-                    if !new_values.is_empty() {
-                        set.values.extend(new_values);
-                    }
+                    // Append new members to existing list, skipping duplicates
+                    set.append_scanned(new_values);
+                    set.apply_default_sort(default_sort);
 
                     // Auto-load more batches when filtering until we have enough results
                     // This provides better UX by showing meaningful results immediately
@@ -343,8 +402,8 @@ impl ZedisServerState {
                 cx.notify();
 
                 // Recursively load more if needed
-                if should_load_more {
-                    this.load_more_set_value(cx);
+                if should_load_more && this.next_auto_load(cx) {
+                    this.fetch_more_set_value(false, cx);
                 }
             },
             cx,
@@ -360,12 +419,20 @@ impl ZedisServerState {
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn remove_set_value(&mut self, remove_value: SharedString, cx: &mut Context<Self>) {
         let val_clone = remove_value.clone();
+        let rollback_value = remove_value.clone();
+        // Position of the removed member, restored if the removal fails
+        let removed_pos = self
+            .value
+            .as_ref()
+            .and_then(|v| v.set_value())
+            .and_then(|set| set.values.iter().position(|v| v == &remove_value));
 
         self.exec_set_op(
             ServerTask::RemoveSetValue,
             cx,
             move |set| {
                 set.size -= 1;
+                set.seen.remove(&val_clone);
                 set.values.retain(|v| v != &val_clone);
             },
             move |key, mut conn| async move {
@@ -379,6 +446,14 @@ impl ZedisServerState {
             |_, _, cx| {
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            move |set| {
+                set.size += 1;
+                if let Some(pos) = removed_pos
+                    && set.seen.insert(rollback_value.clone())
+                {
+                    set.values.insert(pos.min(set.values.len()), rollback_value);
+                }
+            },
         );
     }
 }
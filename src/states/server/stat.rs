@@ -13,15 +13,63 @@
 // limitations under the License.
 
 use crate::connection::get_connection_manager;
+use crate::error::Error;
 use crate::helpers::{unix_ts, unix_ts_millis};
-use crate::states::{ServerEvent, ServerTask, ZedisServerState};
-use gpui::prelude::*;
+use crate::states::{MetricsAlertThresholds, ServerEvent, ServerTask, ZedisGlobalStore, ZedisServerState};
+use gpui::{App, SharedString, prelude::*};
 use parking_lot::RwLock;
 use redis::cmd;
+use rust_i18n::t;
 use std::collections::{HashMap, VecDeque};
 use std::sync::LazyLock;
 use std::time::Instant;
-use tracing::error;
+use tracing::{error, warn};
+
+/// INFO sections parsed by the metrics heartbeat.
+const HEARTBEAT_INFO_SECTIONS: [&str; 8] = [
+    "server",
+    "clients",
+    "memory",
+    "persistence",
+    "stats",
+    "replication",
+    "cpu",
+    "keyspace",
+];
+
+/// Builds the INFO command for the metrics heartbeat.
+///
+/// Redis 7+ accepts multiple sections, so only the charted ones are requested instead of
+/// `INFO ALL` (which adds commandstats, latencystats, errorstats...). Older servers fall
+/// back to the default sections, which already include everything the heartbeat parses.
+fn heartbeat_info_cmd(multi_section: bool) -> redis::Cmd {
+    let mut info = cmd("INFO");
+    if multi_section {
+        info.arg(&HEARTBEAT_INFO_SECTIONS);
+    }
+    info
+}
+
+/// Fields the metrics charts depend on; their absence leaves charts flat at zero.
+const EXPECTED_INFO_FIELDS: [&str; 8] = [
+    "connected_clients",
+    "used_memory",
+    "total_commands_processed",
+    "instantaneous_ops_per_sec",
+    "keyspace_hits",
+    "keyspace_misses",
+    "used_cpu_sys",
+    "used_cpu_user",
+];
+
+/// Maps alternative field names used by Redis forks to the standard names.
+fn normalize_info_field(key: &str) -> &str {
+    match key {
+        // Valkey 8+ and Dragonfly report their own version fields
+        "valkey_version" | "dragonfly_version" => "server_version",
+        _ => key,
+    }
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct RedisKeySpaceStats {
@@ -36,6 +84,10 @@ pub struct RedisServerMeta {
     pub os: String,
     pub role: String,
     pub maxmemory: u64,
+    pub maxmemory_policy: String,
+    pub uptime_in_seconds: u64,
+    /// Expected fields absent from INFO (e.g. on forks with non-standard output)
+    pub missing_fields: Vec<&'static str>,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -71,9 +123,26 @@ pub struct RedisMetrics {
     pub aof_last_write_success: bool,
 }
 
+/// Seeds older than this are not used to compute the deltas of the first sample
+const MAX_SEED_AGE_MS: i64 = 5 * 60 * 1000;
+/// Maximum number of metrics snapshots kept per server
+const MAX_SNAPSHOTS: usize = 10;
+
+/// A named sample captured to compare later metrics against (e.g. before a deploy).
+#[derive(Debug, Clone)]
+pub struct MetricsSnapshot {
+    pub name: SharedString,
+    pub metrics: RedisMetrics,
+}
+
 pub struct MetricsCache {
     max_history_size: usize,
     data: RwLock<HashMap<String, VecDeque<RedisMetrics>>>,
+    /// Sample preceding the history of each server, kept when the history is
+    /// trimmed or removed, so the first sample still has a delta base.
+    seeds: RwLock<HashMap<String, RedisMetrics>>,
+    /// Named snapshots of each server, oldest first, kept for the session
+    snapshots: RwLock<HashMap<String, Vec<MetricsSnapshot>>>,
 }
 
 impl MetricsCache {
@@ -81,13 +150,17 @@ impl MetricsCache {
         Self {
             max_history_size,
             data: RwLock::new(HashMap::new()),
+            seeds: RwLock::new(HashMap::new()),
+            snapshots: RwLock::new(HashMap::new()),
         }
     }
     pub fn add_metrics(&self, server_id: &str, metrics: RedisMetrics) {
         let mut data = self.data.write();
         if let Some(queue) = data.get_mut(server_id) {
-            if queue.len() >= self.max_history_size {
-                queue.pop_front();
+            if queue.len() >= self.max_history_size
+                && let Some(evicted) = queue.pop_front()
+            {
+                self.seeds.write().insert(server_id.to_string(), evicted);
             }
             queue.push_back(metrics);
         } else {
@@ -98,7 +171,63 @@ impl MetricsCache {
     }
     pub fn remove_server(&self, server_id: &str) {
         let mut data = self.data.write();
-        data.remove(server_id);
+        if let Some(last) = data.remove(server_id).and_then(|queue| queue.back().copied()) {
+            self.seeds.write().insert(server_id.to_string(), last);
+        }
+    }
+    /// Drops the history and its seed so the charts start again from the next sample,
+    /// snapshots are kept.
+    pub fn clear_server(&self, server_id: &str) {
+        self.data.write().remove(server_id);
+        self.seeds.write().remove(server_id);
+    }
+    /// Returns the sample preceding the history, to compute the deltas
+    /// (e.g. CPU percent) of the first sample.
+    ///
+    /// The seed is skipped if it is too old or its counters are ahead of the
+    /// first sample, e.g. after the server restarted.
+    pub fn seed_metrics(&self, server_id: &str) -> Option<RedisMetrics> {
+        let seed = self.seeds.read().get(server_id).copied()?;
+        let first = self
+            .data
+            .read()
+            .get(server_id)
+            .and_then(|queue| queue.front().copied())?;
+        let valid = seed.timestamp_ms < first.timestamp_ms
+            && first.timestamp_ms - seed.timestamp_ms <= MAX_SEED_AGE_MS
+            && seed.total_commands_processed <= first.total_commands_processed
+            && seed.keyspace_hits <= first.keyspace_hits
+            && seed.keyspace_misses <= first.keyspace_misses
+            && seed.evicted_keys <= first.evicted_keys
+            && seed.used_cpu_sys <= first.used_cpu_sys
+            && seed.used_cpu_user <= first.used_cpu_user;
+        valid.then_some(seed)
+    }
+    /// Returns the most recent sample without cloning the whole history.
+    pub fn last_metrics(&self, server_id: &str) -> Option<RedisMetrics> {
+        let data = self.data.read();
+        data.get(server_id).and_then(|queue| queue.back().copied())
+    }
+    /// Captures the most recent sample as a named snapshot, replacing one with the same name.
+    pub fn take_snapshot(&self, server_id: &str, name: SharedString) -> Option<MetricsSnapshot> {
+        let metrics = self.last_metrics(server_id)?;
+        let snapshot = MetricsSnapshot { name, metrics };
+        let mut snapshots = self.snapshots.write();
+        let list = snapshots.entry(server_id.to_string()).or_default();
+        list.retain(|item| item.name != snapshot.name);
+        if list.len() >= MAX_SNAPSHOTS {
+            list.remove(0);
+        }
+        list.push(snapshot.clone());
+        Some(snapshot)
+    }
+    pub fn list_snapshots(&self, server_id: &str) -> Vec<MetricsSnapshot> {
+        self.snapshots.read().get(server_id).cloned().unwrap_or_default()
+    }
+    pub fn remove_snapshot(&self, server_id: &str, name: &str) {
+        if let Some(list) = self.snapshots.write().get_mut(server_id) {
+            list.retain(|item| item.name != name);
+        }
     }
     pub fn list_metrics(&self, server_id: &str) -> Vec<RedisMetrics> {
         let data = self.data.read();
@@ -114,6 +243,82 @@ pub fn get_metrics_cache() -> &'static MetricsCache {
     &METRICS_CACHE
 }
 
+/// A metrics threshold crossed by the latest heartbeat sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricsAlert {
+    /// Keyspace hit rate (%) dropped below the threshold
+    LowHitRate { value: f64, threshold: f64 },
+    /// Evicted keys per second exceeded the threshold
+    HighEvictions { value: f64, threshold: f64 },
+    /// Memory usage (% of maxmemory) exceeded the threshold
+    HighMemory { value: f64, threshold: f64 },
+}
+
+impl MetricsAlert {
+    /// Whether both alerts are for the same metric, regardless of values.
+    fn same_kind(&self, other: &MetricsAlert) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+    pub fn message(&self, cx: &App) -> SharedString {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let (key, value, threshold) = match self {
+            MetricsAlert::LowHitRate { value, threshold } => ("metrics.alert_hit_rate", value, threshold),
+            MetricsAlert::HighEvictions { value, threshold } => ("metrics.alert_evictions", value, threshold),
+            MetricsAlert::HighMemory { value, threshold } => ("metrics.alert_memory", value, threshold),
+        };
+        t!(
+            key,
+            value = format!("{value:.1}"),
+            threshold = format!("{threshold:.1}"),
+            locale = locale
+        )
+        .into()
+    }
+}
+
+/// Evaluates alert thresholds against the latest sample.
+///
+/// Hit rate and evictions are rates, so they are computed from the delta
+/// against the previous sample; they are skipped for the first sample.
+pub fn evaluate_metrics_alerts(
+    prev: Option<&RedisMetrics>,
+    current: &RedisMetrics,
+    maxmemory: u64,
+    thresholds: &MetricsAlertThresholds,
+) -> Vec<MetricsAlert> {
+    let mut alerts = Vec::new();
+    if let Some(prev) = prev {
+        let hits = current.keyspace_hits.saturating_sub(prev.keyspace_hits);
+        let misses = current.keyspace_misses.saturating_sub(prev.keyspace_misses);
+        if let Some(threshold) = thresholds.hit_rate_below
+            && hits + misses > 0
+        {
+            let value = hits as f64 * 100.0 / (hits + misses) as f64;
+            if value < threshold {
+                alerts.push(MetricsAlert::LowHitRate { value, threshold });
+            }
+        }
+        let elapsed_secs = (current.timestamp_ms - prev.timestamp_ms) as f64 / 1000.0;
+        if let Some(threshold) = thresholds.evictions_above
+            && elapsed_secs > 0.0
+        {
+            let value = current.evicted_keys.saturating_sub(prev.evicted_keys) as f64 / elapsed_secs;
+            if value > threshold {
+                alerts.push(MetricsAlert::HighEvictions { value, threshold });
+            }
+        }
+    }
+    if let Some(threshold) = thresholds.memory_above
+        && maxmemory > 0
+    {
+        let value = current.used_memory as f64 * 100.0 / maxmemory as f64;
+        if value > threshold {
+            alerts.push(MetricsAlert::HighMemory { value, threshold });
+        }
+    }
+    alerts
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RedisInfo {
     pub meta: RedisServerMeta,
@@ -210,6 +415,8 @@ pub fn aggregate_redis_info(infos: Vec<RedisInfo>) -> RedisInfo {
 impl RedisInfo {
     pub fn parse(info_str: &str) -> Self {
         let mut info = RedisInfo::default();
+        let mut server_version = String::new();
+        let mut found_fields = Vec::with_capacity(EXPECTED_INFO_FIELDS.len());
 
         for line in info_str.lines() {
             let line = line.trim();
@@ -226,10 +433,16 @@ impl RedisInfo {
                     continue;
                 }
 
+                let key = normalize_info_field(key);
+                if let Some(field) = EXPECTED_INFO_FIELDS.iter().find(|field| **field == key) {
+                    found_fields.push(*field);
+                }
                 match key {
                     "redis_version" => info.meta.redis_version = value.to_string(),
+                    "server_version" => server_version = value.to_string(),
                     "os" => info.meta.os = value.to_string(),
                     "role" => info.meta.role = value.to_string(),
+                    "uptime_in_seconds" => info.meta.uptime_in_seconds = parse_u64(value),
 
                     "connected_clients" => info.metrics.connected_clients = parse_u64(value),
                     "rejected_connections" => info.metrics.rejected_connections = parse_u64(value),
@@ -238,6 +451,7 @@ impl RedisInfo {
                     "used_memory" => info.metrics.used_memory = parse_u64(value),
                     "used_memory_rss" => info.metrics.used_memory_rss = parse_u64(value),
                     "maxmemory" => info.meta.maxmemory = parse_u64(value),
+                    "maxmemory_policy" => info.meta.maxmemory_policy = value.to_string(),
                     "mem_fragmentation_ratio" => info.metrics.mem_fragmentation_ratio = parse_f64(value),
 
                     "total_connections_received" => info.metrics.total_connections_received = parse_u64(value),
@@ -260,6 +474,14 @@ impl RedisInfo {
                 }
             }
         }
+        if info.meta.redis_version.is_empty() {
+            info.meta.redis_version = server_version;
+        }
+        info.meta.missing_fields = EXPECTED_INFO_FIELDS
+            .iter()
+            .filter(|field| !found_fields.contains(field))
+            .copied()
+            .collect();
 
         info
     }
@@ -291,11 +513,51 @@ fn parse_keyspace_value(v: &str) -> Result<RedisKeySpaceStats, ()> {
     Ok(stats)
 }
 
+/// Reachability and vital stats of a configured server, shown on the home screen.
+#[derive(Debug, Clone, Default)]
+pub struct ServerHealth {
+    pub latency_ms: u64,
+    pub version: String,
+    pub used_memory: u64,
+    pub maxmemory: u64,
+    pub connected_clients: u64,
+    /// Keys across all databases
+    pub keys: u64,
+    /// Heartbeat sample of the check, for the metrics cache
+    pub metrics: RedisMetrics,
+}
+
+/// Pings a server through the connection manager and summarizes its INFO.
+pub async fn check_server_health(server_id: &str) -> Result<ServerHealth, Error> {
+    let client = get_connection_manager().get_client(server_id, 0).await?;
+    let start = Instant::now();
+    client.ping().await?;
+    let latency = start.elapsed();
+    let info_cmd = heartbeat_info_cmd(client.is_at_least_version("7.0.0"));
+    let (_, list): (_, Vec<String>) = client.query_async_masters(vec![info_cmd]).await?;
+    let mut info = aggregate_redis_info(list.iter().map(|info| RedisInfo::parse(info)).collect());
+    info.metrics.timestamp_ms = unix_ts_millis();
+    info.metrics.latency_ms = latency.as_millis() as u64;
+    Ok(ServerHealth {
+        latency_ms: latency.as_millis() as u64,
+        version: client.version(),
+        used_memory: info.metrics.used_memory,
+        maxmemory: info.meta.maxmemory,
+        connected_clients: info.metrics.connected_clients,
+        keys: info.keyspace.values().map(|stats| stats.keys).sum(),
+        metrics: info.metrics,
+    })
+}
+
 impl ZedisServerState {
     pub fn refresh_redis_info(&mut self, cx: &mut Context<Self>) {
-        if self.server_id.is_empty() {
+        if self.server_id.is_empty() || self.auth_required() {
             return;
         }
+        // keep checking until the cluster recovers
+        if self.cluster_health.is_some() {
+            self.check_cluster_health(cx);
+        }
 
         let slow_logs_check_interval = 60;
         let mut last_slow_logs_checked_at = self.last_slow_logs_checked_at;
@@ -306,6 +568,9 @@ impl ZedisServerState {
         let server_id = self.server_id.clone();
         let db = self.db;
         let server_id_clone = server_id.clone();
+        // Restricted ACL users only get the latency, denied commands are not sent
+        let info_allowed = self.acl_permissions.allows("INFO");
+        let slow_logs_allowed = self.acl_permissions.allows("SLOWLOG GET");
 
         self.spawn(
             ServerTask::RefreshRedisInfo,
@@ -315,7 +580,7 @@ impl ZedisServerState {
                 client.ping().await?;
                 let latency = start.elapsed();
                 let now = unix_ts();
-                let slow_logs = if now - last_slow_logs_checked_at >= slow_logs_check_interval {
+                let slow_logs = if slow_logs_allowed && now - last_slow_logs_checked_at >= slow_logs_check_interval {
                     // ignore get slow error
                     let slow_logs = client.get_slow_logs().await.unwrap_or_default();
                     Some(slow_logs)
@@ -323,17 +588,43 @@ impl ZedisServerState {
                     None
                 };
 
-                let (_, list): (_, Vec<String>) =
-                    client.query_async_masters(vec![cmd("INFO").arg("ALL").clone()]).await?;
-                let infos: Vec<RedisInfo> = list.iter().map(|info| RedisInfo::parse(info)).collect();
-                let mut info = aggregate_redis_info(infos);
+                let mut info = if info_allowed {
+                    let info_cmd = heartbeat_info_cmd(client.is_at_least_version("7.0.0"));
+                    let (_, list): (_, Vec<String>) = client.query_async_masters(vec![info_cmd]).await?;
+                    let infos: Vec<RedisInfo> = list.iter().map(|info| RedisInfo::parse(info)).collect();
+                    aggregate_redis_info(infos)
+                } else {
+                    RedisInfo::default()
+                };
                 info.metrics.timestamp_ms = unix_ts_millis();
                 info.metrics.latency_ms = latency.as_millis() as u64;
-                Ok((info, slow_logs))
+                // per node latency of clusters, the aggregate hides a single slow node
+                let node_latencies = if client.is_cluster() {
+                    client.ping_nodes().await
+                } else {
+                    vec![]
+                };
+                Ok((info, slow_logs, node_latencies))
             },
             move |this, result, cx| match result {
-                Ok((info, slow_logs)) => {
-                    METRICS_CACHE.add_metrics(&server_id_clone, info.metrics);
+                Ok((info, slow_logs, node_latencies)) => {
+                    this.node_latencies = node_latencies;
+                    if info_allowed {
+                        let prev = METRICS_CACHE.last_metrics(&server_id_clone);
+                        this.update_metrics_alerts(prev.as_ref(), &info, cx);
+                        METRICS_CACHE.add_metrics(&server_id_clone, info.metrics);
+                    }
+                    let missing_changed = this
+                        .redis_info
+                        .as_ref()
+                        .is_none_or(|prev| prev.meta.missing_fields != info.meta.missing_fields);
+                    if missing_changed && !info.meta.missing_fields.is_empty() {
+                        warn!(
+                            server_id = server_id_clone.as_str(),
+                            missing_fields = info.meta.missing_fields.join(","),
+                            "INFO is missing expected fields, metrics are limited"
+                        );
+                    }
                     this.redis_info = Some(info);
                     if let Some(slow_logs) = slow_logs {
                         this.last_slow_log_count = slow_logs
@@ -354,4 +645,74 @@ impl ZedisServerState {
             cx,
         );
     }
+
+    /// Reads CLUSTER INFO after a CLUSTERDOWN error, the banner is shown until
+    /// every slot is served again. Only one check runs at a time.
+    pub fn check_cluster_health(&mut self, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        if server_id.is_empty() || self.checking_cluster_health {
+            return;
+        }
+        self.checking_cluster_health = true;
+        let db = self.db;
+        self.spawn(
+            ServerTask::CheckClusterHealth,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                if !client.is_cluster() {
+                    return Ok((server_id, None));
+                }
+                let health = client.cluster_health().await?;
+                Ok((server_id, Some(health)))
+            },
+            move |this, result, cx| {
+                this.checking_cluster_health = false;
+                let Ok((server_id, health)) = result else {
+                    return;
+                };
+                if this.server_id != server_id {
+                    return;
+                }
+                let health = health.filter(|health| !health.is_ok());
+                if this.cluster_health != health {
+                    if let Some(health) = &health {
+                        warn!(
+                            server_id = server_id.as_str(),
+                            state = health.state.as_str(),
+                            slots_assigned = health.slots_assigned,
+                            slots_fail = health.slots_fail,
+                            "Cluster is down"
+                        );
+                    }
+                    this.cluster_health = health;
+                    cx.emit(ServerEvent::ClusterHealthUpdated);
+                    cx.notify();
+                }
+            },
+            cx,
+        );
+    }
+
+    /// Re-evaluates alert thresholds for the latest heartbeat sample.
+    ///
+    /// Emits `MetricsAlertsUpdated` when the set of active alerts changes, and
+    /// pops a warning for newly crossed thresholds if notifications are enabled.
+    fn update_metrics_alerts(&mut self, prev: Option<&RedisMetrics>, info: &RedisInfo, cx: &mut Context<Self>) {
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let thresholds = store.metrics_alert_thresholds();
+        let notification_enabled = store.alert_notification_enabled();
+        let alerts = evaluate_metrics_alerts(prev, &info.metrics, info.meta.maxmemory, &thresholds);
+        if alerts.is_empty() && self.metrics_alerts.is_empty() {
+            return;
+        }
+        if notification_enabled {
+            for alert in alerts.iter() {
+                if !self.metrics_alerts.iter().any(|item| item.same_kind(alert)) {
+                    self.emit_warning_notification(alert.message(cx), cx);
+                }
+            }
+        }
+        self.metrics_alerts = alerts;
+        cx.emit(ServerEvent::MetricsAlertsUpdated);
+    }
 }
@@ -14,20 +14,217 @@
 
 use super::{
     KeyType, RedisValueData, ServerEvent, ServerTask, ZedisServerState,
-    value::{RedisStreamEntry, RedisStreamValue, RedisValue, RedisValueStatus},
+    value::{RedisStreamEntry, RedisStreamValue, RedisValue, RedisValueStatus, ensure_key_type},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
+    states::{ZedisGlobalStore, get_session_option},
 };
 use gpui::{SharedString, prelude::*};
 use redis::cmd;
+use redis::streams::{StreamInfoGroupsReply, StreamPendingCountReply, StreamPendingReply};
+use rust_i18n::t;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
 type RawStreamData = Vec<(String, Vec<String>)>;
 
+/// Poll interval of stream follow when the session has no refresh interval.
+const DEFAULT_STREAM_FOLLOW_INTERVAL_SECS: u64 = 1;
+
+/// Entries fetched per XRANGE while counting what a MINID trim removes.
+const TRIM_PREVIEW_BATCH: usize = 1000;
+/// Entries counted at most for a MINID trim preview, larger counts are shown as a lower bound.
+const TRIM_PREVIEW_LIMIT: usize = 10_000;
+/// Pending entries listed at most per XPENDING, the oldest ones come first.
+const PENDING_ENTRIES_LIMIT: usize = 100;
+
+/// How XTRIM decides which entries to evict.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamTrimStrategy {
+    /// Keep at most `threshold` newest entries.
+    MaxLen,
+    /// Evict entries with an ID lower than `threshold`.
+    MinId,
+}
+
+impl StreamTrimStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StreamTrimStrategy::MaxLen => "MAXLEN",
+            StreamTrimStrategy::MinId => "MINID",
+        }
+    }
+    /// MAXLEN takes an entry count, MINID a stream ID (`ms` or `ms-seq`).
+    pub fn is_valid_threshold(&self, threshold: &str) -> bool {
+        match self {
+            StreamTrimStrategy::MaxLen => threshold.parse::<u64>().is_ok(),
+            StreamTrimStrategy::MinId => {
+                let mut parts = threshold.splitn(2, '-');
+                parts.all(|part| part.parse::<u64>().is_ok())
+            }
+        }
+    }
+}
+
+/// Number of entries a stream trim would remove, shown before trimming.
+#[derive(Debug, Clone)]
+pub struct StreamTrimPreview {
+    pub key: SharedString,
+    pub strategy: StreamTrimStrategy,
+    pub threshold: SharedString,
+    pub count: usize,
+    /// False when counting stopped at the preview limit, `count` is then a lower bound
+    pub complete: bool,
+}
+
+/// A consumer group of a stream, as listed by XINFO GROUPS.
+#[derive(Debug, Clone)]
+pub struct StreamGroup {
+    pub name: SharedString,
+    pub consumers: usize,
+    pub pending: usize,
+}
+
+/// An entry delivered to a consumer but not acknowledged yet, as listed by XPENDING.
+#[derive(Debug, Clone)]
+pub struct StreamPendingEntry {
+    pub id: SharedString,
+    pub consumer: SharedString,
+    /// Time since the entry was last delivered
+    pub idle: Duration,
+    pub deliveries: usize,
+}
+
+/// The oldest pending entries of a consumer group, the actions pick their IDs from them.
+#[derive(Debug, Clone)]
+pub struct StreamPendingEntries {
+    pub group: SharedString,
+    pub entries: Vec<StreamPendingEntry>,
+    /// Pending entries of the group, may exceed the listed ones
+    pub total: usize,
+}
+
+async fn count_stream_trim(
+    conn: &mut RedisAsyncConn,
+    key: &str,
+    strategy: StreamTrimStrategy,
+    threshold: &str,
+) -> Result<(usize, bool)> {
+    match strategy {
+        StreamTrimStrategy::MaxLen => {
+            let max_len: usize = threshold.parse().unwrap_or_default();
+            let size: usize = cmd("XLEN").arg(key).query_async(conn).await?;
+            Ok((size.saturating_sub(max_len), true))
+        }
+        StreamTrimStrategy::MinId => {
+            let Some(min_id) = parse_stream_id(threshold) else {
+                return Ok((0, true));
+            };
+            // Nothing or everything below the threshold can be answered by the stream bounds
+            let first: RawStreamData = cmd("XRANGE")
+                .arg(key)
+                .arg("-")
+                .arg("+")
+                .arg("COUNT")
+                .arg(1)
+                .query_async(conn)
+                .await?;
+            let Some(first_id) = first.first().and_then(|(id, _)| parse_stream_id(id)) else {
+                return Ok((0, true));
+            };
+            if first_id >= min_id {
+                return Ok((0, true));
+            }
+            let last: RawStreamData = cmd("XREVRANGE")
+                .arg(key)
+                .arg("+")
+                .arg("-")
+                .arg("COUNT")
+                .arg(1)
+                .query_async(conn)
+                .await?;
+            if last
+                .first()
+                .and_then(|(id, _)| parse_stream_id(id))
+                .is_some_and(|last_id| last_id < min_id)
+            {
+                let size: usize = cmd("XLEN").arg(key).query_async(conn).await?;
+                return Ok((size, true));
+            }
+            // Walk the IDs below the threshold in batches, XTRIM has no dry run
+            let mut count = 0;
+            let mut start = "-".to_string();
+            loop {
+                let entries: RawStreamData = cmd("XRANGE")
+                    .arg(key)
+                    .arg(&start)
+                    .arg(format!("({threshold}"))
+                    .arg("COUNT")
+                    .arg(TRIM_PREVIEW_BATCH)
+                    .query_async(conn)
+                    .await?;
+                count += entries.len();
+                let last_id = entries.last().map(|(id, _)| id.clone());
+                match last_id {
+                    Some(id) if entries.len() == TRIM_PREVIEW_BATCH => {
+                        if count >= TRIM_PREVIEW_LIMIT {
+                            return Ok((count, false));
+                        }
+                        start = format!("({id}");
+                    }
+                    _ => return Ok((count, true)),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a stream ID (`ms-seq`, or `ms` alone meaning `ms-0`) into a comparable pair.
+fn parse_stream_id(id: &str) -> Option<(u64, u64)> {
+    let id = id.trim();
+    match id.split_once('-') {
+        Some((ms, seq)) => Some((ms.parse().ok()?, seq.parse().ok()?)),
+        None => Some((id.parse().ok()?, 0)),
+    }
+}
+
+fn to_stream_entries(entries: RawStreamData) -> Vec<RedisStreamEntry> {
+    entries
+        .into_iter()
+        .map(|(id, flat_fields)| {
+            let mut field_values = Vec::with_capacity(flat_fields.len() / 2);
+            let mut iter = flat_fields.into_iter();
+
+            while let Some(key) = iter.next() {
+                if let Some(val) = iter.next() {
+                    field_values.push((key.into(), val.into()));
+                }
+            }
+
+            (id.into(), field_values)
+        })
+        .collect()
+}
+
+/// Reads the newest `count` entries with XREVRANGE, returned oldest first.
+async fn get_redis_stream_tail(conn: &mut RedisAsyncConn, key: &str, count: usize) -> Result<Vec<RedisStreamEntry>> {
+    let mut entries: RawStreamData = cmd("XREVRANGE")
+        .arg(key)
+        .arg("+")
+        .arg("-")
+        .arg("COUNT")
+        .arg(count)
+        .query_async(conn)
+        .await?;
+    entries.reverse();
+    Ok(to_stream_entries(entries))
+}
+
 async fn get_redis_stream_value(
     conn: &mut RedisAsyncConn,
     key: &str,
@@ -50,21 +247,7 @@ async fn get_redis_stream_value(
 
     let done = entries.len() < count;
 
-    let values: Vec<RedisStreamEntry> = entries
-        .into_iter()
-        .map(|(id, flat_fields)| {
-            let mut field_values = Vec::with_capacity(flat_fields.len() / 2);
-            let mut iter = flat_fields.into_iter();
-
-            while let Some(key) = iter.next() {
-                if let Some(val) = iter.next() {
-                    field_values.push((key.into(), val.into()));
-                }
-            }
-
-            (id.into(), field_values)
-        })
-        .collect();
+    let values = to_stream_entries(entries);
     let mut cursor = values.last().map(|(id, _)| id.to_string()).unwrap_or_default();
     if done {
         cursor = "".to_string();
@@ -92,6 +275,9 @@ pub(crate) async fn first_load_stream_value(conn: &mut RedisAsyncConn, key: &str
 }
 
 impl ZedisServerState {
+    /// A generic helper for Redis Stream operations.
+    /// Handles status switching, optimistic UI updates, and background task execution.
+    /// `rollback` reverts the optimistic update if the Redis command fails.
     fn exec_stream_op<F, Fut, R>(
         &mut self,
         task: ServerTask,
@@ -99,6 +285,7 @@ impl ZedisServerState {
         optimistic_update: impl FnOnce(&mut RedisStreamValue),
         redis_op: F,
         on_success: impl FnOnce(&mut Self, R, &mut Context<Self>) + Send + 'static,
+        rollback: impl FnOnce(&mut RedisStreamValue) + Send + 'static,
     ) where
         F: FnOnce(String, RedisAsyncConn) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = Result<R>> + Send,
@@ -108,6 +295,7 @@ impl ZedisServerState {
             return;
         };
         let key_str = key.to_string();
+        let rollback_key = key.clone();
         value.status = RedisValueStatus::Updating;
         if let Some(RedisValueData::Stream(stream_data)) = value.data.as_mut() {
             optimistic_update(Arc::make_mut(stream_data));
@@ -117,11 +305,16 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
+        let verify_type = cx.global::<ZedisGlobalStore>().read(cx).verify_key_type();
 
         self.spawn(
             task,
             move || async move {
-                let conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Refuse to write with the commands of another type, the editor asks to reload instead
+                if verify_type {
+                    ensure_key_type(&mut conn, &key_str, KeyType::Stream).await?;
+                }
                 redis_op(key_str, conn).await
             },
             move |this, result, cx| {
@@ -130,7 +323,19 @@ impl ZedisServerState {
                 }
                 match result {
                     Ok(data) => on_success(this, data, cx),
-                    Err(e) => this.emit_error_notification(e.to_string().into(), cx),
+                    Err(e) => {
+                        // Revert the optimistic update if the key is still selected
+                        if this.key.as_ref() == Some(&rollback_key)
+                            && let Some(RedisValueData::Stream(stream_data)) =
+                                this.value.as_mut().and_then(|v| v.data.as_mut())
+                        {
+                            rollback(Arc::make_mut(stream_data));
+                            cx.emit(ServerEvent::ValueUpdated);
+                        }
+                        if !e.is_type_mismatch() {
+                            this.emit_error_notification(e.to_string().into(), cx);
+                        }
+                    }
                 }
                 cx.notify();
             },
@@ -156,20 +361,191 @@ impl ZedisServerState {
         cx.emit(ServerEvent::ValueUpdated);
     }
 
-    pub fn load_more_stream_value(&mut self, cx: &mut Context<Self>) {
-        let Some((key, value)) = self.try_get_mut_key_value() else {
+    pub fn is_stream_following(&self) -> bool {
+        self.stream_following
+    }
+
+    /// Stops following the selected stream; the running loop exits on its next tick.
+    pub fn stop_stream_follow(&mut self, cx: &mut Context<Self>) {
+        if !self.stream_following {
+            return;
+        }
+        self.stream_following = false;
+        self.stream_follow_seq += 1;
+        cx.notify();
+    }
+
+    /// Whether only the first pages of the selected stream are loaded, so its tail is missing.
+    fn is_stream_tail_missing(&self) -> bool {
+        self.value
+            .as_ref()
+            .and_then(|v| v.stream_value())
+            .is_some_and(|stream| !stream.done && stream.values.len() < stream.size)
+    }
+
+    /// Replaces a partially loaded stream with its newest entries (XREVRANGE from `+`),
+    /// then emits `StreamFollowed` so the table jumps to them and starts following if asked.
+    ///
+    /// Streams whose tail is already loaded are left untouched.
+    pub fn load_stream_tail(&mut self, follow: bool, cx: &mut Context<Self>) {
+        if !self.is_stream_tail_missing() {
+            if follow {
+                self.start_stream_follow(cx);
+            }
+            return;
+        }
+        let Some(key) = self.key.clone() else {
             return;
         };
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.spawn(
+            ServerTask::LoadStreamTail,
+            {
+                let key = key.clone();
+                move || async move {
+                    let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                    let size: usize = cmd("XLEN").arg(key.as_str()).query_async(&mut conn).await?;
+                    let values = get_redis_stream_tail(&mut conn, key.as_str(), 100).await?;
+                    Ok((size, values))
+                }
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&key) {
+                    return;
+                }
+                if let Ok((size, values)) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    // Older pages are dropped, paging would otherwise continue before the tail
+                    stream.size = size;
+                    stream.values = values;
+                    stream.cursor = String::new();
+                    stream.done = true;
+                    cx.emit(ServerEvent::StreamFollowed);
+                    if follow {
+                        this.start_stream_follow(cx);
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
 
-        // Update UI to show loading state
-        value.status = RedisValueStatus::Loading;
+    /// Follows the selected stream like `tail -f`.
+    ///
+    /// Polls `XRANGE key (last-id + COUNT 100` at the session refresh interval
+    /// (falling back to one second) and appends new entries, emitting
+    /// `StreamFollowed` so the table can scroll to the bottom.
+    /// A partially loaded stream first loads its tail, so following starts from the real end.
+    pub fn start_stream_follow(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        if self.value.as_ref().and_then(|v| v.stream_value()).is_none() {
+            return;
+        }
+        if self.is_stream_tail_missing() {
+            self.load_stream_tail(true, cx);
+            return;
+        }
+        self.stream_following = true;
+        self.stream_follow_seq += 1;
+        let seq = self.stream_follow_seq;
+        let server_id = self.server_id.clone();
+        let interval = get_session_option(&server_id)
+            .ok()
+            .and_then(|option| option.refresh_interval_sec)
+            .filter(|sec| *sec > 0)
+            .map_or(DEFAULT_STREAM_FOLLOW_INTERVAL_SECS, u64::from);
+        debug!(key = key.as_str(), interval, "Start following stream");
         cx.notify();
 
+        cx.spawn(async move |handle, cx| {
+            loop {
+                cx.background_executor().timer(Duration::from_secs(interval)).await;
+                // Stop when following was cancelled or the selection changed
+                let params = handle.update(cx, |this, _cx| {
+                    if this.stream_follow_seq != seq || this.server_id != server_id || this.key.as_ref() != Some(&key) {
+                        return None;
+                    }
+                    let stream = this.value.as_ref()?.stream_value()?;
+                    let cursor = stream.values.last().map(|(id, _)| id.to_string());
+                    Some((this.db, cursor))
+                });
+                let Ok(Some((db, cursor))) = params else {
+                    break;
+                };
+
+                let result = cx
+                    .background_spawn({
+                        let server_id = server_id.clone();
+                        let key = key.clone();
+                        async move {
+                            let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                            let size: usize = cmd("XLEN").arg(key.as_str()).query_async(&mut conn).await?;
+                            let (cursor, values) = get_redis_stream_value(&mut conn, key.as_str(), cursor, 100).await?;
+                            Ok::<(usize, String, Vec<RedisStreamEntry>), Error>((size, cursor, values))
+                        }
+                    })
+                    .await;
+
+                let keep_following = handle.update(cx, |this, cx| {
+                    if this.stream_follow_seq != seq {
+                        return false;
+                    }
+                    let (size, new_cursor, new_values) = match result {
+                        Ok(data) => data,
+                        Err(e) => {
+                            this.stream_following = false;
+                            this.emit_error_notification(e.to_string().into(), cx);
+                            cx.notify();
+                            return false;
+                        }
+                    };
+                    let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                    else {
+                        return false;
+                    };
+                    let stream = Arc::make_mut(stream_data);
+                    stream.size = size;
+                    // Partially loaded streams continue paging from the new last entry
+                    if !stream.done {
+                        stream.done = new_cursor.is_empty();
+                        stream.cursor = new_cursor;
+                    }
+                    if !new_values.is_empty() {
+                        stream.values.extend(new_values);
+                        cx.emit(ServerEvent::StreamFollowed);
+                    }
+                    cx.notify();
+                    true
+                });
+                if !matches!(keep_following, Ok(true)) {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    pub fn load_more_stream_value(&mut self, cx: &mut Context<Self>) {
+        // Skip while a load or write is in flight, overlapping loads would duplicate entries
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
         let cursor = match value.stream_value() {
             Some(stream) => stream.cursor.clone(),
             None => return,
         };
 
+        // Update UI to show loading state
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
         let server_id = self.server_id.clone();
         let db = self.db;
         cx.emit(ServerEvent::ValuePaginationStarted);
@@ -212,7 +588,7 @@ impl ZedisServerState {
                 }
                 cx.notify();
                 if should_load_more {
-                    this.load_more_hash_value(cx);
+                    this.load_more_stream_value(cx);
                 }
             },
             cx,
@@ -251,10 +627,19 @@ impl ZedisServerState {
                 }
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            |_| {},
         );
     }
     pub fn remove_stream_value(&mut self, entry_id: SharedString, cx: &mut Context<Self>) {
         let entry_id_clone = entry_id.clone();
+        // Removed entry and its position, restored if the removal fails
+        let removed = self.value.as_ref().and_then(|v| v.stream_value()).and_then(|stream| {
+            stream
+                .values
+                .iter()
+                .position(|(id, _)| id == &entry_id)
+                .map(|pos| (pos, stream.values[pos].clone()))
+        });
         self.exec_stream_op(
             ServerTask::RemoveStreamEntry,
             cx,
@@ -276,6 +661,292 @@ impl ZedisServerState {
                 }
                 cx.emit(ServerEvent::ValueUpdated);
             },
+            move |stream| {
+                if let Some((pos, entry)) = removed
+                    && !stream.values.iter().any(|(id, _)| id == &entry.0)
+                {
+                    stream.values.insert(pos.min(stream.values.len()), entry);
+                }
+            },
+        );
+    }
+
+    /// Lists the consumer groups of the stream (XINFO GROUPS) and emits `StreamGroupsLoaded`.
+    pub fn load_stream_groups(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let key_str = key.to_string();
+
+        self.spawn(
+            ServerTask::LoadStreamGroups,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let reply: StreamInfoGroupsReply =
+                    cmd("XINFO").arg("GROUPS").arg(&key_str).query_async(&mut conn).await?;
+                let groups = reply
+                    .groups
+                    .into_iter()
+                    .map(|group| StreamGroup {
+                        name: group.name.into(),
+                        consumers: group.consumers,
+                        pending: group.pending,
+                    })
+                    .collect::<Vec<_>>();
+                Ok(groups)
+            },
+            move |this, result, cx| {
+                let Ok(groups) = result else {
+                    return;
+                };
+                // Skip when the user moved on to another key while listing
+                if this.key.as_ref() != Some(&key) {
+                    return;
+                }
+                if groups.is_empty() {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let message = t!("stream_editor.no_groups", key = key, locale = locale);
+                    this.emit_info_notification(message.into(), cx);
+                    return;
+                }
+                cx.emit(ServerEvent::StreamGroupsLoaded(groups));
+            },
+            cx,
+        );
+    }
+
+    /// Lists the oldest pending entries of a consumer group (XPENDING) and emits `StreamPendingLoaded`.
+    pub fn load_stream_pending(&mut self, group: SharedString, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        if group.is_empty() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let key_str = key.to_string();
+        let group_clone = group.clone();
+
+        self.spawn(
+            ServerTask::LoadStreamPending,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // The summary form only carries the count, the extended form lists the entries
+                let summary: StreamPendingReply = cmd("XPENDING")
+                    .arg(&key_str)
+                    .arg(group_clone.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                let reply: StreamPendingCountReply = cmd("XPENDING")
+                    .arg(&key_str)
+                    .arg(group_clone.as_str())
+                    .arg("-")
+                    .arg("+")
+                    .arg(PENDING_ENTRIES_LIMIT)
+                    .query_async(&mut conn)
+                    .await?;
+                let entries = reply
+                    .ids
+                    .into_iter()
+                    .map(|item| StreamPendingEntry {
+                        id: item.id.into(),
+                        consumer: item.consumer.into(),
+                        idle: Duration::from_millis(item.last_delivered_ms as u64),
+                        deliveries: item.times_delivered,
+                    })
+                    .collect::<Vec<_>>();
+                Ok((summary.count(), entries))
+            },
+            move |this, result, cx| {
+                let Ok((total, entries)) = result else {
+                    return;
+                };
+                if this.key.as_ref() != Some(&key) {
+                    return;
+                }
+                if entries.is_empty() {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let message = t!("stream_editor.no_pending", group = group, locale = locale);
+                    this.emit_info_notification(message.into(), cx);
+                    return;
+                }
+                cx.emit(ServerEvent::StreamPendingLoaded(StreamPendingEntries {
+                    group,
+                    entries,
+                    total,
+                }));
+            },
+            cx,
         );
     }
+
+    /// Acknowledges pending entries of a consumer group (XACK).
+    ///
+    /// Entries stay in the stream; only the group's pending entries list is updated.
+    /// The IDs are picked from the XPENDING listing of the group.
+    pub fn ack_stream(&mut self, group: SharedString, ids: Vec<SharedString>, cx: &mut Context<Self>) {
+        if group.is_empty() || ids.is_empty() {
+            return;
+        }
+        self.exec_stream_op(
+            ServerTask::AckStreamEntries,
+            cx,
+            |_| {},
+            move |key, mut conn| async move {
+                let count: usize = cmd("XACK")
+                    .arg(&key)
+                    .arg(group.as_str())
+                    .arg(ids.iter().map(|id| id.as_str()).collect::<Vec<_>>())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(count)
+            },
+            |this, count, cx| {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                let message = t!("stream_editor.ack_success", count = count, locale = locale);
+                this.emit_info_notification(message.into(), cx);
+            },
+            |_| {},
+        );
+    }
+
+    /// Claims pending entries of a consumer group for another consumer (XCLAIM).
+    ///
+    /// The IDs are picked from the XPENDING listing of the group. Uses a min-idle-time
+    /// of 0 so the entries are claimed regardless of how long they have been pending.
+    pub fn claim_stream(
+        &mut self,
+        group: SharedString,
+        consumer: SharedString,
+        ids: Vec<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        if group.is_empty() || consumer.is_empty() || ids.is_empty() {
+            return;
+        }
+        let consumer_clone = consumer.clone();
+        self.exec_stream_op(
+            ServerTask::ClaimStreamEntries,
+            cx,
+            |_| {},
+            move |key, mut conn| async move {
+                let claimed: Vec<String> = cmd("XCLAIM")
+                    .arg(&key)
+                    .arg(group.as_str())
+                    .arg(consumer_clone.as_str())
+                    .arg(0)
+                    .arg(ids.iter().map(|id| id.as_str()).collect::<Vec<_>>())
+                    .arg("JUSTID")
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(claimed.len())
+            },
+            move |this, count, cx| {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                let message = t!(
+                    "stream_editor.claim_success",
+                    count = count,
+                    consumer = consumer,
+                    locale = locale
+                );
+                this.emit_info_notification(message.into(), cx);
+            },
+            |_| {},
+        );
+    }
+
+    /// Counts the entries a trim would remove and emits `StreamTrimPreviewed` for the confirm.
+    pub fn preview_stream_trim(
+        &mut self,
+        strategy: StreamTrimStrategy,
+        threshold: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let key_str = key.to_string();
+        let threshold_clone = threshold.clone();
+
+        self.spawn(
+            ServerTask::PreviewStreamTrim,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                count_stream_trim(&mut conn, &key_str, strategy, threshold_clone.as_str()).await
+            },
+            move |this, result, cx| {
+                // Skip when the user moved on to another key while counting
+                if let Ok((count, complete)) = result
+                    && this.key.as_ref() == Some(&key)
+                {
+                    cx.emit(ServerEvent::StreamTrimPreviewed(StreamTrimPreview {
+                        key,
+                        strategy,
+                        threshold,
+                        count,
+                        complete,
+                    }));
+                }
+            },
+            cx,
+        );
+    }
+
+    /// Trims the stream by MAXLEN or MINID (XTRIM), then reloads the loaded entries.
+    ///
+    /// The trim is exact (no `~`), so the stream matches the confirmed preview.
+    pub fn trim_stream_value(&mut self, strategy: StreamTrimStrategy, threshold: SharedString, cx: &mut Context<Self>) {
+        if threshold.is_empty() {
+            return;
+        }
+        self.exec_stream_op(
+            ServerTask::TrimStream,
+            cx,
+            |_| {},
+            move |key, mut conn| async move {
+                let count: usize = cmd("XTRIM")
+                    .arg(&key)
+                    .arg(strategy.as_str())
+                    .arg(threshold.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok((key, count))
+            },
+            |this, (key, count), cx| {
+                if let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.size = stream.size.saturating_sub(count);
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                let message = t!("stream_editor.trim_success", count = count, locale = locale);
+                this.emit_info_notification(message.into(), cx);
+                // Trimmed entries may be anywhere in the loaded pages, load them again
+                if count > 0 {
+                    this.reload_value(key.into(), cx);
+                }
+            },
+            |_| {},
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stream_id() {
+        assert_eq!(parse_stream_id("1700000000000-3"), Some((1_700_000_000_000, 3)));
+        assert_eq!(parse_stream_id("1700000000000"), Some((1_700_000_000_000, 0)));
+        assert_eq!(parse_stream_id("abc"), None);
+        assert_eq!(parse_stream_id("1-x"), None);
+        assert!(parse_stream_id("5-0") < parse_stream_id("5-1"));
+        assert!(parse_stream_id("5-9") < parse_stream_id("6"));
+    }
 }
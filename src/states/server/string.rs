@@ -12,21 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::value::{DataFormat, RedisBytesValue, detect_format};
+use super::value::{DataFormat, RedisBytesValue, RedisValueStatus, detect_format};
+use super::{RedisValueData, ServerEvent, ServerTask, ZedisServerState};
+use crate::connection::{RedisAsyncConn, get_connection_manager};
 use crate::db::ProtoManager;
+use crate::error::Error;
 use crate::helpers::decompress_zstd;
-use crate::{connection::RedisAsyncConn, error::Error};
+use crate::states::ZedisGlobalStore;
 use bytes::Bytes;
 use flate2::read::GzDecoder;
-use gpui::SharedString;
+use gpui::{Context, SharedString};
 use lz4_flex::block::decompress_size_prepended;
 use redis::cmd;
+use rust_i18n::t;
 use serde_json::Value;
 use snap::read::FrameDecoder;
 use std::io::Read;
+use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Size of the HyperLogLog header: magic, encoding, unused bytes and cached cardinality
+const HLL_HEADER_SIZE: usize = 16;
+/// Strings longer than this only have their head loaded, rendering them whole freezes the UI
+const LARGE_STRING_THRESHOLD: usize = 5 * 1024 * 1024;
+/// Size of the head loaded for a large string
+const LARGE_STRING_HEAD_SIZE: usize = 256 * 1024;
+
 fn truncate_long_strings(max_truncate_length: usize, v: &mut Value, truncated: &mut bool) {
     match v {
         Value::String(s) => {
@@ -87,7 +99,48 @@ fn format_text(data: &[u8], max_truncate_length: usize) -> Option<(DataFormat, S
     }
 }
 
-pub fn detect_and_decode(data: &[u8], max_truncate_length: usize) -> (DataFormat, SharedString) {
+/// Describes the header of a HyperLogLog value, which is stored as a string.
+///
+/// Layout: `HYLL`, encoding (0 dense, 1 sparse), 3 unused bytes, then the cached
+/// cardinality as little endian u64 whose most significant bit marks it as stale.
+/// The description is localized, so it is built when the value is shown.
+pub fn describe_hyperloglog(data: &[u8], locale: &str) -> Option<SharedString> {
+    if data.len() < HLL_HEADER_SIZE {
+        return None;
+    }
+    let encoding = match data[4] {
+        0 => t!("editor.hll_dense", locale = locale),
+        1 => t!("editor.hll_sparse", locale = locale),
+        _ => return None,
+    };
+    let cardinality = if data[15] & 0x80 == 0 {
+        let bytes: [u8; 8] = data[8..16].try_into().ok()?;
+        u64::from_le_bytes(bytes).to_string()
+    } else {
+        t!("editor.hll_stale", locale = locale).to_string()
+    };
+    Some(
+        t!(
+            "editor.hll_description",
+            encoding = encoding,
+            cardinality = cardinality,
+            size = data.len(),
+            locale = locale
+        )
+        .into(),
+    )
+}
+
+/// Picks the best text representation of a string value by sniffing its content.
+///
+/// Images are left to the image viewer, binary data without a decoder falls back
+/// to the hex viewer. `decode_proto` decodes the data with the protobuf schema
+/// matched to the key, if any.
+fn decode_bytes(
+    data: &[u8],
+    max_truncate_length: usize,
+    decode_proto: impl FnOnce(&[u8]) -> Option<SharedString>,
+) -> (DataFormat, Option<SharedString>) {
     let (initial_format, _) = detect_format(data);
     let process_decompressed = |decompressed: Option<Vec<u8>>| {
         decompressed.and_then(|vec| format_text(&vec, max_truncate_length).map(|(_, text)| (DataFormat::Preview, text)))
@@ -98,6 +151,8 @@ pub fn detect_and_decode(data: &[u8], max_truncate_length: usize) -> (DataFormat
             .and_then(|v| serde_json::to_string_pretty(&v).ok())
             .map(|s| (DataFormat::Preview, SharedString::from(s))),
 
+        DataFormat::HyperLogLog => None,
+
         DataFormat::Gzip => process_decompressed({
             let mut decoder = GzDecoder::new(data);
             let mut vec = Vec::with_capacity(data.len() * 2);
@@ -116,20 +171,29 @@ pub fn detect_and_decode(data: &[u8], max_truncate_length: usize) -> (DataFormat
 
         _ => {
             let is_utf8 = simdutf8::basic::from_utf8(data).is_ok();
-            if !is_utf8 && let Ok(decompressed) = decompress_size_prepended(data) {
+            if let Some(text) = decode_proto(data) {
+                Some((DataFormat::Protobuf, text))
+            } else if !is_utf8 && let Ok(decompressed) = decompress_size_prepended(data) {
                 process_decompressed(Some(decompressed))
             } else {
                 format_text(data, max_truncate_length)
             }
         }
     };
-    if let Some((new_format, text)) = result {
-        (new_format, text)
-    } else {
-        (initial_format, SharedString::new(String::from_utf8_lossy(data)))
+    match result {
+        Some((format, text)) => (format, Some(text)),
+        None => (initial_format, None),
     }
 }
 
+pub fn detect_and_decode(data: &[u8], max_truncate_length: usize) -> (DataFormat, SharedString) {
+    let (format, text) = decode_bytes(data, max_truncate_length, |_| None);
+    (
+        format,
+        text.unwrap_or_else(|| SharedString::new(String::from_utf8_lossy(data))),
+    )
+}
+
 impl RedisBytesValue {
     pub fn detect_and_update(&mut self, server_id: &str, key: &str, max_truncate_length: usize) {
         let data = self.bytes.as_ref();
@@ -137,55 +201,16 @@ impl RedisBytesValue {
             return;
         }
 
-        let (initial_format, mime) = detect_format(data);
+        let (_, mime) = detect_format(data);
         self.mime = mime;
 
-        let process_decompressed = |decompressed: Option<Vec<u8>>| {
-            decompressed
-                .and_then(|vec| format_text(&vec, max_truncate_length).map(|(_, text)| (DataFormat::Preview, text)))
-        };
-
-        let result = match initial_format {
-            DataFormat::MessagePack => rmp_serde::from_slice::<serde_json::Value>(data)
-                .ok()
-                .and_then(|v| serde_json::to_string_pretty(&v).ok())
-                .map(|s| (DataFormat::Preview, SharedString::from(s))),
-
-            DataFormat::Gzip => process_decompressed({
-                let mut decoder = GzDecoder::new(data);
-                let mut vec = Vec::with_capacity(data.len() * 2);
-                decoder.read_to_end(&mut vec).ok().map(|_| vec)
-            }),
-
-            DataFormat::Zstd => process_decompressed(decompress_zstd(data).ok()),
-
-            DataFormat::Snappy => process_decompressed({
-                let mut decoder = FrameDecoder::new(data);
-                let mut vec = Vec::with_capacity(data.len() * 2);
-                decoder.read_to_end(&mut vec).ok().map(|_| vec)
-            }),
-
-            DataFormat::Svg | DataFormat::Jpeg | DataFormat::Png | DataFormat::Webp | DataFormat::Gif => None,
-
-            _ => {
-                let is_utf8 = simdutf8::basic::from_utf8(data).is_ok();
-                if let Some(id) = ProtoManager::match_key_to_name(server_id, key)
-                    && let Ok(data) = ProtoManager::decode_data(&id, data)
-                {
-                    Some((DataFormat::Protobuf, SharedString::from(data)))
-                } else if !is_utf8 && let Ok(decompressed) = decompress_size_prepended(data) {
-                    process_decompressed(Some(decompressed))
-                } else {
-                    format_text(data, max_truncate_length)
-                }
-            }
-        };
-
-        if let Some((new_format, text)) = result {
-            self.format = new_format;
-            self.text = Some(text);
-        } else {
-            self.format = initial_format;
+        let (format, text) = decode_bytes(data, max_truncate_length, |data| {
+            let id = ProtoManager::match_key_to_name(server_id, key)?;
+            ProtoManager::decode_data(&id, data).ok().map(SharedString::from)
+        });
+        self.format = format;
+        if text.is_some() {
+            self.text = text;
         }
     }
 }
@@ -199,6 +224,166 @@ pub(crate) async fn get_redis_bytes_value(conn: &mut RedisAsyncConn, key: &str)
     })
 }
 
+/// Loads a string value, or only its head if it is larger than [`LARGE_STRING_THRESHOLD`].
+///
+/// The head is cut back to the last complete UTF-8 character so text is not shown as binary.
+pub(crate) async fn get_redis_bytes_head_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisBytesValue> {
+    let len: usize = cmd("STRLEN").arg(key).query_async(conn).await?;
+    if len <= LARGE_STRING_THRESHOLD {
+        return get_redis_bytes_value(conn, key).await;
+    }
+    let mut value_bytes: Vec<u8> = cmd("GETRANGE")
+        .arg(key)
+        .arg(0)
+        .arg(LARGE_STRING_HEAD_SIZE - 1)
+        .query_async(conn)
+        .await?;
+    if let Err(e) = std::str::from_utf8(&value_bytes)
+        && e.error_len().is_none()
+    {
+        value_bytes.truncate(e.valid_up_to());
+    }
+    Ok(RedisBytesValue {
+        format: DataFormat::Text,
+        bytes: Bytes::from(value_bytes),
+        full_len: Some(len),
+        ..Default::default()
+    })
+}
+
+/// A numeric string value, or the amount it is incremented by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CounterValue {
+    /// Updated with INCRBY
+    Int(i64),
+    /// Updated with INCRBYFLOAT
+    Float(f64),
+}
+
+impl CounterValue {
+    /// Parses the text like Redis does: no surrounding spaces, integers before floats.
+    pub fn parse(text: &str) -> Option<Self> {
+        if text.is_empty() || text.trim() != text {
+            return None;
+        }
+        if let Ok(value) = text.parse::<i64>() {
+            return Some(CounterValue::Int(value));
+        }
+        text.parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(CounterValue::Float)
+    }
+}
+
+impl ZedisServerState {
+    /// Increments the current numeric string on the server with INCRBY, or
+    /// INCRBYFLOAT if the value or the increment is a float.
+    ///
+    /// Unlike saving the edited text with SET, concurrent updates of the counter
+    /// are not lost and the TTL is kept.
+    pub fn incr_string_value(&mut self, by: SharedString, cx: &mut Context<Self>) {
+        let Some(by) = CounterValue::parse(by.trim()) else {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            self.emit_error_notification(t!("editor.invalid_increment", value = by, locale = locale).into(), cx);
+            return;
+        };
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(bytes_value) = value.bytes_value().cloned() else {
+            return;
+        };
+        let Some(current) = bytes_value.counter_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        self.spawn(
+            ServerTask::IncrStringValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let new_value = match (current, by) {
+                    (CounterValue::Int(_), CounterValue::Int(by)) => {
+                        let value: i64 = cmd("INCRBY").arg(key.as_str()).arg(by).query_async(&mut conn).await?;
+                        value.to_string()
+                    }
+                    (_, by) => {
+                        let by = match by {
+                            CounterValue::Int(by) => by as f64,
+                            CounterValue::Float(by) => by,
+                        };
+                        cmd("INCRBYFLOAT")
+                            .arg(key.as_str())
+                            .arg(by)
+                            .query_async::<String>(&mut conn)
+                            .await?
+                    }
+                };
+                Ok((key, new_value))
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((key, new_value)) = result
+                        && this.key.as_ref() == Some(&key)
+                    {
+                        value.data = Some(RedisValueData::Bytes(Arc::new(RedisBytesValue {
+                            bytes: Bytes::from(new_value.clone().into_bytes()),
+                            text: Some(new_value.into()),
+                            format: bytes_value.format,
+                            ..Default::default()
+                        })));
+                    }
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Loads the whole value of a string whose head only was loaded.
+    pub fn load_full_string_value(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        if !value.bytes_value().is_some_and(|data| data.is_partial()) {
+            return;
+        }
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        self.spawn(
+            ServerTask::LoadMoreValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut data = get_redis_bytes_value(&mut conn, &key).await?;
+                data.detect_and_update(server_id.as_str(), key.as_str(), max_truncate_length);
+                Ok((key, data))
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((key, data)) = result
+                        && this.key.as_ref() == Some(&key)
+                    {
+                        value.data = Some(RedisValueData::Bytes(Arc::new(data)));
+                        cx.emit(ServerEvent::ValueLoaded);
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
+
 // pub(crate) async fn get_redis_value(
 //     mut data: RedisBytesValue,
 //     key: &str,
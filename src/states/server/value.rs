@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::string::CounterValue;
 use super::{ServerEvent, ServerTask, ZedisServerState};
-use crate::connection::get_connection_manager;
+use crate::connection::{RedisAsyncConn, get_connection_manager};
+use crate::error::Error;
+use crate::states::DefaultSort;
 use bytes::Bytes;
 use chrono::Local;
 use gpui::{Hsla, SharedString, prelude::*};
-use redis::cmd;
+use redis::{Cmd, cmd};
 use serde::Deserialize;
 use std::collections::HashSet;
 use std::io::Cursor;
@@ -25,6 +28,28 @@ use std::sync::Arc;
 
 pub(crate) const SUCCESS_NOTIFY_THRESHOLD: usize = 10;
 
+/// A single value read back as raw bytes (e.g. HGET, LINDEX), for the hex dump.
+///
+/// Collection values are decoded lossily for display, so the original bytes
+/// are fetched again on demand.
+#[derive(Clone)]
+pub struct RawBytesSource {
+    pub server_id: SharedString,
+    pub db: usize,
+    pub command: Cmd,
+}
+
+impl RawBytesSource {
+    /// Runs the command, `None` if the value no longer exists.
+    pub async fn load(self) -> Result<Option<Vec<u8>>, Error> {
+        let mut conn = get_connection_manager()
+            .get_connection(&self.server_id, self.db)
+            .await?;
+        let bytes: Option<Vec<u8>> = self.command.query_async(&mut conn).await?;
+        Ok(bytes)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub enum DataFormat {
     #[default]
@@ -42,6 +67,7 @@ pub enum DataFormat {
     Snappy,
     Protobuf,
     MessagePack,
+    HyperLogLog,
 }
 
 impl DataFormat {
@@ -61,6 +87,7 @@ impl DataFormat {
             DataFormat::Zstd => "zstd",
             DataFormat::Protobuf => "protobuf",
             DataFormat::MessagePack => "messagepack",
+            DataFormat::HyperLogLog => "hyperloglog",
         }
     }
 }
@@ -128,10 +155,20 @@ fn is_snappy_framed(bytes: &[u8]) -> bool {
     bytes.starts_with(&[0xFF, 0x06, 0x00, 0x00, 0x73, 0x4E, 0x61, 0x50, 0x70, 0x59])
 }
 
+/// Checks the 16 bytes header of a HyperLogLog: `HYLL`, encoding (0 dense, 1 sparse),
+/// 3 unused zero bytes, then the cached cardinality.
+fn is_hyperloglog(bytes: &[u8]) -> bool {
+    bytes.len() >= 16 && bytes.starts_with(b"HYLL") && bytes[4] <= 1 && bytes[5..8] == [0, 0, 0]
+}
+
 pub fn detect_format(bytes: &[u8]) -> (DataFormat, Option<SharedString>) {
     if bytes.is_empty() {
         return (DataFormat::Bytes, None);
     }
+    // HyperLogLog values are strings with a `HYLL` magic header
+    if is_hyperloglog(bytes) {
+        return (DataFormat::HyperLogLog, None);
+    }
     let Some(kind) = infer::get(bytes) else {
         return if is_snappy_framed(bytes) {
             (DataFormat::Snappy, Some("application/snappy".to_string().into()))
@@ -175,6 +212,35 @@ pub struct RedisSetValue {
     pub size: usize,
     pub values: Vec<SharedString>,
     pub done: bool,
+    /// Loaded members, to skip the duplicates SSCAN may return across pages
+    pub seen: HashSet<SharedString>,
+}
+
+impl RedisSetValue {
+    /// Appends a scanned page, skipping members that are already loaded.
+    ///
+    /// SSCAN may return a member more than once, e.g. while the set is rehashing.
+    pub fn append_scanned(&mut self, values: Vec<SharedString>) {
+        for value in values {
+            if self.seen.insert(value.clone()) {
+                self.values.push(value);
+            }
+        }
+    }
+    /// Sorts the loaded members alphabetically, `descending` reverses the order.
+    pub fn sort_values(&mut self, descending: bool) {
+        self.values.sort();
+        if descending {
+            self.values.reverse();
+        }
+    }
+    pub fn apply_default_sort(&mut self, sort: DefaultSort) {
+        match sort {
+            DefaultSort::ValueAsc => self.sort_values(false),
+            DefaultSort::ValueDesc => self.sort_values(true),
+            DefaultSort::None | DefaultSort::ScoreDesc => {}
+        }
+    }
 }
 
 /// Sort order for sorted sets
@@ -182,6 +248,7 @@ pub struct RedisSetValue {
 pub enum SortOrder {
     #[default]
     Asc, // Ascending order (default)
+    Desc, // Descending order, loaded with ZREVRANGE
 }
 
 /// Redis Sorted Set value structure with pagination and sorting support
@@ -195,6 +262,39 @@ pub struct RedisZsetValue {
     pub sort_order: SortOrder,
 }
 
+impl RedisZsetValue {
+    /// Inserts a member at the position of its score in the current sort order.
+    pub fn insert_sorted(&mut self, member: SharedString, score: f64) {
+        let idx = self.values.partition_point(|(_, s)| {
+            if self.sort_order == SortOrder::Asc {
+                *s < score
+            } else {
+                *s > score
+            }
+        });
+        self.values.insert(idx, (member, score));
+    }
+    /// Sorts the loaded members by score or by member, `descending` reverses the order.
+    pub fn sort_values(&mut self, by_score: bool, descending: bool) {
+        if by_score {
+            self.values.sort_by(|a, b| a.1.total_cmp(&b.1));
+        } else {
+            self.values.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        if descending {
+            self.values.reverse();
+        }
+    }
+    pub fn apply_default_sort(&mut self, sort: DefaultSort) {
+        match sort {
+            DefaultSort::None => {}
+            DefaultSort::ValueAsc => self.sort_values(false, false),
+            DefaultSort::ValueDesc => self.sort_values(false, true),
+            DefaultSort::ScoreDesc => self.sort_values(true, true),
+        }
+    }
+}
+
 /// Redis Hash value structure with pagination support
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct RedisHashValue {
@@ -203,6 +303,36 @@ pub struct RedisHashValue {
     pub size: usize,
     pub done: bool,
     pub values: Vec<(SharedString, SharedString)>,
+    /// Loaded fields, to skip the duplicates HSCAN may return across pages
+    pub seen: HashSet<SharedString>,
+    /// Scan field names only (`HSCAN ... NOVALUES`) and load values when their rows are shown
+    pub novalues: bool,
+    /// Values are large, so each one is loaded on its own with HGET when its row is shown
+    pub lazy_values: bool,
+    /// Fields scanned without their value, which is still to be loaded
+    pub pending: HashSet<SharedString>,
+}
+
+impl RedisHashValue {
+    /// Appends a scanned page, skipping fields that are already loaded.
+    ///
+    /// HSCAN may return a field more than once, e.g. while the hash is rehashing.
+    pub fn append_scanned(&mut self, values: Vec<(SharedString, SharedString)>) {
+        for (field, value) in values {
+            if self.seen.insert(field.clone()) {
+                self.values.push((field, value));
+            }
+        }
+    }
+    /// Appends a page scanned with `NOVALUES`, the values are marked as pending.
+    pub fn append_scanned_fields(&mut self, fields: Vec<SharedString>) {
+        for field in fields {
+            if self.seen.insert(field.clone()) {
+                self.pending.insert(field.clone());
+                self.values.push((field, SharedString::default()));
+            }
+        }
+    }
 }
 
 /// Redis List value structure
@@ -295,6 +425,8 @@ pub struct RedisBytesValue {
     pub mime: Option<SharedString>,
     pub text: Option<SharedString>,
     pub view_mode: ViewMode,
+    /// Length of the whole value when only its head was loaded
+    pub full_len: Option<usize>,
 }
 
 impl RedisBytesValue {
@@ -307,9 +439,28 @@ impl RedisBytesValue {
     pub fn is_utf8_text(&self) -> bool {
         matches!(self.format, DataFormat::Text | DataFormat::Json)
     }
+    /// Whether only the head of a large value was loaded
+    pub fn is_partial(&self) -> bool {
+        self.full_len.is_some()
+    }
+    /// Whether the value is a counter that INCRBY / INCRBYFLOAT can update in place
+    pub fn counter_value(&self) -> Option<CounterValue> {
+        if self.is_partial() {
+            return None;
+        }
+        CounterValue::parse(std::str::from_utf8(&self.bytes).ok()?)
+    }
 }
 
 impl RedisValue {
+    /// Reorders the loaded rows of a set or zset, the other types keep the order of Redis.
+    pub fn apply_default_sort(&mut self, sort: DefaultSort) {
+        match self.data.as_mut() {
+            Some(RedisValueData::Set(set_data)) => Arc::make_mut(set_data).apply_default_sort(sort),
+            Some(RedisValueData::Zset(zset_data)) => Arc::make_mut(zset_data).apply_default_sort(sort),
+            _ => {}
+        }
+    }
     /// Returns the list value if the data is a List type
     pub fn list_value(&self) -> Option<&Arc<RedisListValue>> {
         if let Some(RedisValueData::List(data)) = self.data.as_ref() {
@@ -416,6 +567,25 @@ impl KeyType {
         }
     }
 
+    /// Returns the encoding a collection converts to once it crosses its compact-encoding limits.
+    pub fn large_encoding(&self) -> Option<&'static str> {
+        match self {
+            KeyType::Hash | KeyType::Set => Some("hashtable"),
+            KeyType::Zset => Some("skiplist"),
+            _ => None,
+        }
+    }
+
+    /// Returns the CONFIG GET pattern for the compact-encoding limits of this type.
+    pub fn encoding_config_pattern(&self) -> Option<&'static str> {
+        match self {
+            KeyType::Hash => Some("hash-max-*"),
+            KeyType::Set => Some("set-max-*"),
+            KeyType::Zset => Some("zset-max-*"),
+            _ => None,
+        }
+    }
+
     /// Returns the color associated with this key type for UI display
     pub fn color(&self) -> Hsla {
         match self {
@@ -448,6 +618,10 @@ pub struct RedisValue {
     pub(crate) data: Option<RedisValueData>,
     pub(crate) expire_at: Option<i64>,
     pub(crate) size: u64,
+    /// Internal encoding reported by OBJECT ENCODING
+    pub(crate) encoding: Option<SharedString>,
+    /// Compact-encoding limits (name, value), fetched when the encoding was converted
+    pub(crate) encoding_configs: Vec<(SharedString, SharedString)>,
 }
 
 impl RedisValue {
@@ -504,6 +678,25 @@ impl RedisValue {
         self.key_type
     }
 
+    /// Returns the internal encoding of the key (e.g. listpack, hashtable)
+    pub fn encoding(&self) -> Option<SharedString> {
+        self.encoding.clone()
+    }
+
+    /// Checks if the collection has converted from its compact encoding (listpack/intset)
+    /// to the large one (hashtable/skiplist), meaning it crossed a config threshold.
+    pub fn is_encoding_converted(&self) -> bool {
+        match (self.key_type.large_encoding(), self.encoding.as_ref()) {
+            (Some(large), Some(encoding)) => encoding.as_str() == large,
+            _ => false,
+        }
+    }
+
+    /// Returns the compact-encoding limits relevant to this key type
+    pub fn encoding_configs(&self) -> &[(SharedString, SharedString)] {
+        &self.encoding_configs
+    }
+
     /// Checks if the key is expired (TTL = -2)
     pub fn is_expired(&self) -> bool {
         self.expire_at.is_some_and(|expire_at| expire_at == -2)
@@ -526,13 +719,32 @@ impl From<&str> for KeyType {
     }
 }
 
+/// Fails with `Error::TypeMismatch` if the key now holds another type than the open editor's,
+/// e.g. after it was deleted and recreated by another client.
+///
+/// A missing key passes, the write recreates it with the expected type.
+pub(crate) async fn ensure_key_type(conn: &mut RedisAsyncConn, key: &str, expected: KeyType) -> Result<(), Error> {
+    let actual: String = cmd("TYPE").arg(key).query_async(conn).await?;
+    if actual == "none" || KeyType::from(actual.as_str()) == expected {
+        return Ok(());
+    }
+    Err(Error::TypeMismatch {
+        key: key.to_string(),
+        expected: format!("{expected:?}").to_lowercase(),
+        actual,
+    })
+}
+
 impl ZedisServerState {
     /// Saves a new value for a Redis string key
     ///
     /// This method updates the UI immediately with the new value and then
     /// asynchronously persists it to Redis. If the save fails, the original
     /// value is restored.
-    pub fn save_value(&mut self, key: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
+    ///
+    /// A plain SET clears the TTL, so with `keep_ttl` it uses KEEPTTL (Redis 6.0+),
+    /// or re-applies the current PTTL on older servers.
+    pub fn save_value(&mut self, key: SharedString, new_value: SharedString, keep_ttl: bool, cx: &mut Context<Self>) {
         let server_id = self.server_id.clone();
         let db = self.db;
         let Some(value) = self.value.as_mut() else {
@@ -552,7 +764,10 @@ impl ZedisServerState {
             format,
             ..Default::default()
         })));
-        let ttl = value.ttl().map(|ttl| ttl.num_milliseconds()).unwrap_or_default();
+        let original_expire_at = value.expire_at;
+        if !keep_ttl && value.expire_at.is_some_and(|expire_at| expire_at > 0) {
+            value.expire_at = Some(-1);
+        }
 
         cx.notify();
         self.spawn(
@@ -560,17 +775,20 @@ impl ZedisServerState {
             move || async move {
                 let client = get_connection_manager().get_client(&server_id, db).await?;
                 let mut conn = client.connection();
-                let mut binding = cmd("SET");
-                let mut new_cmd = binding.arg(key.as_str()).arg(new_value.as_str());
-                // keep ttl if the version is at least 6.0.0
-                new_cmd = if client.is_at_least_version("6.0.0") {
-                    new_cmd.arg("KEEPTTL")
-                } else if ttl > 0 {
-                    new_cmd.arg("PX").arg(ttl)
-                } else {
-                    new_cmd
-                };
-                let _: () = new_cmd.query_async(&mut conn).await?;
+                let mut set_cmd = cmd("SET");
+                set_cmd.arg(key.as_str()).arg(new_value.as_str());
+                if keep_ttl {
+                    // KEEPTTL is available since 6.0.0, older servers get the remaining ttl re-applied
+                    if client.is_at_least_version("6.0.0") {
+                        set_cmd.arg("KEEPTTL");
+                    } else {
+                        let ttl: i64 = cmd("PTTL").arg(key.as_str()).query_async(&mut conn).await?;
+                        if ttl > 0 {
+                            set_cmd.arg("PX").arg(ttl);
+                        }
+                    }
+                }
+                let _: () = set_cmd.query_async(&mut conn).await?;
 
                 let mut size = None;
                 if let Ok(memory_usage) = cmd("MEMORY")
@@ -594,6 +812,7 @@ impl ZedisServerState {
                     } else {
                         // Recover original value if save failed
                         value.size = original_size;
+                        value.expire_at = original_expire_at;
                         value.data = Some(RedisValueData::Bytes(original_bytes_value.clone()));
                     }
                     cx.emit(ServerEvent::ValueUpdated);
@@ -604,6 +823,15 @@ impl ZedisServerState {
         );
     }
 
+    /// Builds a raw bytes source that runs `command` on the current server and db.
+    pub fn raw_bytes_source(&self, command: Cmd) -> RawBytesSource {
+        RawBytesSource {
+            server_id: self.server_id.clone(),
+            db: self.db,
+            command,
+        }
+    }
+
     pub fn update_bytes_value_view_mode(&mut self, view_mode: SharedString, cx: &mut Context<Self>) {
         let Some(value) = self.value.as_mut() else {
             return;
@@ -0,0 +1,133 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::value::{RedisValue, RedisValueData, RedisValueStatus};
+use crate::helpers::unix_ts;
+use gpui::SharedString;
+use std::collections::VecDeque;
+
+/// Maximum number of values kept per server
+const MAX_ENTRIES: usize = 32;
+/// Maximum approximate bytes of the values kept per server
+const MAX_BYTES: usize = 32 * 1024 * 1024;
+/// Values older than this are fetched again
+const MAX_AGE_SECS: i64 = 300;
+
+#[derive(Debug, Clone)]
+struct ValueCacheEntry {
+    db: usize,
+    key: SharedString,
+    value: RedisValue,
+    /// Unix timestamp (seconds) of when the value was fetched
+    cached_at: i64,
+    bytes: usize,
+}
+
+/// LRU cache of recently viewed values of a server, bounded by count and approximate bytes.
+///
+/// Lets switching back to a just-viewed key skip the first-load round trips.
+#[derive(Debug, Clone, Default)]
+pub struct ValueCache {
+    /// Most recently used entry last
+    entries: VecDeque<ValueCacheEntry>,
+    bytes: usize,
+}
+
+/// Approximate heap size of the loaded data of a value.
+fn approx_bytes(value: &RedisValue) -> usize {
+    let str_bytes = |values: &[SharedString]| values.iter().map(|v| v.len()).sum::<usize>();
+    match value.data.as_ref() {
+        Some(RedisValueData::Bytes(data)) => data.bytes.len() + data.text.as_ref().map_or(0, |text| text.len()),
+        Some(RedisValueData::List(data)) => str_bytes(&data.values),
+        Some(RedisValueData::Set(data)) => str_bytes(&data.values),
+        Some(RedisValueData::Zset(data)) => data.values.iter().map(|(member, _)| member.len() + 8).sum(),
+        Some(RedisValueData::Hash(data)) => data.values.iter().map(|(field, value)| field.len() + value.len()).sum(),
+        Some(RedisValueData::Stream(data)) => data
+            .values
+            .iter()
+            .map(|(id, fields)| id.len() + fields.iter().map(|(f, v)| f.len() + v.len()).sum::<usize>())
+            .sum(),
+        None => 0,
+    }
+}
+
+impl ValueCache {
+    fn position(&self, db: usize, key: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.db == db && entry.key.as_str() == key)
+    }
+
+    /// Returns the cached value and its fetch timestamp, marking it as recently used.
+    ///
+    /// Entries that are too old or whose key has expired are dropped.
+    pub fn get(&mut self, db: usize, key: &str) -> Option<(RedisValue, i64)> {
+        let index = self.position(db, key)?;
+        let entry = self.entries.remove(index)?;
+        let now = unix_ts();
+        let expired = entry
+            .value
+            .expire_at
+            .is_some_and(|expire_at| expire_at >= 0 && expire_at <= now);
+        if expired || now - entry.cached_at > MAX_AGE_SECS {
+            self.bytes -= entry.bytes;
+            return None;
+        }
+        let result = (entry.value.clone(), entry.cached_at);
+        self.entries.push_back(entry);
+        Some(result)
+    }
+
+    /// Caches an idle value fetched at `cached_at`, evicting the least recently used entries.
+    pub fn insert(&mut self, db: usize, key: SharedString, value: &RedisValue, cached_at: i64) {
+        self.remove(db, &key);
+        if value.is_expired() || value.data.is_none() {
+            return;
+        }
+        let bytes = approx_bytes(value);
+        if bytes > MAX_BYTES {
+            return;
+        }
+        let mut value = value.clone();
+        value.status = RedisValueStatus::Idle;
+        self.entries.push_back(ValueCacheEntry {
+            db,
+            key,
+            value,
+            cached_at,
+            bytes,
+        });
+        self.bytes += bytes;
+        while self.entries.len() > MAX_ENTRIES || self.bytes > MAX_BYTES {
+            let Some(evicted) = self.entries.pop_front() else {
+                break;
+            };
+            self.bytes -= evicted.bytes;
+        }
+    }
+
+    /// Removes the cached value of a key.
+    pub fn remove(&mut self, db: usize, key: &str) {
+        if let Some(index) = self.position(db, key)
+            && let Some(entry) = self.entries.remove(index)
+        {
+            self.bytes -= entry.bytes;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.bytes = 0;
+    }
+}
@@ -16,7 +16,7 @@
 //!
 //! This module provides functionality for managing Redis ZSET operations including:
 //! - Loading ZSET values with range-based pagination (ZRANGE/ZREVRANGE)
-//! - Adding/updating members with scores (ZADD)
+//! - Adding/updating members with scores (ZADD), optionally with NX/XX/GT/LT/CH
 //! - Removing members from a ZSET (ZREM)
 //! - Filtering ZSET members with pattern matching (ZSCAN)
 //! - Support for ascending and descending sort orders
@@ -24,19 +24,70 @@
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
-    value::{RedisValue, RedisValueStatus, RedisZsetValue, SortOrder},
+    value::{RedisValue, RedisValueStatus, RedisZsetValue, SortOrder, ensure_key_type},
 };
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, i18n_zset_editor},
+    helpers::contains_glob,
+    states::{SUCCESS_NOTIFY_THRESHOLD, ServerEvent, ZedisGlobalStore, i18n_zset_editor},
 };
 use gpui::{SharedString, prelude::*};
-use redis::cmd;
+use redis::{Cmd, cmd, pipe};
+use rust_i18n::t;
 use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
+/// Modifiers of ZADD when adding a member.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ZaddFlags {
+    /// Only add new members
+    pub nx: bool,
+    /// Only update existing members
+    pub xx: bool,
+    /// Only update when the new score is greater
+    pub gt: bool,
+    /// Only update when the new score is less
+    pub lt: bool,
+    /// Reply with the number of changed members instead of added ones
+    pub ch: bool,
+}
+
+/// ZADD modifiers in the order of the add dialog checkboxes.
+pub const ZADD_FLAG_NAMES: [&str; 5] = ["NX", "XX", "GT", "LT", "CH"];
+
+impl ZaddFlags {
+    /// Parses the checkbox values of the add dialog, in `ZADD_FLAG_NAMES` order.
+    pub fn from_values(values: &[SharedString]) -> Self {
+        let checked = |index: usize| values.get(index).is_some_and(|v| v == "true");
+        Self {
+            nx: checked(0),
+            xx: checked(1),
+            gt: checked(2),
+            lt: checked(3),
+            ch: checked(4),
+        }
+    }
+    /// Redis rejects NX together with XX, GT or LT, and GT together with LT.
+    pub fn is_valid(&self) -> bool {
+        !(self.nx && (self.xx || self.gt || self.lt)) && !(self.gt && self.lt)
+    }
+    fn apply(&self, zadd: &mut Cmd) {
+        for (enabled, flag) in [
+            (self.nx, "NX"),
+            (self.xx, "XX"),
+            (self.gt, "GT"),
+            (self.lt, "LT"),
+            (self.ch, "CH"),
+        ] {
+            if enabled {
+                zadd.arg(flag);
+            }
+        }
+    }
+}
+
 /// Retrieves ZSET members using range-based commands (ZRANGE or ZREVRANGE).
 ///
 /// This function is used for non-filtered pagination, loading members by their
@@ -212,11 +263,16 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
+        let verify_type = cx.global::<ZedisGlobalStore>().read(cx).verify_key_type();
 
         self.spawn(
             task,
             move || async move {
-                let conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Refuse to write with the commands of another type, the editor asks to reload instead
+                if verify_type {
+                    ensure_key_type(&mut conn, &key_str, KeyType::Zset).await?;
+                }
                 redis_op(key_str, conn).await
             },
             move |this, result, cx| {
@@ -225,6 +281,7 @@ impl ZedisServerState {
                 }
                 match result {
                     Ok(data) => on_success(this, data, cx),
+                    Err(e) if e.is_type_mismatch() => {}
                     Err(e) => this.emit_error_notification(e.to_string().into(), cx),
                 }
                 cx.notify();
@@ -233,19 +290,83 @@ impl ZedisServerState {
         );
     }
 
-    /// Adds or updates a member in the Redis ZSET with the specified score.
+    /// Adds a member to the Redis ZSET with the specified score.
+    ///
+    /// Without flags, uses ZADD NX which only adds the member if it doesn't already exist,
+    /// warning like the set and hash editors otherwise. A new member is inserted into the
+    /// local UI state at its sorted position once the server confirmed it.
     ///
-    /// Uses ZADD command which updates the score if the member already exists,
-    /// or adds it as a new member if it doesn't. The method intelligently updates
-    /// the local UI state by either updating existing members or inserting new ones
-    /// in the correct sorted position.
+    /// With flags, whether the member is written depends on its current score, so the
+    /// local state is updated from the scores read around the ZADD instead of optimistically.
     ///
     /// # Arguments
     /// * `new_value` - The member name to add/update
     /// * `score` - The score to assign to the member
+    /// * `flags` - ZADD modifiers (NX/XX/GT/LT/CH)
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn add_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
-        self.add_or_update_zset_value(new_value, score, None, cx);
+    pub fn add_zset_value(&mut self, new_value: SharedString, score: f64, flags: ZaddFlags, cx: &mut Context<Self>) {
+        if flags == ZaddFlags::default() {
+            self.add_or_update_zset_value(new_value, score, None, cx);
+            return;
+        }
+        if !flags.is_valid() {
+            self.emit_error_notification(i18n_zset_editor(cx, "zadd_flags_invalid"), cx);
+            return;
+        }
+        let member = new_value.clone();
+        self.exec_zset_op(
+            ServerTask::AddZsetValue,
+            cx,
+            |_| {},
+            move |key, mut conn| async move {
+                let mut zadd = cmd("ZADD");
+                zadd.arg(&key);
+                flags.apply(&mut zadd);
+                zadd.arg(score).arg(new_value.as_str());
+                let (before, count, after): (Option<f64>, usize, Option<f64>) = pipe()
+                    .cmd("ZSCORE")
+                    .arg(&key)
+                    .arg(new_value.as_str())
+                    .add_command(zadd)
+                    .cmd("ZSCORE")
+                    .arg(&key)
+                    .arg(new_value.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok((before, count, after))
+            },
+            move |this, (before, count, after), cx| {
+                let Some(score) = after.filter(|_| before != after) else {
+                    this.emit_info_notification(i18n_zset_editor(cx, "zadd_not_written"), cx);
+                    return;
+                };
+                if let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) {
+                    let zset = Arc::make_mut(zset_data);
+                    let existing = zset.values.iter().position(|(m, _)| m == &member);
+                    if let Some(index) = existing
+                        && zset.keyword.is_some()
+                    {
+                        zset.values[index].1 = score;
+                    } else {
+                        if let Some(index) = existing {
+                            zset.values.remove(index);
+                        }
+                        if zset.keyword.is_none() {
+                            zset.insert_sorted(member, score);
+                        }
+                    }
+                    if before.is_none() {
+                        zset.size += 1;
+                    }
+                }
+                if flags.ch {
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let message = t!("zset_editor.zadd_changed", count = count, locale = locale);
+                    this.emit_info_notification(message.into(), cx);
+                }
+                cx.emit(ServerEvent::ValueAdded);
+            },
+        );
     }
     /// Updates a member in the Redis ZSET with the specified score.
     ///
@@ -272,6 +393,7 @@ impl ZedisServerState {
         cx: &mut Context<Self>,
     ) {
         let new_value_clone = new_value.clone();
+        let added_value = new_value.clone();
         let old_value_clone = old_value.clone();
         let is_removed = old_value.is_some();
 
@@ -279,52 +401,50 @@ impl ZedisServerState {
             ServerTask::AddZsetValue,
             cx,
             move |zset| {
-                let mut exists = false;
+                // A new member waits for server confirmation, since it may already exist
+                let Some(old) = old_value_clone else {
+                    return;
+                };
                 // Update if exists or handle "rename" logic
-                if let Some(ref old) = old_value_clone
-                    && let Some(item) = zset.values.iter_mut().find(|v| &v.0 == old)
-                {
-                    *item = (new_value_clone.clone(), score);
-                    exists = true;
+                if let Some(item) = zset.values.iter_mut().find(|v| v.0 == old) {
+                    *item = (new_value_clone, score);
+                    return;
                 }
+                // Remove old if this is a rename that wasn't found in current visible page
+                zset.values.retain(|v| v.0 != old);
 
-                if !exists {
-                    // Remove old if this is a rename that wasn't found in current visible page
-                    if let Some(ref old) = old_value_clone {
-                        zset.values.retain(|v| &v.0 != old);
-                    }
-
-                    // Insert into correct position using binary search if no filter is active
-                    if zset.keyword.is_none() {
-                        let idx = zset.values.partition_point(|(_, s)| {
-                            if zset.sort_order == SortOrder::Asc {
-                                *s < score
-                            } else {
-                                *s > score
-                            }
-                        });
-                        zset.values.insert(idx, (new_value_clone, score));
-                    }
+                // Insert into correct position using binary search if no filter is active
+                if zset.keyword.is_none() {
+                    zset.insert_sorted(new_value_clone, score);
                 }
             },
             move |key, mut conn| async move {
-                let count: usize = cmd("ZADD")
-                    .arg(&key)
-                    .arg(score)
-                    .arg(new_value.as_str())
-                    .query_async(&mut conn)
-                    .await?;
+                let mut zadd = cmd("ZADD");
+                zadd.arg(&key);
+                // NX checks the existence and adds in one step, so an existing member is reported instead of updated
+                if old_value.is_none() {
+                    zadd.arg("NX");
+                }
+                let count: usize = zadd.arg(score).arg(new_value.as_str()).query_async(&mut conn).await?;
                 if let Some(old) = old_value {
                     let _: () = cmd("ZREM").arg(&key).arg(old.as_str()).query_async(&mut conn).await?;
                 }
                 Ok(count)
             },
             move |this, count, cx| {
-                if !is_removed
-                    && let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                if is_removed {
+                    cx.emit(ServerEvent::ValueAdded);
+                    return;
+                }
+                if count == 0 {
+                    this.emit_warning_notification(i18n_zset_editor(cx, "add_value_exists_tips"), cx);
+                } else if let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
                     let zset = Arc::make_mut(zset_data);
                     zset.size += count;
+                    if zset.keyword.is_none() {
+                        zset.insert_sorted(added_value, score);
+                    }
                     if zset.size > SUCCESS_NOTIFY_THRESHOLD {
                         this.emit_success_notification(
                             i18n_zset_editor(cx, "add_value_success_tips"),
@@ -361,6 +481,7 @@ impl ZedisServerState {
         let new_zset = RedisZsetValue {
             keyword,
             size: zset.size,
+            sort_order: zset.sort_order,
             ..Default::default()
         };
         value.data = Some(RedisValueData::Zset(Arc::new(new_zset)));
@@ -368,6 +489,20 @@ impl ZedisServerState {
         // Trigger load with the new filter
         self.load_more_zset_value(cx);
     }
+    /// Sorts the loaded ZSET members client-side, by score or by member.
+    ///
+    /// Only the members loaded so far are reordered; `descending` reverses the order.
+    pub fn sort_zset_value(&mut self, by_score: bool, descending: bool, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(RedisValueData::Zset(zset_data)) = value.data.as_mut() else {
+            return;
+        };
+        Arc::make_mut(zset_data).sort_values(by_score, descending);
+        cx.emit(ServerEvent::ValueUpdated);
+        cx.notify();
+    }
     /// Loads the next batch of ZSET members using appropriate pagination strategy.
     ///
     /// Uses two different strategies based on whether filtering is active:
@@ -380,6 +515,7 @@ impl ZedisServerState {
     /// # Arguments
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn load_more_zset_value(&mut self, cx: &mut Context<Self>) {
+        // Skip while a load or write is in flight, overlapping loads would duplicate entries
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
@@ -419,7 +555,7 @@ impl ZedisServerState {
                     Ok((0, values)) // Cursor is irrelevant for range queries
                 } else {
                     // With filter: use scan-based pagination with pattern matching
-                    let pattern = format!("*{keyword}*");
+                    let pattern = contains_glob(&keyword);
                     let result = search_redis_zset_value(&mut conn, &key, cursor, &pattern, 1000).await?;
                     Ok(result)
                 }
@@ -427,6 +563,10 @@ impl ZedisServerState {
             // UI callback: merge results and handle auto-loading for filters
             move |this, result, cx| {
                 let mut should_load_more = false;
+                let default_sort = cx
+                    .global::<ZedisGlobalStore>()
+                    .read(cx)
+                    .default_sort(KeyType::Zset.as_str());
 
                 if let Ok((new_cursor, new_values)) = result
                     && let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
@@ -436,6 +576,7 @@ impl ZedisServerState {
                     // Append new members to existing list
                     if !new_values.is_empty() {
                         zset.values.extend(new_values);
+                        zset.apply_default_sort(default_sort);
                     }
 
                     // Handle cursor state for filtered searches
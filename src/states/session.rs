@@ -14,15 +14,21 @@
 
 use crate::{error::Error, helpers::get_or_create_config_dir};
 use arc_swap::ArcSwap;
-use gpui::{Action, App, AppContext};
+use gpui::{Action, App};
+use parking_lot::Mutex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use smol::fs;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::{fmt, fs::read_to_string, path::PathBuf, sync::LazyLock};
-use tracing::{debug, error};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+use tracing::{debug, error, warn};
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -68,12 +74,48 @@ impl FromStr for QueryMode {
     }
 }
 
+/// A named keyword and query mode of the key tree search, picked instead of retyping it
+#[derive(Debug, Default, Deserialize, Clone, Serialize, PartialEq)]
+pub struct SavedSearch {
+    pub name: String,
+    pub keyword: String,
+    pub query_mode: Option<String>,
+}
+
+impl SavedSearch {
+    pub fn query_mode(&self) -> QueryMode {
+        self.query_mode
+            .as_deref()
+            .and_then(|s| QueryMode::from_str(s).ok())
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Default, Deserialize, Clone, Serialize)]
 pub struct SessionOption {
     pub id: String,
     pub soft_wrap: Option<bool>,
+    pub line_numbers: Option<bool>,
     pub query_mode: Option<String>,
     pub refresh_interval_sec: Option<u32>,
+    pub saved_searches: Option<Vec<SavedSearch>>,
+}
+
+impl SessionOption {
+    /// Saves a search, replacing the one with the same name.
+    pub fn upsert_saved_search(&mut self, search: SavedSearch) {
+        let searches = self.saved_searches.get_or_insert_with(Vec::new);
+        if let Some(existing) = searches.iter_mut().find(|item| item.name == search.name) {
+            *existing = search;
+        } else {
+            searches.push(search);
+        }
+    }
+    pub fn remove_saved_search(&mut self, name: &str) {
+        if let Some(searches) = self.saved_searches.as_mut() {
+            searches.retain(|item| item.name != name);
+        }
+    }
 }
 
 #[derive(Debug, Default, Deserialize, Clone, Serialize)]
@@ -83,25 +125,132 @@ struct SessionOptions {
 
 static SESSION_OPTION_MAP: LazyLock<ArcSwap<HashMap<String, SessionOption>>> =
     LazyLock::new(|| ArcSwap::from_pointee(HashMap::new()));
+/// Serializes writes of the session file, so a flush waits for the running write
+static SESSION_SAVE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+/// Serializes the read-modify-write of the in-memory options, so concurrent updates are not lost
+static SESSION_UPDATE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+/// Whether the session file was read, an invalid file leaves the map empty but must not be parsed again
+static SESSION_OPTIONS_LOADED: AtomicBool = AtomicBool::new(false);
+/// Serializes the first load, so the file is parsed (and backed up) only once
+static SESSION_LOAD_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+/// Problems found while loading the session file, until they are shown to the user
+static SESSION_LOAD_WARNINGS: LazyLock<Mutex<Vec<String>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Parses one `[[options]]` entry, dropping the fields that do not match the schema.
+///
+/// Returns `None` if the entry has no id, since it can't be matched to a server.
+fn parse_session_option(index: usize, entry: toml::Value, warnings: &mut Vec<String>) -> Option<SessionOption> {
+    let table = match entry {
+        toml::Value::Table(table) => table,
+        other => {
+            let kind = other.type_str();
+            warnings.push(format!("options[{index}]: expected a table, found {kind}"));
+            return None;
+        }
+    };
+    let Some(id) = table.get("id").and_then(|id| id.as_str()).map(|id| id.to_string()) else {
+        warnings.push(format!("options[{index}]: missing string field `id`, entry ignored"));
+        return None;
+    };
+    match toml::Value::Table(table.clone()).try_into::<SessionOption>() {
+        Ok(option) => return Some(option),
+        Err(e) => warn!(id, error = %e, "Invalid session option"),
+    }
+    // Check the fields one by one, so a single bad value only resets that field
+    let mut valid = toml::Table::new();
+    valid.insert("id".to_string(), toml::Value::String(id.clone()));
+    for (field, value) in table.into_iter().filter(|(field, _)| field != "id") {
+        let mut single = valid.clone();
+        single.insert(field.clone(), value.clone());
+        match toml::Value::Table(single).try_into::<SessionOption>() {
+            Ok(_) => {
+                valid.insert(field, value);
+            }
+            Err(e) => {
+                let message = e.message();
+                warnings.push(format!(
+                    "options[{index}] ({id}): `{field}` {message}, reset to default"
+                ));
+            }
+        }
+    }
+    toml::Value::Table(valid).try_into::<SessionOption>().ok()
+}
+
+/// Parses the session file, keeping every entry that can be read.
+///
+/// A syntax error makes the whole file unreadable, so it is backed up before
+/// the defaults are used and a later save overwrites it.
+fn parse_session_options(path: &Path, value: &str, warnings: &mut Vec<String>) -> HashMap<String, SessionOption> {
+    let mut configs = HashMap::new();
+    let mut document = match value.parse::<toml::Table>() {
+        Ok(document) => document,
+        Err(e) => {
+            let backup = path.with_extension("toml.bak");
+            if let Err(e) = std::fs::copy(path, &backup) {
+                error!(error = %e, "Failed to back up session config");
+            }
+            warnings.push(format!(
+                "{} could not be parsed, a copy was saved to {}:\n{e}",
+                path.display(),
+                backup.display()
+            ));
+            return configs;
+        }
+    };
+    let entries = match document.remove("options") {
+        Some(toml::Value::Array(entries)) => entries,
+        Some(other) => {
+            let kind = other.type_str();
+            warnings.push(format!("`options` must be an array of tables, found {kind}"));
+            return configs;
+        }
+        None => return configs,
+    };
+    for (index, entry) in entries.into_iter().enumerate() {
+        if let Some(option) = parse_session_option(index, entry, warnings) {
+            configs.insert(option.id.clone(), option);
+        }
+    }
+    configs
+}
 
 fn get_session_options() -> Result<Arc<HashMap<String, SessionOption>>> {
-    if SESSION_OPTION_MAP.load().is_empty() {
+    if SESSION_OPTIONS_LOADED.load(Ordering::Acquire) {
+        return Ok(SESSION_OPTION_MAP.load().clone());
+    }
+    let _guard = SESSION_LOAD_LOCK.lock();
+    if !SESSION_OPTIONS_LOADED.load(Ordering::Acquire) {
         let path = get_or_create_session_config()?;
-        let value = read_to_string(path)?;
+        let value = read_to_string(&path)?;
         if value.is_empty() {
-            return Ok(Arc::new(HashMap::new()));
+            SESSION_OPTIONS_LOADED.store(true, Ordering::Release);
+            return Ok(SESSION_OPTION_MAP.load().clone());
         }
-        let data: SessionOptions = toml::from_str(&value)?;
-        let options = data.options;
-        let mut configs = HashMap::new();
-        for option in options.iter() {
-            configs.insert(option.id.clone(), option.clone());
+        let mut warnings = vec![];
+        let configs = parse_session_options(&path, &value, &mut warnings);
+        if !warnings.is_empty() {
+            for warning in warnings.iter() {
+                warn!(warning = %warning, "Invalid session config");
+            }
+            SESSION_LOAD_WARNINGS.lock().extend(warnings);
         }
         SESSION_OPTION_MAP.store(Arc::new(configs));
+        SESSION_OPTIONS_LOADED.store(true, Ordering::Release);
     }
     Ok(SESSION_OPTION_MAP.load().clone())
 }
 
+/// Loads the session options and returns the problems found in the file.
+///
+/// Each problem is only returned once, so the warning is shown a single time.
+pub fn take_session_load_warnings() -> Vec<String> {
+    if let Err(e) = get_session_options() {
+        error!(error = %e, "Failed to load session options");
+    }
+    std::mem::take(&mut *SESSION_LOAD_WARNINGS.lock())
+}
+
 pub fn get_session_option(id: &str) -> Result<SessionOption> {
     let options = get_session_options()?;
     Ok(options.get(id).cloned().unwrap_or_else(|| SessionOption {
@@ -110,34 +259,74 @@ pub fn get_session_option(id: &str) -> Result<SessionOption> {
     }))
 }
 
-pub fn save_session_option(id: &str, mut option: SessionOption, cx: &App) {
+/// Returns the options of all sessions, sorted by id.
+pub fn all_session_options() -> Result<Vec<SessionOption>> {
+    let mut options: Vec<SessionOption> = get_session_options()?.values().cloned().collect();
+    options.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(options)
+}
+
+/// Adds imported session options, replacing the ones with the same id, and saves them.
+pub fn import_session_options(imported: Vec<SessionOption>) -> Result<()> {
+    if imported.is_empty() {
+        return Ok(());
+    }
+    {
+        let _guard = SESSION_UPDATE_LOCK.lock();
+        let mut options = get_session_options()?.as_ref().clone();
+        for option in imported.into_iter().filter(|option| !option.id.is_empty()) {
+            options.insert(option.id.clone(), option);
+        }
+        SESSION_OPTION_MAP.store(Arc::new(options));
+    }
+    flush_session_options()
+}
+
+/// Writes the current session options to disk, sorted by id so the file is stable.
+///
+/// Writes are serialized and always write the latest options, so a write never
+/// overwrites a newer one.
+pub fn flush_session_options() -> Result<()> {
+    let _guard = SESSION_SAVE_LOCK.lock();
+    let options = all_session_options()?;
+    let path = get_or_create_session_config()?;
+    let value = toml::to_string(&SessionOptions { options })?;
+    // Write a temporary file and rename it, so a crash never leaves a truncated file
+    let tmp_path = path.with_extension("toml.tmp");
+    std::fs::write(&tmp_path, value)?;
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Updates the option of a session and saves the options in the background.
+///
+/// The change is applied to the latest option under a lock, so updates of
+/// different fields made in quick succession never overwrite each other.
+/// The in-memory options are updated right away, so [`flush_session_options`]
+/// persists the change even if the app quits before the background write ran.
+pub fn save_session_option(id: &str, update: impl FnOnce(&mut SessionOption), cx: &App) {
     if id.is_empty() {
         return;
     }
-    let id = id.to_string();
-    option.id = id.clone();
-    cx.spawn(async move |cx| {
-        let task = cx.background_spawn(async move {
-            let mut options = vec![option.clone()];
-            let mut new_options = HashMap::new();
-            new_options.insert(id.to_string(), option.clone());
-
-            for (key, value) in get_session_options()?.iter() {
-                if *key == id {
-                    continue;
-                }
-                options.push(value.clone());
-                new_options.insert(key.to_string(), value.clone());
+    {
+        let _guard = SESSION_UPDATE_LOCK.lock();
+        let mut options = match get_session_options() {
+            Ok(options) => options.as_ref().clone(),
+            Err(e) => {
+                error!(error = %e, "Failed to load session options");
+                return;
             }
-
-            SESSION_OPTION_MAP.store(Arc::new(new_options));
-            let path = get_or_create_session_config()?;
-            let value = toml::to_string(&SessionOptions { options })?;
-            fs::write(&path, value).await?;
-            Ok(())
+        };
+        let option = options.entry(id.to_string()).or_insert_with(|| SessionOption {
+            id: id.to_string(),
+            ..Default::default()
         });
-        let result: Result<()> = task.await;
-        if let Err(e) = &result {
+        update(option);
+        option.id = id.to_string();
+        SESSION_OPTION_MAP.store(Arc::new(options));
+    }
+    cx.background_spawn(async move {
+        if let Err(e) = flush_session_options() {
             error!(error = %e, "Failed to save session option");
         }
     })
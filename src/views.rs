@@ -13,19 +13,25 @@
 // limitations under the License.
 
 mod about;
+mod bulk_preview;
 mod bytes_editor;
 mod clients_manager;
+mod command_log;
 mod content;
 mod editor;
 mod hash_editor;
+mod hot_keys;
 mod key_tree;
 mod kv_table;
 mod list_editor;
 mod memory_analysis;
 mod metrics;
+mod mini_metrics;
 mod monitor;
 mod proto_editor;
 mod pubsub_editor;
+mod random_keys;
+mod running_tasks;
 mod slowlog_editor;
 mod servers;
 mod set_editor;
@@ -34,22 +40,30 @@ mod sidebar;
 mod status_bar;
 mod stream_editor;
 mod title_bar;
+mod ttl_histogram;
+mod value_diff;
 mod zset_editor;
 
 pub use about::open_about_window;
+pub use bulk_preview::ZedisBulkPreview;
 pub use bytes_editor::ZedisBytesEditor;
 pub use clients_manager::ZedisClientsManager;
+pub use command_log::ZedisCommandLog;
 pub use content::ZedisContent;
 pub use editor::ZedisEditor;
 pub use hash_editor::ZedisHashEditor;
+pub use hot_keys::ZedisHotKeys;
 pub use key_tree::ZedisKeyTree;
 pub use kv_table::ZedisKvTable;
 pub use list_editor::ZedisListEditor;
 pub use memory_analysis::ZedisMemoryAnalysis;
 pub use metrics::ZedisMetrics;
+pub use mini_metrics::ZedisMiniMetrics;
 pub use monitor::ZedisMonitor;
 pub use proto_editor::ZedisProtoEditor;
 pub use pubsub_editor::ZedisPubsubEditor;
+pub use random_keys::ZedisRandomKeys;
+pub use running_tasks::ZedisRunningTasks;
 pub use slowlog_editor::ZedisSlowlogEditor;
 pub use servers::ZedisServers;
 pub use set_editor::ZedisSetEditor;
@@ -58,4 +72,6 @@ pub use sidebar::ZedisSidebar;
 pub use status_bar::ZedisStatusBar;
 pub use stream_editor::ZedisStreamEditor;
 pub use title_bar::ZedisTitleBar;
+pub use ttl_histogram::ZedisTtlHistogram;
+pub use value_diff::ZedisValueDiff;
 pub use zset_editor::ZedisZsetEditor;
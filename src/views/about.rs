@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use crate::assets::Assets;
+use crate::states::{ZedisGlobalStore, i18n_about};
 use chrono::{Datelike, Local};
 use gpui::{
     App, Bounds, Image, ImageFormat, TitlebarOptions, Window, WindowBounds, WindowKind, WindowOptions, prelude::*, px,
     size,
 };
+use rust_i18n::t;
 use std::process::Command;
 use std::sync::Arc;
 use zedis_ui::{AboutConfig, AboutLine, AboutLink, ZedisAboutPage};
@@ -91,7 +93,8 @@ fn collect_system_info(window: &Window) -> String {
     lines.join("\n")
 }
 
-fn build_config() -> AboutConfig {
+fn build_config(cx: &App) -> AboutConfig {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     let year = Local::now().year().to_string();
     let years = if year == "2026" {
         "2026".to_string()
@@ -106,19 +109,30 @@ fn build_config() -> AboutConfig {
         name: "Zedis".into(),
         logo,
         lines: vec![
-            AboutLine::sm("A modern Redis client built with GPUI"),
-            AboutLine::sm(format!("Version {VERSION}")),
-            AboutLine::xs("Built with Rust & GPUI"),
-            AboutLine::xs("Licensed under Apache License 2.0"),
+            AboutLine::sm(i18n_about(cx, "description")),
+            AboutLine::sm(t!("about.version", version = VERSION, locale = locale).to_string()),
+            AboutLine::xs(i18n_about(cx, "built_with")),
+            AboutLine::xs(i18n_about(cx, "license")),
             AboutLine::xs(format!("Git SHA: {GIT_SHA}")),
-            AboutLine::xs(format!("© {years} Tree xie. All rights reserved.")),
+            AboutLine::xs(t!("about.copyright", years = years, locale = locale).to_string()),
         ],
         links: vec![
             AboutLink::new("github", "GitHub", "https://github.com/vicanso/zedis"),
-            AboutLink::new("docs", "Documentation", "https://github.com/vicanso/zedis#readme"),
-            AboutLink::new("issues", "Report Issue", "https://github.com/vicanso/zedis/issues"),
+            AboutLink::new(
+                "docs",
+                i18n_about(cx, "documentation"),
+                "https://github.com/vicanso/zedis#readme",
+            ),
+            AboutLink::new(
+                "issues",
+                i18n_about(cx, "report_issue"),
+                "https://github.com/vicanso/zedis/issues",
+            ),
         ],
+        // The report stays in English so it can be pasted into issues as is
         system_info_collector: Some(Box::new(|window, _cx| collect_system_info(window))),
+        system_info_label: i18n_about(cx, "system_info"),
+        copy_label: i18n_about(cx, "copy"),
     }
 }
 
@@ -132,7 +146,7 @@ pub fn open_about_window(cx: &mut App) {
         is_movable: false,
         is_resizable: false,
         titlebar: Some(TitlebarOptions {
-            title: Some("About Zedis".into()),
+            title: Some(i18n_about(cx, "title")),
             ..Default::default()
         }),
         focus: true,
@@ -140,5 +154,6 @@ pub fn open_about_window(cx: &mut App) {
         ..Default::default()
     };
 
-    let _ = cx.open_window(options, |_, cx| cx.new(|_cx| ZedisAboutPage::new(build_config())));
+    let config = build_config(cx);
+    let _ = cx.open_window(options, |_, cx| cx.new(|_cx| ZedisAboutPage::new(config)));
 }
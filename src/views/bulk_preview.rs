@@ -0,0 +1,112 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::get_font_family;
+use crate::states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common};
+use gpui::{Context, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{ActiveTheme, label::Label, scroll::ScrollableElement, v_flex};
+use rust_i18n::t;
+
+const ROW_HEIGHT: f32 = 22.;
+const LIST_MAX_HEIGHT: f32 = 320.;
+
+/// Keys a pattern based bulk operation will affect, shown before it is confirmed.
+///
+/// The scan of the preview is started by the caller with `preview_matching`.
+pub struct ZedisBulkPreview {
+    server_state: Entity<ZedisServerState>,
+    prompt: SharedString,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisBulkPreview {
+    pub fn new(server_state: Entity<ZedisServerState>, prompt: SharedString, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![cx.subscribe(&server_state, |_this, _server_state, event, cx| {
+            if let ServerEvent::BulkPreviewLoaded = event {
+                cx.notify();
+            }
+        })];
+        Self {
+            server_state,
+            prompt,
+            _subscriptions: subscriptions,
+        }
+    }
+}
+
+impl Render for ZedisBulkPreview {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let warning = cx.theme().warning;
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let state = self.server_state.read(cx);
+        let loading = state.bulk_preview_loading();
+        let preview = state.bulk_preview().cloned();
+        let font_family = get_font_family();
+
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(Label::new(self.prompt.clone()))
+            .when(loading, |this| {
+                this.child(Label::new(i18n_common(cx, "loading")).text_sm().text_color(muted))
+            })
+            .when_some(preview.filter(|_| !loading), |this, preview| {
+                let summary = if preview.complete {
+                    t!(
+                        "key_tree.bulk_preview_count",
+                        count = preview.count,
+                        pattern = preview.pattern,
+                        locale = locale
+                    )
+                } else {
+                    t!(
+                        "key_tree.bulk_preview_count_partial",
+                        count = preview.count,
+                        pattern = preview.pattern,
+                        locale = locale
+                    )
+                };
+                let more = preview.count.saturating_sub(preview.keys.len());
+                this.child(
+                    Label::new(summary)
+                        .text_sm()
+                        .when(preview.count > 0, |this| this.text_color(warning)),
+                )
+                .when(!preview.keys.is_empty(), |this| {
+                    this.child(
+                        v_flex()
+                            .id("bulk-preview-keys")
+                            .max_h(px(LIST_MAX_HEIGHT))
+                            .overflow_y_scrollbar()
+                            .children(preview.keys.into_iter().map(|key| {
+                                div()
+                                    .h(px(ROW_HEIGHT))
+                                    .truncate()
+                                    .text_sm()
+                                    .font_family(font_family.clone())
+                                    .child(key)
+                            })),
+                    )
+                })
+                .when(more > 0, |this| {
+                    this.child(
+                        Label::new(t!("key_tree.bulk_preview_more", count = more, locale = locale))
+                            .text_xs()
+                            .text_color(muted),
+                    )
+                })
+            })
+    }
+}
@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::helpers::get_font_family;
-use crate::states::{DataFormat, RedisBytesValue, ServerEvent, ViewMode, ZedisGlobalStore, ZedisServerState};
-use gpui::{App, Entity, Image, ObjectFit, SharedString, Subscription, Window, img, px};
+use crate::assets::CustomIconName;
+use crate::helpers::{EditorAction, get_font_family};
+use crate::states::{
+    DataFormat, RedisBytesValue, ServerEvent, ViewMode, ZedisGlobalStore, ZedisServerState, describe_hyperloglog,
+    i18n_common, i18n_editor,
+};
+use gpui::{App, Entity, HighlightStyle, Image, ObjectFit, SharedString, StyledText, Subscription, Window, img, px};
 use gpui::{div, hsla, prelude::*};
+use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::highlighter::Language;
-use gpui_component::input::{Input, InputEvent, InputState, TabSize};
+use gpui_component::input::{Input, InputEvent, InputState, Position, TabSize};
 use gpui_component::label::Label;
 use gpui_component::list::{List, ListDelegate, ListItem, ListState};
-use gpui_component::{ActiveTheme, IndexPath, h_flex};
+use gpui_component::{ActiveTheme, Disableable, IconName, IndexPath, Sizable, h_flex, v_flex};
+use humansize::{DECIMAL, format_size};
 use pretty_hex::HexConfig;
 use pretty_hex::config_hex;
+use rust_i18n::t;
+use std::ops::Range;
 use std::sync::Arc;
 use tracing::info;
 
@@ -34,13 +42,15 @@ const HEX_WIDTH_MEDIUM: usize = 24; // Bytes per line for medium viewports
 const HEX_WIDTH_WIDE: usize = 32; // Bytes per line for wide viewports
 const VIEWPORT_WIDE: f32 = 1400.0; // Pixel width to switch hex display width
 const VIEWPORT_MEDIUM: f32 = 1000.0; // Pixel width to switch hex display width
+const FIND_MAX_MATCHES: usize = 10_000; // Matches beyond this are not counted
+const FIND_PREVIEW_CONTEXT: usize = 40; // Bytes of the line shown around the current match
 
 /// String value editor component for Redis String data type
 ///
 /// Features:
 /// - Code editor with syntax highlighting (JSON by default)
 /// - Line numbers and indent guides
-/// - Search functionality
+/// - Find bar moving the cursor between highlighted matches (Cmd/Ctrl+F)
 /// - Soft wrap support
 /// - Automatic hex display for binary data
 /// - Tracks modification state
@@ -69,9 +79,32 @@ pub struct ZedisBytesEditor {
     /// Whether the soft wrap has been changed
     soft_wrap_changed: bool,
 
+    /// Whether to show line numbers
+    line_numbers: bool,
+
+    /// Whether the line numbers setting has been changed
+    line_numbers_changed: bool,
+
     /// The data to display in the editor
     data: ByteEditorData,
 
+    /// Loaded and full length of a large value whose head only was loaded
+    partial: Option<(usize, usize)>,
+
+    /// Whether the value is a counter, edited with INCRBY / INCRBYFLOAT
+    counter: bool,
+    /// Input state of the custom increment of a counter
+    counter_input_state: Entity<InputState>,
+
+    /// Whether the find bar is shown
+    find_visible: bool,
+    /// Input state of the find query
+    find_input_state: Entity<InputState>,
+    /// Whether the find query is matched case-sensitively
+    find_case_sensitive: bool,
+    /// Matches of the find query in the editor
+    find: FindResult,
+
     /// Event subscriptions for reactive updates
     _subscriptions: Vec<Subscription>,
 }
@@ -152,6 +185,13 @@ fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditor
                 return ByteEditorData::Image(Arc::new(data));
             }
 
+            if value.format == DataFormat::HyperLogLog {
+                let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                if let Some(text) = describe_hyperloglog(&value.bytes, locale) {
+                    return ByteEditorData::Text(text);
+                }
+            }
+
             if let Some(text) = &value.text {
                 return ByteEditorData::Text(text.clone());
             }
@@ -160,6 +200,69 @@ fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditor
         }
     }
 }
+/// A match of the find query.
+struct FindMatch {
+    /// Start position in the editor
+    position: Position,
+    /// The text of the line around the match, shown in the find bar
+    preview: SharedString,
+    /// Byte range of the match within `preview`
+    range: Range<usize>,
+}
+
+#[derive(Default)]
+struct FindResult {
+    matches: Vec<FindMatch>,
+    /// Index of the current match
+    current: usize,
+}
+
+/// Returns the byte ranges of the non-overlapping occurrences of `needle` in `haystack`.
+fn find_ranges(haystack: &str, needle: &str, case_sensitive: bool) -> Vec<Range<usize>> {
+    if case_sensitive {
+        return haystack
+            .match_indices(needle)
+            .map(|(start, matched)| start..start + matched.len())
+            .collect();
+    }
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = needle.chars().map(lower).collect();
+    let mut ranges = Vec::new();
+    let mut next_start = 0;
+    for (start, _) in haystack.char_indices() {
+        if start < next_start {
+            continue;
+        }
+        let mut chars = haystack[start..].char_indices();
+        let mut end = start;
+        let matched = needle.iter().all(|expected| match chars.next() {
+            Some((offset, c)) if lower(c) == *expected => {
+                end = start + offset + c.len_utf8();
+                true
+            }
+            _ => false,
+        });
+        if matched {
+            ranges.push(start..end);
+            next_start = end;
+        }
+    }
+    ranges
+}
+
+/// Cuts the text around `range` of `line`, returning it with the range of the match within it.
+fn find_preview(line: &str, range: Range<usize>) -> (String, Range<usize>) {
+    let mut start = range.start.saturating_sub(FIND_PREVIEW_CONTEXT);
+    while !line.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (range.end + FIND_PREVIEW_CONTEXT).min(line.len());
+    while !line.is_char_boundary(end) {
+        end += 1;
+    }
+    (line[start..end].to_string(), range.start - start..range.end - start)
+}
+
 #[derive(Clone)]
 struct HexViewerListDelegate {
     items: Vec<(SharedString, SharedString, SharedString)>,
@@ -230,7 +333,7 @@ impl ZedisBytesEditor {
     /// Initializes a code editor with:
     /// - JSON syntax highlighting by default
     /// - Line numbers and indent guides
-    /// - Search functionality
+    /// - Find bar moving the cursor between highlighted matches (Cmd/Ctrl+F)
     /// - Soft wrap for long lines
     /// - Automatic value updates when server state changes
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
@@ -244,31 +347,40 @@ impl ZedisBytesEditor {
                     this.should_update_editor = true;
                 }
                 ServerEvent::ValueUpdated => {
+                    // An incremented counter changes on the server, not in the editor
+                    let reload = !this.value_modified;
                     this.update_editor_data(cx);
+                    this.should_update_editor = reload;
                 }
                 ServerEvent::SoftWrapToggled(soft_wrap) => {
                     this.soft_wrap_changed = true;
                     this.soft_wrap = *soft_wrap;
                 }
+                ServerEvent::LineNumbersToggled(line_numbers) => {
+                    this.line_numbers_changed = true;
+                    this.line_numbers = *line_numbers;
+                    cx.notify();
+                }
                 _ => {}
             }),
         );
 
         let soft_wrap = server_state.read(cx).soft_wrap();
+        let line_numbers = server_state.read(cx).line_numbers();
 
         // Configure code editor with JSON syntax highlighting
         let default_language = Language::from_str(DEFAULT_LANGUAGE);
         let editor = cx.new(|cx| {
             InputState::new(window, cx)
                 .code_editor(default_language.name())
-                .line_number(true)
+                .line_number(line_numbers)
                 .indent_guides(true)
                 .tab_size(TabSize {
                     tab_size: DEFAULT_TAB_SIZE,
                     hard_tabs: false,
                 })
-                .searchable(true)
                 .soft_wrap(soft_wrap)
+                .searchable(true)
         });
 
         // Subscribe to editor changes to track modification state
@@ -280,10 +392,35 @@ impl ZedisBytesEditor {
                 let original = this.data.to_string().unwrap_or_default();
 
                 this.value_modified = original != value.as_str();
+                // Keep the match count in sync with the edits, without moving the cursor
+                this.update_find(cx);
                 cx.notify();
             }
         }));
 
+        let find_input_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_editor(cx, "find_placeholder"))
+        });
+        subscriptions.push(
+            cx.subscribe_in(&find_input_state, window, |this, _, event, window, cx| match event {
+                InputEvent::Change => {
+                    this.update_find(cx);
+                    this.reveal_find_match(window, cx);
+                }
+                InputEvent::PressEnter { .. } => this.select_find_match(1, window, cx),
+                _ => {}
+            }),
+        );
+
+        let counter_input_state = cx.new(|cx| InputState::new(window, cx).placeholder("1"));
+        subscriptions.push(cx.subscribe(&counter_input_state, |this, _, event, cx| {
+            if let InputEvent::PressEnter { .. } = event {
+                this.incr_counter_by_input(cx);
+            }
+        }));
+
         let readonly = server_state.read(cx).readonly();
         info!("Creating new string editor view");
 
@@ -291,7 +428,16 @@ impl ZedisBytesEditor {
             value_modified: false,
             soft_wrap,
             soft_wrap_changed: false,
+            line_numbers,
+            line_numbers_changed: false,
             data: ByteEditorData::Text(SharedString::default()),
+            partial: None,
+            counter: false,
+            counter_input_state,
+            find_visible: false,
+            find_input_state,
+            find_case_sensitive: false,
+            find: FindResult::default(),
             hex_viewer_state: None,
             editor,
             should_update_editor: true,
@@ -321,15 +467,200 @@ impl ZedisBytesEditor {
 
         let redis_bytes_value = value.and_then(|v| v.bytes_value());
         if let Some(redis_bytes_value) = &redis_bytes_value {
-            self.readonly = readonly || !redis_bytes_value.is_utf8_text();
+            // a partial value can not be saved, it would cut the stored one
+            self.readonly = readonly || !redis_bytes_value.is_utf8_text() || redis_bytes_value.is_partial();
+            self.partial = redis_bytes_value
+                .full_len
+                .map(|full_len| (redis_bytes_value.bytes.len(), full_len));
+            self.counter = !readonly && redis_bytes_value.counter_value().is_some();
             self.data = format_byte_editor_data(redis_bytes_value, cx);
         } else {
+            self.partial = None;
+            self.counter = false;
             self.data = ByteEditorData::Text(SharedString::default());
         }
 
         if !matches!(self.data, ByteEditorData::Hex(_)) {
             self.hex_viewer_state = None;
         }
+        // Matches of the previous value are meaningless, close the find bar for the new value
+        self.find_visible = false;
+        self.find = FindResult::default();
+    }
+
+    /// Whether the value is shown as text, which the find bar searches
+    pub fn is_text(&self) -> bool {
+        matches!(self.data, ByteEditorData::Text(_))
+    }
+
+    /// Shows or hides the find bar of a text value.
+    pub fn toggle_find(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.find_visible {
+            self.find_visible = false;
+            self.find = FindResult::default();
+            cx.notify();
+            return;
+        }
+        if !self.is_text() {
+            return;
+        }
+        self.find_visible = true;
+        self.find_input_state.focus_handle(cx).focus(window, cx);
+        self.update_find(cx);
+        self.reveal_find_match(window, cx);
+    }
+
+    /// Searches the current editor text for the find query.
+    ///
+    /// Keeps the current match index when possible, so edits don't jump to the first match.
+    fn update_find(&mut self, cx: &mut Context<Self>) {
+        if !self.find_visible {
+            return;
+        }
+        let query = self.find_input_state.read(cx).value();
+        if query.is_empty() {
+            self.find = FindResult::default();
+            cx.notify();
+            return;
+        }
+        let text = self.editor.read(cx).value();
+        let mut matches = Vec::new();
+        'lines: for (line_ix, line) in text.split('\n').enumerate() {
+            for range in find_ranges(line, &query, self.find_case_sensitive) {
+                if matches.len() >= FIND_MAX_MATCHES {
+                    break 'lines;
+                }
+                // Editor positions count the column in UTF-16 code units
+                let column = line[..range.start].encode_utf16().count();
+                let (preview, range) = find_preview(line, range);
+                matches.push(FindMatch {
+                    position: Position::new(line_ix as u32, column as u32),
+                    preview: preview.into(),
+                    range,
+                });
+            }
+        }
+        let current = self.find.current.min(matches.len().saturating_sub(1));
+        self.find = FindResult { matches, current };
+        cx.notify();
+    }
+
+    /// Moves to the next (`1`) or previous (`-1`) match, wrapping around.
+    fn select_find_match(&mut self, delta: isize, window: &mut Window, cx: &mut Context<Self>) {
+        let count = self.find.matches.len();
+        if count == 0 {
+            return;
+        }
+        self.find.current = (self.find.current as isize + delta).rem_euclid(count as isize) as usize;
+        self.reveal_find_match(window, cx);
+        cx.notify();
+    }
+
+    /// Moves the editor cursor to the current match, which scrolls it into view.
+    ///
+    /// The match itself is highlighted in the find bar.
+    fn reveal_find_match(&self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(position) = self.find.matches.get(self.find.current).map(|item| item.position) else {
+            return;
+        };
+        self.editor.update(cx, |state, cx| {
+            state.set_cursor_position(position, window, cx);
+        });
+    }
+
+    fn render_find_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let query_empty = self.find_input_state.read(cx).value().is_empty();
+        let count = self.find.matches.len();
+        let count_label = if query_empty {
+            String::new()
+        } else if count == 0 {
+            i18n_editor(cx, "find_no_results").to_string()
+        } else {
+            let more = if count >= FIND_MAX_MATCHES { "+" } else { "" };
+            format!("{}/{count}{more}", self.find.current + 1)
+        };
+        let match_style = HighlightStyle {
+            background_color: Some(cx.theme().warning.opacity(0.8)),
+            ..Default::default()
+        };
+        let current = self.find.matches.get(self.find.current);
+        h_flex()
+            .w_full()
+            .gap_1()
+            .px_2()
+            .py_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(Input::new(&self.find_input_state).small().w(px(240.)).cleanable(true))
+            .child(
+                Button::new("bytes-editor-find-case")
+                    .small()
+                    .map(|this| {
+                        if self.find_case_sensitive {
+                            this.primary()
+                        } else {
+                            this.ghost()
+                        }
+                    })
+                    .icon(CustomIconName::ALargeSmall)
+                    .tooltip(i18n_editor(cx, "find_case_sensitive"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.find_case_sensitive = !this.find_case_sensitive;
+                        this.update_find(cx);
+                        this.reveal_find_match(window, cx);
+                    })),
+            )
+            .child(
+                Label::new(count_label)
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .min_w(px(60.)),
+            )
+            .child(
+                Button::new("bytes-editor-find-prev")
+                    .small()
+                    .ghost()
+                    .icon(CustomIconName::ChevronUp)
+                    .tooltip(i18n_common(cx, "previous_tooltip"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.select_find_match(-1, window, cx);
+                    })),
+            )
+            .child(
+                Button::new("bytes-editor-find-next")
+                    .small()
+                    .ghost()
+                    .icon(IconName::ChevronDown)
+                    .tooltip(i18n_common(cx, "next_tooltip"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.select_find_match(1, window, cx);
+                    })),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .px_2()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_sm()
+                    .font_family(get_font_family())
+                    .when_some(current, |this, item| {
+                        this.child(
+                            StyledText::new(item.preview.clone()).with_highlights([(item.range.clone(), match_style)]),
+                        )
+                    }),
+            )
+            .child(
+                Button::new("bytes-editor-find-close")
+                    .small()
+                    .ghost()
+                    .icon(CustomIconName::X)
+                    .tooltip(i18n_common(cx, "close_tooltip"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.toggle_find(window, cx);
+                    })),
+            )
     }
 
     /// Check if the current editor value differs from the original Redis value
@@ -346,6 +677,106 @@ impl ZedisBytesEditor {
     pub fn value(&self, cx: &mut Context<Self>) -> SharedString {
         self.editor.read(cx).value()
     }
+
+    /// Increments the counter by the amount of the increment input.
+    fn incr_counter_by_input(&mut self, cx: &mut Context<Self>) {
+        let by = self.counter_input_state.read(cx).value();
+        if by.trim().is_empty() {
+            return;
+        }
+        self.incr_counter(by, cx);
+    }
+    fn incr_counter(&mut self, by: SharedString, cx: &mut Context<Self>) {
+        self.server_state.update(cx, |state, cx| {
+            state.incr_string_value(by, cx);
+        });
+    }
+    fn render_counter_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let busy = self.server_state.read(cx).value().is_some_and(|value| value.is_busy());
+        h_flex()
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .items_center()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                Label::new(i18n_editor(cx, "counter"))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(
+                Button::new("counter-decr")
+                    .xsmall()
+                    .outline()
+                    .icon(IconName::Minus)
+                    .tooltip(i18n_editor(cx, "counter_decr_tooltip"))
+                    .disabled(busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.incr_counter("-1".into(), cx);
+                    })),
+            )
+            .child(
+                Button::new("counter-incr")
+                    .xsmall()
+                    .outline()
+                    .icon(IconName::Plus)
+                    .tooltip(i18n_editor(cx, "counter_incr_tooltip"))
+                    .disabled(busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.incr_counter("1".into(), cx);
+                    })),
+            )
+            .child(Input::new(&self.counter_input_state).xsmall().w(px(120.)))
+            .child(
+                Button::new("counter-incr-by")
+                    .xsmall()
+                    .ghost()
+                    .label(i18n_editor(cx, "counter_incr_by"))
+                    .tooltip(i18n_editor(cx, "counter_incr_by_tooltip"))
+                    .loading(busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.incr_counter_by_input(cx);
+                    })),
+            )
+    }
+    /// Notice above a large value whose head only was loaded, with a button to load it whole
+    fn render_partial_bar(&self, loaded: usize, total: usize, cx: &mut Context<Self>) -> impl IntoElement {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let loaded = format_size(loaded as u64, DECIMAL);
+        let total = format_size(total as u64, DECIMAL);
+        let loading = self
+            .server_state
+            .read(cx)
+            .value()
+            .is_some_and(|value| value.is_loading());
+        h_flex()
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .items_center()
+            .justify_between()
+            .bg(cx.theme().warning.opacity(0.1))
+            .child(
+                Label::new(t!("editor.partial_value", loaded = loaded, total = total, locale = locale).to_string())
+                    .text_xs()
+                    .text_color(cx.theme().warning),
+            )
+            .child(
+                Button::new("load-full-value")
+                    .xsmall()
+                    .ghost()
+                    .label(i18n_editor(cx, "load_full_value"))
+                    .loading(loading)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.load_full_string_value(cx);
+                        });
+                    })),
+            )
+    }
 }
 
 impl Render for ZedisBytesEditor {
@@ -362,7 +793,13 @@ impl Render for ZedisBytesEditor {
             });
             self.soft_wrap_changed = false;
         }
-        match &self.data {
+        if self.line_numbers_changed {
+            self.editor.update(cx, |this, cx| {
+                this.set_line_number(self.line_numbers, window, cx);
+            });
+            self.line_numbers_changed = false;
+        }
+        let content = match &self.data {
             ByteEditorData::Image(value) => div()
                 .size_full()
                 .flex()
@@ -386,18 +823,72 @@ impl Render for ZedisBytesEditor {
                         this.set_value(value, window, cx);
                     });
                 }
-                Input::new(&self.editor)
-                    .flex_1()
-                    .bordered(false)
-                    .disabled(self.readonly)
-                    .appearance(false)
-                    .p_0()
-                    .w_full()
-                    .h_full()
-                    .font_family(get_font_family())
-                    .focus_bordered(false)
+                v_flex()
+                    .size_full()
+                    .when(self.counter, |this| this.child(self.render_counter_bar(cx)))
+                    .when(self.find_visible, |this| this.child(self.render_find_bar(cx)))
+                    .child(
+                        Input::new(&self.editor)
+                            .flex_1()
+                            .bordered(false)
+                            .disabled(self.readonly)
+                            .appearance(false)
+                            .p_0()
+                            .w_full()
+                            .h_full()
+                            .font_family(get_font_family())
+                            .focus_bordered(false),
+                    )
+                    .on_action(cx.listener(|this, event: &EditorAction, window, cx| match event {
+                        EditorAction::Search => this.toggle_find(window, cx),
+                        _ => cx.propagate(),
+                    }))
                     .into_any_element()
             }
+        };
+        match self.partial {
+            Some((loaded, total)) => v_flex()
+                .size_full()
+                .child(self.render_partial_bar(loaded, total, cx))
+                .child(div().flex_1().min_h_0().child(content))
+                .into_any_element(),
+            None => content,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ranges_overlapping() {
+        // Overlapping occurrences are found once, left to right
+        assert_eq!(find_ranges("aaaa", "aa", true), vec![0..2, 2..4]);
+        assert_eq!(find_ranges("aaa", "aa", true), vec![0..2]);
+        assert_eq!(find_ranges("AaAa", "aa", false), vec![0..2, 2..4]);
+        assert_eq!(find_ranges("abab", "bab", false), vec![1..4]);
+        assert!(find_ranges("AaAa", "aa", true).is_empty());
+    }
+
+    #[test]
+    fn test_find_ranges_multibyte() {
+        assert_eq!(find_ranges("日本日本", "日本", true), vec![0..6, 6..12]);
+        assert_eq!(find_ranges("héllo wörld", "ö", true), vec![8..10]);
+        assert_eq!(find_ranges("ÄÖ äö", "äö", false), vec![0..4, 5..9]);
+        assert_eq!(find_ranges("🔑key🔑KEY", "key", false), vec![4..7, 11..14]);
+    }
+
+    #[test]
+    fn test_find_preview() {
+        assert_eq!(find_preview("a key here", 2..5), ("a key here".to_string(), 2..5));
+        let line = format!("{}key{}", "x".repeat(100), "y".repeat(100));
+        let (preview, range) = find_preview(&line, 100..103);
+        assert_eq!(preview.len(), 2 * FIND_PREVIEW_CONTEXT + 3);
+        assert_eq!(&preview[range], "key");
+        // The cut never splits a multibyte char
+        let line = format!("{}key{}", "é".repeat(50), "ö".repeat(50));
+        let (preview, range) = find_preview(&line, 100..103);
+        assert_eq!(&preview[range], "key");
+    }
+}
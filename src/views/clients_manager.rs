@@ -18,14 +18,12 @@
 /// Supports sorting by IP, connected time, and idle time, and allows
 /// killing individual client connections via `CLIENT KILL ID`.
 use crate::assets::CustomIconName;
-use crate::connection::{RedisServer, get_connection_manager, open_single_connection};
 use crate::constants::SIDEBAR_WIDTH;
-use crate::error::Error;
 use crate::helpers::format_duration;
 use crate::states::{
-    ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_clients_manager, i18n_common,
+    ClientInfo, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_clients_manager, i18n_common,
 };
-use gpui::{ClipboardItem, Edges, Entity, SharedString, Subscription, Task, Window, div, prelude::*, px};
+use gpui::{ClipboardItem, Edges, Entity, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::button::ButtonVariants;
 use gpui_component::notification::Notification;
 use gpui_component::{
@@ -37,88 +35,10 @@ use gpui_component::{
     table::{Column, ColumnSort, DataTable, TableDelegate, TableState},
     v_flex,
 };
-use redis::cmd;
 use rust_i18n::t;
-use std::sync::Arc;
 use std::time::Duration;
-use tracing::error;
 use zedis_ui::ZedisDialog;
 
-type Result<T, E = Error> = std::result::Result<T, E>;
-
-/// Callback type for killing a client: (id, addr, node).
-type KillCallback = Arc<dyn Fn(SharedString, SharedString, RedisServer) + Send + Sync + 'static>;
-
-/// A single parsed client from `CLIENT LIST` output.
-#[derive(Clone, Debug)]
-struct ClientRow {
-    id: SharedString,
-    addr: SharedString,
-    name: SharedString,
-    /// Connection age in seconds.
-    age: u64,
-    age_display: SharedString,
-    /// Idle time in seconds.
-    idle: u64,
-    idle_display: SharedString,
-    db: SharedString,
-    flags: SharedString,
-    command: SharedString,
-    /// The node this client is connected to (for targeted CLIENT KILL).
-    node: RedisServer,
-}
-
-/// Parses the raw `CLIENT LIST` output (one line per client) into rows.
-fn parse_client_list(raw: &str, node: &RedisServer) -> Vec<ClientRow> {
-    raw.lines()
-        .filter(|line| !line.trim().is_empty())
-        .filter_map(|line| {
-            let mut id = String::new();
-            let mut addr = String::new();
-            let mut name = String::new();
-            let mut age: u64 = 0;
-            let mut idle: u64 = 0;
-            let mut db = String::new();
-            let mut flags = String::new();
-            let mut command = String::new();
-
-            for part in line.split_whitespace() {
-                if let Some((key, value)) = part.split_once('=') {
-                    match key {
-                        "id" => id = value.to_string(),
-                        "addr" => addr = value.to_string(),
-                        "name" => name = value.to_string(),
-                        "age" => age = value.parse().unwrap_or(0),
-                        "idle" => idle = value.parse().unwrap_or(0),
-                        "db" => db = value.to_string(),
-                        "flags" => flags = value.to_string(),
-                        "cmd" => command = value.to_string(),
-                        _ => {}
-                    }
-                }
-            }
-
-            if id.is_empty() {
-                return None;
-            }
-
-            Some(ClientRow {
-                id: id.into(),
-                addr: addr.into(),
-                name: name.into(),
-                age,
-                age_display: format_duration(Duration::from_secs(age)).into(),
-                idle,
-                idle_display: format_duration(Duration::from_secs(idle)).into(),
-                db: db.into(),
-                flags: flags.into(),
-                command: command.into(),
-                node: node.clone(),
-            })
-        })
-        .collect()
-}
-
 const COLUMN_ID: &str = "id";
 const COLUMN_ADDR: &str = "addr";
 const COLUMN_NAME: &str = "name";
@@ -131,18 +51,18 @@ const COLUMN_ACTION: &str = "action";
 
 struct ClientsTableDelegate {
     /// All rows (unfiltered).
-    all_rows: Vec<ClientRow>,
+    all_rows: Vec<ClientInfo>,
     /// Visible rows after filtering.
-    rows: Vec<ClientRow>,
+    rows: Vec<ClientInfo>,
     columns: Vec<Column>,
     column_keys: Vec<&'static str>,
-    /// Callback for killing a client by (ID, addr, node).
-    kill_callback: Option<KillCallback>,
+    /// Server state killing the clients
+    server_state: Entity<ZedisServerState>,
     readonly: bool,
 }
 
 impl ClientsTableDelegate {
-    fn new(rows: Vec<ClientRow>, readonly: bool, window: &mut Window, _cx: &mut gpui::App) -> Self {
+    fn new(server_state: Entity<ZedisServerState>, readonly: bool, window: &mut Window, _cx: &mut gpui::App) -> Self {
         let window_width = window.viewport_size().width;
         let content_width = window_width - SIDEBAR_WIDTH;
         let id_width = 100.;
@@ -224,11 +144,11 @@ impl ClientsTableDelegate {
             .collect();
 
         Self {
-            all_rows: rows.clone(),
-            rows,
+            all_rows: vec![],
+            rows: vec![],
             columns,
             column_keys,
-            kill_callback: None,
+            server_state,
             readonly,
         }
     }
@@ -264,7 +184,7 @@ impl ClientsTableDelegate {
                 }
                 row.addr.to_lowercase().contains(&kw)
                     || row.name.to_lowercase().contains(&kw)
-                    || row.id.to_lowercase().contains(&kw)
+                    || row.id.to_string().contains(&kw)
                     || row.db.to_lowercase().contains(&kw)
                     || row.flags.to_lowercase().contains(&kw)
                     || row.command.to_lowercase().contains(&kw)
@@ -281,7 +201,7 @@ impl Clone for ClientsTableDelegate {
             rows: self.rows.clone(),
             columns: self.columns.clone(),
             column_keys: self.column_keys.clone(),
-            kill_callback: self.kill_callback.clone(),
+            server_state: self.server_state.clone(),
             readonly: self.readonly,
         }
     }
@@ -310,12 +230,8 @@ impl TableDelegate for ClientsTableDelegate {
         let col = &self.columns[col_ix];
         match col.key.as_ref() {
             COLUMN_ID => match sort {
-                ColumnSort::Ascending => self
-                    .rows
-                    .sort_by(|a, b| a.id.parse::<u64>().unwrap_or(0).cmp(&b.id.parse::<u64>().unwrap_or(0))),
-                _ => self
-                    .rows
-                    .sort_by(|a, b| b.id.parse::<u64>().unwrap_or(0).cmp(&a.id.parse::<u64>().unwrap_or(0))),
+                ColumnSort::Ascending => self.rows.sort_by(|a, b| a.id.cmp(&b.id)),
+                _ => self.rows.sort_by(|a, b| b.id.cmp(&a.id)),
             },
             COLUMN_ADDR => match sort {
                 ColumnSort::Ascending => self.rows.sort_by(|a, b| a.addr.cmp(&b.addr)),
@@ -379,19 +295,17 @@ impl TableDelegate for ClientsTableDelegate {
             let Some(row) = self.rows.get(row_ix) else {
                 return div().into_any_element();
             };
-            if row.flags.contains('S') || row.flags.contains('M') {
+            if row.is_replication() {
                 return div().into_any_element();
             }
-            let client_id = row.id.clone();
-            let client_addr = row.addr.clone();
-            let client_node = row.node.clone();
-            let kill_callback = self.kill_callback.clone();
+            let client = row.clone();
+            let server_state = self.server_state.clone();
             let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
             let title = i18n_clients_manager(cx, "kill_confirm_title");
             let prompt = t!(
                 "clients_manager.kill_confirm_prompt",
-                addr = client_addr.as_ref(),
-                id = client_id.as_ref(),
+                addr = client.addr.as_ref(),
+                id = client.id,
                 locale = locale
             )
             .to_string();
@@ -406,16 +320,14 @@ impl TableDelegate for ClientsTableDelegate {
                         .xsmall()
                         .tooltip(i18n_clients_manager(cx, "kill_tooltip"))
                         .on_click(move |_, window, cx: &mut gpui::App| {
-                            let kill_callback = kill_callback.clone();
-                            let client_id = client_id.clone();
-                            let client_addr = client_addr.clone();
-                            let client_node = client_node.clone();
+                            let client = client.clone();
+                            let server_state = server_state.clone();
                             ZedisDialog::new_alert(title.clone(), prompt.clone())
                                 .button_props(dialog_button_props(cx))
                                 .on_ok(move |_, window, cx| {
-                                    if let Some(ref cb) = kill_callback {
-                                        cb(client_id.clone(), client_addr.clone(), client_node.clone());
-                                    }
+                                    server_state.update(cx, |state, cx| {
+                                        state.kill_client(client.clone(), cx);
+                                    });
                                     window.close_dialog(cx);
                                     true
                                 })
@@ -449,11 +361,11 @@ impl TableDelegate for ClientsTableDelegate {
 
         let value: SharedString = if let Some(row) = self.rows.get(row_ix) {
             match col_key {
-                COLUMN_ID => row.id.clone(),
+                COLUMN_ID => row.id.to_string().into(),
                 COLUMN_ADDR => row.addr.clone(),
                 COLUMN_NAME => row.name.clone(),
-                COLUMN_AGE => row.age_display.clone(),
-                COLUMN_IDLE => row.idle_display.clone(),
+                COLUMN_AGE => format_duration(Duration::from_secs(row.age)).into(),
+                COLUMN_IDLE => format_duration(Duration::from_secs(row.idle)).into(),
                 COLUMN_DB => row.db.clone(),
                 COLUMN_CMD => row.command.clone(),
                 _ => "--".into(),
@@ -464,6 +376,7 @@ impl TableDelegate for ClientsTableDelegate {
 
         let group_name: SharedString = format!("clients-td-{}-{}", row_ix, col_ix).into();
         let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
         h_flex()
             .size_full()
             .when_some(column.paddings, |this, paddings| this.paddings(paddings))
@@ -487,6 +400,7 @@ impl TableDelegate for ClientsTableDelegate {
                         Button::new(("copy-cell", row_ix * 100 + col_ix))
                             .ghost()
                             .icon(IconName::Copy)
+                            .tooltip(copy_tooltip.clone())
                             .on_click(move |_, window, cx: &mut gpui::App| {
                                 cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                                 window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -516,8 +430,6 @@ pub struct ZedisClientsManager {
     idle_state: Entity<InputState>,
     age_state: Entity<InputState>,
     row_count: usize,
-    _fetch_task: Option<Task<()>>,
-    _kill_task: Option<Task<()>>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -525,17 +437,22 @@ impl ZedisClientsManager {
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut gpui::Context<Self>) -> Self {
         let mut subscriptions = Vec::new();
         let readonly = server_state.read(cx).readonly();
-        let delegate = ClientsTableDelegate::new(vec![], readonly, window, cx);
+        let delegate = ClientsTableDelegate::new(server_state.clone(), readonly, window, cx);
         let table_state = cx.new(|cx| TableState::new(delegate, window, cx));
 
-        subscriptions.push(cx.subscribe(&server_state, {
-            let table_state = table_state.clone();
-            move |this, _state, event, cx| {
-                if matches!(event, ServerEvent::ServerSelected(_) | ServerEvent::ServerInfoUpdated) {
-                    this.fetch_clients(table_state.clone(), cx);
+        subscriptions.push(
+            cx.subscribe(&server_state, |this, server_state, event, cx| match event {
+                ServerEvent::ServerSelected(_) | ServerEvent::ServerInfoUpdated => {
+                    server_state.update(cx, |state, cx| {
+                        state.fetch_clients(cx);
+                    });
                 }
-            }
-        }));
+                ServerEvent::ClientsLoaded => {
+                    this.update_rows(cx);
+                }
+                _ => {}
+            }),
+        );
 
         let keyword_state = cx.new(|cx| {
             InputState::new(window, cx)
@@ -553,19 +470,19 @@ impl ZedisClientsManager {
             }));
         }
 
+        server_state.update(cx, |state, cx| {
+            state.fetch_clients(cx);
+        });
         let mut this = Self {
             server_state,
-            table_state: table_state.clone(),
+            table_state,
             keyword_state,
             idle_state,
             age_state,
             row_count: 0,
-            _fetch_task: None,
-            _kill_task: None,
             _subscriptions: subscriptions,
         };
-
-        this.fetch_clients(table_state, cx);
+        this.update_rows(cx);
         this
     }
 
@@ -585,104 +502,20 @@ impl ZedisClientsManager {
         cx.notify();
     }
 
-    fn fetch_clients(&mut self, table_state: Entity<TableState<ClientsTableDelegate>>, cx: &mut gpui::Context<Self>) {
-        let server_id = self.server_state.read(cx).server_id().to_string();
-        if server_id.is_empty() {
-            return;
-        }
-        let db = self.server_state.read(cx).db();
-        let readonly = self.server_state.read(cx).readonly();
-
-        self._fetch_task = Some(cx.spawn(async move |handle, cx| {
-            let task = cx.background_spawn(async move {
-                let client = get_connection_manager().get_client(&server_id, db).await?;
-                let (addrs, results): (Vec<RedisServer>, Vec<String>) = client
-                    .query_async_masters(vec![cmd("CLIENT").arg("LIST").clone()])
-                    .await?;
-                let mut all_rows = Vec::new();
-                for (node, raw) in addrs.iter().zip(results.iter()) {
-                    all_rows.extend(parse_client_list(raw, node));
-                }
-                all_rows.sort_by(|a, b| b.age.cmp(&a.age));
-                Ok::<Vec<ClientRow>, Error>(all_rows)
-            });
-
-            let result: Result<Vec<ClientRow>> = task.await;
-            let _ = handle.update(cx, move |this, cx| {
-                match result {
-                    Ok(rows) => {
-                        let (keyword, min_idle, min_age) = this.filter_params(cx);
-                        table_state.update(cx, |state, _| {
-                            state.delegate_mut().all_rows = rows;
-                            state.delegate_mut().readonly = readonly;
-                            state.delegate_mut().apply_filter(&keyword, min_idle, min_age);
-                        });
-                        this.row_count = table_state.read(cx).delegate().rows.len();
-                        this.setup_kill_callback(cx);
-                    }
-                    Err(e) => {
-                        error!(error = %e, "Failed to fetch client list");
-                    }
-                }
-                cx.notify();
-            });
-        }));
-    }
-
-    fn setup_kill_callback(&mut self, cx: &mut gpui::Context<Self>) {
-        let server_state = self.server_state.clone();
-        let table_state = self.table_state.clone();
-
-        let (tx, rx) = smol::channel::unbounded::<(SharedString, SharedString, RedisServer)>();
-
+    /// Shows the clients loaded by the server state, keeping the current filter.
+    fn update_rows(&mut self, cx: &mut gpui::Context<Self>) {
+        let state = self.server_state.read(cx);
+        let rows = state.clients().to_vec();
+        let readonly = state.readonly();
+        let (keyword, min_idle, min_age) = self.filter_params(cx);
         self.table_state.update(cx, |state, _| {
-            state.delegate_mut().kill_callback = Some(Arc::new(move |id, addr, node| {
-                let _ = tx.send_blocking((id, addr, node));
-            }));
+            let delegate = state.delegate_mut();
+            delegate.all_rows = rows;
+            delegate.readonly = readonly;
+            delegate.apply_filter(&keyword, min_idle, min_age);
         });
-
-        self._kill_task = Some(cx.spawn(async move |handle, cx| {
-            while let Ok((client_id, _client_addr, node)) = rx.recv().await {
-                let db = server_state.update(cx, |state, _| state.db());
-
-                let id_clone = client_id.clone();
-                let task = cx.background_spawn(async move {
-                    let mut conn = open_single_connection(&node, db, true).await?;
-                    let _: String = cmd("CLIENT")
-                        .arg("KILL")
-                        .arg("ID")
-                        .arg(id_clone.as_ref())
-                        .query_async(&mut conn)
-                        .await?;
-                    Ok::<(), Error>(())
-                });
-
-                let result = task.await;
-                let locale_table_state = table_state.clone();
-                let _ = handle.update(cx, move |this, cx| {
-                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
-                    match result {
-                        Ok(()) => {
-                            let msg = t!(
-                                "clients_manager.kill_success",
-                                addr = client_id.as_ref(),
-                                locale = locale
-                            );
-                            this.server_state.update(cx, |state, cx| {
-                                state.emit_success_notification(msg.into(), "CLIENT KILL".into(), cx);
-                            });
-                            this.fetch_clients(locale_table_state, cx);
-                        }
-                        Err(e) => {
-                            let msg = t!("clients_manager.kill_failed", error = e.to_string(), locale = locale);
-                            this.server_state.update(cx, |state, cx| {
-                                state.emit_error_notification(msg.into(), cx);
-                            });
-                        }
-                    }
-                });
-            }
-        }));
+        self.row_count = self.table_state.read(cx).delegate().rows.len();
+        cx.notify();
     }
 }
 
@@ -736,6 +569,7 @@ impl gpui::Render for ZedisClientsManager {
                                     .outline()
                                     .small()
                                     .icon(IconName::Search)
+                                    .tooltip(i18n_common(cx, "search_tooltip"))
                                     .on_click(cx.listener(|this, _, _window, cx| {
                                         this.handle_filter(cx);
                                     })),
@@ -746,9 +580,11 @@ impl gpui::Render for ZedisClientsManager {
                                     .small()
                                     .icon(Icon::new(CustomIconName::RotateCw))
                                     .tooltip(i18n_clients_manager(cx, "refresh_tooltip"))
+                                    .loading(self.server_state.read(cx).clients_loading())
                                     .on_click(cx.listener(|this, _, _window, cx| {
-                                        let table_state = this.table_state.clone();
-                                        this.fetch_clients(table_state, cx);
+                                        this.server_state.update(cx, |state, cx| {
+                                            state.fetch_clients(cx);
+                                        });
                                     })),
                             ),
                     ),
@@ -0,0 +1,142 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::connection::{CommandLogEntry, clear_command_logs, list_command_logs};
+use crate::helpers::{format_timestamp_ms, get_font_family};
+use crate::states::{ZedisGlobalStore, i18n_settings};
+use gpui::{Context, SharedString, Task, Window, div, prelude::*, px, uniform_list};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable, h_flex, label::Label, v_flex};
+use std::rc::Rc;
+use std::time::Duration;
+
+const REFRESH_INTERVAL_MS: u64 = 1000;
+const LINE_HEIGHT: f32 = 22.;
+const LIST_HEIGHT: f32 = 420.;
+
+/// Live view of the developer command log, newest first.
+///
+/// The log is refreshed every second while the view is open.
+pub struct ZedisCommandLog {
+    entries: Rc<Vec<CommandLogEntry>>,
+    _refresh_task: Task<()>,
+}
+
+impl ZedisCommandLog {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let refresh_task = cx.spawn(async move |handle, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(REFRESH_INTERVAL_MS))
+                    .await;
+                let result = handle.update(cx, |this, cx| {
+                    this.refresh(cx);
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        });
+        let mut this = Self {
+            entries: Rc::default(),
+            _refresh_task: refresh_task,
+        };
+        this.refresh(cx);
+        this
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        let mut entries = list_command_logs();
+        entries.reverse();
+        self.entries = Rc::new(entries);
+        cx.notify();
+    }
+}
+
+impl Render for ZedisCommandLog {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let danger = cx.theme().danger;
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let time_format = store.clock_format().time_format();
+        let utc = store.utc_time();
+        let font_family = get_font_family();
+        let entries = self.entries.clone();
+        let count = entries.len();
+        let height = (count as f32 * LINE_HEIGHT).clamp(LINE_HEIGHT, LIST_HEIGHT);
+
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        Label::new(i18n_settings(cx, "command_log_hint"))
+                            .text_xs()
+                            .text_color(muted),
+                    )
+                    .child(
+                        Button::new("clear-command-log")
+                            .small()
+                            .ghost()
+                            .label(i18n_settings(cx, "command_log_clear"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                clear_command_logs();
+                                this.refresh(cx);
+                            })),
+                    ),
+            )
+            .when(count == 0, |this| {
+                this.child(
+                    Label::new(i18n_settings(cx, "command_log_empty"))
+                        .text_sm()
+                        .text_color(muted),
+                )
+            })
+            .when(count > 0, |this| {
+                this.child(
+                    uniform_list("command-log-entries", count, move |range, _window, _cx| {
+                        range
+                            .map(|index| {
+                                let entry = &entries[index];
+                                let time: SharedString = format_timestamp_ms(entry.timestamp_ms, time_format, utc)
+                                    .unwrap_or_default()
+                                    .into();
+                                h_flex()
+                                    .h(px(LINE_HEIGHT))
+                                    .gap_3()
+                                    .text_sm()
+                                    .font_family(font_family.clone())
+                                    .child(div().text_color(muted).child(time))
+                                    .child(
+                                        div()
+                                            .w(px(60.))
+                                            .text_color(muted)
+                                            .child(format!("{}ms", entry.elapsed_ms)),
+                                    )
+                                    .child(div().flex_1().truncate().child(entry.command.clone()))
+                                    .when_some(entry.error.clone(), |this, error| {
+                                        this.child(div().text_color(danger).truncate().child(error))
+                                    })
+                            })
+                            .collect()
+                    })
+                    .w_full()
+                    .h(px(height)),
+                )
+            })
+    }
+}
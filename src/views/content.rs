@@ -13,29 +13,37 @@
 // limitations under the License.
 
 use crate::{
-    connection::{get_command_description, get_connection_manager, list_commands},
+    connection::{get_command_description, get_connection_manager, get_server, list_commands},
     db::get_cmd_history_manager,
     error::Error,
     helpers::{
-        EditorAction, get_font_family, get_key_tree_widths, redis_value_to_string, starts_with_ignore_ascii_case,
+        EditorAction, get_font_family, get_key_tree_widths, redis_value_to_resp3_string, redis_value_to_string,
+        starts_with_ignore_ascii_case,
+    },
+    states::{
+        GlobalEvent, Route, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common,
+        i18n_servers, save_app_state,
     },
-    states::{GlobalEvent, Route, ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, save_app_state},
     views::{
-        ZedisClientsManager, ZedisEditor, ZedisKeyTree, ZedisMemoryAnalysis, ZedisMetrics, ZedisMonitor,
-        ZedisProtoEditor, ZedisServers, ZedisSettingEditor, ZedisSlowlogEditor, ZedisStatusBar,
+        ZedisClientsManager, ZedisEditor, ZedisHotKeys, ZedisKeyTree, ZedisMemoryAnalysis, ZedisMetrics,
+        ZedisMiniMetrics, ZedisMonitor, ZedisProtoEditor, ZedisServers, ZedisSettingEditor, ZedisSlowlogEditor,
+        ZedisStatusBar, ZedisTtlHistogram, ZedisValueDiff,
     },
 };
 use gpui::{Entity, FocusHandle, Pixels, ScrollHandle, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme,
+    ActiveTheme, Icon, IconName, Sizable, WindowExt,
+    button::{Button, ButtonVariants},
+    h_flex,
     input::{Input, InputEvent, InputState, MoveDown, MoveUp, Position},
     label::Label,
     resizable::{ResizableState, h_resizable, resizable_panel},
     v_flex,
 };
 use redis::cmd;
+use rust_i18n::t;
 use tracing::{debug, error, info};
-use zedis_ui::ZedisSkeletonLoading;
+use zedis_ui::{ZedisDialog, ZedisFormField, ZedisFormFieldType, ZedisFormOptions, ZedisSkeletonLoading};
 type Result<T, E = Error> = std::result::Result<T, E>;
 
 // Constants for UI dimensions
@@ -43,6 +51,12 @@ const LOADING_SKELETON_WIDTH: f32 = 600.0;
 const SERVERS_MARGIN: f32 = 8.0;
 const CMD_LABEL: &str = "$";
 const CMD_CLEAR: &str = "clear";
+/// Toggles printing replies with their RESP3 type annotations
+const CMD_RESP3: &str = "resp3";
+/// Console commands for building a MULTI/EXEC transaction
+const CMD_MULTI: &str = "multi";
+const CMD_EXEC: &str = "exec";
+const CMD_DISCARD: &str = "discard";
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const ZEDIS_LOGO: &str = r#" __________ ____ ___ ____  
@@ -72,9 +86,13 @@ pub struct ZedisContent {
     metrics: Option<Entity<ZedisMetrics>>,
     slowlog_editor: Option<Entity<ZedisSlowlogEditor>>,
     memory_analysis: Option<Entity<ZedisMemoryAnalysis>>,
+    hot_keys: Option<Entity<ZedisHotKeys>>,
+    ttl_histogram: Option<Entity<ZedisTtlHistogram>>,
+    value_diff: Option<Entity<ZedisValueDiff>>,
     clients_manager: Option<Entity<ZedisClientsManager>>,
     monitor: Option<Entity<ZedisMonitor>>,
     key_tree: Option<Entity<ZedisKeyTree>>,
+    mini_metrics: Option<Entity<ZedisMiniMetrics>>,
     status_bar: Entity<ZedisStatusBar>,
     cmd_output_scroll_handle: ScrollHandle,
     cmd_input_state: Entity<InputState>,
@@ -83,6 +101,10 @@ pub struct ZedisContent {
     cmd_suggestions: Vec<String>,
     cmd_suggestion_index: Option<usize>,
     cmd_history_index: Option<usize>,
+    /// Whether console replies are fetched over RESP3 and printed with their types
+    cmd_resp3: bool,
+    /// Commands queued after MULTI, sent atomically on EXEC (None when not in a transaction)
+    cmd_transaction: Option<Vec<Vec<String>>>,
     /// Persisted width of the key tree panel (resizable by user)
     key_tree_width: Pixels,
 
@@ -105,6 +127,7 @@ impl ZedisContent {
         if route != Route::Editor && route != Route::Metrics {
             self.key_tree.take();
             self.value_editor.take();
+            self.mini_metrics.take();
         }
         if route != Route::Metrics {
             self.metrics.take();
@@ -121,6 +144,15 @@ impl ZedisContent {
         if route != Route::MemoryAnalysis {
             self.memory_analysis.take();
         }
+        if route != Route::HotKeys {
+            self.hot_keys.take();
+        }
+        if route != Route::TtlHistogram {
+            self.ttl_histogram.take();
+        }
+        if route != Route::ValueDiff {
+            self.value_diff.take();
+        }
         if route != Route::Clients {
             self.clients_manager.take();
         }
@@ -152,6 +184,18 @@ impl ZedisContent {
                         state.select(server_id.clone(), *db, cx);
                     });
                 }
+                GlobalEvent::ServerUpdated(server_id) => {
+                    this.server_state.update(cx, |state, cx| {
+                        if state.server_id() == server_id.as_str() {
+                            state.reconnect(cx);
+                        }
+                    });
+                }
+                GlobalEvent::LayoutReset => {
+                    this.key_tree_width = cx.global::<ZedisGlobalStore>().read(cx).key_tree_width();
+                    this.clear_views();
+                    cx.notify();
+                }
                 _ => {}
             }),
         );
@@ -166,9 +210,22 @@ impl ZedisContent {
                 ServerEvent::ServerInfoUpdated => {
                     this.update_redis_commands(cx);
                 }
+                ServerEvent::MetricsAlertsUpdated | ServerEvent::ClusterHealthUpdated => {
+                    cx.notify();
+                }
                 ServerEvent::ServerSelected(_) => {
+                    this.cmd_transaction = None;
                     this.reset_cmd_state(cx);
                 }
+                ServerEvent::TransactionExecuted(replies) => {
+                    let lines = replies
+                        .iter()
+                        .enumerate()
+                        .map(|(index, reply)| format!("{}) {reply}", index + 1).into());
+                    this.cmd_outputs.extend(lines);
+                    this.cmd_outputs.push(SharedString::default());
+                    this.scroll_cmd_output_to_bottom(cx);
+                }
                 _ => {}
             }),
         );
@@ -200,7 +257,7 @@ impl ZedisContent {
                     });
                     this.cmd_suggestions.clear();
                     this.cmd_suggestion_index = None;
-                    this.execute_command(cmd, cx);
+                    this.execute_command(cmd, window, cx);
                 }
                 InputEvent::Change => {
                     if this.cmd_history_index.is_some() {
@@ -224,6 +281,15 @@ impl ZedisContent {
                 _ => {}
             }),
         );
+        subscriptions.push(
+            cx.subscribe_in(&server_state, window, |this, _server_state, event, window, cx| {
+                if let ServerEvent::AuthRequired(server_id) = event {
+                    this.prompt_reauth(server_id, window, cx);
+                } else if let ServerEvent::KeyTypeChanged(key) = event {
+                    this.prompt_reload_value(key.clone(), window, cx);
+                }
+            }),
+        );
         info!("Creating new content view");
 
         Self {
@@ -236,9 +302,13 @@ impl ZedisContent {
             metrics: None,
             slowlog_editor: None,
             memory_analysis: None,
+            hot_keys: None,
+            ttl_histogram: None,
+            value_diff: None,
             clients_manager: None,
             monitor: None,
             key_tree: None,
+            mini_metrics: None,
             cmd_outputs: Vec::with_capacity(5),
             redis_commands: Vec::new(),
             key_tree_width,
@@ -249,11 +319,66 @@ impl ZedisContent {
             should_focus_cmd_input: false,
             cmd_output_scroll_handle: ScrollHandle::new(),
             cmd_history_index: None,
+            cmd_resp3: false,
+            cmd_transaction: None,
             focus_handle,
             proto_editor: None,
             _subscriptions: subscriptions,
         }
     }
+    /// Asks for the new password of a server that rejected the stored credentials.
+    fn prompt_reauth(&mut self, server_id: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let server_name = get_server(server_id)
+            .map(|server| server.name)
+            .unwrap_or_else(|_| server_id.to_string());
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let description = t!("servers.reauth_prompt", server = server_name, locale = locale).to_string();
+        let fields = vec![
+            ZedisFormField::new("password", i18n_common(cx, "password"))
+                .placeholder(i18n_common(cx, "password_placeholder"))
+                .focus()
+                .mask(),
+            ZedisFormField::new("save", i18n_servers(cx, "reauth_save"))
+                .default_value("false")
+                .placeholder(i18n_servers(cx, "reauth_save_check_label"))
+                .field_type(ZedisFormFieldType::Checkbox),
+        ];
+        let server_state = self.server_state.clone();
+
+        ZedisFormOptions::new(fields)
+            .title(i18n_servers(cx, "reauth_title"))
+            .description(description)
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let password = values.get("password").map(|v| v.to_string()).unwrap_or_default();
+                let save = values.get("save").is_some_and(|v| v == "true");
+                server_state.update(cx, |state, cx| {
+                    state.reauthenticate(password, save, cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Asks to reload a key whose type changed on the server since its editor was opened.
+    fn prompt_reload_value(&mut self, key: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let message = t!("common.key_type_changed_prompt", key = key, locale = locale).to_string();
+        let server_state = self.server_state.clone();
+        ZedisDialog::new_alert(i18n_common(cx, "key_type_changed_title"), message)
+            .button_props(dialog_button_props(cx))
+            .on_ok(move |_, window, cx| {
+                server_state.update(cx, |state, cx| {
+                    // the user may have moved on to another key meanwhile
+                    if state.key().as_ref() == Some(&key) {
+                        state.reload_value(key.clone(), cx);
+                    }
+                });
+                window.close_dialog(cx);
+                true
+            })
+            .open(window, cx);
+    }
     fn reset_cmd_state(&mut self, _cx: &mut Context<Self>) {
         self.cmd_outputs.clear();
         self.cmd_outputs.extend(
@@ -333,7 +458,113 @@ impl ZedisContent {
             cx.notify();
         }
     }
-    fn execute_command(&mut self, command: SharedString, cx: &mut Context<Self>) {
+    fn scroll_cmd_output_to_bottom(&mut self, cx: &mut Context<Self>) {
+        let scroll_handle = self.cmd_output_scroll_handle.clone();
+        cx.notify();
+        cx.defer(move |_cx| {
+            scroll_handle.scroll_to_bottom();
+        });
+    }
+    /// Appends a command echo line followed by its output to the console.
+    fn append_cmd_output(&mut self, command: &str, content: Option<SharedString>, cx: &mut Context<Self>) {
+        self.cmd_outputs.push(format!("{CMD_LABEL} {command}").into());
+        if let Some(content) = content {
+            self.cmd_outputs.extend([content, SharedString::default()]);
+        }
+        self.scroll_cmd_output_to_bottom(cx);
+    }
+    /// Handles console input while building a MULTI/EXEC transaction.
+    ///
+    /// Commands are queued locally and only sent on EXEC, as one atomic pipeline.
+    fn handle_transaction_command(&mut self, command: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        if command.eq_ignore_ascii_case(CMD_DISCARD) {
+            self.cmd_transaction = None;
+            self.append_cmd_output(&command, Some("OK".into()), cx);
+            return;
+        }
+        if command.eq_ignore_ascii_case(CMD_EXEC) {
+            let commands = self.cmd_transaction.as_deref().unwrap_or_default();
+            if commands.is_empty() {
+                self.cmd_transaction = None;
+                self.append_cmd_output(&command, Some("(empty transaction)".into()), cx);
+                return;
+            }
+            let dangerous = commands
+                .iter()
+                .find_map(|parts| cx.global::<ZedisGlobalStore>().read(cx).dangerous_command(parts));
+            // The queue is only taken once confirmed, cancelling keeps the transaction open
+            let exec = move |this: &mut Self, cx: &mut Context<Self>| {
+                let Some(commands) = this.cmd_transaction.take() else {
+                    return;
+                };
+                this.append_cmd_output(&command, None, cx);
+                this.server_state.update(cx, |state, cx| {
+                    state.exec_transaction(commands, cx);
+                });
+            };
+            match dangerous {
+                Some(dangerous) => self.confirm_dangerous_command(dangerous, exec, window, cx),
+                None => exec(self, cx),
+            }
+            return;
+        }
+        let mut queued = vec![];
+        for line in command.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let content = match shlex::split(line) {
+                // The server refuses a nested MULTI and would fail the whole transaction on EXEC
+                Some(parts) if parts.first().is_some_and(|name| name.eq_ignore_ascii_case(CMD_MULTI)) => {
+                    "(error) ERR MULTI calls can not be nested"
+                }
+                Some(parts) if !parts.is_empty() => {
+                    if let Some(queue) = self.cmd_transaction.as_mut() {
+                        queue.push(parts);
+                    }
+                    queued.push(line.to_string());
+                    "QUEUED"
+                }
+                _ => "(error) invalid command",
+            };
+            self.append_cmd_output(line, Some(content.into()), cx);
+        }
+        if queued.is_empty() {
+            return;
+        }
+        // Queued commands go to the history like the ones run directly
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        cx.background_spawn(async move {
+            for line in queued {
+                let _ = get_cmd_history_manager().add_record(server_id.as_str(), line.as_str());
+            }
+        })
+        .detach();
+    }
+    /// Asks for confirmation before running a command from the dangerous command list.
+    fn confirm_dangerous_command<F>(
+        &mut self,
+        dangerous: String,
+        on_confirm: F,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) where
+        F: Fn(&mut Self, &mut Context<Self>) + 'static,
+    {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let message = t!("common.dangerous_command_prompt", command = dangerous, locale = locale).to_string();
+        let view = cx.entity();
+        ZedisDialog::new_alert(i18n_common(cx, "dangerous_command_title"), message)
+            .button_props(dialog_button_props(cx))
+            .on_ok(move |_, window, cx| {
+                view.update(cx, |this, cx| on_confirm(this, cx));
+                window.close_dialog(cx);
+                true
+            })
+            .open(window, cx);
+    }
+    fn execute_command(&mut self, command: SharedString, window: &mut Window, cx: &mut Context<Self>) {
         if command.is_empty() {
             return;
         }
@@ -341,6 +572,48 @@ impl ZedisContent {
             self.reset_cmd_state(cx);
             return;
         }
+        if self.cmd_transaction.is_some() {
+            self.handle_transaction_command(command, window, cx);
+            return;
+        }
+        if command.eq_ignore_ascii_case(CMD_MULTI) {
+            self.cmd_transaction = Some(Vec::new());
+            self.append_cmd_output(&command, Some("OK".into()), cx);
+            return;
+        }
+        if command.eq_ignore_ascii_case(CMD_RESP3) {
+            self.cmd_resp3 = !self.cmd_resp3;
+            let status_key = if self.cmd_resp3 {
+                "resp3_mode_on"
+            } else {
+                "resp3_mode_off"
+            };
+            let status = i18n_common(cx, status_key);
+            self.append_cmd_output(&command, Some(status), cx);
+            return;
+        }
+        let dangerous = command.lines().find_map(|line| {
+            let parts = shlex::split(line.trim())?;
+            cx.global::<ZedisGlobalStore>().read(cx).dangerous_command(&parts)
+        });
+        if let Some(dangerous) = dangerous {
+            self.confirm_dangerous_command(
+                dangerous,
+                move |this, cx| this.run_commands(command.clone(), cx),
+                window,
+                cx,
+            );
+            return;
+        }
+        self.run_commands(command, cx);
+    }
+    /// Runs each line of the console input and appends the replies to the output.
+    fn run_commands(&mut self, command: SharedString, cx: &mut Context<Self>) {
+        let resp3 = self.cmd_resp3;
+        // Console commands may write any key
+        self.server_state.update(cx, |state, _cx| {
+            state.invalidate_value_cache();
+        });
         let server_state = self.server_state.read(cx);
         let server_id = server_state.server_id().to_string();
         let db = server_state.db();
@@ -358,10 +631,21 @@ impl ZedisContent {
                     }
                     let cmd_name = parts[0].clone();
                     let args = parts[1..].to_vec();
-                    let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
-                    let data: redis::Value = cmd(&cmd_name).arg(&args).query_async(&mut conn).await?;
+                    let data: redis::Value = if resp3 {
+                        // A dedicated connection keeps the shared one on RESP2
+                        let mut conn = get_connection_manager().get_resp3_connection(&server_id, db).await?;
+                        cmd(&cmd_name).arg(&args).query_async(&mut conn).await?
+                    } else {
+                        let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                        cmd(&cmd_name).arg(&args).query_async(&mut conn).await?
+                    };
                     let _ = get_cmd_history_manager().add_record(server_id.as_str(), command.as_str());
-                    Ok(redis_value_to_string(&data).into())
+                    let content = if resp3 {
+                        redis_value_to_resp3_string(&data)
+                    } else {
+                        redis_value_to_string(&data)
+                    };
+                    Ok(content.into())
                 });
                 let result: Result<SharedString> = task.await;
                 let content: SharedString = match result {
@@ -370,16 +654,7 @@ impl ZedisContent {
                 };
 
                 let _ = handle.update(cx, |this, cx| {
-                    this.cmd_outputs.extend(vec![
-                        format!("{CMD_LABEL} {command_clone}").into(),
-                        content,
-                        SharedString::default(),
-                    ]);
-                    let scroll_handle = this.cmd_output_scroll_handle.clone();
-                    cx.notify();
-                    cx.defer(move |_cx| {
-                        scroll_handle.scroll_to_bottom();
-                    });
+                    this.append_cmd_output(&command_clone, Some(content), cx);
                 });
             }
         })
@@ -451,6 +726,36 @@ impl ZedisContent {
             .clone();
         div().size_full().child(memory_analysis)
     }
+    fn render_hot_keys(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let hot_keys = self
+            .hot_keys
+            .get_or_insert_with(|| {
+                debug!("Creating new hot keys view");
+                cx.new(|cx| ZedisHotKeys::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().size_full().child(hot_keys)
+    }
+    fn render_ttl_histogram(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let ttl_histogram = self
+            .ttl_histogram
+            .get_or_insert_with(|| {
+                debug!("Creating new ttl histogram view");
+                cx.new(|cx| ZedisTtlHistogram::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().size_full().child(ttl_histogram)
+    }
+    fn render_value_diff(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let value_diff = self
+            .value_diff
+            .get_or_insert_with(|| {
+                debug!("Creating new value diff view");
+                cx.new(|cx| ZedisValueDiff::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().size_full().child(value_diff)
+    }
     fn render_clients(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let clients = self
             .clients_manager
@@ -519,6 +824,86 @@ impl ZedisContent {
             self.cmd_history_index = Some(index);
         }
     }
+    /// Renders a persistent warning banner while any metrics alert threshold is crossed.
+    fn render_metrics_alerts(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let alerts = self.server_state.read(cx).metrics_alerts();
+        if alerts.is_empty() {
+            return None;
+        }
+        let message = alerts
+            .iter()
+            .map(|alert| alert.message(cx).to_string())
+            .collect::<Vec<_>>()
+            .join(" · ");
+        let warning = cx.theme().warning;
+        Some(
+            h_flex()
+                .id("metrics-alerts-banner")
+                .w_full()
+                .gap_2()
+                .px_3()
+                .py_1()
+                .bg(warning.opacity(0.15))
+                .border_b_1()
+                .border_color(warning)
+                .text_color(warning)
+                .child(Icon::new(IconName::TriangleAlert))
+                .child(Label::new(message).text_sm()),
+        )
+    }
+    /// Renders one banner while the cluster can not serve every slot, key operations
+    /// failing with CLUSTERDOWN don't pop their own errors meanwhile.
+    fn render_cluster_down(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let health = self.server_state.read(cx).cluster_health()?.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let message = t!(
+            "servers.cluster_down",
+            state = health.state,
+            unassigned = health.unassigned_slots(),
+            fail = health.slots_fail,
+            nodes = health.known_nodes,
+            locale = locale
+        )
+        .to_string();
+        let danger = cx.theme().danger;
+        Some(
+            h_flex()
+                .id("cluster-down-banner")
+                .w_full()
+                .gap_2()
+                .px_3()
+                .py_1()
+                .items_center()
+                .bg(danger.opacity(0.15))
+                .border_b_1()
+                .border_color(danger)
+                .text_color(danger)
+                .child(Icon::new(IconName::TriangleAlert))
+                .child(Label::new(message).text_sm().flex_1())
+                .child(
+                    Button::new("cluster-down-nodes")
+                        .xsmall()
+                        .ghost()
+                        .label(i18n_servers(cx, "cluster_view_nodes"))
+                        .on_click(cx.listener(|_this, _, _window, cx| {
+                            cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                                state.go_to(Route::Metrics, cx);
+                            });
+                        })),
+                )
+                .child(
+                    Button::new("cluster-down-recheck")
+                        .xsmall()
+                        .ghost()
+                        .label(i18n_servers(cx, "cluster_recheck"))
+                        .on_click(cx.listener(|this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.check_cluster_health(cx);
+                            });
+                        })),
+                ),
+        )
+    }
     /// Render the main editor interface with resizable panels
     ///
     /// Layout:
@@ -543,6 +928,20 @@ impl ZedisContent {
             right_panel = right_panel.size(content_width);
         }
         let (key_tree_width, min_width, max_width) = get_key_tree_widths(self.key_tree_width);
+        // Vital signs strip above the editor, toggleable in settings
+        let mini_metrics = if cx.global::<ZedisGlobalStore>().read(cx).mini_metrics_enabled() {
+            let mini_metrics = self
+                .mini_metrics
+                .get_or_insert_with(|| {
+                    debug!("Creating new mini metrics view");
+                    cx.new(|cx| ZedisMiniMetrics::new(server_state.clone(), window, cx))
+                })
+                .clone();
+            Some(mini_metrics)
+        } else {
+            self.mini_metrics.take();
+            None
+        };
         let right_panel_content = if server_state.read(cx).is_terminal() {
             if std::mem::take(&mut self.should_focus_cmd_input) {
                 self.cmd_input_state.update(cx, |this, cx| this.focus(window, cx));
@@ -587,6 +986,11 @@ impl ZedisContent {
             let handle_move_down = cx.listener(move |this, _: &MoveDown, window, cx| {
                 handle_cmd_arrow(this, false, window, cx);
             });
+            // Show the number of queued commands while building a transaction
+            let cmd_prefix: SharedString = match &self.cmd_transaction {
+                Some(queue) => format!("MULTI({}) {CMD_LABEL}", queue.len()).into(),
+                None => CMD_LABEL.into(),
+            };
             let handle_other_keys = cx.listener(|this, _: &gpui::KeyDownEvent, _window, _cx| {
                 this.cmd_history_index = None;
             });
@@ -659,7 +1063,7 @@ impl ZedisContent {
                                 .child(
                                     Input::new(&self.cmd_input_state)
                                         .font_family(font_family)
-                                        .prefix(Label::new(CMD_LABEL).text_color(cx.theme().yellow))
+                                        .prefix(Label::new(cmd_prefix).text_color(cx.theme().yellow))
                                         .appearance(false),
                                 ),
                         ),
@@ -676,18 +1080,38 @@ impl ZedisContent {
             value_editor.into_any_element()
         };
 
-        h_resizable("editor-container")
-            .child(
-                // Left panel: Resizable key tree
-                resizable_panel()
-                    .size(key_tree_width)
-                    .size_range(min_width..max_width)
-                    .child(key_tree),
-            )
-            .child(right_panel.child(right_panel_content))
+        // Resizable key tree, leading in the reading direction
+        let key_tree_panel = resizable_panel()
+            .size(key_tree_width)
+            .size_range(min_width..max_width)
+            .child(key_tree);
+        let editor_panel = right_panel.child(
+            v_flex()
+                .size_full()
+                .children(mini_metrics)
+                .child(div().flex_1().min_h_0().w_full().child(right_panel_content)),
+        );
+        let rtl = cx.global::<ZedisGlobalStore>().read(cx).is_rtl();
+        // A separate id keeps the panel sizes of each direction apart
+        let container_id = if rtl {
+            "editor-container-rtl"
+        } else {
+            "editor-container"
+        };
+
+        h_resizable(container_id)
+            .map(|this| {
+                if rtl {
+                    this.child(editor_panel).child(key_tree_panel)
+                } else {
+                    this.child(key_tree_panel).child(editor_panel)
+                }
+            })
             .on_resize(cx.listener(move |this, event: &Entity<ResizableState>, _window, cx| {
-                // Get the new width from the resize event
-                let Some(width) = event.read(cx).sizes().first() else {
+                // Get the new key tree width from the resize event
+                let sizes = event.read(cx).sizes();
+                let width = if rtl { sizes.last() } else { sizes.first() };
+                let Some(width) = width else {
                     return;
                 };
 
@@ -740,11 +1164,16 @@ impl Render for ZedisContent {
                 let is_metrics = route == Route::Metrics;
                 let is_slowlog = route == Route::Slowlog;
                 let is_memory_analysis = route == Route::MemoryAnalysis;
+                let is_hot_keys = route == Route::HotKeys;
+                let is_ttl_histogram = route == Route::TtlHistogram;
+                let is_value_diff = route == Route::ValueDiff;
                 let is_clients = route == Route::Clients;
                 let is_monitor = route == Route::Monitor;
 
                 // Route 3: Main editor interface
                 base.when(is_busy, |this| this.child(self.render_loading(window, cx)))
+                    .when(!is_busy, |this| this.children(self.render_cluster_down(cx)))
+                    .when(!is_busy, |this| this.children(self.render_metrics_alerts(cx)))
                     .when(!is_busy, |this| {
                         this.child(
                             div().flex_1().w_full().relative().child(
@@ -758,10 +1187,22 @@ impl Render for ZedisContent {
                                     .when(is_memory_analysis, |this| {
                                         this.child(self.render_memory_analysis(window, cx))
                                     })
+                                    .when(is_hot_keys, |this| this.child(self.render_hot_keys(window, cx)))
+                                    .when(is_ttl_histogram, |this| {
+                                        this.child(self.render_ttl_histogram(window, cx))
+                                    })
+                                    .when(is_value_diff, |this| this.child(self.render_value_diff(window, cx)))
                                     .when(is_clients, |this| this.child(self.render_clients(window, cx)))
                                     .when(is_monitor, |this| this.child(self.render_monitor(window, cx)))
                                     .when(
-                                        !is_metrics && !is_slowlog && !is_memory_analysis && !is_clients && !is_monitor,
+                                        !is_metrics
+                                            && !is_slowlog
+                                            && !is_memory_analysis
+                                            && !is_hot_keys
+                                            && !is_ttl_histogram
+                                            && !is_value_diff
+                                            && !is_clients
+                                            && !is_monitor,
                                         |this| this.child(self.render_editor(window, cx)),
                                     ),
                             ),
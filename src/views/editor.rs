@@ -14,10 +14,12 @@
 
 use crate::{
     assets::CustomIconName,
-    constants::EDITOR_KEY_BAR_HEIGHT,
+    constants::{EDITOR_KEY_BAR_HEIGHT, EDITOR_TAB_INDEX},
     db::get_favorites_manager,
-    helpers::{EditorAction, format_duration, humanize_keystroke, validate_ttl},
-    states::{KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common, i18n_editor},
+    helpers::{EditorAction, format_duration, format_timestamp_ms, humanize_keystroke, validate_ttl},
+    states::{
+        KeyType, Route, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common, i18n_editor,
+    },
     views::{
         ZedisBytesEditor, ZedisHashEditor, ZedisListEditor, ZedisPubsubEditor, ZedisSetEditor, ZedisStreamEditor,
         ZedisZsetEditor,
@@ -25,19 +27,21 @@ use crate::{
 };
 use gpui::{ClipboardItem, Entity, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, WindowExt,
+    ActiveTheme, Disableable, Icon, IconName, Sizable, WindowExt,
     button::{Button, DropdownButton},
+    checkbox::Checkbox,
     h_flex,
     input::{Input, InputEvent, InputState},
     label::Label,
     notification::Notification,
+    spinner::Spinner,
     v_flex,
 };
 use humansize::{DECIMAL, format_size};
 use rust_i18n::t;
 use std::time::{Duration, Instant};
 use tracing::{debug, info};
-use zedis_ui::ZedisDialog;
+use zedis_ui::{ZedisDialog, ZedisSkeletonLoading};
 
 // Constants
 const RECENTLY_SELECTED_THRESHOLD_MS: u64 = 300;
@@ -68,6 +72,9 @@ pub struct ZedisEditor {
 
     readonly: bool,
 
+    /// Whether saving a string value keeps its TTL, reset to true for each key
+    keep_ttl: bool,
+
     auto_refresh_task: Option<Task<()>>,
     auto_refresh_interval_sec: u64,
 
@@ -116,6 +123,7 @@ impl ZedisEditor {
             cx.subscribe(&server_state, |this, server_state, event, cx| match event {
                 ServerEvent::KeySelected(_) => {
                     this.selected_key_at = Some(Instant::now());
+                    this.keep_ttl = true;
                     this.start_auto_refresh(None, cx);
                 }
                 ServerEvent::ValueLoaded => {
@@ -170,6 +178,7 @@ impl ZedisEditor {
             stream_editor: None,
             pubsub_editor: None,
             readonly,
+            keep_ttl: true,
             ttl_edit_mode: false,
             ttl_input_state,
             should_enter_ttl_edit_mode: None,
@@ -267,10 +276,11 @@ impl ZedisEditor {
         let Some(editor) = self.bytes_editor.as_ref() else {
             return;
         };
+        let keep_ttl = self.keep_ttl;
         editor.clone().update(cx, move |state, cx| {
             let value = state.value(cx);
             self.server_state.update(cx, move |state, cx| {
-                state.save_value(key, value, cx);
+                state.save_value(key, value, keep_ttl, cx);
             });
         });
     }
@@ -332,6 +342,46 @@ impl ZedisEditor {
 
         // Show loading only if busy and not recently selected (avoid flashing)
         let should_show_loading = is_busy && !self.is_selected_key_recently();
+        // Inline status of pagination and writes of the value
+        if should_show_loading && let Some(value) = server_state.value() {
+            let status_text = if value.is_loading() {
+                i18n_common(cx, "loading")
+            } else {
+                i18n_editor(cx, "updating")
+            };
+            btns.push(
+                h_flex()
+                    .gap_1()
+                    .items_center()
+                    .child(Spinner::new().small().color(cx.theme().muted_foreground))
+                    .child(
+                        Label::new(status_text)
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground),
+                    )
+                    .into_any_element(),
+            );
+        }
+        // Values served from the value cache may be stale, show when they were fetched and reload on click
+        if let Some(cached_at) = server_state.value_cached_at() {
+            let store = cx.global::<ZedisGlobalStore>().read(cx);
+            let locale = store.locale();
+            let utc = store.utc_time();
+            let time =
+                format_timestamp_ms(cached_at * 1000, store.clock_format().time_format(), utc).unwrap_or_default();
+            let date_time = format_timestamp_ms(cached_at * 1000, "%Y-%m-%d %H:%M:%S", utc).unwrap_or_default();
+            btns.push(
+                Button::new("zedis-editor-cached")
+                    .outline()
+                    .icon(Icon::new(CustomIconName::History).text_color(cx.theme().warning))
+                    .label(t!("editor.cached_badge", time = time, locale = locale))
+                    .tooltip(t!("editor.cached_tooltip", time = date_time, locale = locale))
+                    .on_click(cx.listener(|this, _event, _window, cx| {
+                        this.reload(cx);
+                    }))
+                    .into_any_element(),
+            );
+        }
         // Add size label if available
         if !size.is_empty() {
             let size_label = i18n_common(cx, "size");
@@ -342,8 +392,100 @@ impl ZedisEditor {
             );
         }
 
+        // Warn when a collection has left its memory-efficient encoding
+        if let Some(value) = server_state.value()
+            && value.is_encoding_converted()
+            && let Some(encoding) = value.encoding()
+        {
+            let configs = value
+                .encoding_configs()
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let tooltip: SharedString = if configs.is_empty() {
+                t!(
+                    "editor.encoding_converted_tooltip",
+                    encoding = encoding,
+                    locale = locale
+                )
+                .into()
+            } else {
+                t!(
+                    "editor.encoding_converted_config_tooltip",
+                    encoding = encoding,
+                    configs = configs,
+                    locale = locale
+                )
+                .into()
+            };
+            btns.push(
+                Button::new("zedis-editor-encoding-warning")
+                    .outline()
+                    .icon(Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning))
+                    .label(encoding)
+                    .tooltip(tooltip)
+                    .into_any_element(),
+            );
+        }
+
+        // Add find button for text values of the string editor
+        if let Some(bytes_editor) = &self.bytes_editor
+            && bytes_editor.read(cx).is_text()
+        {
+            let bytes_editor = bytes_editor.clone();
+            btns.push(
+                Button::new("zedis-editor-find")
+                    .outline()
+                    .tooltip(format!(
+                        "{} ({})",
+                        i18n_editor(cx, "find_tooltip"),
+                        humanize_keystroke("cmd-f")
+                    ))
+                    .icon(IconName::Search)
+                    .on_click(move |_event, window, cx| {
+                        bytes_editor.update(cx, |editor, cx| {
+                            editor.toggle_find(window, cx);
+                        });
+                    })
+                    .into_any_element(),
+            );
+        }
+        // Add compare button to diff the value against another key or server
+        btns.push(
+            Button::new("zedis-editor-compare")
+                .outline()
+                .tooltip(i18n_editor(cx, "compare_tooltip"))
+                .icon(CustomIconName::GitCompare)
+                .on_click(cx.listener(|_this, _event, _window, cx| {
+                    cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                        state.go_to(Route::ValueDiff, cx);
+                    });
+                }))
+                .into_any_element(),
+        );
         // Add save button for string editor if value is modified
         if let Some(bytes_editor) = &self.bytes_editor {
+            let has_ttl = self
+                .server_state
+                .read(cx)
+                .value()
+                .and_then(|value| value.expire_at)
+                .is_some_and(|expire_at| expire_at > 0);
+            if has_ttl {
+                btns.push(
+                    Checkbox::new("zedis-editor-keep-ttl")
+                        .label(i18n_editor(cx, "keep_ttl"))
+                        .checked(self.keep_ttl)
+                        .disabled(self.readonly)
+                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                            this.keep_ttl = *checked;
+                            cx.notify();
+                        }))
+                        .into_any_element(),
+                );
+            }
             let state = bytes_editor.read(cx);
             let value_modified = state.is_value_modified();
             let readonly = state.is_readonly();
@@ -383,6 +525,7 @@ impl ZedisEditor {
                     .suffix(
                         Button::new("zedis-editor-ttl-update-btn")
                             .icon(Icon::new(IconName::Check))
+                            .tooltip(i18n_editor(cx, "update_ttl_tooltip"))
                             .on_click(cx.listener(move |this, _event, window, cx| {
                                 this.handle_update_ttl(window, cx);
                             })),
@@ -582,9 +725,16 @@ impl ZedisEditor {
             return div().into_any_element();
         };
 
-        // Don't render anything if key type is unknown and still loading
+        // Key type is unknown until the first load completes
         if value.key_type == KeyType::Unknown && value.is_busy() {
-            return div().into_any_element();
+            // Don't render anything right after selection to avoid flashing
+            if self.is_selected_key_recently() {
+                return div().into_any_element();
+            }
+            return div()
+                .m_5()
+                .child(ZedisSkeletonLoading::new().text(i18n_common(cx, "loading")))
+                .into_any_element();
         }
 
         match value.key_type() {
@@ -665,8 +815,11 @@ impl Render for ZedisEditor {
         }
 
         v_flex()
+            .id("zedis-editor")
             .w_full()
             .h_full()
+            .tab_group()
+            .tab_index(EDITOR_TAB_INDEX)
             .when(!is_channel_mode, |this| this.child(self.render_select_key(cx)))
             .child(self.render_editor(window, cx))
             .on_action(cx.listener(move |this, event: &EditorAction, window, cx| match event {
@@ -20,16 +20,21 @@
 //! - Adding new fields with values via a dialog form
 //! - Updating values of existing fields (inline editing)
 //! - Removing field-value pairs
+//! - Setting and removing several fields at once (batch edit)
+//! - Inspecting the raw bytes of a field value as a hex dump
 //! - Filtering fields with pattern matching
 //! - Incremental loading of large HASHes with pagination
+//! - Loading the values of wide HASHes per shown row when only field names are scanned
+//! - Loading very large values one field at a time (HGET) once their row is shown
 
 use crate::{
     components::KvTableColumn,
     components::ZedisKvFetcher,
-    states::{KeyType, RedisValue, ZedisServerState},
+    states::{KeyType, RawBytesSource, RedisValue, ZedisServerState},
     views::{ZedisKvTable, kv_table::define_kv_editor},
 };
 use gpui::{App, Entity, SharedString, Window, prelude::*};
+use redis::cmd;
 use zedis_ui::ZedisFormFieldType;
 
 /// Data adapter for Redis HASH values to work with the KV table component.
@@ -64,12 +69,51 @@ impl ZedisKvFetcher for ZedisHashValues {
 
         // Column 2 is the value, others show the field name
         if col_ix == 2 {
+            // Not loaded yet for fields scanned with NOVALUES
+            if hash.pending.contains(field) {
+                return None;
+            }
             Some(value.clone())
         } else {
             Some(field.clone())
         }
     }
 
+    /// The length column shows the size of the field value.
+    fn value_column(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    /// Field values are read back with HGET.
+    fn raw_bytes_source(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<RawBytesSource> {
+        if col_ix != 2 {
+            return None;
+        }
+        let (field, _) = self.value.hash_value()?.values.get(row_ix)?;
+        let state = self.server_state.read(cx);
+        let key = state.key()?;
+        Some(state.raw_bytes_source(cmd("HGET").arg(key.as_str()).arg(field.as_str()).clone()))
+    }
+
+    /// Values of fields scanned with NOVALUES are requested once their row is shown.
+    fn request_cell(&self, row_ix: usize, col_ix: usize, cx: &mut App) -> bool {
+        if col_ix != 2 {
+            return false;
+        }
+        let Some(hash) = self.value.hash_value() else {
+            return false;
+        };
+        let Some((field, _)) = hash.values.get(row_ix) else {
+            return false;
+        };
+        if !hash.pending.contains(field) {
+            return false;
+        }
+        let field = field.clone();
+        self.server_state
+            .update(cx, |this, cx| this.request_hash_value(field, cx))
+    }
+
     /// Returns the total number of fields in the HASH (from Redis HLEN).
     fn count(&self) -> usize {
         self.value.hash_value().map_or(0, |v| v.size)
@@ -0,0 +1,514 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Redis Hot Keys finder.
+///
+/// Samples keys from the database and reads their LFU access counter via
+/// `OBJECT FREQ`, ranking the most frequently accessed keys. Only available
+/// when `maxmemory-policy` is an LFU policy.
+use super::memory_analysis::{TopN, format_thousands, make_paddings, render_copy_cell, table_height};
+use crate::assets::CustomIconName;
+use crate::connection::{KeyFrequency, get_connection_manager};
+use crate::constants::SIDEBAR_WIDTH;
+use crate::error::Error;
+use crate::states::{ZedisServerState, i18n_common, i18n_hot_keys};
+use gpui::{Entity, SharedString, Subscription, Task, Window, div, prelude::*, px};
+use gpui_component::button::ButtonVariants;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName, Sizable, StyledExt,
+    button::Button,
+    h_flex,
+    label::Label,
+    table::{Column, ColumnSort, DataTable, TableDelegate, TableState},
+    v_flex,
+};
+use std::time::{Duration, Instant};
+use tracing::error;
+use zedis_ui::ZedisDivider;
+
+/// Default and maximum number of hot keys kept in the table.
+const DEFAULT_HOT_KEYS_LIMIT: usize = 20;
+const MAX_HOT_KEYS_LIMIT: usize = 500;
+
+const DEFAULT_SCAN_COUNT: u64 = 100;
+
+const COL_RANK: &str = "rank";
+const COL_KEY: &str = "key";
+const COL_KEY_TYPE: &str = "key_type";
+const COL_FREQ: &str = "freq";
+
+const RANK_WIDTH: f32 = 80.;
+const TYPE_WIDTH: f32 = 140.;
+const FREQ_WIDTH: f32 = 160.;
+
+/// A row in the hot key table.
+#[derive(Clone, Debug)]
+struct HotKeyRow {
+    /// Full key name
+    key: SharedString,
+    /// Key type
+    key_type: SharedString,
+    /// LFU access frequency counter (0-255, logarithmic)
+    freq: u64,
+    /// Display frequency
+    display_freq: SharedString,
+}
+
+// ─── Hot key table delegate ──────────────────────────────────────────────────
+
+struct HotKeyTableDelegate {
+    rows: Vec<HotKeyRow>,
+    columns: Vec<Column>,
+    column_keys: Vec<&'static str>,
+}
+
+impl HotKeyTableDelegate {
+    fn new(window: &mut Window, _cx: &mut gpui::App) -> Self {
+        let content_width = (window.viewport_size().width - SIDEBAR_WIDTH).as_f32();
+        let padding_offset = 16.0;
+        let scrollbar_offset = 10.0;
+        let key_w = content_width - RANK_WIDTH - TYPE_WIDTH - FREQ_WIDTH - padding_offset - scrollbar_offset;
+
+        let column_keys = vec![COL_RANK, COL_KEY, COL_KEY_TYPE, COL_FREQ];
+        let widths = [RANK_WIDTH, key_w, TYPE_WIDTH, FREQ_WIDTH];
+        let columns = column_keys
+            .clone()
+            .into_iter()
+            .zip(widths)
+            .map(|(key, w)| {
+                let mut c = Column::new(key, SharedString::default()).width(w);
+                if key != COL_RANK {
+                    c = c.sortable();
+                }
+                c.paddings = make_paddings();
+                c
+            })
+            .collect();
+
+        Self {
+            rows: Vec::new(),
+            columns,
+            column_keys,
+        }
+    }
+}
+
+impl TableDelegate for HotKeyTableDelegate {
+    fn columns_count(&self, _cx: &gpui::App) -> usize {
+        self.columns.len()
+    }
+    fn rows_count(&self, _cx: &gpui::App) -> usize {
+        self.rows.len()
+    }
+    fn column(&self, ix: usize, _cx: &gpui::App) -> Column {
+        self.columns[ix].clone()
+    }
+
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _: &mut Window,
+        _: &mut gpui::Context<TableState<Self>>,
+    ) {
+        let key = self.columns[col_ix].key.as_ref();
+        self.rows.sort_by(|a, b| {
+            let ord = match key {
+                COL_KEY => a.key.cmp(&b.key),
+                COL_KEY_TYPE => a.key_type.cmp(&b.key_type),
+                COL_FREQ => a.freq.cmp(&b.freq),
+                _ => std::cmp::Ordering::Equal,
+            };
+            if matches!(sort, ColumnSort::Ascending) {
+                ord
+            } else {
+                ord.reverse()
+            }
+        });
+    }
+
+    fn render_th(
+        &mut self,
+        col_ix: usize,
+        _: &mut Window,
+        cx: &mut gpui::Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let col = &self.columns[col_ix];
+        div()
+            .size_full()
+            .when_some(col.paddings, |this, p| this.paddings(p))
+            .child(
+                Label::new(i18n_hot_keys(cx, self.column_keys[col_ix]))
+                    .text_align(col.align)
+                    .text_color(cx.theme().primary)
+                    .text_sm(),
+            )
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut Window,
+        cx: &mut gpui::Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let col = &self.columns[col_ix];
+        let value: SharedString = self
+            .rows
+            .get(row_ix)
+            .map(|r| match col_ix {
+                0 => (row_ix + 1).to_string().into(),
+                1 => r.key.clone(),
+                2 => r.key_type.clone(),
+                3 => r.display_freq.clone(),
+                _ => "--".into(),
+            })
+            .unwrap_or_else(|| "--".into());
+
+        render_copy_cell(row_ix, col_ix, value, col, "hot-keys", cx)
+    }
+
+    fn has_more(&self, _cx: &gpui::App) -> bool {
+        false
+    }
+    fn load_more_threshold(&self) -> usize {
+        0
+    }
+    fn load_more(&mut self, _: &mut Window, _: &mut gpui::Context<TableState<Self>>) {}
+}
+
+fn build_rows(top: &TopN<HotKeyRow>) -> Vec<HotKeyRow> {
+    top.items.clone()
+}
+
+// ─── Main component ──────────────────────────────────────────────────────────
+
+pub struct ZedisHotKeys {
+    server_state: Entity<ZedisServerState>,
+    table: Entity<TableState<HotKeyTableDelegate>>,
+    running: bool,
+    row_count: usize,
+    progress: SharedString,
+    /// Error from the last scan (e.g. OBJECT FREQ rejected by the server)
+    error: Option<SharedString>,
+    scan_task: Option<Task<()>>,
+    /// Database key count fetched on load.
+    dbsize: Option<u64>,
+    /// Current `maxmemory-policy` reported by INFO.
+    policy: SharedString,
+    /// User-editable number of hot keys to keep.
+    limit: usize,
+    limit_input_state: Entity<InputState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisHotKeys {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut gpui::Context<Self>) -> Self {
+        let table = cx.new(|cx| TableState::new(HotKeyTableDelegate::new(window, cx), window, cx));
+        let limit_input_state =
+            cx.new(|cx| InputState::new(window, cx).default_value(DEFAULT_HOT_KEYS_LIMIT.to_string()));
+
+        let state = server_state.read(cx);
+        let dbsize = state.dbsize();
+        let policy = state
+            .redis_info()
+            .map(|info| info.meta.maxmemory_policy.clone())
+            .unwrap_or_default();
+
+        let subscriptions = vec![
+            cx.subscribe_in(&limit_input_state, window, |this, state, event, _window, cx| {
+                if let InputEvent::Change = event {
+                    let text = state.read(cx).value();
+                    if let Ok(v) = text.parse::<usize>() {
+                        this.limit = v.clamp(1, MAX_HOT_KEYS_LIMIT);
+                        cx.notify();
+                    }
+                }
+            }),
+        ];
+
+        Self {
+            server_state,
+            table,
+            running: false,
+            row_count: 0,
+            progress: SharedString::default(),
+            error: None,
+            scan_task: None,
+            dbsize,
+            policy: policy.into(),
+            limit: DEFAULT_HOT_KEYS_LIMIT,
+            limit_input_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Whether OBJECT FREQ is usable, i.e. the eviction policy is LFU.
+    ///
+    /// An unknown policy (INFO not loaded yet) is allowed and left to the server to reject.
+    fn is_lfu_policy(&self) -> bool {
+        self.policy.is_empty() || self.policy.contains("lfu")
+    }
+
+    fn stop(&mut self, cx: &mut gpui::Context<Self>) {
+        self.scan_task.take();
+        self.running = false;
+        cx.notify();
+    }
+
+    /// Scans the keyspace, pipelining `OBJECT FREQ` per batch, and keeps the `limit`
+    /// most frequently accessed keys. Dropping the task via `stop` cancels the scan.
+    fn find_hot_keys(&mut self, limit: usize, cx: &mut gpui::Context<Self>) {
+        self.running = true;
+        self.progress = "0%".into();
+        self.row_count = 0;
+        self.error = None;
+        self.table.update(cx, |s, _| s.delegate_mut().rows.clear());
+
+        let server_state = self.server_state.read(cx);
+        let server_id = server_state.server_id().to_string();
+        let db = server_state.db();
+        let table = self.table.clone();
+        let dbsize = self.dbsize.unwrap_or(0);
+
+        self.scan_task = Some(cx.spawn(async move |handle, cx| {
+            let mut top: TopN<HotKeyRow> = TopN::new(limit);
+            let mut cursors: Option<Vec<u64>> = None;
+            let mut scanned: u64 = 0;
+            let min_sleep = Duration::from_micros(500);
+            let max_sleep = Duration::from_millis(20);
+
+            loop {
+                let scan_task = cx.background_spawn({
+                    let server_id = server_id.clone();
+                    let cursors = cursors.clone();
+                    async move {
+                        let start = Instant::now();
+                        let client = get_connection_manager().get_client(&server_id, db).await?;
+                        let result = client.sample_scan_object_freq(1.0, DEFAULT_SCAN_COUNT, cursors).await?;
+                        // Throttle to leave room for other clients
+                        smol::Timer::after(start.elapsed().mul_f64(0.5).clamp(min_sleep, max_sleep)).await;
+                        Ok::<(u64, Vec<u64>, Vec<KeyFrequency>), Error>(result)
+                    }
+                });
+
+                let (count, new_cursors, keys_frequency) = match scan_task.await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        error!(error = %e, "Failed to sample scan for hot keys");
+                        let message: SharedString = e.to_string().into();
+                        let _ = handle.update(cx, |this, cx| {
+                            this.error = Some(message);
+                        });
+                        break;
+                    }
+                };
+                scanned += count;
+
+                for item in keys_frequency {
+                    if top.should_insert(item.freq) {
+                        let row = HotKeyRow {
+                            display_freq: format_thousands(item.freq).into(),
+                            key: item.key,
+                            key_type: item.key_type.into(),
+                            freq: item.freq,
+                        };
+                        top.insert(row, |r| r.freq);
+                    }
+                }
+
+                let pct = if scanned > 0 && dbsize > 0 {
+                    ((scanned as f32 / dbsize as f32) * 100.0).min(99.0) as u32
+                } else {
+                    99
+                };
+                let rows = build_rows(&top);
+                let _ = handle.update(cx, |this, cx| {
+                    this.progress = format!("{pct}%").into();
+                    this.row_count = rows.len();
+                    table.update(cx, |s, _| s.delegate_mut().rows = rows);
+                    cx.notify();
+                });
+
+                if new_cursors.iter().all(|c| *c == 0) {
+                    break;
+                }
+                cursors = Some(new_cursors);
+            }
+
+            let rows = build_rows(&top);
+            let _ = handle.update(cx, |this, cx| {
+                this.running = false;
+                if this.error.is_none() {
+                    this.progress = "100%".into();
+                }
+                this.row_count = rows.len();
+                table.update(cx, |s, _| s.delegate_mut().rows = rows);
+                cx.notify();
+            });
+        }));
+
+        cx.notify();
+    }
+
+    fn render_toolbar_functions(&self, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let stat_item = |cx: &mut gpui::Context<Self>, key: &'static str, value: SharedString| {
+            h_flex()
+                .gap_1()
+                .child(
+                    Label::new(i18n_hot_keys(cx, key))
+                        .text_color(cx.theme().muted_foreground)
+                        .text_sm(),
+                )
+                .child(Label::new(value).text_sm().font_weight(gpui::FontWeight::MEDIUM))
+        };
+        let is_lfu = self.is_lfu_policy();
+
+        ZedisDivider::new()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .when(!self.policy.is_empty(), |this| {
+                        this.child(stat_item(cx, "policy", self.policy.clone()))
+                    })
+                    .when_some(self.dbsize, |this, dbsize| {
+                        this.child(stat_item(cx, "dbsize", format_thousands(dbsize).into()))
+                    })
+                    .when(!self.progress.is_empty(), |this| {
+                        this.child(stat_item(cx, "progress", self.progress.clone()))
+                    }),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                Label::new(i18n_hot_keys(cx, "limit"))
+                                    .text_color(cx.theme().muted_foreground)
+                                    .text_sm(),
+                            )
+                            .child(
+                                Input::new(&self.limit_input_state)
+                                    .small()
+                                    .w(px(60.))
+                                    .disabled(self.running),
+                            ),
+                    )
+                    .child(if self.running {
+                        Button::new("stop-hot-keys")
+                            .danger()
+                            .small()
+                            .label(i18n_hot_keys(cx, "stop"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.stop(cx);
+                            }))
+                    } else {
+                        Button::new("start-hot-keys")
+                            .primary()
+                            .small()
+                            .disabled(!is_lfu)
+                            .label(i18n_hot_keys(cx, "start"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                let limit = this.limit;
+                                this.find_hot_keys(limit, cx);
+                            }))
+                    }),
+            )
+    }
+
+    /// Renders a warning explaining why OBJECT FREQ is unavailable.
+    fn render_warning(&self, message: SharedString, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().warning.opacity(0.1))
+            .child(Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning))
+            .child(Label::new(message).text_sm().text_color(cx.theme().warning))
+    }
+}
+
+impl gpui::Render for ZedisHotKeys {
+    fn render(&mut self, _window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let has_data = self.row_count > 0;
+        let warning = if !self.is_lfu_policy() {
+            Some(i18n_hot_keys(cx, "lfu_required"))
+        } else {
+            self.error.clone()
+        };
+
+        v_flex()
+            .size_full()
+            .overflow_hidden()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.))
+                    .px_4()
+                    .justify_between()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Icon::new(CustomIconName::Zap))
+                            .child(Label::new(i18n_hot_keys(cx, "title")).text_color(cx.theme().foreground)),
+                    )
+                    .child(self.render_toolbar_functions(cx)),
+            )
+            .child({
+                let mut body = v_flex()
+                    .flex_1()
+                    .w_full()
+                    .p_2()
+                    .min_h_0()
+                    .gap_2()
+                    .id("hot-keys-body")
+                    .overflow_y_scroll();
+
+                if let Some(message) = warning {
+                    body = body.child(self.render_warning(message, cx));
+                }
+
+                if has_data {
+                    let table = DataTable::new(&self.table)
+                        .stripe(true)
+                        .bordered(true)
+                        .scrollbar_visible(false, false);
+                    body = body.child(div().w_full().h(table_height(self.row_count)).child(table));
+                } else if !self.running {
+                    body =
+                        body.child(
+                            div().size_full().flex().items_center().justify_center().child(
+                                Label::new(i18n_hot_keys(cx, "no_data")).text_color(cx.theme().muted_foreground),
+                            ),
+                        );
+                }
+
+                body
+            })
+            .into_any_element()
+    }
+}
@@ -12,23 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::bulk_preview::ZedisBulkPreview;
+use super::memory_analysis::format_thousands;
+use super::random_keys::{RANDOM_KEYS_COUNT, ZedisRandomKeys};
 use crate::{
     assets::CustomIconName,
-    constants::KEY_TREE_KEYWORD_INPUT_HEIGHT,
+    constants::{FILTER_DEBOUNCE, KEY_TREE_KEYWORD_INPUT_HEIGHT, KEY_TREE_TAB_INDEX},
     db::{get_favorites_manager, get_search_history_manager},
     helpers::{EditorAction, get_font_family, humanize_keystroke, validate_long_string, validate_ttl},
     states::{
-        KeyType, QueryMode, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, get_session_option,
-        i18n_common, i18n_key_tree, save_session_option,
+        BULK_PREVIEW_LIMIT, BackupKind, KeyType, QueryMode, SavedSearch, ServerEvent, ZedisGlobalStore,
+        ZedisServerState, dialog_button_props, get_session_option, i18n_common, i18n_key_tree, save_session_option,
+        update_app_state_and_save,
     },
 };
 use ahash::{AHashMap, AHashSet};
+use futures::future::{FutureExt, Shared};
 use gpui::{
-    Action, App, AppContext, Corner, Entity, FocusHandle, Focusable, Hsla, ScrollStrategy, SharedString, Subscription,
-    Task, Window, div, prelude::*, px,
+    Action, App, AppContext, Corner, Entity, FocusHandle, Focusable, Hsla, PathPromptOptions, ScrollStrategy,
+    SharedString, Subscription, Task, Window, div, prelude::*, px,
 };
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, IndexPath, StyledExt,
+    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable, StyledExt,
     button::{Button, ButtonVariants, DropdownButton},
     h_flex,
     input::{Input, InputEvent, InputState},
@@ -40,10 +45,11 @@ use gpui_component::{
     list::{List, ListDelegate, ListEvent, ListItem, ListState},
     menu::DropdownMenu,
 };
+use parking_lot::Mutex;
 use rust_i18n::t;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::BTreeMap;
 use std::{str::FromStr, sync::Arc, time::Duration};
 use tracing::info;
 use zedis_ui::{ZedisDialog, ZedisFormField, ZedisFormFieldType, ZedisFormOptions, ZedisSkeletonLoading};
@@ -56,6 +62,7 @@ const KEY_TYPE_FADE_ALPHA: f32 = 0.8; // Background transparency for key type ba
 const KEY_TYPE_BORDER_FADE_ALPHA: f32 = 0.5; // Border transparency for key type badges
 const STRIPE_BACKGROUND_ALPHA_DARK: f32 = 0.1; // Odd row background alpha for dark theme
 const STRIPE_BACKGROUND_ALPHA_LIGHT: f32 = 0.03; // Odd row background alpha for light theme
+const SCAN_DEBUG_RECENT_BATCHES: usize = 20; // Iterations listed in the scan debug panel
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Action)]
 enum KeyTreeAction {
@@ -65,11 +72,23 @@ enum KeyTreeAction {
     DeleteKey(SharedString),
     DeleteFolder(SharedString),
     CollapseAllKeys,
+    ExpandAllKeys,
     ToggleMultiSelectMode,
     ChangeChannelMode,
     AutoRefresh(u32),
     SelectFavoriteKey(SharedString),
     ClearFavorites,
+    SelectRecentKey(SharedString),
+    ClearRecentKeys,
+    ToggleKeyEncoding,
+    ExportDatabase,
+    ImportDatabase,
+    SampleRandomKeys,
+    PasteKeyList,
+    LoadKeyListFile,
+    ApplySavedSearch(SharedString),
+    RemoveSavedSearch(SharedString),
+    SaveSearch,
 }
 
 #[derive(Default)]
@@ -79,10 +98,14 @@ struct KeyTreeState {
     server_id: SharedString,
     /// Unique ID for the current key tree (changes when keys are reloaded)
     key_tree_id: SharedString,
-    /// Cached key tree ID — tracks which key_tree_id the cached_keys correspond to
-    cached_key_tree_id: SharedString,
-    /// Cached sorted keys snapshot to avoid re-cloning from server state on every rebuild
-    cached_keys: Arc<Vec<(SharedString, KeyType)>>,
+    /// Key tree ID the index has been updated to
+    indexed_key_tree_id: SharedString,
+    /// Keyword, separator and max depth the index was built with
+    index_options: (SharedString, String, usize),
+    /// Prefix tree of the loaded keys, updated in place as scan batches arrive
+    index: Arc<Mutex<KeyTreeIndex>>,
+    /// Latest index update; each update waits for the previous one to keep them ordered
+    index_task: Option<Shared<Task<()>>>,
     /// Whether the tree is empty (no keys found)
     is_empty: bool,
     /// Current query mode (All/Prefix/Exact)
@@ -104,136 +127,168 @@ struct KeyTreeItem {
     depth: usize,
     key_type: KeyType,
     expanded: bool,
-    children_count: usize,
+    /// Number of scanned keys under this folder, across all nested levels
+    keys_count: usize,
     is_folder: bool,
 }
 
-fn new_key_tree_items(
-    mut keys: Vec<(SharedString, KeyType)>,
-    keyword: SharedString,
-    expanded_items: AHashSet<SharedString>,
-    separator: &str,
-    max_key_tree_depth: usize,
-) -> Vec<KeyTreeItem> {
-    keys.sort_unstable_by_key(|(k, _)| k.clone());
-    let expanded_items_set = expanded_items.iter().map(|s| s.as_str()).collect::<AHashSet<&str>>();
-    let mut items: AHashMap<SharedString, KeyTreeItem> = AHashMap::with_capacity(100);
-    // Tracks standalone keys whose HashMap slot was taken over by a folder
-    // with the same name (e.g. key "test" exists alongside "test:key1").
-    // These are re-inserted as **siblings** of the folder so both remain
-    // visible at the same tree level.
-    let mut promoted_leaves: Vec<(SharedString, KeyType, SharedString, usize)> = Vec::new();
-
-    for (key, key_type) in keys {
-        if !keyword.is_empty() && !key.contains(keyword.as_str()) {
-            continue;
-        }
-        if !key.contains(separator) {
-            items.insert(
-                key.clone(),
-                KeyTreeItem {
-                    id: key.clone(),
-                    label: key.clone(),
-                    key_type,
-                    ..Default::default()
-                },
-            );
-            continue;
+/// A node of the key prefix tree.
+#[derive(Default)]
+struct KeyTreeNode {
+    /// Type of the key stored at exactly this path, if any
+    key_type: Option<KeyType>,
+    /// Number of keys below this node, across all nested levels
+    keys_count: usize,
+    /// Child nodes keyed by their path segment (sorted by label)
+    children: BTreeMap<String, KeyTreeNode>,
+}
+
+/// Splits a key into the folder segments (and key label) it is shown at in the tree.
+fn key_tree_path<'a>(key: &'a str, separator: &str, max_depth: usize) -> Vec<&'a str> {
+    if separator.is_empty() {
+        return vec![key];
+    }
+    key.splitn(max_depth.max(1), separator).collect()
+}
+
+/// Previews where a new key will appear in the tree, and warns about separators
+/// that create empty segments (leading, trailing or doubled).
+fn key_path_hint(key: &str, separator: &str, max_depth: usize, locale: &str) -> Option<SharedString> {
+    if key.is_empty() {
+        return None;
+    }
+    let path = key_tree_path(key, separator, max_depth)
+        .into_iter()
+        .map(|part| if part.is_empty() { "∅" } else { part })
+        .collect::<Vec<_>>()
+        .join(" › ");
+    let mut lines = vec![t!("key_tree.key_path_preview", path = path, locale = locale).to_string()];
+    if !separator.is_empty() {
+        let inner = key.trim_start_matches(separator).trim_end_matches(separator);
+        let warnings = [
+            (key.starts_with(separator), "key_tree.key_path_leading_separator"),
+            (key.ends_with(separator), "key_tree.key_path_trailing_separator"),
+            (inner.contains(&separator.repeat(2)), "key_tree.key_path_empty_segment"),
+        ];
+        for (_, warning) in warnings.into_iter().filter(|(matched, _)| *matched) {
+            lines.push(t!(warning, separator = separator, locale = locale).to_string());
         }
+    }
+    Some(lines.join("\n").into())
+}
 
-        let mut dir = String::with_capacity(50);
-        let mut key_tree_item: Option<KeyTreeItem> = None;
-        for (index, k) in key.splitn(max_key_tree_depth, separator).enumerate() {
-            let expanded = index == 0 || expanded_items_set.contains(dir.as_str());
-            if let Some(pending) = key_tree_item.take() {
-                match items.entry(pending.id.clone()) {
-                    Occupied(mut e) => {
-                        let existing = e.get_mut();
-                        if !existing.is_folder {
-                            promoted_leaves.push((
-                                existing.id.clone(),
-                                existing.key_type,
-                                existing.label.clone(),
-                                existing.depth,
-                            ));
-                        }
-                        existing.is_folder = true;
-                        existing.children_count += 1;
-                        existing.expanded = expanded;
-                    }
-                    Vacant(e) => {
-                        let mut item = pending;
-                        item.is_folder = true;
-                        item.children_count = 1;
-                        item.expanded = expanded;
-                        e.insert(item);
-                    }
-                }
-            }
+/// Prefix tree of the scanned keys.
+///
+/// Scan batches are upserted in place, so each batch only costs its own keys
+/// instead of re-sorting and rebuilding the whole hierarchy from the flat key list.
+#[derive(Default)]
+struct KeyTreeIndex {
+    keyword: SharedString,
+    separator: String,
+    max_depth: usize,
+    root: KeyTreeNode,
+}
 
-            if !expanded {
-                break;
-            }
-            let name: SharedString = k.to_string().into();
-            if index != 0 {
-                dir.push_str(separator);
-            };
-            dir.push_str(k);
+impl KeyTreeIndex {
+    fn new(keyword: SharedString, separator: String, max_depth: usize) -> Self {
+        Self {
+            keyword,
+            separator,
+            max_depth: max_depth.max(1),
+            root: KeyTreeNode::default(),
+        }
+    }
 
-            key_tree_item = Some(KeyTreeItem {
-                id: dir.clone().into(),
-                label: name.clone(),
-                key_type,
-                depth: index,
-                expanded,
-                ..Default::default()
-            });
+    /// Inserts a key, or only updates its type if it is already in the tree.
+    fn upsert(&mut self, key: &str, key_type: KeyType) {
+        if !self.keyword.is_empty() && !key.contains(self.keyword.as_str()) {
+            return;
         }
-        if let Some(key_tree_item) = key_tree_item.take() {
-            items.insert(key_tree_item.id.clone(), key_tree_item);
+        let parts = key_tree_path(key, &self.separator, self.max_depth);
+
+        let mut existing = Some(&mut self.root);
+        for part in parts.iter() {
+            existing = existing.and_then(|node| node.children.get_mut(*part));
+        }
+        if let Some(node) = existing
+            && node.key_type.is_some()
+        {
+            node.key_type = Some(key_type);
+            return;
         }
-    }
 
-    let mut children_map: AHashMap<String, Vec<KeyTreeItem>> = AHashMap::new();
+        let mut node = &mut self.root;
+        for part in parts {
+            node.keys_count += 1;
+            node = node.children.entry(part.to_string()).or_default();
+        }
+        node.key_type = Some(key_type);
+    }
 
-    for item in items.into_values() {
-        let size = item.id.len() - item.label.len();
-        let parent_id = if size == 0 { "" } else { &item.id[..(size - 1)] };
-        children_map.entry(parent_id.to_string()).or_default().push(item);
+    /// Flattens the tree into list items, descending only into expanded folders.
+    ///
+    /// Folders come first, then keys, each sorted by label. A key that shares its
+    /// path with a folder (e.g. "test" alongside "test:key1") is listed as a
+    /// sibling of the folder so both remain visible.
+    fn items(&self, expanded_items: &AHashSet<SharedString>) -> Vec<KeyTreeItem> {
+        let mut result = Vec::with_capacity(self.root.children.len());
+        self.push_children(&self.root, "", 0, expanded_items, &mut result);
+        result
     }
 
-    for (key_id, key_type, label, depth) in promoted_leaves {
-        let size = key_id.len() - label.len();
-        let parent_id = if size == 0 { "" } else { &key_id[..(size - 1)] };
-        children_map
-            .entry(parent_id.to_string())
-            .or_default()
-            .push(KeyTreeItem {
-                id: key_id,
-                label,
+    fn push_children(
+        &self,
+        node: &KeyTreeNode,
+        parent_id: &str,
+        depth: usize,
+        expanded_items: &AHashSet<SharedString>,
+        result: &mut Vec<KeyTreeItem>,
+    ) {
+        let child_id = |label: &str| -> SharedString {
+            if depth == 0 {
+                label.to_string().into()
+            } else {
+                format!("{parent_id}{}{label}", self.separator).into()
+            }
+        };
+        for (label, child) in node.children.iter().filter(|(_, child)| !child.children.is_empty()) {
+            let id = child_id(label);
+            let expanded = expanded_items.contains(&id);
+            result.push(KeyTreeItem {
+                id: id.clone(),
+                label: label.clone().into(),
                 depth,
-                key_type,
+                expanded,
+                keys_count: child.keys_count,
+                is_folder: true,
                 ..Default::default()
             });
-    }
-
-    let mut result = Vec::with_capacity(children_map.values().map(|v| v.len()).sum());
-
-    fn build_sorted_list(parent_id: &str, map: &mut AHashMap<String, Vec<KeyTreeItem>>, result: &mut Vec<KeyTreeItem>) {
-        if let Some(mut children) = map.remove(parent_id) {
-            children.sort_unstable_by(|a, b| b.is_folder.cmp(&a.is_folder).then_with(|| a.label.cmp(&b.label)));
-
-            for child in children {
-                let child_id = child.id.to_string();
-                result.push(child);
-                build_sorted_list(&child_id, map, result);
+            if expanded {
+                self.push_children(child, &id, depth + 1, expanded_items, result);
+            }
+        }
+        for (label, child) in node.children.iter() {
+            if let Some(key_type) = child.key_type {
+                result.push(KeyTreeItem {
+                    id: child_id(label),
+                    label: label.clone().into(),
+                    depth,
+                    key_type,
+                    ..Default::default()
+                });
             }
         }
     }
+}
 
-    build_sorted_list("", &mut children_map, &mut result);
-
-    result
+/// How the key tree index is brought up to date before flattening.
+enum KeyTreeIndexUpdate {
+    /// Keys are unchanged (e.g. a folder was expanded or collapsed)
+    None,
+    /// Only these keys were added or had their type resolved
+    Upsert(Arc<Vec<(SharedString, KeyType)>>),
+    /// Keys were removed or the tree options changed
+    Rebuild(Vec<(SharedString, KeyType)>),
 }
 
 struct KeyTreeDelegate {
@@ -242,6 +297,16 @@ struct KeyTreeDelegate {
     enabled_multiple_selection: bool,
     selected_items: AHashSet<SharedString>,
     readonly: bool,
+    /// Whether the OBJECT ENCODING of keys is shown
+    show_encoding: bool,
+    encodings: AHashMap<SharedString, SharedString>,
+}
+
+/// Encodings of collections that outgrew their compact representation
+/// (listpack/ziplist/intset). `quicklist` is not listed, it is the only list
+/// encoding before Redis 7.2.
+fn is_outgrown_encoding(encoding: &str) -> bool {
+    matches!(encoding, "hashtable" | "skiplist" | "linkedlist")
 }
 
 impl KeyTreeDelegate {
@@ -330,6 +395,14 @@ impl ListDelegate for KeyTreeDelegate {
             false
         };
         let selected_items_count = self.selected_items.len();
+        let encoding = if self.show_encoding && !is_folder {
+            self.encodings
+                .get(&entry.id)
+                .filter(|encoding| !encoding.is_empty())
+                .cloned()
+        } else {
+            None
+        };
         let id = entry.id.clone();
         let readonly = self.readonly;
         Some(
@@ -390,6 +463,14 @@ impl ListDelegate for KeyTreeDelegate {
                                         .min_w_0()
                                         .child(Label::new(entry.label.clone()).text_color(label_color).text_ellipsis()),
                                 )
+                                .when_some(encoding, |this, encoding| {
+                                    let color = if is_outgrown_encoding(&encoding) {
+                                        cx.theme().warning
+                                    } else {
+                                        cx.theme().muted_foreground
+                                    };
+                                    this.child(Label::new(encoding).text_xs().text_color(color))
+                                })
                                 .when(show_check_icon, |this| {
                                     let check_icon = if selected {
                                         CustomIconName::SquareCheck
@@ -400,7 +481,7 @@ impl ListDelegate for KeyTreeDelegate {
                                 })
                                 .when(entry.is_folder, |this| {
                                     this.child(
-                                        Label::new(entry.children_count.to_string())
+                                        Label::new(format!("({})", format_thousands(entry.keys_count as u64)))
                                             .text_sm()
                                             .text_color(cx.theme().muted_foreground),
                                     )
@@ -440,6 +521,9 @@ pub struct ZedisKeyTree {
 
     auto_refresh_task: Option<Task<()>>,
 
+    /// Pending debounced filter, replaced on every keystroke
+    filter_task: Option<Task<()>>,
+
     state: KeyTreeState,
 
     current_keyword: Entity<SharedString>,
@@ -471,7 +555,8 @@ impl ZedisKeyTree {
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let mut subscriptions = Vec::new();
 
-        let focus_handle = cx.focus_handle();
+        // The tree itself is a tab stop, so it can be reached and navigated with the keyboard
+        let focus_handle = cx.focus_handle().tab_stop(true);
         focus_handle.focus(window, cx);
 
         subscriptions.push(
@@ -500,13 +585,24 @@ impl ZedisKeyTree {
                 ServerEvent::KeySelected(key) => {
                     this.update_expand(key.clone(), cx);
                 }
+                // Keep folders expanded across rescans, only scroll back to the top
                 ServerEvent::KeyScanReset => {
-                    this.reset_expand(cx);
+                    this.reset_scroll(cx);
                 }
                 ServerEvent::KeyScanFinished => {
                     this.check_and_expand_keys(cx);
                     cx.notify();
                 }
+                ServerEvent::BackupProgressUpdated => {
+                    cx.notify();
+                }
+                ServerEvent::KeyEncodingsLoaded => {
+                    let encodings = server_state.read(cx).key_encodings().clone();
+                    this.key_tree_list_state.update(cx, |state, cx| {
+                        state.delegate_mut().encodings = encodings;
+                        cx.notify();
+                    });
+                }
                 _ => {}
             }),
         );
@@ -536,12 +632,17 @@ impl ZedisKeyTree {
         }
         let readonly = server_state_value.readonly();
 
-        // Subscribe to search input events (Enter key triggers filter)
-        subscriptions.push(cx.subscribe_in(&keyword_state, window, |view, _, event, _, cx| {
-            if let InputEvent::PressEnter { .. } = &event {
-                view.handle_filter(cx);
-            }
-        }));
+        // Enter filters immediately, typing filters live after a short debounce
+        subscriptions.push(
+            cx.subscribe_in(&keyword_state, window, |view, _, event, _, cx| match event {
+                InputEvent::PressEnter { .. } => {
+                    view.filter_task = None;
+                    view.handle_filter(cx);
+                }
+                InputEvent::Change => view.schedule_live_filter(cx),
+                _ => {}
+            }),
+        );
 
         info!(server_id, "Creating new key tree view");
 
@@ -551,6 +652,8 @@ impl ZedisKeyTree {
             selected_index: None,
             selected_items: AHashSet::with_capacity(5),
             readonly,
+            show_encoding: cx.global::<ZedisGlobalStore>().read(cx).key_encoding_enabled(),
+            encodings: AHashMap::new(),
         };
         let key_tree_list_state = cx.new(|cx| ListState::new(delegate, window, cx));
         subscriptions.push(cx.subscribe(&key_tree_list_state, |view, _, event, cx| match event {
@@ -578,6 +681,7 @@ impl ZedisKeyTree {
             server_state,
             should_enter_add_key_mode: None,
             auto_refresh_task: None,
+            filter_task: None,
             _subscriptions: subscriptions,
         };
 
@@ -613,8 +717,7 @@ impl ZedisKeyTree {
     fn reset(&mut self, _cx: &mut Context<Self>) {
         self.state = KeyTreeState::default();
     }
-    fn reset_expand(&mut self, _cx: &mut Context<Self>) {
-        self.state.expanded_items.clear();
+    fn reset_scroll(&mut self, _cx: &mut Context<Self>) {
         self.state.scroll_to_index = Some(IndexPath::new(0));
     }
     fn update_expand(&mut self, selected_key: SharedString, cx: &mut Context<Self>) {
@@ -628,7 +731,7 @@ impl ZedisKeyTree {
         if !selected_key.contains(separator.as_str()) {
             return;
         }
-        let parts: Vec<&str> = selected_key.splitn(max_depth, separator.as_str()).collect();
+        let parts = key_tree_path(&selected_key, &separator, max_depth);
         let mut inserted_count = 0;
         for i in 1..parts.len() {
             let prefix: SharedString = parts[..i].join(separator.as_str()).into();
@@ -641,25 +744,37 @@ impl ZedisKeyTree {
             self.update_key_tree(true, cx);
         }
     }
-    fn check_and_expand_keys(&mut self, cx: &mut Context<Self>) {
+    /// Returns every folder path of the loaded keys.
+    fn all_folders(&self, cx: &Context<Self>) -> AHashSet<SharedString> {
         let keys = self.server_state.read(cx).keys();
         let global_state = cx.global::<ZedisGlobalStore>().read(cx);
-        if keys.len() < global_state.auto_expand_threshold() {
-            let key_separator = global_state.key_separator();
-            let mut expanded_items: AHashSet<SharedString> = AHashSet::new();
-            keys.iter().for_each(|(key, _)| {
-                if !key.contains(key_separator) {
-                    return;
-                }
-                let parts: Vec<&str> = key.split(key_separator).collect();
-                for i in 1..parts.len() {
-                    let prefix = parts[..i].join(key_separator);
-                    expanded_items.insert(prefix.into());
-                }
-            });
-            self.state.expanded_items = expanded_items;
+        let key_separator = global_state.key_separator();
+        let max_depth = global_state.max_key_tree_depth();
+        let mut folders: AHashSet<SharedString> = AHashSet::new();
+        keys.iter().for_each(|(key, _)| {
+            if !key.contains(key_separator) {
+                return;
+            }
+            let parts = key_tree_path(key, key_separator, max_depth);
+            for i in 1..parts.len() {
+                let prefix = parts[..i].join(key_separator);
+                folders.insert(prefix.into());
+            }
+        });
+        folders
+    }
+    fn check_and_expand_keys(&mut self, cx: &mut Context<Self>) {
+        let keys_count = self.server_state.read(cx).keys().len();
+        if keys_count < cx.global::<ZedisGlobalStore>().read(cx).auto_expand_threshold() {
+            let folders = self.all_folders(cx);
+            self.state.expanded_items.extend(folders);
         }
     }
+    /// Expands every folder of the loaded keys.
+    fn expand_all_keys(&mut self, cx: &mut Context<Self>) {
+        self.state.expanded_items = self.all_folders(cx);
+        self.update_key_tree(true, cx);
+    }
 
     /// Update the key tree structure when server state changes
     ///
@@ -667,8 +782,9 @@ impl ZedisKeyTree {
     /// Preserves expanded folder state across rebuilds. Auto-expands all folders
     /// if the total key count is below the threshold.
     ///
-    /// Uses a cached keys snapshot to avoid re-cloning all keys from server state
-    /// when only expanded_items changed (e.g., folder expand/collapse).
+    /// Scan batches only upsert their own keys into the prefix tree index; the full
+    /// key list is re-read only when keys were removed or the tree options changed.
+    /// For expand/collapse the index is just flattened again.
     fn update_key_tree(&mut self, force_update: bool, cx: &mut Context<Self>) {
         let server_state = self.server_state.read(cx);
         let key_tree_id = server_state.key_tree_id();
@@ -687,36 +803,70 @@ impl ZedisKeyTree {
         }
         self.state.key_tree_id = key_tree_id.to_string().into();
 
-        // Only re-clone keys from server state when key_tree_id actually changed
-        // (keys added/removed/type changed). For expand/collapse, reuse cached snapshot.
-        if self.state.cached_key_tree_id != key_tree_id {
-            let keys_snapshot: Vec<(SharedString, KeyType)> =
-                server_state.keys().iter().map(|(k, v)| (k.clone(), *v)).collect();
-            self.state.cached_keys = Arc::new(keys_snapshot);
-            self.state.cached_key_tree_id = key_tree_id.to_string().into();
-        }
+        let app_state = cx.global::<ZedisGlobalStore>().read(cx);
+        let index_options = (
+            self.state.keyword.clone(),
+            app_state.key_separator().to_string(),
+            app_state.max_key_tree_depth(),
+        );
+        let update = if self.state.index_options != index_options {
+            KeyTreeIndexUpdate::Rebuild(server_state.keys().iter().map(|(k, v)| (k.clone(), *v)).collect())
+        } else if self.state.indexed_key_tree_id == key_tree_id {
+            KeyTreeIndexUpdate::None
+        } else if let Some(delta) = server_state.key_tree_delta()
+            && delta.from == self.state.indexed_key_tree_id
+            && delta.to == key_tree_id
+        {
+            KeyTreeIndexUpdate::Upsert(delta.keys.clone())
+        } else {
+            KeyTreeIndexUpdate::Rebuild(server_state.keys().iter().map(|(k, v)| (k.clone(), *v)).collect())
+        };
+        self.state.indexed_key_tree_id = key_tree_id.to_string().into();
+        self.state.index_options = index_options.clone();
+
+        let index = self.state.index.clone();
+        let previous = self.state.index_task.take();
+        let index_task = cx
+            .background_spawn({
+                let index = index.clone();
+                async move {
+                    if let Some(previous) = previous {
+                        previous.await;
+                    }
+                    let start = std::time::Instant::now();
+                    let mut index = index.lock();
+                    match update {
+                        KeyTreeIndexUpdate::None => return,
+                        KeyTreeIndexUpdate::Upsert(keys) => {
+                            for (key, key_type) in keys.iter() {
+                                index.upsert(key, *key_type);
+                            }
+                        }
+                        KeyTreeIndexUpdate::Rebuild(keys) => {
+                            let (keyword, separator, max_depth) = index_options;
+                            *index = KeyTreeIndex::new(keyword, separator, max_depth);
+                            for (key, key_type) in keys.iter() {
+                                index.upsert(key, *key_type);
+                            }
+                        }
+                    }
+                    tracing::debug!("Key tree index update time: {:?}", start.elapsed());
+                }
+            })
+            .shared();
+        self.state.index_task = Some(index_task.clone());
 
-        let keys_snapshot = self.state.cached_keys.clone();
         let readonly = server_state.readonly();
         let expanded_items = self.state.expanded_items.clone();
 
         let view_handle = cx.entity().downgrade();
-        let keyword = self.state.keyword.clone();
 
         self.key_tree_list_state.update(cx, move |_state, cx| {
-            let app_state = cx.global::<ZedisGlobalStore>().value(cx);
-            let separator = app_state.key_separator().to_string();
-            let max_key_tree_depth = app_state.max_key_tree_depth();
             cx.spawn(async move |handle, cx| {
                 let task = cx.background_spawn(async move {
+                    index_task.await;
                     let start = std::time::Instant::now();
-                    let items = new_key_tree_items(
-                        (*keys_snapshot).clone(),
-                        keyword,
-                        expanded_items,
-                        &separator,
-                        max_key_tree_depth,
-                    );
+                    let items = index.lock().items(&expanded_items);
                     tracing::debug!("Key tree build time: {:?}", start.elapsed());
                     items
                 });
@@ -724,20 +874,40 @@ impl ZedisKeyTree {
                 let result = task.await;
                 if result.is_empty() {
                     let _ = view_handle.update(cx, |view: &mut ZedisKeyTree, cx| {
-                        view.reset_expand(cx);
+                        view.reset_scroll(cx);
                     });
                 }
-                handle.update(cx, |this, cx| {
+                let _ = handle.update(cx, |this, cx| {
                     this.delegate_mut().selected_items.clear();
                     this.delegate_mut().items = result;
                     this.delegate_mut().readonly = readonly;
                     cx.notify();
-                })
+                });
+                let _ = view_handle.update(cx, |view: &mut ZedisKeyTree, cx| {
+                    view.fetch_key_encodings(cx);
+                });
             })
             .detach();
         });
     }
 
+    /// Fetches the OBJECT ENCODING of the keys listed in the tree, if the column is shown.
+    fn fetch_key_encodings(&mut self, cx: &mut Context<Self>) {
+        let delegate = self.key_tree_list_state.read(cx).delegate();
+        if !delegate.show_encoding {
+            return;
+        }
+        let keys: Vec<SharedString> = delegate
+            .items
+            .iter()
+            .filter(|item| !item.is_folder)
+            .map(|item| item.id.clone())
+            .collect();
+        self.server_state.update(cx, |state, cx| {
+            state.fetch_key_encodings(keys, cx);
+        });
+    }
+
     /// Handle filter/search action when user submits keyword
     ///
     /// Delegates to server state to perform the actual filtering based on
@@ -775,6 +945,124 @@ impl ZedisKeyTree {
             handle.handle_filter(keyword, cx);
         });
     }
+    /// Schedules a live filter for the keyword being typed, canceling the pending one.
+    ///
+    /// Exact mode is excluded since it selects the key instead of filtering.
+    fn schedule_live_filter(&mut self, cx: &mut Context<Self>) {
+        if self.state.query_mode == QueryMode::Exact {
+            return;
+        }
+        self.filter_task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(FILTER_DEBOUNCE).await;
+            let _ = this.update(cx, |this, cx| {
+                this.apply_live_filter(cx);
+            });
+        }));
+    }
+
+    /// Applies the typed keyword without recording it in the search history.
+    ///
+    /// A running scan is superseded, since scan results for a stale keyword are discarded.
+    fn apply_live_filter(&mut self, cx: &mut Context<Self>) {
+        let keyword = self.keyword_state.read(cx).value();
+        if keyword == self.state.keyword {
+            return;
+        }
+        self.state.keyword = keyword.clone();
+        let keyword_clone = keyword.clone();
+        self.current_keyword.update(cx, |state, _cx| *state = keyword_clone);
+        self.server_state.update(cx, move |handle, cx| {
+            handle.handle_filter(keyword, cx);
+        });
+    }
+    /// Switches the query mode and remembers it for the server.
+    fn change_query_mode(&mut self, mode: QueryMode, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id();
+        save_session_option(server_id, |option| option.query_mode = Some(mode.to_string()), cx);
+
+        // Step 1: Update server state with new query mode
+        self.server_state.update(cx, |state, cx| {
+            state.set_query_mode(mode, cx);
+        });
+
+        // Step 2: Update local UI state
+        self.state.query_mode = mode;
+    }
+    /// Restores the query mode and keyword of a saved search and runs it.
+    fn apply_saved_search(&mut self, name: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        let Some(search) = get_session_option(&server_id)
+            .ok()
+            .and_then(|option| option.saved_searches)
+            .and_then(|searches| searches.into_iter().find(|search| search.name == name))
+        else {
+            return;
+        };
+        let mode = search.query_mode();
+        if mode != self.state.query_mode {
+            self.change_query_mode(mode, cx);
+        }
+        self.keyword_state.update(cx, |state, cx| {
+            state.set_value(search.keyword.clone(), window, cx);
+        });
+        self.filter_task = None;
+        self.handle_filter(cx);
+    }
+    /// Saves the typed keyword and query mode under a name, replacing a search with the same name.
+    fn handle_save_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let keyword = self.keyword_state.read(cx).value();
+        let modes = [QueryMode::All, QueryMode::Prefix, QueryMode::Exact];
+        let mode_index = modes
+            .iter()
+            .position(|mode| *mode == self.state.query_mode)
+            .unwrap_or_default();
+        let fields = vec![
+            ZedisFormField::new("name", i18n_key_tree(cx, "saved_search_name"))
+                .placeholder(i18n_key_tree(cx, "saved_search_name_placeholder"))
+                .required()
+                .focus(),
+            ZedisFormField::new("keyword", i18n_key_tree(cx, "saved_search_keyword"))
+                .placeholder(i18n_common(cx, "filter_placeholder"))
+                .default_value(keyword)
+                .required(),
+            ZedisFormField::new("query_mode", i18n_key_tree(cx, "query_mode"))
+                .field_type(ZedisFormFieldType::RadioGroup)
+                .options(
+                    ["query_mode_all", "query_mode_prefix", "query_mode_exact"]
+                        .iter()
+                        .map(|key| i18n_key_tree(cx, key))
+                        .collect(),
+                )
+                .default_value(mode_index.to_string()),
+        ];
+        let server_id = self.server_state.read(cx).server_id().to_string();
+
+        ZedisFormOptions::new(fields)
+            .title(i18n_key_tree(cx, "save_search_title"))
+            .description(i18n_key_tree(cx, "save_search_description"))
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let name = values.get("name").map(|v| v.trim().to_string()).unwrap_or_default();
+                let keyword = values.get("keyword").map(|v| v.to_string()).unwrap_or_default();
+                if name.is_empty() || keyword.is_empty() {
+                    return false;
+                }
+                let mode = values
+                    .get("query_mode")
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .and_then(|index| modes.get(index).copied())
+                    .unwrap_or_default();
+                let search = SavedSearch {
+                    name,
+                    keyword,
+                    query_mode: Some(mode.to_string()),
+                };
+                save_session_option(&server_id, |option| option.upsert_saved_search(search), cx);
+                true
+            })
+            .open_dialog(window, cx);
+    }
     fn handle_clear_history(&mut self, cx: &mut Context<Self>) {
         let server_state = self.server_state.read(cx);
         let server_id = server_state.server_id().to_string();
@@ -800,6 +1088,14 @@ impl ZedisKeyTree {
         } else {
             None
         };
+        let (separator, max_depth, locale) = {
+            let global_state = cx.global::<ZedisGlobalStore>().read(cx);
+            (
+                global_state.key_separator().to_string(),
+                global_state.max_key_tree_depth(),
+                global_state.locale().to_string(),
+            )
+        };
         let category_list = ["String", "List", "Set", "Zset", "Hash", "Stream"];
         // Category indices: String=0, List=1, Set=2, Zset=3, Hash=4, Stream=5
         let fields = vec![
@@ -811,6 +1107,7 @@ impl ZedisKeyTree {
                 .required()
                 .when_some(prefix, |this, prefix| this.default_value(prefix))
                 .focus()
+                .hint(move |s| key_path_hint(s, &separator, max_depth, &locale))
                 .validate(move |s| {
                     if validate_long_string(s) {
                         None
@@ -1053,6 +1350,268 @@ impl ZedisKeyTree {
             .child(List::new(&self.key_tree_list_state))
             .into_any_element()
     }
+    /// Render the SCAN debug panel: next cursors, iteration count and keys per iteration
+    fn render_scan_debug(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let iterations = server_state.scan_iterations();
+        let cursors = server_state
+            .scan_cursors()
+            .map(|cursors| cursors.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", "))
+            .unwrap_or_else(|| "0".to_string());
+        // Most recent iterations, oldest first
+        let batches = iterations
+            .iter()
+            .rev()
+            .take(SCAN_DEBUG_RECENT_BATCHES)
+            .rev()
+            .map(|iteration| format!("{}({}ms)", iteration.keys, iteration.elapsed_ms))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (min, max, total) = iterations
+            .iter()
+            .fold((usize::MAX, 0, 0), |(min, max, total), iteration| {
+                (min.min(iteration.keys), max.max(iteration.keys), total + iteration.keys)
+            });
+        let stats = if iterations.is_empty() {
+            "--".to_string()
+        } else {
+            format!("{min} / {} / {max}", total / iterations.len())
+        };
+        let iteration_count = server_state.scan_iteration_count();
+        let text_color = cx.theme().muted_foreground;
+        let row = |label: SharedString, value: String| {
+            h_flex()
+                .w_full()
+                .gap_2()
+                .child(Label::new(label).text_color(text_color).flex_none())
+                .child(Label::new(value).text_ellipsis().flex_1().min_w_0())
+        };
+
+        v_flex()
+            .id("key-tree-scan-debug")
+            .flex_none()
+            .w_full()
+            .p_2()
+            .gap_1()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().sidebar)
+            .text_xs()
+            .font_family(get_font_family())
+            .child(row(i18n_key_tree(cx, "scan_debug_cursor"), cursors))
+            .child(row(
+                i18n_key_tree(cx, "scan_debug_iterations"),
+                iteration_count.to_string(),
+            ))
+            .child(row(i18n_key_tree(cx, "scan_debug_keys_stats"), stats))
+            .child(row(i18n_key_tree(cx, "scan_debug_recent"), batches))
+    }
+    /// Asks for a target file and exports the whole database into it.
+    fn handle_export_database(&mut self, cx: &mut Context<Self>) {
+        let server_state = self.server_state.read(cx);
+        let file_name = format!("{}-db{}.zedisdb", server_state.server_id(), server_state.db());
+        let directory = home::home_dir().unwrap_or_default();
+        let receiver = cx.prompt_for_new_path(&directory, Some(&file_name));
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_, cx| {
+            let Ok(Ok(Some(path))) = receiver.await else {
+                return;
+            };
+            let _ = server_state.update(cx, |state, cx| {
+                state.export_database(path, cx);
+            });
+        })
+        .detach();
+    }
+    /// Samples random keys and shows them in a dialog.
+    fn handle_sample_random_keys(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        server_state.update(cx, |state, cx| {
+            state.sample_random_keys(RANDOM_KEYS_COUNT, cx);
+        });
+        let view = cx.new(|cx| ZedisRandomKeys::new(server_state, cx));
+        ZedisDialog::new(i18n_key_tree(cx, "random_keys"))
+            .alert()
+            .child(move || view.clone())
+            .open(window, cx);
+    }
+    /// Shows exactly the pasted keys (one per line) instead of scanning the database.
+    fn handle_paste_key_list(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let fields = vec![
+            ZedisFormField::new("keys", i18n_key_tree(cx, "key_list"))
+                .placeholder(i18n_key_tree(cx, "key_list_placeholder"))
+                .field_type(ZedisFormFieldType::AutoGrow(8, 20))
+                .required()
+                .focus(),
+        ];
+        let server_state = self.server_state.clone();
+        ZedisFormOptions::new(fields)
+            .title(i18n_key_tree(cx, "paste_key_list"))
+            .description(i18n_key_tree(cx, "key_list_hint"))
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let keys = values
+                    .get("keys")
+                    .map(|value| value.lines().map(|line| line.to_string()).collect())
+                    .unwrap_or_default();
+                server_state.update(cx, |state, cx| {
+                    state.load_key_list(keys, cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Reads a key list file, e.g. the output of `redis-cli --scan > keys.txt`.
+    fn handle_load_key_list_file(&mut self, cx: &mut Context<Self>) {
+        let receiver = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_, cx| {
+            let Ok(Ok(Some(paths))) = receiver.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let result = cx
+                .background_spawn(async move {
+                    let content = std::fs::read_to_string(&path)?;
+                    Ok::<_, std::io::Error>(content.lines().map(|line| line.to_string()).collect::<Vec<_>>())
+                })
+                .await;
+            let _ = server_state.update(cx, |state, cx| match result {
+                Ok(keys) => state.load_key_list(keys, cx),
+                Err(e) => state.emit_error_notification(e.to_string().into(), cx),
+            });
+        })
+        .detach();
+    }
+    /// Asks for an exported archive and restores its keys into the database.
+    fn handle_import_database(&mut self, cx: &mut Context<Self>) {
+        let receiver = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_, cx| {
+            let Ok(Ok(Some(paths))) = receiver.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            let _ = server_state.update(cx, |state, cx| {
+                state.import_database(path, cx);
+            });
+        })
+        .detach();
+    }
+    /// Renders the progress of a running database export/import with a cancel button.
+    fn render_backup_progress(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let progress = self.server_state.read(cx).backup_progress()?.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let processed = format_thousands(progress.processed as u64);
+        let total = progress.total.map(format_thousands).unwrap_or_else(|| "--".to_string());
+        let message = match progress.kind {
+            BackupKind::Export => t!(
+                "key_tree.exporting_database",
+                processed = processed,
+                total = total,
+                locale = locale
+            ),
+            BackupKind::Import => t!(
+                "key_tree.importing_database",
+                processed = processed,
+                total = total,
+                locale = locale
+            ),
+        }
+        .to_string();
+        Some(
+            h_flex()
+                .flex_none()
+                .w_full()
+                .px_2()
+                .py_1()
+                .gap_2()
+                .items_center()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .bg(cx.theme().sidebar)
+                .child(
+                    Label::new(message)
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .text_ellipsis()
+                        .flex_1()
+                        .min_w_0(),
+                )
+                .child(
+                    Button::new("key-tree-cancel-backup")
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::Close)
+                        .tooltip(i18n_key_tree(cx, "cancel_backup"))
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.cancel_backup(cx);
+                            });
+                        })),
+                ),
+        )
+    }
+    /// Renders a notice while a loaded key list is browsed instead of a SCAN,
+    /// with a button to scan the database again.
+    fn render_key_list_notice(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let server_state = self.server_state.read(cx);
+        if !server_state.key_list_loaded() {
+            return None;
+        }
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let message = t!(
+            "key_tree.key_list_browsing",
+            count = format_thousands(server_state.keys().len() as u64),
+            locale = locale
+        )
+        .to_string();
+        Some(
+            h_flex()
+                .flex_none()
+                .w_full()
+                .px_2()
+                .py_1()
+                .gap_2()
+                .items_center()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .bg(cx.theme().sidebar)
+                .child(
+                    Label::new(message)
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .text_ellipsis()
+                        .flex_1()
+                        .min_w_0(),
+                )
+                .child(
+                    Button::new("key-tree-back-to-scan")
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::Search)
+                        .label(i18n_key_tree(cx, "back_to_scan"))
+                        .on_click(cx.listener(|this, _, _, cx| {
+                            this.handle_filter(cx);
+                        })),
+                ),
+        )
+    }
     /// Render the search/filter input bar with query mode selector
     ///
     /// Features:
@@ -1073,6 +1632,7 @@ impl ZedisKeyTree {
             self.keyword_state.update(cx, |state, cx| {
                 state.set_value(SharedString::default(), window, cx);
             });
+            self.filter_task = None;
         }
         let query_mode = self.state.query_mode;
 
@@ -1089,8 +1649,60 @@ impl ZedisKeyTree {
                 let favorites = get_favorites_manager()
                     .records(server_id_for_favorites.as_ref())
                     .unwrap_or_default();
+                let saved_searches = get_session_option(server_id_for_favorites.as_ref())
+                    .ok()
+                    .and_then(|option| option.saved_searches)
+                    .unwrap_or_default();
                 let server_state_for_history = server_state_clone.clone();
+                let recent_keys = server_state_clone.read(cx).recent_keys();
                 menu.submenu_with_icon(
+                    Some(Icon::new(IconName::Search)),
+                    i18n_key_tree(cx, "saved_searches"),
+                    window,
+                    cx,
+                    move |submenu, window, cx| {
+                        let mut submenu = submenu;
+                        if saved_searches.is_empty() {
+                            submenu = submenu.label(i18n_key_tree(cx, "no_saved_searches"));
+                        }
+                        for search in &saved_searches {
+                            let label: SharedString =
+                                format!("{} ({}{})", search.name, search.query_mode(), search.keyword).into();
+                            submenu = submenu.menu_element(
+                                Box::new(KeyTreeAction::ApplySavedSearch(search.name.clone().into())),
+                                move |_, _cx| Label::new(label.clone()).text_ellipsis(),
+                            );
+                        }
+                        submenu = submenu.separator().menu_element_with_icon(
+                            CustomIconName::Save,
+                            Box::new(KeyTreeAction::SaveSearch),
+                            move |_, cx| Label::new(i18n_key_tree(cx, "save_search")),
+                        );
+                        if !saved_searches.is_empty() {
+                            let names: Vec<SharedString> =
+                                saved_searches.iter().map(|search| search.name.clone().into()).collect();
+                            submenu = submenu.submenu_with_icon(
+                                Some(Icon::new(CustomIconName::Eraser)),
+                                i18n_key_tree(cx, "remove_saved_search"),
+                                window,
+                                cx,
+                                move |submenu, _window, _cx| {
+                                    let mut submenu = submenu;
+                                    for name in &names {
+                                        let name_clone = name.clone();
+                                        submenu = submenu.menu_element(
+                                            Box::new(KeyTreeAction::RemoveSavedSearch(name.clone())),
+                                            move |_, _cx| Label::new(name_clone.clone()).text_ellipsis(),
+                                        );
+                                    }
+                                    submenu
+                                },
+                            );
+                        }
+                        submenu
+                    },
+                )
+                .submenu_with_icon(
                     Some(Icon::new(CustomIconName::Clock3)),
                     i18n_key_tree(cx, "search_history"),
                     window,
@@ -1116,6 +1728,32 @@ impl ZedisKeyTree {
                         submenu
                     },
                 )
+                .submenu_with_icon(
+                    Some(Icon::new(CustomIconName::History)),
+                    i18n_key_tree(cx, "recent_keys"),
+                    window,
+                    cx,
+                    move |submenu, _window, cx| {
+                        let mut submenu = submenu;
+                        if recent_keys.is_empty() {
+                            submenu = submenu.label(i18n_key_tree(cx, "no_recent_keys"));
+                        } else {
+                            for key in &recent_keys {
+                                let key_clone = key.clone();
+                                submenu = submenu.menu_element(
+                                    Box::new(KeyTreeAction::SelectRecentKey(key.clone())),
+                                    move |_, _cx| Label::new(key_clone.clone()).text_ellipsis(),
+                                );
+                            }
+                            submenu = submenu.separator().menu_element_with_icon(
+                                CustomIconName::Eraser,
+                                Box::new(KeyTreeAction::ClearRecentKeys),
+                                move |_, cx| Label::new(i18n_key_tree(cx, "clear_recent_keys")),
+                            );
+                        }
+                        submenu
+                    },
+                )
                 .submenu_with_icon(
                     Some(Icon::new(IconName::Star)),
                     i18n_key_tree(cx, "favorite_keys"),
@@ -1167,6 +1805,7 @@ impl ZedisKeyTree {
             });
         let search_btn = Button::new("key-tree-search-btn")
             .ghost()
+            .tooltip(i18n_common(cx, "search_tooltip"))
             .loading(scanning)
             .disabled(scanning)
             .icon(IconName::Search)
@@ -1183,10 +1822,13 @@ impl ZedisKeyTree {
             .suffix(search_btn)
             .cleanable(true);
         let enabled_multiple_selection = self.key_tree_list_state.read(cx).delegate().enabled_multiple_selection;
+        let show_encoding = self.key_tree_list_state.read(cx).delegate().show_encoding;
+        let supports_encoding = self.server_state.read(cx).supports_key_encoding();
         let refresh_interval_sec = self.state.refresh_interval_sec;
 
         let more_dropdown = Button::new("key-tree-more-dropdown")
             .outline()
+            .tooltip(i18n_common(cx, "more_tooltip"))
             .icon(Icon::new(IconName::Ellipsis))
             .dropdown_menu_with_anchor(Corner::TopRight, move |menu, window, cx| {
                 menu.menu_element_with_icon(
@@ -1194,6 +1836,11 @@ impl ZedisKeyTree {
                     Box::new(KeyTreeAction::CollapseAllKeys),
                     move |_, cx| Label::new(i18n_key_tree(cx, "collapse_keys")),
                 )
+                .menu_element_with_icon(
+                    Icon::new(CustomIconName::ListChevronsUpDown),
+                    Box::new(KeyTreeAction::ExpandAllKeys),
+                    move |_, cx| Label::new(i18n_key_tree(cx, "expand_keys")),
+                )
                 .when(!readonly, |this| {
                     let icon = if enabled_multiple_selection {
                         Icon::new(IconName::Check)
@@ -1227,11 +1874,46 @@ impl ZedisKeyTree {
                         submenu
                     },
                 )
+                .when(supports_encoding, |this| {
+                    this.menu_element_with_check(
+                        show_encoding,
+                        Box::new(KeyTreeAction::ToggleKeyEncoding),
+                        move |_, cx| Label::new(i18n_key_tree(cx, "show_key_encoding")),
+                    )
+                })
                 .menu_element_with_icon(
                     Icon::new(CustomIconName::Rss),
                     Box::new(KeyTreeAction::ChangeChannelMode),
                     move |_, cx| Label::new(i18n_key_tree(cx, "pubsub_mode")),
                 )
+                .menu_element_with_icon(
+                    Icon::new(CustomIconName::Radar),
+                    Box::new(KeyTreeAction::SampleRandomKeys),
+                    move |_, cx| Label::new(i18n_key_tree(cx, "random_keys")),
+                )
+                .menu_element_with_icon(
+                    Icon::new(CustomIconName::FilePenLine),
+                    Box::new(KeyTreeAction::PasteKeyList),
+                    move |_, cx| Label::new(i18n_key_tree(cx, "paste_key_list")),
+                )
+                .menu_element_with_icon(
+                    Icon::new(CustomIconName::FileCheckCorner),
+                    Box::new(KeyTreeAction::LoadKeyListFile),
+                    move |_, cx| Label::new(i18n_key_tree(cx, "load_key_list_file")),
+                )
+                .separator()
+                .menu_element_with_icon(
+                    Icon::new(CustomIconName::HardDriveDownload),
+                    Box::new(KeyTreeAction::ExportDatabase),
+                    move |_, cx| Label::new(i18n_key_tree(cx, "export_database")),
+                )
+                .when(!readonly, |this| {
+                    this.menu_element_with_icon(
+                        Icon::new(CustomIconName::HardDriveUpload),
+                        Box::new(KeyTreeAction::ImportDatabase),
+                        move |_, cx| Label::new(i18n_key_tree(cx, "import_database")),
+                    )
+                })
             });
 
         h_flex()
@@ -1280,26 +1962,19 @@ impl Render for ZedisKeyTree {
         v_flex()
             .id("key-tree-container")
             .track_focus(&self.focus_handle)
+            .tab_group()
+            .tab_index(KEY_TREE_TAB_INDEX)
             .h_full()
             .w_full()
             .child(self.render_keyword_input(window, cx))
-            .child(self.render_tree(cx))
+            .child(div().flex_1().min_h_0().child(self.render_tree(cx)))
+            .when_some(self.render_key_list_notice(cx), |this, notice| this.child(notice))
+            .when_some(self.render_backup_progress(cx), |this, progress| this.child(progress))
+            .when(cx.global::<ZedisGlobalStore>().read(cx).scan_debug(), |this| {
+                this.child(self.render_scan_debug(cx))
+            })
             .on_action(cx.listener(|this, e: &QueryMode, _window, cx| {
-                let new_mode = *e;
-
-                let server_id = this.server_state.read(cx).server_id();
-                if let Ok(mut option) = get_session_option(server_id) {
-                    option.query_mode = Some(new_mode.to_string());
-                    save_session_option(server_id, option, cx);
-                }
-
-                // Step 1: Update server state with new query mode
-                this.server_state.update(cx, |state, cx| {
-                    state.set_query_mode(new_mode, cx);
-                });
-
-                // Step 2: Update local UI state
-                this.state.query_mode = new_mode;
+                this.change_query_mode(*e, cx);
             }))
             .on_action(cx.listener(|this, e: &KeyTreeAction, window, cx| match e {
                 KeyTreeAction::ChangeChannelMode => {
@@ -1311,16 +1986,16 @@ impl Render for ZedisKeyTree {
                     this.state.refresh_interval_sec = *interval;
                     this.start_auto_refresh(cx);
                     let server_id = this.server_state.read(cx).server_id();
-                    if let Ok(mut option) = get_session_option(server_id) {
-                        option.refresh_interval_sec = Some(*interval);
-                        save_session_option(server_id, option, cx);
-                    }
+                    save_session_option(server_id, |option| option.refresh_interval_sec = Some(*interval), cx);
                 }
                 KeyTreeAction::CollapseAllKeys => {
                     this.server_state.update(cx, |state, cx| {
                         state.collapse_all_keys(cx);
                     });
                 }
+                KeyTreeAction::ExpandAllKeys => {
+                    this.expand_all_keys(cx);
+                }
                 KeyTreeAction::ToggleMultiSelectMode => {
                     this.key_tree_list_state.update(cx, |state, cx| {
                         state.delegate_mut().toggle_multiple_selection(cx);
@@ -1335,9 +2010,50 @@ impl Render for ZedisKeyTree {
                 KeyTreeAction::Clear => {
                     this.handle_clear_history(cx);
                 }
-                KeyTreeAction::SelectFavoriteKey(key) => {
+                KeyTreeAction::ApplySavedSearch(name) => {
+                    this.apply_saved_search(name, window, cx);
+                }
+                KeyTreeAction::RemoveSavedSearch(name) => {
+                    let server_id = this.server_state.read(cx).server_id();
+                    save_session_option(server_id, |option| option.remove_saved_search(name), cx);
+                }
+                KeyTreeAction::SaveSearch => {
+                    this.handle_save_search(window, cx);
+                }
+                KeyTreeAction::ExportDatabase => {
+                    this.handle_export_database(cx);
+                }
+                KeyTreeAction::ImportDatabase => {
+                    this.handle_import_database(cx);
+                }
+                KeyTreeAction::SampleRandomKeys => {
+                    this.handle_sample_random_keys(window, cx);
+                }
+                KeyTreeAction::PasteKeyList => {
+                    this.handle_paste_key_list(window, cx);
+                }
+                KeyTreeAction::LoadKeyListFile => {
+                    this.handle_load_key_list_file(cx);
+                }
+                KeyTreeAction::SelectFavoriteKey(key) | KeyTreeAction::SelectRecentKey(key) => {
                     this.select_item(key.clone(), false, false, cx);
                 }
+                KeyTreeAction::ToggleKeyEncoding => {
+                    let enabled = !this.key_tree_list_state.read(cx).delegate().show_encoding;
+                    this.key_tree_list_state.update(cx, |state, cx| {
+                        state.delegate_mut().show_encoding = enabled;
+                        cx.notify();
+                    });
+                    update_app_state_and_save(cx, "save_key_encoding_enabled", move |state, _| {
+                        state.set_key_encoding_enabled(enabled);
+                    });
+                    this.fetch_key_encodings(cx);
+                }
+                KeyTreeAction::ClearRecentKeys => {
+                    this.server_state.update(cx, |state, cx| {
+                        state.clear_recent_keys(cx);
+                    });
+                }
                 KeyTreeAction::ClearFavorites => {
                     let server_id = this.server_state.read(cx).server_id().to_string();
                     cx.spawn(async move |_, cx| {
@@ -1393,8 +2109,17 @@ impl Render for ZedisKeyTree {
                     let server_state = this.server_state.clone();
                     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
                     let text = t!("key_tree.delete_folder_prompt", folder = id.clone(), locale = locale).to_string();
+                    // Dry run the SCAN so the deletion is confirmed against the actual keys
+                    server_state.update(cx, |state, cx| {
+                        let pattern = state.folder_pattern(&id, cx);
+                        state.preview_matching(pattern, BULK_PREVIEW_LIMIT, cx);
+                    });
+                    let preview = cx.new(|cx| ZedisBulkPreview::new(server_state.clone(), text.into(), cx));
 
-                    ZedisDialog::new_alert(i18n_key_tree(cx, "delete_folder_title"), text)
+                    ZedisDialog::new(i18n_key_tree(cx, "delete_folder_title"))
+                        .alert()
+                        .icon(IconName::Info)
+                        .child(move || preview.clone())
                         .button_props(dialog_button_props(cx))
                         .on_ok(move |_, _, cx| {
                             server_state.update(cx, |state, cx| {
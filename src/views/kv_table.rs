@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::constants::{EDITOR_KEY_BAR_HEIGHT, STATUS_BAR_HEIGHT};
+use crate::constants::{EDITOR_KEY_BAR_HEIGHT, FILTER_DEBOUNCE, STATUS_BAR_HEIGHT};
 use crate::helpers::get_font_family;
 use crate::{
     assets::CustomIconName,
     components::{INDEX_COLUMN_NAME, KvTableColumn, KvTableColumnType, KvTableMode, ZedisKvDelegate, ZedisKvFetcher},
-    helpers::{EditorAction, humanize_keystroke},
+    helpers::{EditorAction, format_duration, humanize_keystroke},
     states::{
-        KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common, i18n_kv_table,
-        i18n_list_editor,
+        HashFieldsError, KeyType, ServerEvent, StreamGroup, StreamPendingEntries, StreamTrimPreview,
+        StreamTrimStrategy, ZADD_FLAG_NAMES, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common,
+        i18n_hash_editor, i18n_kv_table, i18n_list_editor, i18n_stream_editor, i18n_zset_editor, parse_hash_fields,
+        update_app_state_and_save,
     },
 };
-use gpui::{Entity, SharedString, Subscription, TextAlign, Window, div, prelude::*, px};
+use gpui::{
+    App, ClipboardItem, Entity, Pixels, SharedString, Subscription, Task, TextAlign, Window, div, prelude::*, px,
+};
 use gpui_component::TITLE_BAR_HEIGHT;
 use gpui_component::highlighter::Language;
 use gpui_component::{
@@ -32,11 +36,14 @@ use gpui_component::{
     h_flex,
     input::{Escape, Input, InputEvent, InputState},
     label::Label,
+    notification::Notification,
+    scroll::ScrollableElement,
     table::{DataTable, TableEvent, TableState},
     v_flex,
 };
 use indexmap::IndexMap;
 use rust_i18n::t;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::info;
 use zedis_ui::{ZedisDialog, ZedisForm, ZedisFormField, ZedisFormFieldType, ZedisFormOptions};
@@ -46,6 +53,13 @@ const FOOTER_HEIGHT: f32 = 50.0;
 /// Width of the keyword search input field in pixels
 const KEYWORD_INPUT_WIDTH: f32 = 200.0;
 
+/// Form field of the hash batch edit dialog listing the fields to remove
+const HASH_REMOVE_FIELDS_NAME: &str = "zedis:remove_fields";
+
+/// Saved width ids of the row number and byte length columns, whose names are not stable
+const INDEX_COLUMN_ID: &str = "index";
+const LENGTH_COLUMN_ID: &str = "value_length";
+
 /// A generic table view for displaying Redis key-value data.
 ///
 /// This component handles:
@@ -58,6 +72,10 @@ pub struct ZedisKvTable<T: ZedisKvFetcher> {
     table_state: Entity<TableState<ZedisKvDelegate<T>>>,
     /// Input field state for keyword search/filter
     keyword_state: Entity<InputState>,
+    /// Keyword of the last applied filter
+    filter_keyword: SharedString,
+    /// Pending debounced filter, replaced on every keystroke
+    filter_task: Option<Task<()>>,
     /// Number of currently loaded items
     items_count: usize,
     /// Total number of items available
@@ -70,6 +88,8 @@ pub struct ZedisKvTable<T: ZedisKvFetcher> {
     key_changed: Option<bool>,
     /// Whether the table is readonly
     readonly: bool,
+    /// Whether the byte length column of the values is shown
+    show_value_length: bool,
     /// Supported operations mode (add, update, remove, filter)
     mode: KvTableMode,
     /// The row index that is being edited
@@ -89,6 +109,8 @@ pub struct ZedisKvTable<T: ZedisKvFetcher> {
     editor_form: Option<Entity<ZedisForm>>,
     /// Fetcher instance
     fetcher: Arc<T>,
+    /// Server state, used to follow new stream entries
+    server_state: Entity<ZedisServerState>,
     /// Event subscriptions for server state and input changes
     _subscriptions: Vec<Subscription>,
 }
@@ -104,9 +126,17 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
     /// # Logic:
     /// 1. Adds an index column at the start (80px, right-aligned)
     /// 2. Adds an action column at the end (100px, center-aligned)
-    /// 3. Calculates remaining space for columns without fixed widths
-    /// 4. Distributes remaining width evenly among flexible columns
-    fn new_columns(mut columns: Vec<KvTableColumn>, window: &Window, cx: &mut Context<Self>) -> Vec<KvTableColumn> {
+    /// 3. Appends the value length column when `value_length` is set
+    /// 4. Calculates remaining space for columns without fixed widths
+    /// 5. Distributes remaining width evenly among flexible columns
+    /// 6. Applies the widths the user resized for this key type
+    fn new_columns(
+        mut columns: Vec<KvTableColumn>,
+        value_length: bool,
+        key_type: KeyType,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<KvTableColumn> {
         // Calculate available width (window - sidebar - key tree - padding)
         let window_width = window.viewport_size().width;
 
@@ -116,11 +146,23 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             KvTableColumn {
                 column_type: KvTableColumnType::Index,
                 name: INDEX_COLUMN_NAME.to_string().into(),
+                id: Some(INDEX_COLUMN_ID.into()),
                 width: Some(80.),
                 align: Some(TextAlign::Right),
                 ..Default::default()
             },
         );
+        if value_length {
+            columns.push(KvTableColumn {
+                column_type: KvTableColumnType::Length,
+                name: i18n_kv_table(cx, "value_length"),
+                id: Some(LENGTH_COLUMN_ID.into()),
+                width: Some(100.),
+                align: Some(TextAlign::Right),
+                readonly: true,
+                ..Default::default()
+            });
+        }
 
         // Calculate remaining width and count columns without fixed width
         let content_width = cx
@@ -156,6 +198,18 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             }
         }
 
+        if let Some(widths) = cx
+            .global::<ZedisGlobalStore>()
+            .read(cx)
+            .column_widths(key_type.as_str())
+        {
+            for column in &mut columns {
+                if let Some(width) = column.id.as_ref().and_then(|id| widths.get(id.as_str())) {
+                    column.width = Some(*width);
+                }
+            }
+        }
+
         columns
     }
     /// Creates a new table view with the given columns and server state.
@@ -190,31 +244,52 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         let mut subscriptions = Vec::new();
 
         // Subscribe to server events to update table data
-        subscriptions.push(cx.subscribe(&server_state, |this, server_state, event, cx| {
-            match event {
-                // Update fetcher when data changes
-                ServerEvent::ValuePaginationFinished
-                | ServerEvent::ValueLoaded
-                | ServerEvent::ValueAdded
-                | ServerEvent::ValueUpdated => {
-                    let fetcher = Arc::new(Self::new_values(server_state.clone(), cx));
-                    this.fetcher = fetcher.clone();
-                    this.loading = false;
-                    this.done = fetcher.is_done();
-                    this.items_count = fetcher.rows_count();
-                    this.total_count = fetcher.count();
-                    this.table_state.update(cx, |state, _| {
-                        state.delegate_mut().set_fetcher(fetcher);
-                    });
-                }
-                // Clear search when key selection changes
-                ServerEvent::KeySelected(_) => {
-                    this.edit_row = None;
-                    this.key_changed = Some(true);
+        subscriptions.push(
+            cx.subscribe_in(&server_state, window, |this, server_state, event, window, cx| {
+                match event {
+                    // Update fetcher when data changes
+                    ServerEvent::ValuePaginationFinished
+                    | ServerEvent::ValueCellsLoaded
+                    | ServerEvent::ValueLoaded
+                    | ServerEvent::ValueAdded
+                    | ServerEvent::ValueUpdated
+                    | ServerEvent::StreamFollowed => {
+                        let fetcher = Arc::new(Self::new_values(server_state.clone(), cx));
+                        this.fetcher = fetcher.clone();
+                        this.loading = false;
+                        this.done = fetcher.is_done();
+                        this.items_count = fetcher.rows_count();
+                        this.total_count = fetcher.count();
+                        this.table_state.update(cx, |state, _| {
+                            state.delegate_mut().set_fetcher(fetcher.clone());
+                        });
+                        // Data-driven schemas may reveal new columns as more data loads
+                        if let Some(columns) = fetcher.columns() {
+                            this.update_columns(columns, window, cx);
+                        }
+                        // Followed streams stick to the newest entry
+                        if matches!(event, ServerEvent::StreamFollowed) {
+                            this.scroll_to_latest(cx);
+                        }
+                    }
+                    ServerEvent::StreamTrimPreviewed(preview) => {
+                        this.confirm_stream_trim(preview.clone(), window, cx);
+                    }
+                    ServerEvent::StreamGroupsLoaded(groups) => {
+                        this.select_stream_group(groups.clone(), window, cx);
+                    }
+                    ServerEvent::StreamPendingLoaded(pending) => {
+                        this.handle_stream_pending_entries(pending.clone(), window, cx);
+                    }
+                    // Clear search when key selection changes
+                    ServerEvent::KeySelected(_) => {
+                        this.edit_row = None;
+                        this.key_changed = Some(true);
+                    }
+                    _ => {}
                 }
-                _ => {}
-            }
-        }));
+            }),
+        );
 
         // Initialize keyword search input field
         let keyword_state = cx.new(|cx| {
@@ -223,11 +298,14 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
                 .placeholder(i18n_common(cx, "keyword_placeholder"))
         });
 
-        // Subscribe to input events to trigger search on Enter
-        subscriptions.push(cx.subscribe(&keyword_state, |this, _, event, cx| {
-            if matches!(event, InputEvent::PressEnter { .. }) {
+        // Filter immediately on Enter, or debounced while typing
+        subscriptions.push(cx.subscribe(&keyword_state, |this, _, event, cx| match event {
+            InputEvent::PressEnter { .. } => {
+                this.filter_task = None;
                 this.handle_filter(cx);
             }
+            InputEvent::Change => this.schedule_filter(cx),
+            _ => {}
         }));
 
         let readonly = server_state.read(cx).readonly();
@@ -240,12 +318,24 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         };
 
         // Initialize table data and state
-        let fetcher = Arc::new(Self::new_values(server_state, cx));
+        let fetcher = Arc::new(Self::new_values(server_state.clone(), cx));
         let done = fetcher.is_done();
         let items_count = fetcher.rows_count();
         let total_count = fetcher.count();
+        let show_value_length = cx.global::<ZedisGlobalStore>().read(cx).show_value_length();
+        let key_type = server_state
+            .read(cx)
+            .value()
+            .map(|value| value.key_type())
+            .unwrap_or_default();
         let delegate = ZedisKvDelegate::new(
-            Self::new_columns(columns.clone(), window, cx),
+            Self::new_columns(
+                columns.clone(),
+                show_value_length && fetcher.value_column().is_some(),
+                key_type,
+                window,
+                cx,
+            ),
             fetcher.clone(),
             window,
             cx,
@@ -261,36 +351,20 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             TableEvent::ClearSelection => {
                 this.edit_row = None;
             }
+            TableEvent::ColumnWidthsChanged(widths) => {
+                this.save_column_widths(widths, cx);
+            }
             _ => {}
         }));
 
-        let value_states = columns
-            .iter()
-            .enumerate()
-            .flat_map(|(index, column)| {
-                if column.column_type != KvTableColumnType::Value {
-                    return None;
-                }
-                let state = cx.new(|cx| {
-                    if column.readonly {
-                        InputState::new(window, cx)
-                    } else {
-                        InputState::new(window, cx)
-                            .code_editor(Language::from_str("json").name())
-                            .line_number(true)
-                            .indent_guides(true)
-                            .searchable(true)
-                            .soft_wrap(true)
-                    }
-                });
-                Some((index, state))
-            })
-            .collect::<Vec<_>>();
+        let value_states = Self::new_value_states(&columns, window, cx);
         info!("Creating new key value table view with mode: {:?}", mode);
 
         Self {
             table_state,
             keyword_state,
+            filter_keyword: SharedString::default(),
+            filter_task: None,
             items_count,
             total_count,
             done,
@@ -302,8 +376,10 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             values_modified: false,
             value_states,
             readonly,
+            show_value_length,
             mode,
             fetcher,
+            server_state,
             columns,
             editor_form: None,
             list_push_mode_state: cx.new(|_cx| 0),
@@ -311,6 +387,138 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         }
     }
 
+    /// Scrolls the table to the last loaded row.
+    fn scroll_to_latest(&mut self, cx: &mut Context<Self>) {
+        let rows_count = self.fetcher.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+        self.table_state.update(cx, |state, cx| {
+            state.scroll_to_row(rows_count - 1, cx);
+        });
+    }
+
+    /// Jumps to the newest entry, loading the stream tail first if only its first pages are loaded.
+    fn jump_to_latest(&mut self, cx: &mut Context<Self>) {
+        self.scroll_to_latest(cx);
+        self.server_state.update(cx, |state, cx| {
+            state.load_stream_tail(false, cx);
+        });
+    }
+
+    /// Creates input states for the editable value columns, keyed by column index.
+    fn new_value_states(
+        columns: &[KvTableColumn],
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<(usize, Entity<InputState>)> {
+        columns
+            .iter()
+            .enumerate()
+            .flat_map(|(index, column)| {
+                if column.column_type != KvTableColumnType::Value {
+                    return None;
+                }
+                let state = cx.new(|cx| {
+                    if column.readonly {
+                        InputState::new(window, cx)
+                    } else {
+                        InputState::new(window, cx)
+                            .code_editor(Language::from_str("json").name())
+                            .line_number(true)
+                            .indent_guides(true)
+                            .searchable(true)
+                            .soft_wrap(true)
+                    }
+                });
+                Some((index, state))
+            })
+            .collect()
+    }
+
+    /// Replaces the table columns if their names differ from the current ones.
+    ///
+    /// Existing columns keep their order, so rows already rendered stay aligned
+    /// while newly discovered columns are appended.
+    fn update_columns(&mut self, columns: Vec<KvTableColumn>, window: &mut Window, cx: &mut Context<Self>) {
+        let changed = self.columns.len() != columns.len()
+            || self
+                .columns
+                .iter()
+                .zip(columns.iter())
+                .any(|(current, column)| current.name != column.name);
+        if !changed {
+            return;
+        }
+        self.value_states = Self::new_value_states(&columns, window, cx);
+        let table_columns = Self::new_columns(
+            columns.clone(),
+            self.value_length_visible(),
+            self.key_type(cx),
+            window,
+            cx,
+        );
+        self.columns = columns;
+        self.editor_form = None;
+        self.table_state.update(cx, |state, cx| {
+            state.delegate_mut().set_columns(table_columns);
+            state.refresh(cx);
+        });
+    }
+
+    /// Returns the type of the displayed key.
+    fn key_type(&self, cx: &App) -> KeyType {
+        self.server_state
+            .read(cx)
+            .value()
+            .map(|value| value.key_type())
+            .unwrap_or_default()
+    }
+
+    /// Persists the column widths after the user resized a column, per key type.
+    fn save_column_widths(&mut self, widths: &[Pixels], cx: &mut Context<Self>) {
+        let key_type = self.key_type(cx);
+        if key_type == KeyType::Unknown {
+            return;
+        }
+        let ids = self.table_state.read(cx).delegate().column_ids();
+        let widths: HashMap<String, f32> = ids
+            .iter()
+            .zip(widths)
+            .filter_map(|(id, width)| Some((id.as_ref()?.to_string(), width.as_f32())))
+            .collect();
+        let key_type = key_type.as_str().to_string();
+        update_app_state_and_save(cx, "save_column_widths", move |state, _| {
+            state.set_column_widths(key_type, widths);
+        });
+    }
+
+    /// Whether the byte length column is shown, only collections provide a value column.
+    fn value_length_visible(&self) -> bool {
+        self.show_value_length && self.fetcher.value_column().is_some()
+    }
+
+    /// Shows or hides the byte length column and remembers the choice.
+    fn toggle_value_length(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.show_value_length = !self.show_value_length;
+        let enabled = self.show_value_length;
+        update_app_state_and_save(cx, "save_show_value_length", move |state, _| {
+            state.set_show_value_length(enabled);
+        });
+        let table_columns = Self::new_columns(
+            self.columns.clone(),
+            self.value_length_visible(),
+            self.key_type(cx),
+            window,
+            cx,
+        );
+        self.table_state.update(cx, |state, cx| {
+            state.delegate_mut().set_columns(table_columns);
+            state.refresh(cx);
+        });
+        cx.notify();
+    }
+
     /// Sets the operation mode for the table.
     ///
     /// This method allows you to customize which operations are available:
@@ -369,6 +577,104 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         self.editor_form = None;
         self.values_modified = false;
     }
+    /// Opens a dialog to stage several hash field changes and commit them in one atomic write.
+    fn handle_hash_batch_edit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.mode.contains(KvTableMode::ADD) {
+            return;
+        }
+        let fields = vec![
+            ZedisFormField::new(HASH_REMOVE_FIELDS_NAME, i18n_hash_editor(cx, "remove_fields"))
+                .placeholder(i18n_hash_editor(cx, "remove_fields_placeholder"))
+                .font_family(get_font_family())
+                .field_type(ZedisFormFieldType::AutoGrow(2, 6)),
+        ];
+        let server_state = self.server_state.clone();
+        ZedisFormOptions::new(fields)
+            .title(i18n_hash_editor(cx, "batch_edit_title"))
+            .description(i18n_hash_editor(cx, "batch_edit_description"))
+            .confirm_label(i18n_common(cx, "save"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .support_add_fields()
+            .add_field_placeholder(i18n_common(cx, "field_placeholder"))
+            .add_value_placeholder(i18n_common(cx, "value_placeholder"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let mut changes: Vec<(SharedString, Option<SharedString>)> = Vec::new();
+                for (name, value) in values.iter() {
+                    if name.as_ref() == HASH_REMOVE_FIELDS_NAME {
+                        changes.extend(
+                            value
+                                .lines()
+                                .map(|field| field.trim())
+                                .filter(|field| !field.is_empty())
+                                .map(|field| (field.to_string().into(), None)),
+                        );
+                    } else if !name.is_empty() {
+                        changes.push((name.clone(), Some(value.clone())));
+                    }
+                }
+                server_state.update(cx, |state, cx| {
+                    state.apply_hash_changes(changes, cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Opens a dialog to paste `field=value` lines or a JSON object, set with a single HSET.
+    fn handle_hash_paste_fields(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.mode.contains(KvTableMode::ADD) {
+            return;
+        }
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let hint_locale = locale.clone();
+        let fields = vec![
+            ZedisFormField::new("fields", i18n_hash_editor(cx, "paste_fields"))
+                .placeholder(i18n_hash_editor(cx, "paste_fields_placeholder"))
+                .font_family(get_font_family())
+                .field_type(ZedisFormFieldType::AutoGrow(8, 20))
+                .required()
+                .focus()
+                .validate(move |text| match parse_hash_fields(text) {
+                    Ok(_) => None,
+                    Err(HashFieldsError::Json(e)) => {
+                        Some(t!("hash_editor.paste_fields_invalid_json", error = e, locale = &locale).into())
+                    }
+                    Err(HashFieldsError::Lines(lines)) => {
+                        let lines = lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().join(", ");
+                        Some(
+                            t!(
+                                "hash_editor.paste_fields_invalid_lines",
+                                lines = lines,
+                                locale = &locale
+                            )
+                            .into(),
+                        )
+                    }
+                })
+                .hint(move |text| {
+                    let count = parse_hash_fields(text).ok()?.len();
+                    (count > 0)
+                        .then(|| t!("hash_editor.paste_fields_count", count = count, locale = &hint_locale).into())
+                }),
+        ];
+        let server_state = self.server_state.clone();
+        ZedisFormOptions::new(fields)
+            .title(i18n_hash_editor(cx, "paste_fields_title"))
+            .description(i18n_hash_editor(cx, "paste_fields_description"))
+            .confirm_label(i18n_common(cx, "save"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let text = values.get("fields").cloned().unwrap_or_default();
+                let Ok(fields) = parse_hash_fields(&text) else {
+                    return false;
+                };
+                let changes = fields.into_iter().map(|(field, value)| (field, Some(value))).collect();
+                server_state.update(cx, |state, cx| {
+                    state.apply_hash_changes(changes, cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
     fn handle_add_row(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // Only allow adding if ADD mode is enabled
         if !self.mode.contains(KvTableMode::ADD) {
@@ -408,12 +714,29 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         }
 
         let keyword = self.keyword_state.read(cx).value();
+        self.filter_keyword = keyword.clone();
         self.loading = true;
         self.table_state.update(cx, |state, cx| {
             state.delegate().fetcher().filter(keyword, cx);
         });
     }
 
+    /// Schedules a filter for the keyword being typed, canceling the pending one.
+    fn schedule_filter(&mut self, cx: &mut Context<Self>) {
+        if !self.mode.contains(KvTableMode::FILTER) {
+            return;
+        }
+        self.filter_task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(FILTER_DEBOUNCE).await;
+            let _ = this.update(cx, |this, cx| {
+                // Skip when the keyword has already been applied (e.g. via Enter)
+                if this.keyword_state.read(cx).value() != this.filter_keyword {
+                    this.handle_filter(cx);
+                }
+            });
+        }));
+    }
+
     fn handle_remove_row(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // Only allow removing if REMOVE mode is enabled
         if !self.mode.contains(KvTableMode::REMOVE) {
@@ -448,6 +771,258 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             })
             .open(window, cx);
     }
+    /// Lists the consumer groups of the stream, their pending entries are picked next.
+    fn handle_stream_pending(&mut self, cx: &mut Context<Self>) {
+        if self.readonly {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            state.load_stream_groups(cx);
+        });
+    }
+    /// Asks which consumer group to list the pending entries (XPENDING) of.
+    ///
+    /// A stream with a single group skips the question.
+    fn select_stream_group(&mut self, groups: Vec<StreamGroup>, window: &mut Window, cx: &mut Context<Self>) {
+        if let [group] = groups.as_slice() {
+            let group = group.name.clone();
+            self.server_state.update(cx, |state, cx| {
+                state.load_stream_pending(group, cx);
+            });
+            return;
+        }
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let options: Vec<SharedString> = groups
+            .iter()
+            .map(|group| {
+                t!(
+                    "stream_editor.group_option",
+                    group = group.name,
+                    consumers = group.consumers,
+                    pending = group.pending,
+                    locale = &locale
+                )
+                .to_string()
+                .into()
+            })
+            .collect();
+        let fields = vec![
+            ZedisFormField::new("group", i18n_stream_editor(cx, "group"))
+                .field_type(ZedisFormFieldType::RadioGroup)
+                .options(options)
+                .default_value("0"),
+        ];
+        let server_state = self.server_state.clone();
+        ZedisFormOptions::new(fields)
+            .title(i18n_stream_editor(cx, "pending_title"))
+            .description(i18n_stream_editor(cx, "group_description"))
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let Some(group) = values
+                    .get("group")
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .and_then(|index| groups.get(index))
+                else {
+                    return false;
+                };
+                let group = group.name.clone();
+                server_state.update(cx, |state, cx| {
+                    state.load_stream_pending(group, cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Lists the pending entries of a group to acknowledge (XACK) or claim (XCLAIM).
+    ///
+    /// Only entries that are pending can be picked, claiming also asks for the consumer
+    /// that takes them over.
+    fn handle_stream_pending_entries(
+        &mut self,
+        pending: StreamPendingEntries,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        let mut fields = vec![
+            ZedisFormField::new("action", i18n_stream_editor(cx, "pending_action"))
+                .field_type(ZedisFormFieldType::RadioGroup)
+                .options(vec![i18n_stream_editor(cx, "ack"), i18n_stream_editor(cx, "claim")])
+                .default_value("0"),
+            ZedisFormField::new("consumer", i18n_stream_editor(cx, "consumer"))
+                .placeholder(i18n_stream_editor(cx, "consumer_placeholder"))
+                .visible_on("action", &[1]),
+        ];
+        fields.extend(pending.entries.iter().enumerate().map(|(index, entry)| {
+            let detail = t!(
+                "stream_editor.pending_entry",
+                consumer = entry.consumer,
+                idle = format_duration(entry.idle),
+                deliveries = entry.deliveries,
+                locale = &locale
+            );
+            ZedisFormField::new(format!("entry-{index}"), entry.id.clone())
+                .placeholder(detail.to_string())
+                .field_type(ZedisFormFieldType::Checkbox)
+                .default_value("false")
+        }));
+        let description = t!(
+            "stream_editor.pending_description",
+            group = pending.group,
+            count = pending.entries.len(),
+            total = pending.total,
+            locale = &locale
+        );
+        let max_h = (window.bounds().size.height - px(300.0)).min(px(600.0));
+        let server_state = self.server_state.clone();
+        ZedisFormOptions::new(fields)
+            .title(i18n_stream_editor(cx, "pending_title"))
+            .description(description.to_string())
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .dialog_max_height(max_h)
+            .on_dialog_submit(move |values, window, cx| {
+                let ids: Vec<SharedString> = pending
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| {
+                        values
+                            .get(format!("entry-{index}").as_str())
+                            .is_some_and(|v| v == "true")
+                    })
+                    .map(|(_, entry)| entry.id.clone())
+                    .collect();
+                let claim = values.get("action").is_some_and(|v| v == "1");
+                let consumer: SharedString = values
+                    .get("consumer")
+                    .map(|v| v.trim().to_string())
+                    .unwrap_or_default()
+                    .into();
+                // Keep the form open until entries and, for a claim, the consumer are given
+                let missing = if ids.is_empty() {
+                    Some("pending_none_selected")
+                } else if claim && consumer.is_empty() {
+                    Some("consumer_required")
+                } else {
+                    None
+                };
+                if let Some(missing) = missing {
+                    let message = t!(format!("stream_editor.{missing}"), locale = &locale);
+                    window.push_notification(Notification::warning(message.to_string()), cx);
+                    return false;
+                }
+                let group = pending.group.clone();
+                server_state.update(cx, |state, cx| {
+                    if claim {
+                        state.claim_stream(group, consumer, ids, cx);
+                    } else {
+                        state.ack_stream(group, ids, cx);
+                    }
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Asks for the XTRIM strategy and threshold, then counts the entries it would remove.
+    fn handle_stream_trim(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.readonly {
+            return;
+        }
+        let strategies = [StreamTrimStrategy::MaxLen, StreamTrimStrategy::MinId];
+        let fields = vec![
+            ZedisFormField::new("strategy", i18n_stream_editor(cx, "trim_strategy"))
+                .field_type(ZedisFormFieldType::RadioGroup)
+                .options(
+                    ["trim_strategy_maxlen", "trim_strategy_minid"]
+                        .iter()
+                        .map(|key| i18n_stream_editor(cx, key))
+                        .collect(),
+                )
+                .default_value("0"),
+            ZedisFormField::new("threshold", i18n_stream_editor(cx, "trim_threshold"))
+                .placeholder(i18n_stream_editor(cx, "trim_threshold_placeholder"))
+                .required()
+                .focus(),
+        ];
+        let server_state = self.server_state.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        ZedisFormOptions::new(fields)
+            .title(i18n_stream_editor(cx, "trim_title"))
+            .description(i18n_stream_editor(cx, "trim_description"))
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, window, cx| {
+                let strategy = values
+                    .get("strategy")
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .and_then(|index| strategies.get(index).copied())
+                    .unwrap_or(StreamTrimStrategy::MaxLen);
+                let threshold = values
+                    .get("threshold")
+                    .map(|v| v.trim().to_string())
+                    .unwrap_or_default();
+                // Keep the form open on a threshold the strategy does not accept
+                if !strategy.is_valid_threshold(&threshold) {
+                    let message = t!(
+                        "stream_editor.trim_invalid_threshold",
+                        strategy = strategy.as_str(),
+                        locale = &locale
+                    );
+                    window.push_notification(Notification::warning(message.to_string()), cx);
+                    return false;
+                }
+                server_state.update(cx, |state, cx| {
+                    state.preview_stream_trim(strategy, threshold.into(), cx);
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+    /// Shows how many entries the trim removes and runs XTRIM once confirmed.
+    fn confirm_stream_trim(&mut self, preview: StreamTrimPreview, window: &mut Window, cx: &mut Context<Self>) {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale().to_string();
+        if preview.count == 0 {
+            let message = t!("stream_editor.trim_nothing", locale = &locale);
+            window.push_notification(Notification::info(message.to_string()), cx);
+            return;
+        }
+        let strategy = preview.strategy.as_str();
+        let message = if preview.complete {
+            t!(
+                "stream_editor.trim_prompt",
+                count = preview.count,
+                key = preview.key,
+                strategy = strategy,
+                threshold = preview.threshold,
+                locale = &locale
+            )
+        } else {
+            t!(
+                "stream_editor.trim_prompt_partial",
+                count = preview.count,
+                key = preview.key,
+                strategy = strategy,
+                threshold = preview.threshold,
+                locale = &locale
+            )
+        };
+        let server_state = self.server_state.clone();
+        ZedisDialog::new_alert(i18n_stream_editor(cx, "trim_title"), message.to_string())
+            .button_props(dialog_button_props(cx))
+            .on_ok(move |_, window, cx| {
+                server_state.update(cx, |state, cx| {
+                    // the user may have moved on to another key meanwhile
+                    if state.key().as_ref() == Some(&preview.key) {
+                        state.trim_stream_value(preview.strategy, preview.threshold.clone(), cx);
+                    }
+                });
+                window.close_dialog(cx);
+                true
+            })
+            .open(window, cx);
+    }
     fn enhance_handle_add_or_update_value(
         &mut self,
         data: IndexMap<SharedString, SharedString>,
@@ -513,6 +1088,20 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             );
             reset_form_height -= normal_field_height;
         }
+        // ZADD modifiers, appended after the member and score
+        let mut flag_fields = Vec::new();
+        if is_adding && self.fetcher.key_type() == KeyType::Zset {
+            for name in ZADD_FLAG_NAMES {
+                let key = format!("zadd_{}", name.to_lowercase());
+                flag_fields.push(
+                    ZedisFormField::new(name, name)
+                        .default_value("false")
+                        .placeholder(i18n_zset_editor(cx, &key))
+                        .field_type(ZedisFormFieldType::Checkbox),
+                );
+                reset_form_height -= normal_field_height;
+            }
+        }
 
         let mut flex_field_count = 0;
 
@@ -559,6 +1148,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             }
             fields.push(field);
         }
+        fields.extend(flag_fields);
         let submit_entity = cx.entity().clone();
         let cancel_entity = submit_entity.clone();
         let remove_entity = submit_entity.clone();
@@ -612,6 +1202,106 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         self.editor_form = Some(form.clone());
         form.clone()
     }
+    /// Renders all fields of the selected row as a vertical `field: value` list.
+    ///
+    /// Used instead of the edit form when the fetcher provides row details,
+    /// so rows with fields outside the column schema can still be inspected.
+    fn render_row_detail(
+        &self,
+        row_ix: usize,
+        fields: Vec<(SharedString, SharedString)>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let title = self
+            .fetcher
+            .get(row_ix, self.fetcher.primary_index())
+            .unwrap_or_default();
+        let can_remove = self.mode.contains(KvTableMode::REMOVE);
+        let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
+        let text_color = cx.theme().muted_foreground;
+        let border_color = cx.theme().border;
+        let field_color = cx.theme().primary;
+        let fields_count = fields.len();
+
+        let items = fields.into_iter().enumerate().map(|(index, (field, value))| {
+            let copied_message = copied_message.clone();
+            let copy_value = value.clone();
+            v_flex()
+                .w_full()
+                .gap_1()
+                .py_2()
+                .border_b_1()
+                .border_color(border_color)
+                .child(
+                    h_flex()
+                        .w_full()
+                        .child(Label::new(field).text_sm().text_color(field_color).flex_1())
+                        .child(
+                            Button::new(("kv-table-detail-copy", index))
+                                .ghost()
+                                .icon(IconName::Copy)
+                                .tooltip(copy_tooltip.clone())
+                                .on_click(move |_, window, cx| {
+                                    cx.write_to_clipboard(ClipboardItem::new_string(copy_value.to_string()));
+                                    window.push_notification(Notification::info(copied_message.clone()), cx);
+                                }),
+                        ),
+                )
+                .child(Label::new(value).font_family(get_font_family()).text_sm())
+        });
+
+        v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .pb_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        div()
+                            .flex_1()
+                            .w_0()
+                            .overflow_hidden()
+                            .child(Label::new(title).text_ellipsis().whitespace_nowrap()),
+                    )
+                    .child(
+                        Label::new(format!("{fields_count} {}", i18n_kv_table(cx, "fields")))
+                            .text_sm()
+                            .text_color(text_color),
+                    )
+                    .when(can_remove, |this| {
+                        this.child(
+                            Button::new("kv-table-detail-remove")
+                                .icon(CustomIconName::FileXCorner)
+                                .tooltip(i18n_common(cx, "remove"))
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.handle_remove_row(window, cx);
+                                })),
+                        )
+                    })
+                    .child(
+                        Button::new("kv-table-detail-close")
+                            .icon(IconName::Close)
+                            .tooltip(i18n_kv_table(cx, "close"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.edit_row = None;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .id("kv-table-row-detail")
+                    .flex_1()
+                    .w_full()
+                    .child(v_flex().w_full().children(items))
+                    .overflow_y_scrollbar(),
+            )
+    }
 }
 impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
@@ -621,6 +1311,8 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
             self.keyword_state.update(cx, |input, cx| {
                 input.set_value(SharedString::default(), window, cx);
             });
+            self.filter_keyword = SharedString::default();
+            self.filter_task = None;
         }
 
         // Determine if operations are allowed based on mode
@@ -638,6 +1330,11 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                 this.handle_filter(cx);
             }));
 
+        let is_stream = self.fetcher.key_type() == KeyType::Stream;
+        let is_hash = self.fetcher.key_type() == KeyType::Hash;
+        let stream_following = is_stream && self.server_state.read(cx).is_stream_following();
+        let scan_capped = !self.done && self.server_state.read(cx).value_scan_capped();
+
         // Completion indicator icon
         let status_icon = if self.done {
             Icon::new(CustomIconName::CircleCheckBig) // All data loaded
@@ -683,6 +1380,24 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                                                 })),
                                         )
                                     })
+                                    .when(can_add && is_hash, |this| {
+                                        this.child(
+                                            Button::new("kv-table-hash-batch-edit-btn")
+                                                .icon(CustomIconName::FilePenLine)
+                                                .tooltip(i18n_hash_editor(cx, "batch_edit_tooltip"))
+                                                .on_click(cx.listener(|this, _, window, cx| {
+                                                    this.handle_hash_batch_edit(window, cx);
+                                                })),
+                                        )
+                                        .child(
+                                            Button::new("kv-table-hash-paste-fields-btn")
+                                                .icon(CustomIconName::ClipboardPaste)
+                                                .tooltip(i18n_hash_editor(cx, "paste_fields_tooltip"))
+                                                .on_click(cx.listener(|this, _, window, cx| {
+                                                    this.handle_hash_paste_fields(window, cx);
+                                                })),
+                                        )
+                                    })
                                     .when(can_filter, |this| {
                                         this.child(
                                             Input::new(&self.keyword_state)
@@ -693,6 +1408,108 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                                     })
                                     .flex_1(),
                             )
+                            // Stream controls: pending entries, trim, jump to latest and auto-follow
+                            .when(is_stream, |this| {
+                                this.child(
+                                    h_flex()
+                                        .gap_1()
+                                        .mr_2()
+                                        .when(!self.readonly, |this| {
+                                            this.child(
+                                                Button::new("kv-table-pending-stream-btn")
+                                                    .ghost()
+                                                    .icon(CustomIconName::ListCheck)
+                                                    .tooltip(i18n_stream_editor(cx, "pending_tooltip"))
+                                                    .on_click(cx.listener(|this, _, _, cx| {
+                                                        this.handle_stream_pending(cx);
+                                                    })),
+                                            )
+                                            .child(
+                                                Button::new("kv-table-trim-stream-btn")
+                                                    .ghost()
+                                                    .icon(CustomIconName::Scissors)
+                                                    .tooltip(i18n_stream_editor(cx, "trim_tooltip"))
+                                                    .on_click(cx.listener(|this, _, window, cx| {
+                                                        this.handle_stream_trim(window, cx);
+                                                    })),
+                                            )
+                                        })
+                                        .child(
+                                            Button::new("kv-table-jump-latest-btn")
+                                                .ghost()
+                                                .icon(CustomIconName::ChevronsDown)
+                                                .tooltip(i18n_stream_editor(cx, "jump_to_latest"))
+                                                .on_click(cx.listener(|this, _, _, cx| {
+                                                    this.jump_to_latest(cx);
+                                                })),
+                                        )
+                                        .child(
+                                            Button::new("kv-table-follow-btn")
+                                                .map(
+                                                    |this| if stream_following { this.primary() } else { this.ghost() },
+                                                )
+                                                .icon(CustomIconName::Activity)
+                                                .tooltip(if stream_following {
+                                                    i18n_stream_editor(cx, "stop_follow")
+                                                } else {
+                                                    i18n_stream_editor(cx, "auto_follow")
+                                                })
+                                                .on_click(cx.listener(move |this, _, _, cx| {
+                                                    this.server_state.update(cx, |state, cx| {
+                                                        if stream_following {
+                                                            state.stop_stream_follow(cx);
+                                                        } else {
+                                                            state.start_stream_follow(cx);
+                                                        }
+                                                    });
+                                                    if !stream_following {
+                                                        this.scroll_to_latest(cx);
+                                                    }
+                                                })),
+                                        ),
+                                )
+                            })
+                            // Automatic loading stopped early, the server may ignore the COUNT hint
+                            .when(scan_capped, |this| {
+                                this.child(
+                                    Button::new("kv-table-continue-scan-btn")
+                                        .ghost()
+                                        .label(i18n_kv_table(cx, "still_scanning"))
+                                        .tooltip(i18n_kv_table(cx, "still_scanning_tooltip"))
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.fetcher.load_more(window, cx);
+                                        }))
+                                        .mr_2(),
+                                )
+                            })
+                            // Byte length column toggle for collections
+                            .when(self.fetcher.value_column().is_some(), |this| {
+                                this.child(
+                                    Button::new("kv-table-value-length-btn")
+                                        .map(|this| {
+                                            if self.show_value_length {
+                                                this.primary()
+                                            } else {
+                                                this.ghost()
+                                            }
+                                        })
+                                        .icon(CustomIconName::MemoryStick)
+                                        .tooltip(i18n_kv_table(cx, "value_length_tooltip"))
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.toggle_value_length(window, cx);
+                                        }))
+                                        .mr_2(),
+                                )
+                            })
+                            // Sorting only reorders the rows loaded so far
+                            .when(!self.done && self.columns.iter().any(|c| c.sortable), |this| {
+                                this.child(
+                                    Label::new(i18n_kv_table(cx, "sort_loaded_only"))
+                                        .text_xs()
+                                        .text_color(text_color)
+                                        .mr_2(),
+                                )
+                            })
                             // Right side: Status icon and count
                             .child(status_icon.text_color(text_color).mr_2())
                             .child(
@@ -704,6 +1521,16 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
             )
             // Right side: edit panel (full height)
             .when(self.edit_row.is_some(), |this| {
+                // Rows with details are inspected field by field instead of edited
+                let row_detail = self
+                    .edit_row
+                    .filter(|_| !self.is_adding_row())
+                    .and_then(|row_ix| self.fetcher.row_detail(row_ix).map(|fields| (row_ix, fields)));
+                let panel = if let Some((row_ix, fields)) = row_detail {
+                    self.render_row_detail(row_ix, fields, cx).into_any_element()
+                } else {
+                    self.enhance_render_edit_form(window, cx).into_any_element()
+                };
                 this.child(
                     div()
                         .id("kv-table-on-edit-overlay")
@@ -715,7 +1542,7 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                         .p_2()
                         .flex()
                         .flex_col()
-                        .child(self.enhance_render_edit_form(window, cx))
+                        .child(panel)
                         .on_click(cx.listener(|_this, _, _, cx| {
                             cx.stop_propagation();
                         })),
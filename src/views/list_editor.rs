@@ -16,10 +16,11 @@ use crate::{
     components::KvTableColumn,
     components::ZedisKvFetcher,
     helpers::fast_contains_ignore_case,
-    states::{KeyType, RedisValue, ZedisServerState},
+    states::{KeyType, RawBytesSource, RedisValue, ZedisServerState},
     views::{ZedisKvTable, kv_table::define_kv_editor},
 };
 use gpui::{App, Entity, SharedString, Window, prelude::*};
+use redis::cmd;
 use tracing::info;
 use zedis_ui::ZedisFormFieldType;
 
@@ -97,6 +98,22 @@ impl ZedisKvFetcher for ZedisListValues {
         }
     }
 
+    /// The length column shows the size of the item.
+    fn value_column(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    /// Items are read back with LINDEX at their real index.
+    fn raw_bytes_source(&self, row_ix: usize, _col_ix: usize, cx: &App) -> Option<RawBytesSource> {
+        let real_index = match &self.visible_item_indexes {
+            Some(indexes) => *indexes.get(row_ix)?,
+            None => row_ix,
+        };
+        let state = self.server_state.read(cx);
+        let key = state.key()?;
+        Some(state.raw_bytes_source(cmd("LINDEX").arg(key.as_str()).arg(real_index).clone()))
+    }
+
     /// Returns the total count of items in the Redis list (from LLEN).
     fn count(&self) -> usize {
         self.value.list_value().map_or(0, |v| v.size)
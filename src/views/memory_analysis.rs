@@ -16,14 +16,14 @@
 ///
 /// Samples keys from the database, groups by prefix and displays two tables:
 /// 1. Top 20 prefix groups by estimated memory (keys containing the separator)
-/// 2. Top 20 single keys by memory (keys without the separator)
+/// 2. Top N largest keys by memory (big-key finder, like `redis-cli --bigkeys`)
 use crate::assets::CustomIconName;
 use crate::connection::{KeyMemoryUsage, get_connection_manager};
 use crate::constants::SIDEBAR_WIDTH;
 use crate::error::Error;
 use crate::helpers::format_duration;
 use crate::states::{ZedisGlobalStore, ZedisServerState, i18n_common, i18n_memory_analysis};
-use gpui::{ClipboardItem, Edges, Entity, Pixels, SharedString, Subscription, Task, Window, div, prelude::*, px};
+use gpui::{App, ClipboardItem, Edges, Entity, Pixels, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::button::ButtonVariants;
 use gpui_component::input::{Input, InputEvent, InputState};
 use gpui_component::notification::Notification;
@@ -40,9 +40,13 @@ use std::time::{Duration, Instant};
 use tracing::{debug, error};
 use zedis_ui::ZedisDivider;
 
-/// Maximum rows kept per table.
+/// Maximum rows kept in the prefix table.
 const TOP_N: usize = 20;
 
+/// Default and maximum number of big keys kept in the single-key table.
+const DEFAULT_BIG_KEYS_LIMIT: usize = 20;
+const MAX_BIG_KEYS_LIMIT: usize = 500;
+
 /// Default table row height (Medium size in gpui-component).
 const TABLE_ROW_HEIGHT: f32 = 32.;
 
@@ -53,7 +57,7 @@ const DEFAULT_SCAN_COUNT: u64 = 100;
 
 /// Calculate the pixel height needed for a DataTable with the given row count.
 /// Includes 1 header row + data rows.
-fn table_height(row_count: usize) -> Pixels {
+pub(super) fn table_height(row_count: usize) -> Pixels {
     px(((row_count + 1) as f32) * TABLE_ROW_HEIGHT)
 }
 
@@ -110,7 +114,7 @@ const COL_TTL: &str = "ttl";
 
 // ─── Helpers ─────────────────────────────────────────────────────────────────
 
-fn make_paddings() -> Option<Edges<Pixels>> {
+pub(super) fn make_paddings() -> Option<Edges<Pixels>> {
     Some(Edges {
         top: px(2.),
         bottom: px(2.),
@@ -130,7 +134,7 @@ fn format_ttl(avg_secs: f64) -> String {
     format_duration(Duration::from_secs(avg_secs as u64))
 }
 
-fn format_thousands(n: u64) -> String {
+pub(super) fn format_thousands(n: u64) -> String {
     let s = n.to_string();
     let mut result = String::with_capacity(s.len() + s.len() / 3);
     for (i, c) in s.chars().enumerate() {
@@ -142,14 +146,16 @@ fn format_thousands(n: u64) -> String {
     result
 }
 
-fn render_copy_cell(
+pub(super) fn render_copy_cell(
     row_ix: usize,
     col_ix: usize,
     value: SharedString,
     column: &Column,
     id_prefix: &'static str,
-    copied_message: SharedString,
+    cx: &App,
 ) -> impl IntoElement {
+    let copied_message = i18n_common(cx, "copied_to_clipboard");
+    let copy_tooltip = i18n_common(cx, "copy_tooltip");
     // This is the only necessary string allocation.
     // It serves as a globally unique Group identifier for the hover state.
     let group_name: SharedString = format!("{id_prefix}-td-{row_ix}-{col_ix}").into();
@@ -183,6 +189,7 @@ fn render_copy_cell(
                     Button::new((group_name.clone(), 1_usize))
                         .ghost()
                         .icon(IconName::Copy)
+                        .tooltip(copy_tooltip)
                         .on_click(move |_, window, cx: &mut gpui::App| {
                             cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                             window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -326,14 +333,7 @@ impl TableDelegate for PrefixTableDelegate {
             .unwrap_or_else(|| "--".into());
 
         // Uses our highly optimized render_copy_cell function
-        render_copy_cell(
-            row_ix,
-            col_ix,
-            value,
-            col,
-            "prefix",
-            i18n_common(cx, "copied_to_clipboard"),
-        )
+        render_copy_cell(row_ix, col_ix, value, col, "prefix", cx)
     }
 
     fn has_more(&self, _cx: &gpui::App) -> bool {
@@ -457,14 +457,7 @@ impl TableDelegate for SingleKeyTableDelegate {
                 _ => "--".into(),
             })
             .unwrap_or_else(|| "--".into());
-        render_copy_cell(
-            row_ix,
-            col_ix,
-            value,
-            col,
-            "singlekey",
-            i18n_common(cx, "copied_to_clipboard"),
-        )
+        render_copy_cell(row_ix, col_ix, value, col, "singlekey", cx)
     }
 
     fn has_more(&self, _cx: &gpui::App) -> bool {
@@ -492,15 +485,15 @@ struct PrefixStats {
 }
 
 /// Keeps a capped top-N collection sorted by memory descending.
-struct TopN<T> {
-    items: Vec<T>,
+pub(super) struct TopN<T> {
+    pub(super) items: Vec<T>,
     limit: usize,
     /// Minimum memory_bytes in the current list (for fast rejection).
     min_memory: u64,
 }
 
 impl<T> TopN<T> {
-    fn new(limit: usize) -> Self {
+    pub(super) fn new(limit: usize) -> Self {
         Self {
             items: Vec::with_capacity(limit + 1),
             limit,
@@ -508,11 +501,11 @@ impl<T> TopN<T> {
         }
     }
 
-    fn should_insert(&self, memory_bytes: u64) -> bool {
+    pub(super) fn should_insert(&self, memory_bytes: u64) -> bool {
         self.items.len() < self.limit || memory_bytes > self.min_memory
     }
 
-    fn insert(&mut self, item: T, get_mem: impl Fn(&T) -> u64) {
+    pub(super) fn insert(&mut self, item: T, get_mem: impl Fn(&T) -> u64) {
         let val = get_mem(&item);
         if self.items.len() < self.limit || val > self.min_memory {
             let pos = self
@@ -620,6 +613,9 @@ pub struct ZedisMemoryAnalysis {
     /// User-editable scan count per round.
     scan_count: u64,
     scan_count_input_state: Entity<InputState>,
+    /// User-editable number of big keys to keep.
+    big_keys_limit: usize,
+    big_keys_limit_input_state: Entity<InputState>,
     /// Estimated Redis commands.
     est_commands: u64,
     _subscriptions: Vec<Subscription>,
@@ -636,6 +632,8 @@ impl ZedisMemoryAnalysis {
         let ratio_input_state = cx.new(|cx| InputState::new(window, cx).default_value("1".to_string()));
         let scan_count_input_state =
             cx.new(|cx| InputState::new(window, cx).default_value(DEFAULT_SCAN_COUNT.to_string()));
+        let big_keys_limit_input_state =
+            cx.new(|cx| InputState::new(window, cx).default_value(DEFAULT_BIG_KEYS_LIMIT.to_string()));
 
         let dbsize = server_state.read(cx).dbsize();
 
@@ -669,6 +667,21 @@ impl ZedisMemoryAnalysis {
             }),
         );
 
+        // Listen for top-N input changes to update big_keys_limit
+        subscriptions.push(cx.subscribe_in(
+            &big_keys_limit_input_state,
+            window,
+            |this, state, event, _window, cx| {
+                if let InputEvent::Change = event {
+                    let text = state.read(cx).value();
+                    if let Ok(v) = text.parse::<usize>() {
+                        this.big_keys_limit = v.clamp(1, MAX_BIG_KEYS_LIMIT);
+                        cx.notify();
+                    }
+                }
+            },
+        ));
+
         let mut this = Self {
             server_state,
             prefix_table,
@@ -684,6 +697,8 @@ impl ZedisMemoryAnalysis {
             ratio_dirty: false,
             scan_count: DEFAULT_SCAN_COUNT,
             scan_count_input_state,
+            big_keys_limit: DEFAULT_BIG_KEYS_LIMIT,
+            big_keys_limit_input_state,
             est_commands: 0,
             _subscriptions: subscriptions,
         };
@@ -711,7 +726,10 @@ impl ZedisMemoryAnalysis {
         cx.notify();
     }
 
-    fn start_analysis(&mut self, cx: &mut gpui::Context<Self>) {
+    /// Scans (or samples) the keyspace, pipelining `MEMORY USAGE` per batch, and keeps
+    /// the `limit` largest keys. Progress is reported after every batch, and dropping
+    /// the task via `stop_analysis` cancels the scan.
+    fn find_big_keys(&mut self, limit: usize, cx: &mut gpui::Context<Self>) {
         self.status = AnalysisStatus::Running;
         self.progress = "0%".into();
         self.prefix_count = 0;
@@ -734,7 +752,7 @@ impl ZedisMemoryAnalysis {
             debug!(dbsize, ratio, "Memory analysis: using sample ratio");
 
             let mut prefix_map: HashMap<String, PrefixStats> = HashMap::new();
-            let mut single_top: TopN<SingleKeyRow> = TopN::new(TOP_N);
+            let mut single_top: TopN<SingleKeyRow> = TopN::new(limit);
             let mut cursors: Option<Vec<u64>> = None;
             let mut analysis_count: u64 = 0;
             let redis_process_ratio = 0.5;
@@ -912,25 +930,40 @@ impl ZedisMemoryAnalysis {
                                     .disabled(is_running),
                             ),
                     )
-                    // Sample Ratio input
-                    .when_some(self.dbsize, |this, _| {
-                        this.child(
-                            h_flex()
-                                .gap_2()
-                                .items_center()
-                                .child(
-                                    Label::new(i18n_memory_analysis(cx, "sample_ratio"))
-                                        .text_color(cx.theme().muted_foreground)
-                                        .text_sm(),
-                                )
-                                .child(
-                                    Input::new(&self.ratio_input_state)
-                                        .small()
-                                        .w(px(70.))
-                                        .disabled(is_running),
-                                ),
-                        )
-                    })
+                    // Top N input
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                Label::new(i18n_memory_analysis(cx, "big_keys_limit"))
+                                    .text_color(cx.theme().muted_foreground)
+                                    .text_sm(),
+                            )
+                            .child(
+                                Input::new(&self.big_keys_limit_input_state)
+                                    .small()
+                                    .w(px(60.))
+                                    .disabled(is_running),
+                            ),
+                    )
+                    // Sample Ratio input, also usable when the key count is unknown (no DBSIZE permission)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                Label::new(i18n_memory_analysis(cx, "sample_ratio"))
+                                    .text_color(cx.theme().muted_foreground)
+                                    .text_sm(),
+                            )
+                            .child(
+                                Input::new(&self.ratio_input_state)
+                                    .small()
+                                    .w(px(70.))
+                                    .disabled(is_running),
+                            ),
+                    )
                     // Start / Stop Button
                     .child(if is_running {
                         Button::new("stop-analysis")
@@ -944,10 +977,10 @@ impl ZedisMemoryAnalysis {
                         Button::new("start-analysis")
                             .primary()
                             .small()
-                            .disabled(self.dbsize.is_none())
                             .label(i18n_memory_analysis(cx, "start"))
                             .on_click(cx.listener(|this, _, _window, cx| {
-                                this.start_analysis(cx);
+                                let limit = this.big_keys_limit;
+                                this.find_big_keys(limit, cx);
                             }))
                     }),
             )
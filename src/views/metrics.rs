@@ -13,38 +13,44 @@
 // limitations under the License.
 
 use crate::connection::get_server;
-use crate::states::{RedisMetrics, get_metrics_cache};
-use crate::states::{ZedisServerState, i18n_common, i18n_metrics};
-use chrono::{Local, LocalResult, TimeZone};
+use crate::helpers::{format_timestamp_ms, timestamp_date, unix_ts_millis};
+use crate::states::{MetricsSnapshot, RedisMetrics, get_metrics_cache};
+use crate::states::{ZedisGlobalStore, ZedisServerState, i18n_common, i18n_metrics};
 use core::f64;
 use gpui::{
     App, Background, Bounds, Entity, Hsla, Pixels, SharedString, Subscription, Task, TextAlign, Window, canvas, div,
     linear_color_stop, linear_gradient, prelude::*, px,
 };
-use gpui_component::h_flex;
+use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::plot::{
     AXIS_GAP, AxisText, Grid, PlotAxis, StrokeStyle,
     scale::{Scale, ScaleBand, ScaleLinear, ScalePoint},
     shape::{Area, Bar, Line},
 };
 use gpui_component::{ActiveTheme, StyledExt, label::Label, scroll::ScrollableElement, v_flex};
+use gpui_component::{IconName, Sizable, h_flex};
+use rust_i18n::t;
 use std::time::Duration;
-use zedis_ui::ZedisSkeletonLoading;
+use zedis_ui::{ZedisFormField, ZedisFormOptions, ZedisSkeletonLoading};
 
-const TIME_FORMAT: &str = "%H:%M:%S";
+const DATE_FORMAT: &str = "%m-%d";
 const CHART_CARD_HEIGHT: Pixels = px(300.);
 const HEARTBEAT_INTERVAL_SECS: u64 = 2;
+/// Heartbeats without any sample before the metrics are reported as unavailable
+const MAX_EMPTY_HEARTBEATS: usize = 3;
 const BYTES_TO_MB: f64 = 1_000_000.;
 const Y_LABEL_WIDTH: f32 = 45.;
 const Y_TICK_COUNT: usize = 4;
 
-struct ChartParams {
-    dates: Vec<SharedString>,
-    y_max: f64,
-    y_format: Box<dyn Fn(f64) -> String>,
-    tick_margin: usize,
-    border: Hsla,
-    muted_fg: Hsla,
+pub(super) struct ChartParams {
+    pub(super) dates: Vec<SharedString>,
+    pub(super) y_max: f64,
+    pub(super) y_format: Box<dyn Fn(f64) -> String>,
+    pub(super) tick_margin: usize,
+    pub(super) border: Hsla,
+    pub(super) muted_fg: Hsla,
+    /// Value drawn as a dashed horizontal line, e.g. the compared snapshot
+    pub(super) baseline: Option<f64>,
 }
 
 struct ChartFrame {
@@ -53,6 +59,8 @@ struct ChartFrame {
     y_grid: Vec<f32>,
     y_labels: Vec<AxisText>,
     border: Hsla,
+    /// y position and color of the baseline
+    baseline: Option<(f32, Hsla)>,
 }
 
 impl ChartFrame {
@@ -70,6 +78,14 @@ impl ChartFrame {
             .y_label(self.y_labels)
             .stroke(self.border)
             .paint(bounds, window, cx);
+
+        if let Some((y, color)) = self.baseline {
+            Grid::new()
+                .y(vec![y])
+                .stroke(color)
+                .dash_array(&[px(6.), px(3.)])
+                .paint(bounds, window);
+        }
     }
 }
 
@@ -150,23 +166,59 @@ struct MetricsChartData {
 }
 
 pub struct ZedisMetrics {
+    server_state: Entity<ZedisServerState>,
     title: SharedString,
     latest_metrics: Option<RedisMetrics>,
     metrics_chart_data: MetricsChartData,
     tick_margin: usize,
+    /// Snapshots of the server, oldest first
+    snapshots: Vec<MetricsSnapshot>,
+    /// Snapshot the current metrics are compared to
+    compare_snapshot: Option<MetricsSnapshot>,
+    /// Heartbeats in a row that found no sample, to tell "waiting for the
+    /// first sample" from "INFO returns nothing usable"
+    empty_heartbeats: usize,
     heartbeat_task: Option<Task<()>>,
     _subscriptions: Vec<Subscription>,
 }
 
-fn format_timestamp_ms(ts_ms: i64) -> SharedString {
-    match Local.timestamp_millis_opt(ts_ms) {
-        LocalResult::Single(dt) => dt.format(TIME_FORMAT).to_string().into(),
-        _ => "--".into(),
+/// Formats a signed difference with an explicit `+` sign.
+fn format_delta(value: f64, precision: usize, unit: &str) -> String {
+    let sign = if value >= 0. { "+" } else { "-" };
+    format!("{sign}{:.precision$}{unit}", value.abs())
+}
+
+/// Difference of a monotonically increasing counter, zero if the server restarted in between.
+fn counter_delta(current: u64, previous: u64) -> u64 {
+    current.saturating_sub(previous)
+}
+
+/// Returns the x-axis time format from the clock setting, prefixed with the date
+/// when enabled and the history spans more than one day.
+fn metrics_time_format(cx: &App, history_metrics: &[RedisMetrics]) -> String {
+    let store = cx.global::<ZedisGlobalStore>().read(cx);
+    let time_format = store.clock_format().time_format();
+    let utc = store.utc_time();
+    let date = |metrics: Option<&RedisMetrics>| metrics.and_then(|metrics| timestamp_date(metrics.timestamp_ms, utc));
+    let multi_day = date(history_metrics.first()) != date(history_metrics.last());
+    if store.metrics_show_date() && multi_day {
+        format!("{DATE_FORMAT} {time_format}")
+    } else {
+        time_format.to_string()
     }
 }
 
-fn convert_metrics_to_chart_data(history_metrics: Vec<RedisMetrics>) -> (MetricsChartData, usize) {
-    let mut prev_metrics = RedisMetrics::default();
+/// Converts the metrics history to chart data.
+///
+/// Rates are computed from the deltas between consecutive samples, `seed` is the
+/// sample preceding the history so that its first sample has a delta too.
+fn convert_metrics_to_chart_data(
+    history_metrics: Vec<RedisMetrics>,
+    seed: Option<RedisMetrics>,
+    time_format: &str,
+    utc: bool,
+) -> (MetricsChartData, usize) {
+    let mut prev_metrics = seed.unwrap_or_default();
     let n = history_metrics.len();
 
     let mut cpu_list = Vec::with_capacity(n);
@@ -212,7 +264,9 @@ fn convert_metrics_to_chart_data(history_metrics: Vec<RedisMetrics>) -> (Metrics
             continue;
         }
 
-        let date = format_timestamp_ms(metrics.timestamp_ms);
+        let date: SharedString = format_timestamp_ms(metrics.timestamp_ms, time_format, utc)
+            .unwrap_or_else(|| "--".to_string())
+            .into();
         let delta_time = (duration_ms as f64) / 1000.;
         let used_cpu_sys_percent = (metrics.used_cpu_sys - prev_metrics.used_cpu_sys) / delta_time * 100.;
         let used_cpu_user_percent = (metrics.used_cpu_user - prev_metrics.used_cpu_user) / delta_time * 100.;
@@ -413,6 +467,7 @@ fn make_area_canvas(params: ChartParams, series: Vec<(Vec<f64>, Hsla, Background
                 tick_margin,
                 border,
                 muted_fg,
+                baseline,
             } = &params;
             if dates.is_empty() {
                 return;
@@ -431,6 +486,7 @@ fn make_area_canvas(params: ChartParams, series: Vec<(Vec<f64>, Hsla, Background
                 y_grid,
                 y_labels,
                 border: *border,
+                baseline: baseline.and_then(|v| y.tick(&v)).map(|tick| (tick, *muted_fg)),
             }
             .paint(&bounds, window, cx);
 
@@ -464,6 +520,7 @@ fn make_line_canvas(params: ChartParams, values: Vec<f64>, stroke: Hsla, step_af
                 tick_margin,
                 border,
                 muted_fg,
+                baseline,
             } = &params;
             if dates.is_empty() {
                 return;
@@ -482,6 +539,7 @@ fn make_line_canvas(params: ChartParams, values: Vec<f64>, stroke: Hsla, step_af
                 y_grid,
                 y_labels,
                 border: *border,
+                baseline: baseline.and_then(|v| y.tick(&v)).map(|tick| (tick, *muted_fg)),
             }
             .paint(&bounds, window, cx);
 
@@ -503,7 +561,34 @@ fn make_line_canvas(params: ChartParams, values: Vec<f64>, stroke: Hsla, step_af
     .size_full()
 }
 
-fn make_bar_canvas(params: ChartParams, values: Vec<f64>, fill_color: Hsla) -> impl IntoElement {
+/// Axis-less line of the values scaled to the bounds, for inline trends.
+pub(super) fn make_sparkline_canvas(values: Vec<f64>, stroke: Hsla) -> impl IntoElement {
+    canvas(
+        |_, _, _| {},
+        move |bounds, _, window, _cx| {
+            if values.len() < 2 {
+                return;
+            }
+            let width = bounds.size.width.as_f32();
+            let height = bounds.size.height.as_f32();
+            let y_max = values.iter().copied().fold(0., f64::max).max(1.);
+            let x = ScaleLinear::new(vec![0., (values.len() - 1) as f64], vec![0., width]);
+            let y = ScaleLinear::new(vec![0., y_max], vec![height - 1., 1.]);
+            let data: Vec<(f64, f64)> = values.iter().copied().enumerate().map(|(i, v)| (i as f64, v)).collect();
+
+            Line::new()
+                .data(data)
+                .x(move |d: &(f64, f64)| x.tick(&d.0))
+                .y(move |d: &(f64, f64)| y.tick(&d.1))
+                .stroke(stroke)
+                .stroke_width(1.5)
+                .paint(&bounds, window);
+        },
+    )
+    .size_full()
+}
+
+pub(super) fn make_bar_canvas(params: ChartParams, values: Vec<f64>, fill_color: Hsla) -> impl IntoElement {
     canvas(
         |_, _, _| {},
         move |bounds, _, window, cx| {
@@ -514,6 +599,7 @@ fn make_bar_canvas(params: ChartParams, values: Vec<f64>, fill_color: Hsla) -> i
                 tick_margin,
                 border,
                 muted_fg,
+                baseline,
             } = &params;
             if dates.is_empty() {
                 return;
@@ -535,6 +621,7 @@ fn make_bar_canvas(params: ChartParams, values: Vec<f64>, fill_color: Hsla) -> i
                 y_grid,
                 y_labels,
                 border: *border,
+                baseline: baseline.and_then(|v| y.tick(&v)).map(|tick| (tick, *muted_fg)),
             }
             .paint(&bounds, window, cx);
 
@@ -556,31 +643,40 @@ fn make_bar_canvas(params: ChartParams, values: Vec<f64>, fill_color: Hsla) -> i
 impl ZedisMetrics {
     pub fn new(server_state: Entity<ZedisServerState>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
         let state = server_state.read(cx);
-        let server_id = state.server_id();
-        let name = if let Ok(server) = get_server(server_id) {
+        let server_id = state.server_id().to_string();
+        let name = if let Ok(server) = get_server(&server_id) {
             server.name
         } else {
             "--".to_string()
         };
         let nodes_description = state.nodes_description();
         let title = format!(
-            "{name} - {}({})",
-            nodes_description.server_type, nodes_description.master_nodes
+            "{name} - {} {}({})",
+            nodes_description.flavor.as_str(),
+            nodes_description.server_type,
+            nodes_description.master_nodes
         )
         .into();
-        let metrics_history = get_metrics_cache().list_metrics(server_id);
+        let metrics_history = get_metrics_cache().list_metrics(&server_id);
+        let seed = get_metrics_cache().seed_metrics(&server_id);
         let latest_metrics = metrics_history.last().copied();
-        let (metrics_chart_data, tick_margin) = convert_metrics_to_chart_data(metrics_history);
+        let time_format = metrics_time_format(cx, &metrics_history);
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
+        let (metrics_chart_data, tick_margin) = convert_metrics_to_chart_data(metrics_history, seed, &time_format, utc);
 
         let mut this = Self {
+            server_state,
             title,
             latest_metrics,
             metrics_chart_data,
             tick_margin,
+            snapshots: get_metrics_cache().list_snapshots(&server_id),
+            compare_snapshot: None,
+            empty_heartbeats: 0,
             heartbeat_task: None,
             _subscriptions: vec![],
         };
-        this.start_heartbeat(server_id.to_string(), cx);
+        this.start_heartbeat(server_id, cx);
         this
     }
     /// Start the heartbeat task
@@ -591,17 +687,36 @@ impl ZedisMetrics {
                 cx.background_executor()
                     .timer(Duration::from_secs(HEARTBEAT_INTERVAL_SECS))
                     .await;
-                let metrics_history = get_metrics_cache().list_metrics(&server_id);
                 let _ = this.update(cx, |state, cx| {
-                    state.latest_metrics = metrics_history.last().copied();
-                    let (metrics_chart_data, tick_margin) = convert_metrics_to_chart_data(metrics_history);
-                    state.metrics_chart_data = metrics_chart_data;
-                    state.tick_margin = tick_margin;
-                    cx.notify();
+                    state.refresh_metrics(&server_id, cx);
                 });
             }
         }));
     }
+    /// Rebuilds the chart data from the cached history.
+    fn refresh_metrics(&mut self, server_id: &str, cx: &mut Context<Self>) {
+        let metrics_history = get_metrics_cache().list_metrics(server_id);
+        let seed = get_metrics_cache().seed_metrics(server_id);
+        self.latest_metrics = metrics_history.last().copied();
+        if self.latest_metrics.is_some() {
+            self.empty_heartbeats = 0;
+        } else {
+            self.empty_heartbeats += 1;
+        }
+        let time_format = metrics_time_format(cx, &metrics_history);
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
+        let (metrics_chart_data, tick_margin) = convert_metrics_to_chart_data(metrics_history, seed, &time_format, utc);
+        self.metrics_chart_data = metrics_chart_data;
+        self.tick_margin = tick_margin;
+        cx.notify();
+    }
+    /// Clears the history of the server, the charts start again from the next sample.
+    fn clear_history(&mut self, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        get_metrics_cache().clear_server(&server_id);
+        self.refresh_metrics(&server_id, cx);
+        self.empty_heartbeats = 0;
+    }
     fn render_chart_card<E: IntoElement>(
         &self,
         cx: &mut Context<Self>,
@@ -633,10 +748,173 @@ impl ZedisMetrics {
             tick_margin: self.tick_margin,
             border: cx.theme().border,
             muted_fg: cx.theme().muted_foreground,
+            baseline: None,
+        }
+    }
+
+    /// Chart params with the compared snapshot's value drawn as the baseline.
+    ///
+    /// The y axis is extended so the baseline stays visible.
+    fn chart_params_with_baseline(
+        &self,
+        cx: &mut Context<Self>,
+        dates: Vec<SharedString>,
+        y_max: f64,
+        y_format: impl Fn(f64) -> String + 'static,
+        baseline: impl Fn(&RedisMetrics) -> f64,
+    ) -> ChartParams {
+        let baseline = self
+            .compare_snapshot
+            .as_ref()
+            .map(|snapshot| baseline(&snapshot.metrics));
+        let y_max = baseline.map_or(y_max, |value| y_max.max(value));
+        ChartParams {
+            baseline,
+            ..self.chart_params(cx, dates, y_max, y_format)
+        }
+    }
+
+    /// Appends the compared snapshot's name to a chart label.
+    fn compare_label(&self, label: String) -> String {
+        match &self.compare_snapshot {
+            Some(snapshot) => format!("{label} ({})", snapshot.name),
+            None => label,
         }
     }
 
-    fn render_stat_card(&self, cx: &mut Context<Self>, label: SharedString, value: String) -> impl IntoElement {
+    /// Captures the latest metrics as a named snapshot and compares to it.
+    fn handle_take_snapshot(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let time_format = store.clock_format().time_format();
+        let default_name = format_timestamp_ms(unix_ts_millis(), time_format, store.utc_time()).unwrap_or_default();
+        let fields = vec![
+            ZedisFormField::new("name", i18n_metrics(cx, "snapshot_name"))
+                .placeholder(i18n_metrics(cx, "snapshot_name_placeholder"))
+                .default_value(default_name)
+                .focus(),
+        ];
+        let view = cx.entity();
+        ZedisFormOptions::new(fields)
+            .title(i18n_metrics(cx, "take_snapshot"))
+            .description(i18n_metrics(cx, "take_snapshot_description"))
+            .confirm_label(i18n_common(cx, "confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let name = values.get("name").map(|v| v.trim().to_string()).unwrap_or_default();
+                if name.is_empty() {
+                    return false;
+                }
+                view.update(cx, |this, cx| {
+                    let snapshot = get_metrics_cache().take_snapshot(&server_id, name.into());
+                    this.snapshots = get_metrics_cache().list_snapshots(&server_id);
+                    if snapshot.is_some() {
+                        this.compare_snapshot = snapshot;
+                    }
+                    cx.notify();
+                });
+                true
+            })
+            .open_dialog(window, cx);
+    }
+
+    /// Compares to the snapshot, or stops comparing if it is already selected.
+    fn toggle_compare_snapshot(&mut self, snapshot: MetricsSnapshot, cx: &mut Context<Self>) {
+        let selected = self
+            .compare_snapshot
+            .as_ref()
+            .is_some_and(|item| item.name == snapshot.name);
+        self.compare_snapshot = if selected { None } else { Some(snapshot) };
+        cx.notify();
+    }
+
+    fn remove_snapshot(&mut self, name: SharedString, cx: &mut Context<Self>) {
+        let server_id = self.server_state.read(cx).server_id().to_string();
+        get_metrics_cache().remove_snapshot(&server_id, &name);
+        self.snapshots = get_metrics_cache().list_snapshots(&server_id);
+        if self.compare_snapshot.as_ref().is_some_and(|item| item.name == name) {
+            self.compare_snapshot = None;
+        }
+        cx.notify();
+    }
+
+    fn render_snapshots(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let selected_name = self.compare_snapshot.as_ref().map(|snapshot| snapshot.name.clone());
+        h_flex()
+            .col_span_full()
+            .px_2()
+            .gap_1()
+            .flex_wrap()
+            .items_center()
+            .child(
+                Button::new("metrics-take-snapshot")
+                    .small()
+                    .outline()
+                    .icon(IconName::Plus)
+                    .label(i18n_metrics(cx, "take_snapshot"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_take_snapshot(window, cx);
+                    })),
+            )
+            .children(self.snapshots.iter().enumerate().map(|(index, snapshot)| {
+                let selected = selected_name.as_ref() == Some(&snapshot.name);
+                let toggle_snapshot = snapshot.clone();
+                let name = snapshot.name.clone();
+                h_flex()
+                    .child(
+                        Button::new(("metrics-snapshot", index))
+                            .small()
+                            .map(|this| if selected { this.primary() } else { this.ghost() })
+                            .label(snapshot.name.clone())
+                            .tooltip(i18n_metrics(cx, "compare_snapshot_tooltip"))
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.toggle_compare_snapshot(toggle_snapshot.clone(), cx);
+                            })),
+                    )
+                    .child(
+                        Button::new(("metrics-remove-snapshot", index))
+                            .xsmall()
+                            .ghost()
+                            .icon(IconName::Close)
+                            .tooltip(i18n_metrics(cx, "remove_snapshot"))
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                this.remove_snapshot(name.clone(), cx);
+                            })),
+                    )
+            }))
+    }
+
+    /// Returns the configured decimal places of the chart labels, or the chart's default.
+    fn precision(&self, cx: &mut Context<Self>, default: usize) -> usize {
+        cx.global::<ZedisGlobalStore>()
+            .read(cx)
+            .metrics_precision()
+            .unwrap_or(default)
+    }
+
+    /// Formats the `min - max` range label of a chart.
+    fn range_label(
+        &self,
+        cx: &mut Context<Self>,
+        key: &str,
+        min: f64,
+        max: f64,
+        unit: &str,
+        precision: usize,
+    ) -> String {
+        format!(
+            "{}: {min:.precision$}{unit} - {max:.precision$}{unit}",
+            i18n_metrics(cx, key)
+        )
+    }
+
+    fn render_stat_card(
+        &self,
+        cx: &mut Context<Self>,
+        label: SharedString,
+        value: String,
+        delta: Option<String>,
+    ) -> impl IntoElement {
         let theme = cx.theme();
         v_flex()
             .flex_1()
@@ -646,6 +924,111 @@ impl ZedisMetrics {
             .p_4()
             .child(Label::new(label).text_sm().text_color(theme.muted_foreground))
             .child(Label::new(value).font_semibold())
+            .when_some(delta, |this, delta| {
+                this.child(Label::new(delta).text_xs().text_color(theme.muted_foreground))
+            })
+    }
+
+    /// Deltas of the stat cards since the compared snapshot, in card order.
+    ///
+    /// Gauges show the difference, counters (hit rate, evictions) what happened since the snapshot.
+    fn stat_deltas(&self, m: &RedisMetrics, cx: &mut Context<Self>) -> Option<[String; 8]> {
+        let s = self.compare_snapshot.as_ref()?.metrics;
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let memory_diff = m.used_memory as f64 - s.used_memory as f64;
+        let memory = format!(
+            "{}{}",
+            if memory_diff >= 0. { "+" } else { "-" },
+            humansize::format_size(
+                memory_diff.abs() as u64,
+                humansize::FormatSizeOptions::default().decimal_places(1)
+            )
+        );
+        let clients = format_delta(m.connected_clients as f64 - s.connected_clients as f64, 0, "");
+        let ops = format_delta(
+            m.instantaneous_ops_per_sec as f64 - s.instantaneous_ops_per_sec as f64,
+            0,
+            "",
+        );
+        let latency = format_delta(m.latency_ms as f64 - s.latency_ms as f64, 0, " ms");
+
+        let hits = counter_delta(m.keyspace_hits, s.keyspace_hits);
+        let total = hits + counter_delta(m.keyspace_misses, s.keyspace_misses);
+        let hit_rate = if total > 0 {
+            format!("{:.1}%", hits as f64 / total as f64 * 100.)
+        } else {
+            "--".to_string()
+        };
+        let hit_rate = t!("metrics.since_snapshot", value = hit_rate, locale = locale).to_string();
+        let net_in = format_delta(m.instantaneous_input_kbps - s.instantaneous_input_kbps, 1, "");
+        let net_out = format_delta(m.instantaneous_output_kbps - s.instantaneous_output_kbps, 1, "");
+        let evicted = t!(
+            "metrics.since_snapshot",
+            value = format!("+{}", counter_delta(m.evicted_keys, s.evicted_keys)),
+            locale = locale
+        )
+        .to_string();
+        Some([memory, clients, ops, latency, hit_rate, net_in, net_out, evicted])
+    }
+
+    /// Latency of every cluster node, a node twice as slow as the median (and at
+    /// least 5 ms slower) is highlighted.
+    fn render_node_latencies(&self, columns: u16, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let nodes = self.server_state.read(cx).node_latencies().to_vec();
+        if nodes.is_empty() {
+            return None;
+        }
+        let mut latencies: Vec<u64> = nodes.iter().filter_map(|node| node.latency_ms).collect();
+        latencies.sort_unstable();
+        let median = latencies.get(latencies.len() / 2).copied().unwrap_or_default();
+        let theme = cx.theme();
+        let (muted, warning, danger, border) = (theme.muted_foreground, theme.warning, theme.danger, theme.border);
+        let radius = theme.radius_lg;
+        let master = i18n_metrics(cx, "node_master");
+        let replica = i18n_metrics(cx, "node_replica");
+        let unreachable = i18n_metrics(cx, "node_unreachable");
+        Some(
+            v_flex()
+                .col_span_full()
+                .border_1()
+                .border_color(border)
+                .rounded(radius)
+                .p_4()
+                .gap_2()
+                .child(Label::new(i18n_metrics(cx, "node_latency")).text_sm().text_color(muted))
+                .child(
+                    div()
+                        .w_full()
+                        .grid()
+                        .gap_x_4()
+                        .gap_y_1()
+                        .grid_cols(columns * 3)
+                        .children(nodes.into_iter().map(|node| {
+                            let (latency, color) = match node.latency_ms {
+                                Some(ms) if ms >= median * 2 && ms >= median + 5 => (format!("{ms} ms"), Some(warning)),
+                                Some(ms) => (format!("{ms} ms"), None),
+                                None => (unreachable.to_string(), Some(danger)),
+                            };
+                            let role = if node.is_master {
+                                master.clone()
+                            } else {
+                                replica.clone()
+                            };
+                            h_flex()
+                                .gap_2()
+                                .text_sm()
+                                .child(div().flex_1().truncate().child(node.addr))
+                                .child(div().text_xs().text_color(muted).child(role))
+                                .child(
+                                    div()
+                                        .w(px(64.))
+                                        .text_right()
+                                        .when_some(color, |this, color| this.text_color(color))
+                                        .child(latency),
+                                )
+                        })),
+                ),
+        )
     }
 
     fn render_stat_cards(&self, columns: u16, cx: &mut Context<Self>) -> impl IntoElement {
@@ -662,7 +1045,13 @@ impl ZedisMetrics {
 
         let clients = format!("{} / {}", m.connected_clients, m.blocked_clients);
 
-        let ops = format!("{} ops/s", m.instantaneous_ops_per_sec);
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let ops = t!(
+            "metrics.ops_per_sec",
+            value = m.instantaneous_ops_per_sec,
+            locale = locale
+        )
+        .to_string();
 
         let latency = format!("{} ms", m.latency_ms);
 
@@ -673,35 +1062,63 @@ impl ZedisMetrics {
             "100%".to_string()
         };
 
-        let net_in = format!("{:.1} KB/s", m.instantaneous_input_kbps);
-        let net_out = format!("{:.1} KB/s", m.instantaneous_output_kbps);
+        let net_in = t!(
+            "metrics.kb_per_sec",
+            value = format!("{:.1}", m.instantaneous_input_kbps),
+            locale = locale
+        )
+        .to_string();
+        let net_out = t!(
+            "metrics.kb_per_sec",
+            value = format!("{:.1}", m.instantaneous_output_kbps),
+            locale = locale
+        )
+        .to_string();
 
         let evicted = m.evicted_keys.to_string();
 
+        let [
+            memory_delta,
+            clients_delta,
+            ops_delta,
+            latency_delta,
+            hit_rate_delta,
+            net_in_delta,
+            net_out_delta,
+            evicted_delta,
+        ] = match self.stat_deltas(&m, cx) {
+            Some(deltas) => deltas.map(Some),
+            None => Default::default(),
+        };
+
         div()
             .col_span_full()
             .w_full()
             .grid()
             .gap_2()
             .grid_cols(columns * 2)
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "memory"), memory))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "clients"), clients))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "ops"), ops))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "latency"), latency))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "hit_rate"), hit_rate))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "net_in"), net_in))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "net_out"), net_out))
-            .child(self.render_stat_card(cx, i18n_metrics(cx, "evicted_keys"), evicted))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "memory"), memory, memory_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "clients"), clients, clients_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "ops"), ops, ops_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "latency"), latency, latency_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "hit_rate"), hit_rate, hit_rate_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "net_in"), net_in, net_in_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "net_out"), net_out, net_out_delta))
+            .child(self.render_stat_card(cx, i18n_metrics(cx, "evicted_keys"), evicted, evicted_delta))
             .into_any_element()
     }
 
     fn render_cpu_usage_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.2}% - {:.2}%",
-            i18n_metrics(cx, "cpu_usage"),
+        let precision = self.precision(cx, 2);
+        let label = self.range_label(
+            cx,
+            "cpu_usage",
             self.metrics_chart_data.min_cpu_percent,
-            self.metrics_chart_data.max_cpu_percent
+            self.metrics_chart_data.max_cpu_percent,
+            "%",
+            precision,
         );
+        let axis_precision = self.precision(cx, 1);
         let dates: Vec<SharedString> = self.metrics_chart_data.cpu.iter().map(|d| d.date.clone()).collect();
         let sys_values: Vec<f64> = self
             .metrics_chart_data
@@ -720,7 +1137,7 @@ impl ZedisMetrics {
         let chart_2 = cx.theme().chart_2;
         let bg = cx.theme().background;
         let chart = make_area_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.1}%", v)),
+            self.chart_params(cx, dates, max_val, move |v| format!("{v:.axis_precision$}%")),
             vec![
                 (
                     sys_values,
@@ -746,18 +1163,28 @@ impl ZedisMetrics {
     }
 
     fn render_memory_usage_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0}MB - {:.0}MB",
-            i18n_metrics(cx, "memory_usage"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "memory_usage",
             self.metrics_chart_data.min_memory,
-            self.metrics_chart_data.max_memory
+            self.metrics_chart_data.max_memory,
+            "MB",
+            precision,
         );
+        let label = self.compare_label(label);
         let dates: Vec<SharedString> = self.metrics_chart_data.memory.iter().map(|d| d.date.clone()).collect();
         let values: Vec<f64> = self.metrics_chart_data.memory.iter().map(|d| d.used_memory).collect();
         let max_val = self.metrics_chart_data.max_memory.max(0.01);
         let fill_color = cx.theme().chart_2;
         let chart = make_bar_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params_with_baseline(
+                cx,
+                dates,
+                max_val,
+                move |v| format!("{v:.precision$}"),
+                |m| m.used_memory as f64 / BYTES_TO_MB,
+            ),
             values,
             fill_color,
         );
@@ -765,18 +1192,28 @@ impl ZedisMetrics {
     }
 
     fn render_latency_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0}ms - {:.0}ms",
-            i18n_metrics(cx, "latency"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "latency",
             self.metrics_chart_data.min_latency_ms,
-            self.metrics_chart_data.max_latency_ms
+            self.metrics_chart_data.max_latency_ms,
+            "ms",
+            precision,
         );
+        let label = self.compare_label(label);
         let dates: Vec<SharedString> = self.metrics_chart_data.latency.iter().map(|d| d.date.clone()).collect();
         let values: Vec<f64> = self.metrics_chart_data.latency.iter().map(|d| d.latency_ms).collect();
         let max_val = self.metrics_chart_data.max_latency_ms.max(0.01);
         let stroke = cx.theme().chart_2;
         let chart = make_line_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params_with_baseline(
+                cx,
+                dates,
+                max_val,
+                move |v| format!("{v:.precision$}"),
+                |m| m.latency_ms as f64,
+            ),
             values,
             stroke,
             false,
@@ -785,12 +1222,16 @@ impl ZedisMetrics {
     }
 
     fn render_connected_clients_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0} - {:.0}",
-            i18n_metrics(cx, "connected_clients"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "connected_clients",
             self.metrics_chart_data.min_connected_clients,
-            self.metrics_chart_data.max_connected_clients
+            self.metrics_chart_data.max_connected_clients,
+            "",
+            precision,
         );
+        let label = self.compare_label(label);
         let dates: Vec<SharedString> = self
             .metrics_chart_data
             .connected_clients
@@ -806,7 +1247,13 @@ impl ZedisMetrics {
         let max_val = self.metrics_chart_data.max_connected_clients.max(0.01);
         let stroke = cx.theme().chart_2;
         let chart = make_line_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params_with_baseline(
+                cx,
+                dates,
+                max_val,
+                move |v| format!("{v:.precision$}"),
+                |m| m.connected_clients as f64,
+            ),
             values,
             stroke,
             true,
@@ -815,11 +1262,14 @@ impl ZedisMetrics {
     }
 
     fn render_total_commands_processed_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0} - {:.0}",
-            i18n_metrics(cx, "total_commands_processed"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "total_commands_processed",
             self.metrics_chart_data.min_total_commands_processed,
-            self.metrics_chart_data.max_total_commands_processed
+            self.metrics_chart_data.max_total_commands_processed,
+            "",
+            precision,
         );
         let dates: Vec<SharedString> = self
             .metrics_chart_data
@@ -836,7 +1286,7 @@ impl ZedisMetrics {
         let max_val = self.metrics_chart_data.max_total_commands_processed.max(0.01);
         let stroke = cx.theme().chart_2;
         let chart = make_line_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params(cx, dates, max_val, move |v| format!("{v:.precision$}")),
             values,
             stroke,
             false,
@@ -845,12 +1295,16 @@ impl ZedisMetrics {
     }
 
     fn render_output_kbps_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0} - {:.0}",
-            i18n_metrics(cx, "output_kbps"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "output_kbps",
             self.metrics_chart_data.min_output_kbps,
-            self.metrics_chart_data.max_output_kbps
+            self.metrics_chart_data.max_output_kbps,
+            "",
+            precision,
         );
+        let label = self.compare_label(label);
         let dates: Vec<SharedString> = self
             .metrics_chart_data
             .output_kbps
@@ -866,18 +1320,27 @@ impl ZedisMetrics {
         let max_val = self.metrics_chart_data.max_output_kbps.max(0.01);
         let chart_2 = cx.theme().chart_2;
         let chart = make_area_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params_with_baseline(
+                cx,
+                dates,
+                max_val,
+                move |v| format!("{v:.precision$}"),
+                |m| m.instantaneous_output_kbps,
+            ),
             vec![(values, chart_2, chart_2.opacity(0.4).into())],
         );
         self.render_chart_card(cx, label, chart)
     }
 
     fn render_key_hit_rate_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0}% - {:.0}%",
-            i18n_metrics(cx, "key_hit_rate"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "key_hit_rate",
             self.metrics_chart_data.min_key_hit_rate,
-            self.metrics_chart_data.max_key_hit_rate
+            self.metrics_chart_data.max_key_hit_rate,
+            "%",
+            precision,
         );
         let dates: Vec<SharedString> = self
             .metrics_chart_data
@@ -894,7 +1357,7 @@ impl ZedisMetrics {
         let max_val = self.metrics_chart_data.max_key_hit_rate.max(0.01);
         let fill_color = cx.theme().chart_2;
         let chart = make_bar_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}%", v)),
+            self.chart_params(cx, dates, max_val, move |v| format!("{v:.precision$}%")),
             values,
             fill_color,
         );
@@ -902,11 +1365,14 @@ impl ZedisMetrics {
     }
 
     fn render_evicted_keys_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let label = format!(
-            "{}: {:.0} - {:.0}",
-            i18n_metrics(cx, "evicted_keys"),
+        let precision = self.precision(cx, 0);
+        let label = self.range_label(
+            cx,
+            "evicted_keys",
             self.metrics_chart_data.min_evicted_keys,
-            self.metrics_chart_data.max_evicted_keys
+            self.metrics_chart_data.max_evicted_keys,
+            "",
+            precision,
         );
         let dates: Vec<SharedString> = self
             .metrics_chart_data
@@ -923,7 +1389,7 @@ impl ZedisMetrics {
         let max_val = self.metrics_chart_data.max_evicted_keys.max(0.01);
         let chart_2 = cx.theme().chart_2;
         let chart = make_area_canvas(
-            self.chart_params(cx, dates, max_val, |v| format!("{:.0}", v)),
+            self.chart_params(cx, dates, max_val, move |v| format!("{v:.precision$}")),
             vec![(values, chart_2, chart_2.opacity(0.4).into())],
         );
         self.render_chart_card(cx, label, chart)
@@ -935,8 +1401,22 @@ impl Render for ZedisMetrics {
         let window_width = window.viewport_size().width;
         let columns = if window_width > px(1200.) { 2 } else { 1 };
         if self.latest_metrics.is_none() {
-            return ZedisSkeletonLoading::new()
-                .text(i18n_common(cx, "loading"))
+            if self.empty_heartbeats < MAX_EMPTY_HEARTBEATS {
+                return ZedisSkeletonLoading::new()
+                    .text(i18n_common(cx, "loading"))
+                    .into_any_element();
+            }
+            return v_flex()
+                .size_full()
+                .items_center()
+                .justify_center()
+                .gap_1()
+                .child(Label::new(i18n_metrics(cx, "no_metrics")))
+                .child(
+                    Label::new(i18n_metrics(cx, "no_metrics_hint"))
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground),
+                )
                 .into_any_element();
         }
         let time_range = if let Some(first) = self.metrics_chart_data.cpu.first()
@@ -947,6 +1427,12 @@ impl Render for ZedisMetrics {
             "".to_string()
         };
         let has_chart_data = !self.metrics_chart_data.cpu.is_empty();
+        let missing_fields = self
+            .server_state
+            .read(cx)
+            .redis_info()
+            .map(|info| info.meta.missing_fields.join(", "))
+            .unwrap_or_default();
         div()
             .size_full()
             .p_2()
@@ -966,9 +1452,32 @@ impl Render for ZedisMetrics {
                             .justify_between()
                             .px_2()
                             .child(Label::new(self.title.clone()))
-                            .child(Label::new(time_range)),
+                            .child(
+                                h_flex().gap_1().items_center().child(Label::new(time_range)).child(
+                                    Button::new("metrics-clear-history")
+                                        .xsmall()
+                                        .ghost()
+                                        .icon(IconName::Eraser)
+                                        .tooltip(i18n_metrics(cx, "clear_history"))
+                                        .on_click(cx.listener(|this, _, _window, cx| {
+                                            this.clear_history(cx);
+                                        })),
+                                ),
+                            ),
                     )
+                    .when(!missing_fields.is_empty(), |this| {
+                        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                        this.child(
+                            div().col_span_full().px_2().child(
+                                Label::new(t!("metrics.limited_metrics", fields = missing_fields, locale = locale))
+                                    .text_xs()
+                                    .text_color(cx.theme().warning),
+                            ),
+                        )
+                    })
+                    .child(self.render_snapshots(cx))
                     .child(self.render_stat_cards(columns, cx))
+                    .children(self.render_node_latencies(columns, cx))
                     .when(has_chart_data, |this| {
                         this.child(self.render_cpu_usage_chart(cx))
                             .child(self.render_memory_usage_chart(cx))
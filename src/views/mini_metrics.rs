@@ -0,0 +1,128 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::states::{ServerEvent, ZedisServerState, get_metrics_cache, i18n_metrics, update_app_state_and_save};
+use gpui::{Entity, SharedString, Subscription, Window, prelude::*};
+use gpui_component::{
+    ActiveTheme, IconName, Sizable,
+    button::{Button, ButtonVariants},
+    h_flex,
+    label::Label,
+};
+
+/// Formatted values of the latest metrics sample.
+struct MiniMetricsValues {
+    ops: SharedString,
+    memory: SharedString,
+    latency: SharedString,
+    clients: SharedString,
+}
+
+/// A thin strip showing vital server signs (ops/sec, memory, latency, clients)
+/// at the top of the editor, fed by the same metrics heartbeat as the metrics view.
+pub struct ZedisMiniMetrics {
+    server_state: Entity<ZedisServerState>,
+    values: Option<MiniMetricsValues>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisMiniMetrics {
+    pub fn new(server_state: Entity<ZedisServerState>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![
+            cx.subscribe(&server_state, |this, _server_state, event, cx| match event {
+                ServerEvent::ServerRedisInfoUpdated | ServerEvent::ServerSelected(_) => {
+                    this.update_values(cx);
+                }
+                _ => {}
+            }),
+        ];
+        let mut this = Self {
+            server_state,
+            values: None,
+            _subscriptions: subscriptions,
+        };
+        this.update_values(cx);
+        this
+    }
+
+    /// Reads the latest sample from the metrics cache and formats it for display.
+    fn update_values(&mut self, cx: &mut Context<Self>) {
+        let server_state = self.server_state.read(cx);
+        let maxmemory = server_state.redis_info().map(|info| info.meta.maxmemory).unwrap_or(0);
+        let Some(metrics) = get_metrics_cache().last_metrics(server_state.server_id()) else {
+            self.values = None;
+            cx.notify();
+            return;
+        };
+        // Show memory as a percentage only when maxmemory is configured
+        let memory = if maxmemory > 0 {
+            format!("{:.1}%", metrics.used_memory as f64 * 100.0 / maxmemory as f64)
+        } else {
+            humansize::format_size(
+                metrics.used_memory,
+                humansize::FormatSizeOptions::default().decimal_places(1),
+            )
+        };
+        self.values = Some(MiniMetricsValues {
+            ops: metrics.instantaneous_ops_per_sec.to_string().into(),
+            memory: memory.into(),
+            latency: format!("{}ms", metrics.latency_ms).into(),
+            clients: metrics.connected_clients.to_string().into(),
+        });
+        cx.notify();
+    }
+}
+
+impl Render for ZedisMiniMetrics {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let label_color = cx.theme().muted_foreground;
+        let value_color = cx.theme().foreground;
+        let item = |label: SharedString, value: SharedString| {
+            h_flex()
+                .gap_1()
+                .child(Label::new(label).text_xs().text_color(label_color))
+                .child(Label::new(value).text_xs().text_color(value_color))
+        };
+        let (ops, memory, latency, clients) = match &self.values {
+            Some(v) => (v.ops.clone(), v.memory.clone(), v.latency.clone(), v.clients.clone()),
+            None => Default::default(),
+        };
+        let or_empty = |value: SharedString| if value.is_empty() { "--".into() } else { value };
+
+        h_flex()
+            .w_full()
+            .gap_4()
+            .px_3()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(item(i18n_metrics(cx, "ops"), or_empty(ops)))
+            .child(item(i18n_metrics(cx, "memory"), or_empty(memory)))
+            .child(item(i18n_metrics(cx, "latency"), or_empty(latency)))
+            .child(item(i18n_metrics(cx, "clients"), or_empty(clients)))
+            .child(h_flex().flex_1())
+            .child(
+                Button::new("mini-metrics-hide")
+                    .ghost()
+                    .xsmall()
+                    .icon(IconName::Close)
+                    .tooltip(i18n_metrics(cx, "hide_mini_metrics"))
+                    .on_click(|_, _, cx| {
+                        update_app_state_and_save(cx, "save_mini_metrics_enabled", |state, _| {
+                            state.set_mini_metrics_enabled(false);
+                        });
+                    }),
+            )
+    }
+}
@@ -22,8 +22,8 @@ use crate::assets::CustomIconName;
 use crate::connection::{RedisServer, get_connection_manager, open_monitor_connection};
 use crate::constants::SIDEBAR_WIDTH;
 use crate::error::Error;
-use crate::states::{ServerEvent, ZedisServerState, i18n_common, i18n_monitor};
-use chrono::Local;
+use crate::helpers::format_timestamp_ms;
+use crate::states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_monitor};
 use futures::StreamExt;
 use gpui::{App, ClipboardItem, Edges, Entity, Render, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::button::ButtonVariants;
@@ -65,7 +65,7 @@ struct MonitorEntry {
 /// Parse a raw MONITOR output line into a `MonitorEntry`.
 ///
 /// Format: `+<unix_ts> [<db> <client_addr>] "<cmd>" "<arg1>" ...`
-fn parse_monitor_line(line: &str, node_label: &str) -> Option<MonitorEntry> {
+fn parse_monitor_line(line: &str, node_label: &str, utc: bool) -> Option<MonitorEntry> {
     // The RESP '+' prefix is already stripped by the redis library.
     // Skip preamble lines like "OK".
     let line = line.strip_prefix('+').unwrap_or(line);
@@ -80,8 +80,7 @@ fn parse_monitor_line(line: &str, node_label: &str) -> Option<MonitorEntry> {
     // Parse timestamp
     let ts_str = ts_part.trim();
     let timestamp: SharedString = if let Ok(secs) = ts_str.parse::<f64>() {
-        let dt = chrono::DateTime::from_timestamp(secs as i64, ((secs.fract()) * 1_000_000_000.0) as u32);
-        dt.map(|d| d.with_timezone(&Local).format("%H:%M:%S%.3f").to_string())
+        format_timestamp_ms((secs * 1000.0) as i64, "%H:%M:%S%.3f", utc)
             .unwrap_or_else(|| ts_str.to_string())
             .into()
     } else {
@@ -347,6 +346,7 @@ impl TableDelegate for MonitorTableDelegate {
 
         let group_name: SharedString = format!("monitor-td-{}-{}", row_ix, col_ix).into();
         let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
         h_flex()
             .size_full()
             .when_some(column.paddings, |this, paddings| this.paddings(paddings))
@@ -370,6 +370,7 @@ impl TableDelegate for MonitorTableDelegate {
                         Button::new(("copy-cell", row_ix * 100 + col_ix))
                             .ghost()
                             .icon(IconName::Copy)
+                            .tooltip(copy_tooltip.clone())
                             .on_click(move |_, window, cx: &mut gpui::App| {
                                 cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                                 window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -460,6 +461,7 @@ impl ZedisMonitor {
             return;
         }
         let db = self.server_state.read(cx).db();
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
 
         self.monitoring = true;
         cx.notify();
@@ -504,7 +506,7 @@ impl ZedisMonitor {
 
                     let mut stream = monitor.into_on_message::<String>();
                     while let Some(line) = stream.next().await {
-                        if let Some(entry) = parse_monitor_line(&line, &node_label)
+                        if let Some(entry) = parse_monitor_line(&line, &node_label, utc)
                             && tx.send(entry).await.is_err()
                         {
                             break;
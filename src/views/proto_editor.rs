@@ -18,8 +18,8 @@ use crate::db::{ProtoConfig, ProtoManager};
 use crate::error::Error;
 use crate::helpers::get_font_family;
 use crate::states::ZedisGlobalStore;
-use crate::states::i18n_proto_editor;
 use crate::states::{ZedisServerState, dialog_button_props};
+use crate::states::{i18n_common, i18n_proto_editor};
 use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::label::Label;
@@ -119,6 +119,8 @@ impl TableDelegate for ProtoTableDelegate {
         if col_ix == self.columns_count(cx) - 1 {
             let on_edit = self.on_edit.clone();
             let on_delete = self.on_delete.clone();
+            let edit_tooltip = i18n_common(cx, "edit_tooltip");
+            let delete_tooltip = i18n_common(cx, "delete");
             return div().size_full().flex().items_center().child(
                 h_flex()
                     .gap_2()
@@ -126,6 +128,7 @@ impl TableDelegate for ProtoTableDelegate {
                         Button::new("edit-proto-btn")
                             .icon(CustomIconName::FilePenLine)
                             .ghost()
+                            .tooltip(edit_tooltip)
                             .on_click(cx.listener(move |_this, _, window, cx| {
                                 (on_edit)(row_ix, window, cx);
                             })),
@@ -134,6 +137,7 @@ impl TableDelegate for ProtoTableDelegate {
                         Button::new("delete-proto-btn")
                             .icon(CustomIconName::X)
                             .ghost()
+                            .tooltip(delete_tooltip)
                             .on_click(cx.listener(move |_this, _, window, cx| {
                                 (on_delete)(row_ix, window, cx);
                             })),
@@ -20,7 +20,7 @@
 use crate::connection::get_connection_manager;
 use crate::error::Error;
 use crate::states::{ZedisGlobalStore, ZedisServerState, detect_and_decode, i18n_common, i18n_pubsub_editor};
-use chrono::Local;
+use chrono::{Local, Utc};
 use gpui::{ClipboardItem, Edges, Entity, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::button::ButtonVariants;
 use gpui_component::notification::Notification;
@@ -159,6 +159,7 @@ impl TableDelegate for PubsubTableDelegate {
         // Unique group name per cell so hover state is scoped correctly.
         let group_name: SharedString = format!("pubsub-td-{}-{}", row_ix, col_ix).into();
         let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
         h_flex()
             .size_full()
             .when_some(column.paddings, |this, paddings| this.paddings(paddings))
@@ -182,6 +183,7 @@ impl TableDelegate for PubsubTableDelegate {
                         Button::new(("copy-cell", row_ix * 100 + col_ix))
                             .ghost()
                             .icon(IconName::Copy)
+                            .tooltip(copy_tooltip.clone())
                             .on_click(move |_, window, cx: &mut gpui::App| {
                                 cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                                 window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -310,6 +312,7 @@ impl ZedisPubsubEditor {
 
         let server_state = self.server_state.read(cx);
         let server_id = server_state.server_id().to_string();
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
         self.subscribing = true;
         cx.notify();
 
@@ -351,7 +354,11 @@ impl ZedisPubsubEditor {
                                 let channel: String = msg.get_channel_name().to_string();
                                 let payload = msg.get_payload_bytes();
                                 let (_, text) = detect_and_decode(payload, 1024);
-                                let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+                                let timestamp = if utc {
+                                    Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+                                } else {
+                                    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+                                };
                                 // Update both the editor's own message list and the
                                 // table delegate's reference so the UI stays in sync.
                                 let result = entity.update(cx, move |this, cx| {
@@ -0,0 +1,143 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::assets::CustomIconName;
+use crate::helpers::{format_duration, get_font_family};
+use crate::states::{KeyType, ServerEvent, ZedisServerState, i18n_common, i18n_key_tree};
+use gpui::{Context, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Disableable, Sizable, WindowExt, h_flex, label::Label, v_flex};
+use std::time::Duration;
+
+/// Number of keys picked by a sampling
+pub const RANDOM_KEYS_COUNT: usize = 20;
+const ROW_HEIGHT: f32 = 26.;
+
+/// Random keys of the database with their type, size and TTL, to get a feel of
+/// an unfamiliar keyspace. Clicking a key selects it.
+pub struct ZedisRandomKeys {
+    server_state: Entity<ZedisServerState>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisRandomKeys {
+    pub fn new(server_state: Entity<ZedisServerState>, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![cx.subscribe(&server_state, |_this, _server_state, event, cx| {
+            if let ServerEvent::RandomKeysSampled = event {
+                cx.notify();
+            }
+        })];
+        Self {
+            server_state,
+            _subscriptions: subscriptions,
+        }
+    }
+}
+
+fn format_ttl(ttl: i64) -> SharedString {
+    if ttl < 0 {
+        return "Perm".into();
+    }
+    format_duration(Duration::from_secs(ttl as u64)).into()
+}
+
+impl Render for ZedisRandomKeys {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_hover;
+        let state = self.server_state.read(cx);
+        let sampling = state.sampling_random_keys();
+        let keys = state.random_keys().to_vec();
+        let font_family = get_font_family();
+
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        Label::new(i18n_key_tree(cx, "random_keys_hint"))
+                            .text_xs()
+                            .text_color(muted),
+                    )
+                    .child(
+                        Button::new("random-keys-resample")
+                            .small()
+                            .ghost()
+                            .icon(CustomIconName::RotateCw)
+                            .label(i18n_key_tree(cx, "random_keys_resample"))
+                            .disabled(sampling)
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.server_state.update(cx, |state, cx| {
+                                    state.sample_random_keys(RANDOM_KEYS_COUNT, cx);
+                                });
+                            })),
+                    ),
+            )
+            .when(sampling, |this| {
+                this.child(Label::new(i18n_common(cx, "loading")).text_sm().text_color(muted))
+            })
+            .when(!sampling && keys.is_empty(), |this| {
+                this.child(
+                    Label::new(i18n_key_tree(cx, "random_keys_empty"))
+                        .text_sm()
+                        .text_color(muted),
+                )
+            })
+            .when(!sampling, |this| {
+                this.children(keys.into_iter().enumerate().map(|(index, item)| {
+                    let key_type = KeyType::from(item.key_type.as_str());
+                    let size = humansize::format_size(
+                        item.memory_usage,
+                        humansize::FormatSizeOptions::default().decimal_places(1),
+                    );
+                    let key = item.key.clone();
+                    h_flex()
+                        .id(("random-key", index))
+                        .h(px(ROW_HEIGHT))
+                        .px_1()
+                        .gap_3()
+                        .items_center()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .hover(|this| this.bg(hover_bg))
+                        .text_sm()
+                        .child(
+                            div()
+                                .w(px(48.))
+                                .text_xs()
+                                .text_color(key_type.color())
+                                .child(key_type.as_str()),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .truncate()
+                                .font_family(font_family.clone())
+                                .child(item.key.clone()),
+                        )
+                        .child(div().w(px(80.)).text_color(muted).child(size))
+                        .child(div().w(px(80.)).text_color(muted).child(format_ttl(item.ttl)))
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.select_key(key.clone(), cx);
+                            });
+                            window.close_dialog(cx);
+                        }))
+                }))
+            })
+    }
+}
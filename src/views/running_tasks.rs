@@ -0,0 +1,118 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{format_duration, get_font_family};
+use crate::states::{ServerEvent, ZedisServerState, i18n_status_bar};
+use gpui::{Context, Entity, Subscription, Task, Window, div, prelude::*, px};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, IconName, Sizable, h_flex, label::Label, v_flex};
+use std::time::Duration;
+
+const REFRESH_INTERVAL_MS: u64 = 1000;
+const ROW_HEIGHT: f32 = 26.;
+
+/// Background tasks of the server that have not finished yet, with how long they
+/// have been running. Reads can be cancelled, writes can not.
+pub struct ZedisRunningTasks {
+    server_state: Entity<ZedisServerState>,
+    _refresh_task: Task<()>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisRunningTasks {
+    pub fn new(server_state: Entity<ZedisServerState>, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![cx.subscribe(&server_state, |_this, _server_state, event, cx| {
+            if let ServerEvent::TaskStarted(_) | ServerEvent::TaskFinished(_) = event {
+                cx.notify();
+            }
+        })];
+        // refresh the elapsed time of long running tasks
+        let refresh_task = cx.spawn(async move |handle, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_millis(REFRESH_INTERVAL_MS))
+                    .await;
+                if handle.update(cx, |_this, cx| cx.notify()).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            server_state,
+            _refresh_task: refresh_task,
+            _subscriptions: subscriptions,
+        }
+    }
+}
+
+impl Render for ZedisRunningTasks {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let tasks = self.server_state.read(cx).running_tasks().to_vec();
+        let font_family = get_font_family();
+
+        v_flex()
+            .w_full()
+            .gap_2()
+            .child(
+                Label::new(i18n_status_bar(cx, "running_tasks_hint"))
+                    .text_xs()
+                    .text_color(muted),
+            )
+            .when(tasks.is_empty(), |this| {
+                this.child(
+                    Label::new(i18n_status_bar(cx, "running_tasks_empty"))
+                        .text_sm()
+                        .text_color(muted),
+                )
+            })
+            .children(tasks.into_iter().map(|item| {
+                let id = item.id;
+                let elapsed = item.started_at.elapsed();
+                let elapsed = if elapsed.as_secs() == 0 {
+                    format!("{}ms", elapsed.as_millis())
+                } else {
+                    format_duration(elapsed)
+                };
+                h_flex()
+                    .h(px(ROW_HEIGHT))
+                    .px_1()
+                    .gap_3()
+                    .items_center()
+                    .text_sm()
+                    .child(
+                        div()
+                            .flex_1()
+                            .truncate()
+                            .font_family(font_family.clone())
+                            .child(item.task.as_str()),
+                    )
+                    .child(div().w(px(80.)).text_color(muted).child(elapsed))
+                    .child(div().w(px(24.)).when(item.task.is_cancellable(), |this| {
+                        this.child(
+                            Button::new(("running-task-cancel", id as usize))
+                                .xsmall()
+                                .ghost()
+                                .icon(IconName::Close)
+                                .tooltip(i18n_status_bar(cx, "cancel_task_tooltip"))
+                                .on_click(cx.listener(move |this, _, _window, cx| {
+                                    this.server_state.update(cx, |state, _cx| {
+                                        state.cancel_task(id);
+                                    });
+                                })),
+                        )
+                    }))
+            }))
+    }
+}
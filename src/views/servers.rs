@@ -12,22 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::metrics::make_sparkline_canvas;
 use crate::assets::CustomIconName;
-use crate::connection::{RedisServer, get_servers, open_single_connection};
+use crate::connection::{RedisServer, SERVER_COLORS, get_servers, open_single_connection};
 use crate::error::Error;
+use crate::helpers::server_accent_color;
 use crate::states::{
-    GlobalEvent, NotificationAction, Route, ZedisGlobalStore, dialog_button_props, i18n_common, i18n_servers,
+    GlobalEvent, NotificationAction, Route, ServerHealth, ZedisGlobalStore, check_server_health, dialog_button_props,
+    i18n_common, i18n_servers,
 };
-use gpui::{SharedString, Subscription, Window, div, prelude::*, px};
+use gpui::{App, ClipboardItem, Hsla, SharedString, Subscription, Task, Window, div, prelude::*, px};
 use gpui_component::{
-    ActiveTheme, Colorize, Icon, IconName, WindowExt,
+    ActiveTheme, Colorize, Disableable, Icon, IconName, Sizable, WindowExt,
     button::{Button, ButtonVariants},
+    h_flex,
     label::Label,
+    tooltip::Tooltip,
+    v_flex,
 };
+use humansize::{DECIMAL, format_size};
 use redis::cmd;
 use rust_i18n::t;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
 use substring::Substring;
-use tracing::info;
+use tracing::{error, info};
 use zedis_ui::ZedisCard;
 use zedis_ui::ZedisDialog;
 use zedis_ui::{ZedisFormField, ZedisFormFieldType, ZedisFormOptions};
@@ -39,6 +48,18 @@ const VIEWPORT_BREAKPOINT_MEDIUM: f32 = 1200.0; // Two columns
 const UPDATED_AT_SUBSTRING_LENGTH: usize = 10; // Length of date string to display
 const THEME_LIGHTEN_AMOUNT_DARK: f32 = 1.0;
 const THEME_DARKEN_AMOUNT_LIGHT: f32 = 0.02;
+const AUTO_REFRESH_INTERVAL_SECS: u64 = 10;
+const SPARKLINE_SAMPLES: usize = 30;
+const SPARKLINE_WIDTH: f32 = 60.0;
+const SPARKLINE_HEIGHT: f32 = 14.0;
+
+/// Health check state of a configured server
+#[derive(Debug, Clone)]
+enum HealthStatus {
+    Checking,
+    Healthy(ServerHealth),
+    Unreachable(SharedString),
+}
 
 /// Server management view component
 ///
@@ -51,6 +72,16 @@ const THEME_DARKEN_AMOUNT_LIGHT: f32 = 0.02;
 /// Uses a responsive grid layout that adjusts columns based on viewport width.
 pub struct ZedisServers {
     should_popup_new_server: bool,
+    /// Health of each server by id, filled as the checks complete
+    health: HashMap<String, HealthStatus>,
+    /// Recent latencies of each server for the sparkline, kept apart from the metrics view history
+    latencies: HashMap<String, VecDeque<f64>>,
+    /// Number of health checks still in flight
+    pending_checks: usize,
+    /// Whether the periodic health refresh is paused
+    auto_refresh_paused: bool,
+    _health_tasks: Vec<Task<()>>,
+    _refresh_task: Option<Task<()>>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -64,15 +95,21 @@ impl ZedisServers {
         let global_state = cx.global::<ZedisGlobalStore>().state();
         let mut subscriptions = Vec::new();
         subscriptions.push(cx.subscribe(&global_state, |this, state, event, cx| {
-            if let GlobalEvent::RouteChanged(Route::Home) = event
-                && state
-                    .read(cx)
-                    .get_route_query()
-                    .map(|query| query.contains_key("new"))
-                    .unwrap_or(false)
-            {
-                this.should_popup_new_server = true;
-                cx.notify();
+            match event {
+                GlobalEvent::RouteChanged(Route::Home)
+                    if state
+                        .read(cx)
+                        .get_route_query()
+                        .map(|query| query.contains_key("new"))
+                        .unwrap_or(false) =>
+                {
+                    this.should_popup_new_server = true;
+                    cx.notify();
+                }
+                GlobalEvent::ServerListUpdated => {
+                    this.check_all_servers(cx);
+                }
+                _ => {}
             }
         }));
         if let Some(query) = global_state.read(cx).get_route_query()
@@ -90,9 +127,148 @@ impl ZedisServers {
             });
         }
 
-        Self {
+        let mut this = Self {
             should_popup_new_server: false,
+            health: HashMap::new(),
+            latencies: HashMap::new(),
+            pending_checks: 0,
+            auto_refresh_paused: false,
+            _health_tasks: Vec::new(),
+            _refresh_task: None,
             _subscriptions: subscriptions,
+        };
+        this.check_all_servers(cx);
+        this.start_auto_refresh(cx);
+        this
+    }
+    /// Re-checks all servers on a timer while the dashboard is open, unless paused.
+    fn start_auto_refresh(&mut self, cx: &mut Context<Self>) {
+        self._refresh_task = Some(cx.spawn(async move |handle, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS))
+                    .await;
+                let result = handle.update(cx, |this, cx| {
+                    if !this.auto_refresh_paused && this.pending_checks == 0 {
+                        this.check_all_servers(cx);
+                    }
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+    fn toggle_auto_refresh(&mut self, cx: &mut Context<Self>) {
+        self.auto_refresh_paused = !self.auto_refresh_paused;
+        cx.notify();
+    }
+    /// Concurrently pings every configured server and records its health as the results arrive.
+    ///
+    /// Known servers keep their last status until the new result arrives, so a refresh doesn't flicker.
+    fn check_all_servers(&mut self, cx: &mut Context<Self>) {
+        let servers = get_servers().unwrap_or_default();
+        self.health
+            .retain(|server_id, _| servers.iter().any(|server| &server.id == server_id));
+        self.latencies
+            .retain(|server_id, _| servers.iter().any(|server| &server.id == server_id));
+        self.pending_checks = servers.len();
+        let mut tasks = Vec::with_capacity(servers.len());
+        for server in servers {
+            let server_id = server.id;
+            self.health.entry(server_id.clone()).or_insert(HealthStatus::Checking);
+            tasks.push(cx.spawn(async move |handle, cx| {
+                let check = cx.background_spawn({
+                    let server_id = server_id.clone();
+                    async move { check_server_health(&server_id).await }
+                });
+                let status = match check.await {
+                    Ok(health) => HealthStatus::Healthy(health),
+                    Err(e) => {
+                        error!(error = %e, server_id = server_id.as_str(), "Server health check failed");
+                        HealthStatus::Unreachable(e.to_string().into())
+                    }
+                };
+                let _ = handle.update(cx, |this, cx| {
+                    if let HealthStatus::Healthy(health) = &status {
+                        let latencies = this.latencies.entry(server_id.clone()).or_default();
+                        if latencies.len() >= SPARKLINE_SAMPLES {
+                            latencies.pop_front();
+                        }
+                        latencies.push_back(health.latency_ms as f64);
+                    }
+                    this.health.insert(server_id, status);
+                    this.pending_checks = this.pending_checks.saturating_sub(1);
+                    cx.notify();
+                });
+            }));
+        }
+        self._health_tasks = tasks;
+        cx.notify();
+    }
+    /// Renders the health line of a server card.
+    fn render_health(&self, server_id: &str, index: usize, cx: &Context<Self>) -> impl IntoElement {
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let dot = |color: Hsla| div().size_2().flex_none().rounded_full().bg(color);
+        let line = h_flex().gap_2().items_center().min_w_0();
+        match self.health.get(server_id) {
+            Some(HealthStatus::Healthy(health)) => {
+                let memory = if health.maxmemory > 0 {
+                    format!(
+                        "{} / {}",
+                        format_size(health.used_memory, DECIMAL),
+                        format_size(health.maxmemory, DECIMAL)
+                    )
+                } else {
+                    format_size(health.used_memory, DECIMAL)
+                };
+                let summary = t!(
+                    "servers.health_summary",
+                    version = health.version,
+                    latency = health.latency_ms,
+                    memory = memory,
+                    clients = health.connected_clients,
+                    keys = health.keys,
+                    locale = locale
+                );
+                let latencies: Vec<f64> = self
+                    .latencies
+                    .get(server_id)
+                    .map(|latencies| latencies.iter().copied().collect())
+                    .unwrap_or_default();
+                line.child(dot(cx.theme().green))
+                    .when(latencies.len() > 1, |this| {
+                        this.child(
+                            div()
+                                .flex_none()
+                                .w(px(SPARKLINE_WIDTH))
+                                .h(px(SPARKLINE_HEIGHT))
+                                .child(make_sparkline_canvas(latencies, cx.theme().chart_2)),
+                        )
+                    })
+                    .child(Label::new(summary).text_xs().text_ellipsis())
+                    .into_any_element()
+            }
+            Some(HealthStatus::Unreachable(message)) => {
+                let message = message.clone();
+                line.id(("servers-card-health", index))
+                    .child(dot(cx.theme().red))
+                    .child(
+                        Label::new(i18n_servers(cx, "health_unreachable"))
+                            .text_xs()
+                            .text_color(cx.theme().red),
+                    )
+                    .tooltip(move |window, cx| Tooltip::new(message.clone()).build(window, cx))
+                    .into_any_element()
+            }
+            _ => line
+                .child(dot(cx.theme().muted_foreground))
+                .child(
+                    Label::new(i18n_servers(cx, "health_checking"))
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .into_any_element(),
         }
     }
     /// Show confirmation dialog and remove server from configuration
@@ -124,6 +300,45 @@ impl ZedisServers {
             .open(window, cx);
     }
 
+    /// Copies the connection as a redis URL, asking first whether to include the password.
+    fn copy_server_url(&mut self, server: &RedisServer, window: &mut Window, cx: &mut Context<Self>) {
+        let copy = |server: &RedisServer, include_password: bool, cx: &mut App| {
+            cx.write_to_clipboard(ClipboardItem::new_string(server.to_redis_url(include_password)));
+            let message = i18n_servers(cx, "copy_url_success");
+            cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
+                store.update(cx, |_state, cx| {
+                    cx.emit(GlobalEvent::Notification(NotificationAction::new_success(message)));
+                });
+            });
+        };
+        let has_password = server
+            .password
+            .as_deref()
+            .is_some_and(|password| !password.trim().is_empty());
+        if !has_password {
+            copy(server, false, cx);
+            return;
+        }
+        let server = server.clone();
+        let fields = vec![
+            ZedisFormField::new("include_password", i18n_servers(cx, "copy_url_password"))
+                .default_value("false")
+                .placeholder(i18n_servers(cx, "copy_url_password_label"))
+                .field_type(ZedisFormFieldType::Checkbox),
+        ];
+        ZedisFormOptions::new(fields)
+            .title(i18n_servers(cx, "copy_url_title"))
+            .description(i18n_servers(cx, "copy_url_description"))
+            .confirm_label(i18n_servers(cx, "copy_url_confirm"))
+            .cancel_label(i18n_common(cx, "cancel"))
+            .on_dialog_submit(move |values, _window, cx| {
+                let include_password = values.get("include_password").is_some_and(|v| v == "true");
+                copy(&server, include_password, cx);
+                true
+            })
+            .open_dialog(window, cx);
+    }
+
     fn add_or_update_server_dialog(&mut self, redis_server: &RedisServer, window: &mut Window, cx: &mut Context<Self>) {
         let server_id = redis_server.id.clone();
         let is_new = server_id.is_empty();
@@ -135,6 +350,18 @@ impl ZedisServers {
             Some("host is invalid".into())
         };
 
+        let color_index = SERVER_COLORS
+            .iter()
+            .position(|color| Some(*color) == redis_server.color.as_deref())
+            .unwrap_or_default();
+        let color_options = SERVER_COLORS
+            .iter()
+            .map(|color| {
+                let key = if color.is_empty() { "none" } else { color };
+                i18n_servers(cx, &format!("color_{key}"))
+            })
+            .collect::<Vec<SharedString>>();
+
         let fields = vec![
             ZedisFormField::new("name", i18n_common(cx, "name"))
                 .default_value(redis_server.name.clone())
@@ -169,6 +396,15 @@ impl ZedisServers {
                 .default_value(redis_server.description.clone().unwrap_or_default())
                 .placeholder(i18n_common(cx, "description_placeholder"))
                 .tab_index(0),
+            ZedisFormField::new("label", i18n_servers(cx, "label"))
+                .default_value(redis_server.label.clone().unwrap_or_default())
+                .placeholder(i18n_servers(cx, "label_placeholder"))
+                .tab_index(0),
+            ZedisFormField::new("color", i18n_servers(cx, "color"))
+                .default_value(color_index.to_string())
+                .options(color_options)
+                .tab_index(0)
+                .field_type(ZedisFormFieldType::RadioGroup),
             // tab tls
             ZedisFormField::new("tls", i18n_common(cx, "tls"))
                 .default_value(redis_server.tls.unwrap_or(false).to_string())
@@ -236,6 +472,16 @@ impl ZedisServers {
                 .placeholder(i18n_servers(cx, "readonly_check_label"))
                 .tab_index(3)
                 .field_type(ZedisFormFieldType::Checkbox),
+            ZedisFormField::new("allowed_commands", i18n_servers(cx, "allowed_commands"))
+                .default_value(redis_server.allowed_commands.clone().unwrap_or_default())
+                .placeholder(i18n_servers(cx, "allowed_commands_placeholder"))
+                .tab_index(3)
+                .field_type(ZedisFormFieldType::AutoGrow(2, 10)),
+            ZedisFormField::new("denied_commands", i18n_servers(cx, "denied_commands"))
+                .default_value(redis_server.denied_commands.clone().unwrap_or_default())
+                .placeholder(i18n_servers(cx, "denied_commands_placeholder"))
+                .tab_index(3)
+                .field_type(ZedisFormFieldType::AutoGrow(2, 10)),
         ];
         let title = if is_new {
             i18n_servers(cx, "add_server_title")
@@ -376,6 +622,7 @@ impl Render for ZedisServers {
 
         let update_tooltip = i18n_servers(cx, "update_tooltip");
         let remove_tooltip = i18n_servers(cx, "remove_tooltip");
+        let copy_url_tooltip = i18n_servers(cx, "copy_url_tooltip");
 
         // Build card for each configured server
         let children: Vec<_> = get_servers()
@@ -386,6 +633,7 @@ impl Render for ZedisServers {
                 // Clone values for use in closures
                 let select_server_id = server.id.clone();
                 let update_server = server.clone();
+                let copy_server = server.clone();
                 let remove_server_id = server.id.clone();
 
                 let description = server.description.as_deref().unwrap_or_default();
@@ -397,7 +645,12 @@ impl Render for ZedisServers {
                     String::new()
                 };
 
-                let title = format!("{} ({}:{})", server.name, server.host, server.port);
+                let title = match server.label.as_deref() {
+                    Some(label) => format!("[{label}] {} ({}:{})", server.name, server.host, server.port),
+                    None => format!("{} ({}:{})", server.name, server.host, server.port),
+                };
+                let accent = server.color.as_deref().and_then(|color| server_accent_color(color, cx));
+                let health = self.render_health(&server.id, index, cx);
 
                 // Action buttons for each server card
                 let actions = vec![
@@ -410,6 +663,15 @@ impl Render for ZedisServers {
                             cx.stop_propagation(); // Don't trigger card click
                             this.add_or_update_server_dialog(&update_server, window, cx);
                         })),
+                    // Copy button - copies the connection as a redis URL
+                    Button::new(("servers-card-action-copy-url", index))
+                        .ghost()
+                        .tooltip(copy_url_tooltip.clone())
+                        .icon(IconName::Copy)
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            cx.stop_propagation(); // Don't trigger card click
+                            this.copy_server_url(&copy_server, window, cx);
+                        })),
                     // Delete button - shows confirmation before removing
                     Button::new(("servers-card-action-delete", index))
                         .ghost()
@@ -425,10 +687,10 @@ impl Render for ZedisServers {
                 let handle_select_server = cx.listener(move |_this, _, _, cx| {
                     let select_server_id = select_server_id.clone();
 
-                    // Navigate to editor view
+                    // Navigate to editor (or metrics) view
                     cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
                         store.update(cx, |state, cx| {
-                            state.go_to(Route::Editor, cx);
+                            state.go_to(state.server_route(), cx);
                             state.set_selected_server((select_server_id.clone(), 0), cx);
                         });
                     });
@@ -436,52 +698,119 @@ impl Render for ZedisServers {
 
                 // Build server card with conditional footer
                 ZedisCard::new(("servers-card", index))
-                    .icon(Icon::new(CustomIconName::DatabaseZap))
+                    .icon(
+                        Icon::new(CustomIconName::DatabaseZap).when_some(accent, |this, color| this.text_color(color)),
+                    )
                     .title(title)
                     .bg(bg)
                     .when(!description.is_empty(), |this| {
                         this.description(description.to_string())
                     })
-                    .when(!updated_at.is_empty(), |this| {
-                        this.footer(
-                            Label::new(updated_at)
-                                .text_sm()
-                                .text_right()
-                                .whitespace_normal()
-                                .text_color(cx.theme().muted_foreground),
-                        )
-                    })
+                    .footer(
+                        h_flex()
+                            .w_full()
+                            .gap_2()
+                            .justify_between()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(health)
+                            .when(!updated_at.is_empty(), |this| {
+                                this.child(
+                                    Label::new(updated_at)
+                                        .text_sm()
+                                        .text_right()
+                                        .whitespace_normal()
+                                        .text_color(cx.theme().muted_foreground),
+                                )
+                            }),
+                    )
                     .actions(actions)
                     .on_click(Box::new(handle_select_server))
             })
             .collect();
-        // Render responsive grid with server cards + add new server card
-        div()
-            .grid()
-            .grid_cols(cols)
-            .gap_1()
+        let reachable = self
+            .health
+            .values()
+            .filter(|status| matches!(status, HealthStatus::Healthy(_)))
+            .count();
+        let checking = self.pending_checks > 0;
+        let (refresh_icon, refresh_tooltip) = if self.auto_refresh_paused {
+            (CustomIconName::Play, i18n_servers(cx, "auto_refresh_resume_tooltip"))
+        } else {
+            (CustomIconName::Pause, i18n_servers(cx, "auto_refresh_pause_tooltip"))
+        };
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let health_summary = t!(
+            "servers.health_reachable",
+            reachable = reachable,
+            total = self.health.len(),
+            locale = locale
+        );
+        let header = h_flex()
             .w_full()
-            .children(children)
+            .px_1()
+            .pb_1()
+            .gap_2()
+            .items_center()
+            .justify_end()
             .child(
-                // "Add New Server" card at the end
-                ZedisCard::new("servers-card-add")
-                    .icon(IconName::Plus)
-                    .title(i18n_servers(cx, "add_server_title"))
-                    .bg(bg)
-                    .description(i18n_servers(cx, "add_server_description"))
-                    .actions(vec![Button::new("add").ghost().icon(CustomIconName::FilePlusCorner)])
-                    .on_click(Box::new(cx.listener(move |this, _, window, cx| {
-                        // Fill with empty server data for new entry
-                        this.add_or_update_server_dialog(
-                            &RedisServer {
-                                port: DEFAULT_REDIS_PORT,
-                                ..Default::default()
-                            },
-                            window,
-                            cx,
-                        );
-                    }))),
+                Label::new(health_summary)
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground),
             )
+            .child(
+                Button::new("servers-auto-refresh")
+                    .ghost()
+                    .small()
+                    .icon(refresh_icon)
+                    .tooltip(refresh_tooltip)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.toggle_auto_refresh(cx);
+                    })),
+            )
+            .child(
+                Button::new("servers-check-all")
+                    .ghost()
+                    .small()
+                    .icon(CustomIconName::RotateCw)
+                    .loading(checking)
+                    .disabled(checking)
+                    .tooltip(i18n_servers(cx, "health_check_tooltip"))
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.check_all_servers(cx);
+                    })),
+            );
+
+        // Render responsive grid with server cards + add new server card
+        let grid = div().grid().grid_cols(cols).gap_1().w_full().children(children).child(
+            // "Add New Server" card at the end
+            ZedisCard::new("servers-card-add")
+                .icon(IconName::Plus)
+                .title(i18n_servers(cx, "add_server_title"))
+                .bg(bg)
+                .description(i18n_servers(cx, "add_server_description"))
+                .actions(vec![
+                    Button::new("add")
+                        .ghost()
+                        .icon(CustomIconName::FilePlusCorner)
+                        .tooltip(i18n_servers(cx, "add_server_title")),
+                ])
+                .on_click(Box::new(cx.listener(move |this, _, window, cx| {
+                    // Fill with empty server data for new entry
+                    this.add_or_update_server_dialog(
+                        &RedisServer {
+                            port: DEFAULT_REDIS_PORT,
+                            ..Default::default()
+                        },
+                        window,
+                        cx,
+                    );
+                }))),
+        );
+
+        v_flex()
+            .w_full()
+            .when(!self.health.is_empty(), |this| this.child(header))
+            .child(grid)
             .into_any_element()
     }
 }
@@ -19,6 +19,7 @@ use crate::{
     views::{ZedisKvTable, kv_table::define_kv_editor},
 };
 use gpui::{App, Entity, SharedString, Window, prelude::*};
+use gpui_component::table::ColumnSort;
 use tracing::info;
 use zedis_ui::ZedisFormFieldType;
 
@@ -56,6 +57,11 @@ impl ZedisKvFetcher for ZedisSetValues {
         });
     }
 
+    /// The length column shows the size of the member.
+    fn value_column(&self) -> Option<usize> {
+        Some(1)
+    }
+
     /// Returns the total cardinality of the SET (from Redis SCARD).
     fn count(&self) -> usize {
         self.value.set_value().map_or(0, |v| v.size)
@@ -135,6 +141,18 @@ impl ZedisKvFetcher for ZedisSetValues {
             this.remove_set_value(value.clone(), cx);
         });
     }
+
+    /// Sorts the loaded members alphabetically; the default order keeps them as loaded.
+    fn sort(&self, _col_ix: usize, sort: ColumnSort, cx: &mut App) {
+        let descending = match sort {
+            ColumnSort::Ascending => false,
+            ColumnSort::Descending => true,
+            ColumnSort::Default => return,
+        };
+        self.server_state.update(cx, |this, cx| {
+            this.sort_set_value(descending, cx);
+        });
+    }
 }
 
 define_kv_editor!(ZedisSetEditor, ZedisSetValues);
@@ -143,7 +161,11 @@ impl ZedisSetEditor {
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let table_state = cx.new(|cx| {
             ZedisKvTable::<ZedisSetValues>::new(
-                vec![KvTableColumn::new_flex("Value").field_type(ZedisFormFieldType::Editor)],
+                vec![
+                    KvTableColumn::new_flex("Value")
+                        .field_type(ZedisFormFieldType::Editor)
+                        .sortable(),
+                ],
                 server_state,
                 window,
                 cx,
@@ -14,16 +14,29 @@
 
 use crate::{
     helpers::{get_or_create_config_dir, parse_duration},
-    states::{ZedisGlobalStore, i18n_settings, update_app_state_and_save},
+    states::{
+        ClockFormat, DefaultSort, GlobalEvent, KeyType, NotificationAction, ZedisGlobalStore, export_config,
+        i18n_settings, save_app_state, update_app_state_and_save,
+    },
+    views::ZedisCommandLog,
 };
-use gpui::{Entity, Subscription, Window, prelude::*, px};
+use chrono::Local;
+use gpui::{Entity, PathPromptOptions, Subscription, Window, prelude::*, px};
 use gpui_component::{
+    Sizable, WindowExt,
+    button::{Button, ButtonVariants},
     checkbox::Checkbox,
     form::{Field, field, v_form},
+    h_flex,
     input::{Input, InputEvent, InputState, NumberInput, NumberInputEvent, StepAction},
     label::Label,
+    notification::Notification,
+    radio::RadioGroup,
     v_flex,
 };
+use rust_i18n::t;
+use tracing::error;
+use zedis_ui::ZedisDialog;
 
 pub struct ZedisSettingEditor {
     max_key_tree_depth_state: Entity<InputState>,
@@ -31,10 +44,34 @@ pub struct ZedisSettingEditor {
     max_truncate_length_state: Entity<InputState>,
     config_dir_state: Entity<InputState>,
     key_scan_count_state: Entity<InputState>,
+    max_filter_scan_pages_state: Entity<InputState>,
     auto_expand_threshold_state: Entity<InputState>,
     redis_connection_timeout_state: Entity<InputState>,
     redis_response_timeout_state: Entity<InputState>,
+    alert_hit_rate_below_state: Entity<InputState>,
+    alert_evictions_above_state: Entity<InputState>,
+    alert_memory_above_state: Entity<InputState>,
+    wait_replicas_state: Entity<InputState>,
+    wait_timeout_state: Entity<InputState>,
+    dangerous_commands_state: Entity<InputState>,
+    client_name_template_state: Entity<InputState>,
+    metrics_precision_state: Entity<InputState>,
     tray_enabled: bool,
+    alert_notification_enabled: bool,
+    mini_metrics_enabled: bool,
+    open_metrics_on_select: bool,
+    wait_replication_enabled: bool,
+    verify_key_type: bool,
+    set_default_sort: DefaultSort,
+    zset_default_sort: DefaultSort,
+    clock_format: ClockFormat,
+    metrics_show_date: bool,
+    utc_time: bool,
+    force_rtl: bool,
+    scan_debug: bool,
+    command_log: bool,
+    /// Whether the exported config includes the server passwords and ssh keys
+    export_secrets: bool,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -73,6 +110,10 @@ impl ZedisSettingEditor {
             }
         })
     }
+    /// Parses an optional threshold, where an empty value disables the alert.
+    fn parse_threshold(text: &str) -> Option<f64> {
+        text.trim().parse::<f64>().ok().filter(|value| *value >= 0.0)
+    }
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let store = cx.global::<ZedisGlobalStore>().read(cx);
         let max_key_tree_depth = store.max_key_tree_depth();
@@ -82,7 +123,29 @@ impl ZedisSettingEditor {
         let redis_connection_timeout = store.redis_connection_timeout();
         let redis_response_timeout = store.redis_response_timeout();
         let key_scan_count = store.key_scan_count();
+        let max_filter_scan_pages = store.max_filter_scan_pages();
         let tray_enabled = store.tray_enabled();
+        let alert_thresholds = store.metrics_alert_thresholds();
+        let alert_notification_enabled = store.alert_notification_enabled();
+        let mini_metrics_enabled = store.mini_metrics_enabled();
+        let open_metrics_on_select = store.open_metrics_on_select();
+        let wait_replication_enabled = store.wait_replication_enabled();
+        let verify_key_type = store.verify_key_type();
+        let wait_replicas = store.wait_replicas();
+        let wait_timeout_ms = store.wait_timeout_ms();
+        let dangerous_commands = store.dangerous_commands().to_string();
+        let client_name_template = store.client_name_template().to_string();
+        let set_default_sort = store.default_sort(KeyType::Set.as_str());
+        let zset_default_sort = store.default_sort(KeyType::Zset.as_str());
+        let clock_format = store.clock_format();
+        let metrics_show_date = store.metrics_show_date();
+        let metrics_precision = store.metrics_precision();
+        let utc_time = store.utc_time();
+        let force_rtl = store.force_rtl();
+        let scan_debug = store.scan_debug();
+        let command_log = store.command_log();
+        let threshold_text = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+        let validate_threshold: Option<fn(&str) -> bool> = Some(|s| s.is_empty() || s.parse::<f64>().is_ok());
         let max_key_tree_depth_state = Self::create_input_state(
             window,
             cx,
@@ -99,6 +162,13 @@ impl ZedisSettingEditor {
             key_scan_count.to_string(),
             Some(|s| s.parse::<usize>().is_ok()),
         );
+        let max_filter_scan_pages_state = Self::create_input_state(
+            window,
+            cx,
+            "max_filter_scan_pages_placeholder",
+            max_filter_scan_pages.to_string(),
+            Some(|s| s.parse::<usize>().is_ok()),
+        );
         let auto_expand_threshold_state = Self::create_input_state(
             window,
             cx,
@@ -127,6 +197,49 @@ impl ZedisSettingEditor {
             redis_response_timeout,
             None,
         );
+        let alert_hit_rate_below_state = Self::create_input_state(
+            window,
+            cx,
+            "alert_hit_rate_below_placeholder",
+            threshold_text(alert_thresholds.hit_rate_below),
+            validate_threshold,
+        );
+        let alert_evictions_above_state = Self::create_input_state(
+            window,
+            cx,
+            "alert_evictions_above_placeholder",
+            threshold_text(alert_thresholds.evictions_above),
+            validate_threshold,
+        );
+        let alert_memory_above_state = Self::create_input_state(
+            window,
+            cx,
+            "alert_memory_above_placeholder",
+            threshold_text(alert_thresholds.memory_above),
+            validate_threshold,
+        );
+        let wait_replicas_state = Self::create_input_state(
+            window,
+            cx,
+            "wait_replicas_placeholder",
+            wait_replicas.to_string(),
+            Some(|s| s.parse::<usize>().is_ok()),
+        );
+        let wait_timeout_state = Self::create_input_state(
+            window,
+            cx,
+            "wait_timeout_placeholder",
+            wait_timeout_ms.to_string(),
+            Some(|s| s.parse::<u64>().is_ok()),
+        );
+
+        let metrics_precision_state = Self::create_input_state(
+            window,
+            cx,
+            "metrics_precision_placeholder",
+            metrics_precision.map(|v| v.to_string()).unwrap_or_default(),
+            Some(|s| s.is_empty() || s.parse::<usize>().is_ok()),
+        );
 
         let config_dir = get_or_create_config_dir().unwrap_or_default();
 
@@ -205,6 +318,21 @@ impl ZedisSettingEditor {
                 });
             }
         }));
+        // Max Filter Scan Pages
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &max_filter_scan_pages_state,
+            window,
+            |text, cx| {
+                if let Ok(value) = text.parse::<usize>()
+                    && value >= 1
+                {
+                    update_app_state_and_save(cx, "save_max_filter_scan_pages", move |state, _| {
+                        state.set_max_filter_scan_pages(value);
+                    });
+                }
+            },
+        ));
         // Auto Expand Threshold
         subscriptions.push(Self::bind_blur_save(
             cx,
@@ -236,12 +364,108 @@ impl ZedisSettingEditor {
                 }
             },
         ));
+        // Metrics alert thresholds
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &alert_hit_rate_below_state,
+            window,
+            |text, cx| {
+                let value = Self::parse_threshold(&text);
+                update_app_state_and_save(cx, "save_alert_hit_rate_below", move |state, _| {
+                    state.set_alert_hit_rate_below(value);
+                });
+            },
+        ));
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &alert_evictions_above_state,
+            window,
+            |text, cx| {
+                let value = Self::parse_threshold(&text);
+                update_app_state_and_save(cx, "save_alert_evictions_above", move |state, _| {
+                    state.set_alert_evictions_above(value);
+                });
+            },
+        ));
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &alert_memory_above_state,
+            window,
+            |text, cx| {
+                let value = Self::parse_threshold(&text);
+                update_app_state_and_save(cx, "save_alert_memory_above", move |state, _| {
+                    state.set_alert_memory_above(value);
+                });
+            },
+        ));
+        // WAIT replication confirmation
+        subscriptions.push(Self::bind_blur_save(cx, &wait_replicas_state, window, |text, cx| {
+            if let Ok(value) = text.parse::<usize>()
+                && value >= 1
+            {
+                update_app_state_and_save(cx, "save_wait_replicas", move |state, _| {
+                    state.set_wait_replicas(value);
+                });
+            }
+        }));
+        subscriptions.push(Self::bind_blur_save(cx, &wait_timeout_state, window, |text, cx| {
+            // 0 makes WAIT block until the replicas acknowledge, which may be forever
+            if let Ok(value) = text.parse::<u64>()
+                && value >= 1
+            {
+                update_app_state_and_save(cx, "save_wait_timeout_ms", move |state, _| {
+                    state.set_wait_timeout_ms(value);
+                });
+            }
+        }));
+        // Metrics precision, empty keeps the per-chart defaults
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &metrics_precision_state,
+            window,
+            |text, cx| {
+                let value = text.trim().parse::<usize>().ok();
+                update_app_state_and_save(cx, "save_metrics_precision", move |state, _| {
+                    state.set_metrics_precision(value);
+                });
+            },
+        ));
+        let dangerous_commands_state =
+            Self::create_input_state(window, cx, "dangerous_commands_placeholder", dangerous_commands, None);
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &dangerous_commands_state,
+            window,
+            |text, cx| {
+                update_app_state_and_save(cx, "save_dangerous_commands", move |state, _| {
+                    state.set_dangerous_commands(text.trim().to_string());
+                });
+            },
+        ));
+        let client_name_template_state = Self::create_input_state(
+            window,
+            cx,
+            "client_name_template_placeholder",
+            client_name_template,
+            None,
+        );
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &client_name_template_state,
+            window,
+            |text, cx| {
+                update_app_state_and_save(cx, "save_client_name_template", move |state, _| {
+                    state.set_client_name_template(text.trim().to_string());
+                });
+            },
+        ));
         let config_dir_state =
             cx.new(|cx| InputState::new(window, cx).default_value(config_dir.to_string_lossy().to_string()));
 
         Self {
             _subscriptions: subscriptions,
             key_scan_count_state,
+            max_filter_scan_pages_state,
             config_dir_state,
             auto_expand_threshold_state,
             max_truncate_length_state,
@@ -249,9 +473,88 @@ impl ZedisSettingEditor {
             max_key_tree_depth_state,
             redis_response_timeout_state,
             redis_connection_timeout_state,
+            alert_hit_rate_below_state,
+            alert_evictions_above_state,
+            alert_memory_above_state,
+            wait_replicas_state,
+            wait_timeout_state,
+            dangerous_commands_state,
+            client_name_template_state,
+            metrics_precision_state,
             tray_enabled,
+            alert_notification_enabled,
+            mini_metrics_enabled,
+            open_metrics_on_select,
+            wait_replication_enabled,
+            verify_key_type,
+            set_default_sort,
+            zset_default_sort,
+            clock_format,
+            metrics_show_date,
+            utc_time,
+            force_rtl,
+            scan_debug,
+            command_log,
+            export_secrets: false,
         }
     }
+    /// Asks for a target file and exports the whole setup into it.
+    fn handle_export_config(&mut self, cx: &mut Context<Self>) {
+        let file_name = format!("zedis-config-{}.json", Local::now().format("%Y%m%d"));
+        let directory = home::home_dir().unwrap_or_default();
+        let receiver = cx.prompt_for_new_path(&directory, Some(&file_name));
+        let store = cx.global::<ZedisGlobalStore>().clone();
+        let app = store.value(cx);
+        let include_secrets = self.export_secrets;
+        cx.spawn(async move |_, cx| {
+            let Ok(Ok(Some(path))) = receiver.await else {
+                return;
+            };
+            let result = cx
+                .background_spawn(async move { export_config(&path, &app, include_secrets) })
+                .await;
+            store.update(cx, |state, cx| {
+                let notification = match result {
+                    Ok(count) => NotificationAction::new_success(
+                        t!(
+                            "settings.export_config_success",
+                            servers = count,
+                            locale = state.locale()
+                        )
+                        .into(),
+                    ),
+                    Err(e) => {
+                        error!(error = %e, "Failed to export config");
+                        NotificationAction::new_error(e.to_string().into())
+                    }
+                };
+                cx.emit(GlobalEvent::Notification(notification));
+            });
+        })
+        .detach();
+    }
+    /// Asks for an exported config file and imports it.
+    fn handle_import_config(&mut self, cx: &mut Context<Self>) {
+        let receiver = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+        });
+        let store = cx.global::<ZedisGlobalStore>().clone();
+        cx.spawn(async move |_, cx| {
+            let Ok(Ok(Some(paths))) = receiver.await else {
+                return;
+            };
+            let Some(path) = paths.into_iter().next() else {
+                return;
+            };
+            store.update(cx, |state, cx| {
+                state.import_config_file(path, cx);
+            });
+        })
+        .detach();
+    }
     fn render_field(cx: &Context<Self>, label_key: &str, input_element: impl IntoElement) -> Field {
         field().label(i18n_settings(cx, label_key)).child(input_element)
     }
@@ -283,6 +586,11 @@ impl Render for ZedisSettingEditor {
                         "key_scan_count",
                         Input::new(&self.key_scan_count_state),
                     ))
+                    .child(Self::render_field(
+                        cx,
+                        "max_filter_scan_pages",
+                        Input::new(&self.max_filter_scan_pages_state),
+                    ))
                     .child(Self::render_field(
                         cx,
                         "auto_expand_threshold",
@@ -303,6 +611,272 @@ impl Render for ZedisSettingEditor {
                         "redis_response_timeout",
                         Input::new(&self.redis_response_timeout_state),
                     ))
+                    .child(Self::render_field(
+                        cx,
+                        "alert_hit_rate_below",
+                        Input::new(&self.alert_hit_rate_below_state),
+                    ))
+                    .child(Self::render_field(
+                        cx,
+                        "alert_evictions_above",
+                        Input::new(&self.alert_evictions_above_state),
+                    ))
+                    .child(Self::render_field(
+                        cx,
+                        "alert_memory_above",
+                        Input::new(&self.alert_memory_above_state),
+                    ))
+                    .child(
+                        field().label(i18n_settings(cx, "alert_notification")).child(
+                            Checkbox::new("alert-notification-enabled")
+                                .label(i18n_settings(cx, "alert_notification_label"))
+                                .checked(self.alert_notification_enabled)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.alert_notification_enabled = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(
+                                        cx,
+                                        "save_alert_notification_enabled",
+                                        move |state, _| {
+                                            state.set_alert_notification_enabled(enabled);
+                                        },
+                                    );
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "mini_metrics")).child(
+                            Checkbox::new("mini-metrics-enabled")
+                                .label(i18n_settings(cx, "mini_metrics_label"))
+                                .checked(self.mini_metrics_enabled)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.mini_metrics_enabled = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_mini_metrics_enabled", move |state, _| {
+                                        state.set_mini_metrics_enabled(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "open_metrics_on_select")).child(
+                            Checkbox::new("open-metrics-on-select")
+                                .label(i18n_settings(cx, "open_metrics_on_select_label"))
+                                .checked(self.open_metrics_on_select)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.open_metrics_on_select = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_open_metrics_on_select", move |state, _| {
+                                        state.set_open_metrics_on_select(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "wait_replication")).child(
+                            Checkbox::new("wait-replication-enabled")
+                                .label(i18n_settings(cx, "wait_replication_label"))
+                                .checked(self.wait_replication_enabled)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.wait_replication_enabled = *checked;
+                                    cx.notify();
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_wait_replication_enabled", move |state, _| {
+                                        state.set_wait_replication_enabled(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .when(self.wait_replication_enabled, |this| {
+                        this.child(Self::render_field(
+                            cx,
+                            "wait_replicas",
+                            Input::new(&self.wait_replicas_state),
+                        ))
+                        .child(Self::render_field(
+                            cx,
+                            "wait_timeout",
+                            Input::new(&self.wait_timeout_state),
+                        ))
+                    })
+                    .child(
+                        field().label(i18n_settings(cx, "verify_key_type")).child(
+                            Checkbox::new("verify-key-type")
+                                .label(i18n_settings(cx, "verify_key_type_label"))
+                                .checked(self.verify_key_type)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.verify_key_type = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_verify_key_type", move |state, _| {
+                                        state.set_verify_key_type(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "set_default_sort")).child(
+                            RadioGroup::horizontal("set-default-sort")
+                                .children(
+                                    ["default_sort_none", "default_sort_value_asc", "default_sort_value_desc"]
+                                        .map(|key| i18n_settings(cx, key)),
+                                )
+                                .selected_index(DefaultSort::SET.iter().position(|item| *item == self.set_default_sort))
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, index: &usize, _window, cx| {
+                                    let sort = DefaultSort::SET.get(*index).copied().unwrap_or_default();
+                                    this.set_default_sort = sort;
+                                    cx.notify();
+                                    update_app_state_and_save(cx, "save_set_default_sort", move |state, _| {
+                                        state.set_default_sort(KeyType::Set.as_str().to_string(), sort);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "zset_default_sort")).child(
+                            RadioGroup::horizontal("zset-default-sort")
+                                .children(
+                                    [
+                                        "default_sort_none",
+                                        "default_sort_score_desc",
+                                        "default_sort_value_asc",
+                                        "default_sort_value_desc",
+                                    ]
+                                    .map(|key| i18n_settings(cx, key)),
+                                )
+                                .selected_index(
+                                    DefaultSort::ZSET
+                                        .iter()
+                                        .position(|item| *item == self.zset_default_sort),
+                                )
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, index: &usize, _window, cx| {
+                                    let sort = DefaultSort::ZSET.get(*index).copied().unwrap_or_default();
+                                    this.zset_default_sort = sort;
+                                    cx.notify();
+                                    update_app_state_and_save(cx, "save_zset_default_sort", move |state, _| {
+                                        state.set_default_sort(KeyType::Zset.as_str().to_string(), sort);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "clock_format")).child(
+                            RadioGroup::horizontal("clock-format")
+                                .children(
+                                    ["clock_format_auto", "clock_format_12h", "clock_format_24h"]
+                                        .map(|key| i18n_settings(cx, key)),
+                                )
+                                .selected_index(ClockFormat::ALL.iter().position(|item| *item == self.clock_format))
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, index: &usize, _window, cx| {
+                                    let clock_format = ClockFormat::ALL.get(*index).copied().unwrap_or_default();
+                                    this.clock_format = clock_format;
+                                    cx.notify();
+                                    update_app_state_and_save(cx, "save_clock_format", move |state, _| {
+                                        state.set_clock_format(clock_format);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(Self::render_field(
+                        cx,
+                        "metrics_precision",
+                        Input::new(&self.metrics_precision_state),
+                    ))
+                    .child(
+                        field().label(i18n_settings(cx, "metrics_show_date")).child(
+                            Checkbox::new("metrics-show-date")
+                                .label(i18n_settings(cx, "metrics_show_date_label"))
+                                .checked(self.metrics_show_date)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.metrics_show_date = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_metrics_show_date", move |state, _| {
+                                        state.set_metrics_show_date(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "utc_time")).child(
+                            Checkbox::new("utc-time")
+                                .label(i18n_settings(cx, "utc_time_label"))
+                                .checked(self.utc_time)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.utc_time = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_utc_time", move |state, _| {
+                                        state.set_utc_time(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "scan_debug")).child(
+                            Checkbox::new("scan-debug")
+                                .label(i18n_settings(cx, "scan_debug_label"))
+                                .checked(self.scan_debug)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.scan_debug = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_scan_debug", move |state, _| {
+                                        state.set_scan_debug(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "command_log")).child(
+                            h_flex()
+                                .gap_2()
+                                .mt(px(7.))
+                                .child(
+                                    Checkbox::new("command-log")
+                                        .label(i18n_settings(cx, "command_log_label"))
+                                        .checked(self.command_log)
+                                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                            this.command_log = *checked;
+                                            let enabled = *checked;
+                                            update_app_state_and_save(cx, "save_command_log", move |state, _| {
+                                                state.set_command_log(enabled);
+                                            });
+                                        })),
+                                )
+                                .when(self.command_log, |this| {
+                                    this.child(
+                                        Button::new("view-command-log")
+                                            .xsmall()
+                                            .ghost()
+                                            .label(i18n_settings(cx, "command_log_view"))
+                                            .on_click(|_, window, cx| {
+                                                let view = cx.new(ZedisCommandLog::new);
+                                                ZedisDialog::new(i18n_settings(cx, "command_log"))
+                                                    .alert()
+                                                    .child(move || view.clone())
+                                                    .open(window, cx);
+                                            }),
+                                    )
+                                }),
+                        ),
+                    )
+                    .child(
+                        Self::render_field(cx, "dangerous_commands", Input::new(&self.dangerous_commands_state))
+                            .col_span(cols as u16),
+                    )
+                    .child(Self::render_field(
+                        cx,
+                        "client_name_template",
+                        Input::new(&self.client_name_template_state),
+                    ))
                     .when(cfg!(not(target_os = "linux")), |this| {
                         this.child(
                             field().label(i18n_settings(cx, "tray_enabled")).child(
@@ -320,6 +894,80 @@ impl Render for ZedisSettingEditor {
                             ),
                         )
                     })
+                    .child(
+                        field().label(i18n_settings(cx, "force_rtl")).child(
+                            Checkbox::new("force-rtl")
+                                .label(i18n_settings(cx, "force_rtl_label"))
+                                .checked(self.force_rtl)
+                                .mt(px(7.))
+                                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                    this.force_rtl = *checked;
+                                    let enabled = *checked;
+                                    update_app_state_and_save(cx, "save_force_rtl", move |state, _| {
+                                        state.set_force_rtl(enabled);
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "reset_layout")).child(
+                            Button::new("reset-layout")
+                                .outline()
+                                .label(i18n_settings(cx, "reset_layout_button"))
+                                .tooltip(i18n_settings(cx, "reset_layout_tooltip"))
+                                .on_click(cx.listener(|_this, _, window, cx| {
+                                    let store = cx.global::<ZedisGlobalStore>().clone();
+                                    let value = store.update(cx, |state, cx| {
+                                        state.reset_layout(cx);
+                                        state.clone()
+                                    });
+                                    cx.background_spawn(async move {
+                                        if let Err(e) = save_app_state(&value) {
+                                            error!(error = %e, "Failed to save reset layout");
+                                        }
+                                    })
+                                    .detach();
+                                    window.push_notification(
+                                        Notification::info(i18n_settings(cx, "reset_layout_success")),
+                                        cx,
+                                    );
+                                })),
+                        ),
+                    )
+                    .child(
+                        field().label(i18n_settings(cx, "config_transfer")).child(
+                            h_flex()
+                                .gap_2()
+                                .flex_wrap()
+                                .child(
+                                    Button::new("export-config")
+                                        .outline()
+                                        .label(i18n_settings(cx, "export_config"))
+                                        .tooltip(i18n_settings(cx, "export_config_tooltip"))
+                                        .on_click(cx.listener(|this, _, _window, cx| {
+                                            this.handle_export_config(cx);
+                                        })),
+                                )
+                                .child(
+                                    Button::new("import-config")
+                                        .outline()
+                                        .label(i18n_settings(cx, "import_config"))
+                                        .tooltip(i18n_settings(cx, "import_config_tooltip"))
+                                        .on_click(cx.listener(|this, _, _window, cx| {
+                                            this.handle_import_config(cx);
+                                        })),
+                                )
+                                .child(
+                                    Checkbox::new("export-secrets")
+                                        .label(i18n_settings(cx, "export_secrets_label"))
+                                        .checked(self.export_secrets)
+                                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                                            this.export_secrets = *checked;
+                                            cx.notify();
+                                        })),
+                                ),
+                        ),
+                    )
                     .child(
                         field()
                             .col_span(cols as u16)
@@ -14,10 +14,11 @@
 
 use crate::{
     connection::get_servers,
+    helpers::{SERVER_HOT_KEYS, humanize_keystroke, server_accent_color},
     states::{GlobalEvent, Route, ZedisGlobalStore, i18n_sidebar},
 };
 use gpui::{Context, SharedString, Subscription, Window, div, prelude::*, px, uniform_list};
-use gpui_component::{ActiveTheme, Icon, IconName, label::Label, list::ListItem, v_flex};
+use gpui_component::{ActiveTheme, Icon, IconName, StyledExt, label::Label, list::ListItem, tooltip::Tooltip, v_flex};
 use tracing::info;
 
 // Constants for UI layout
@@ -26,11 +27,22 @@ const SERVER_LIST_ITEM_BORDER_WIDTH: f32 = 3.0;
 /// Internal state for sidebar component
 ///
 /// Caches server list to avoid repeated queries and tracks current selection.
+/// A server entry of the sidebar list
+#[derive(Clone, Default)]
+struct SidebarServer {
+    id: SharedString,
+    name: SharedString,
+    /// Accent color name of the server, see [`SERVER_COLORS`](crate::connection::SERVER_COLORS)
+    color: Option<SharedString>,
+    /// Short environment tag, e.g. `PROD`
+    label: Option<SharedString>,
+}
+
 #[derive(Default)]
 struct SidebarState {
-    /// List of (server_id, server_name) tuples for display
-    /// First entry is always (empty, empty) representing the home page
-    server_names: Vec<(SharedString, SharedString)>,
+    /// List of servers for display
+    /// First entry is always an empty one representing the home page
+    server_names: Vec<SidebarServer>,
 
     /// Currently selected server ID (empty string means home page)
     server_id: SharedString,
@@ -94,18 +106,19 @@ impl ZedisSidebar {
     /// Update cached server list from server state
     ///
     /// Rebuilds the server_names list with:
-    /// - First entry: (empty, empty) for home page
-    /// - Remaining entries: (server_id, server_name) for each configured server
+    /// - First entry: an empty entry for home page
+    /// - Remaining entries: id, name, color and label of each configured server
     fn update_server_names(&mut self, _cx: &mut Context<Self>) {
         // Start with home page entry
-        let mut server_names = vec![(SharedString::default(), SharedString::default())];
+        let mut server_names = vec![SidebarServer::default()];
 
         if let Ok(servers) = get_servers() {
-            server_names.extend(
-                servers
-                    .iter()
-                    .map(|server| (server.id.clone().into(), server.name.clone().into())),
-            );
+            server_names.extend(servers.iter().map(|server| SidebarServer {
+                id: server.id.clone().into(),
+                name: server.name.clone().into(),
+                color: server.color.clone().map(SharedString::from),
+                label: server.label.clone().map(SharedString::from),
+            }));
             self.state.server_names = server_names;
         }
     }
@@ -129,11 +142,15 @@ impl ZedisSidebar {
         let home_label = i18n_sidebar(cx, "home");
         let list_active_color = cx.theme().list_active;
         let list_active_border_color = cx.theme().list_active_border;
+        let rtl = cx.global::<ZedisGlobalStore>().read(cx).is_rtl();
 
-        uniform_list("sidebar-redis-servers", servers.len(), move |range, _window, _cx| {
+        uniform_list("sidebar-redis-servers", servers.len(), move |range, _window, cx| {
             range
                 .map(|index| {
-                    let (server_id, server_name) = servers.get(index).cloned().unwrap_or_default();
+                    let server = servers.get(index).cloned().unwrap_or_default();
+                    let server_id = server.id;
+                    let server_name = server.name;
+                    let accent = server.color.and_then(|color| server_accent_color(&color, cx));
 
                     let is_home = server_id.is_empty();
                     let is_current = is_match_route && server_id == current_server_id_clone;
@@ -144,18 +161,45 @@ impl ZedisSidebar {
                     } else {
                         server_name.clone()
                     };
+                    // Servers reachable with cmd-1..9 show their shortcut in the tooltip
+                    let tooltip: SharedString = if !is_home && index <= SERVER_HOT_KEYS {
+                        format!("{name} ({})", humanize_keystroke(&format!("cmd-{index}"))).into()
+                    } else {
+                        name.clone()
+                    };
 
                     ListItem::new(("sidebar-redis-server", index))
                         .w_full()
                         .when(is_current, |this| this.bg(list_active_color))
                         .py_4()
-                        .border_r(px(SERVER_LIST_ITEM_BORDER_WIDTH))
+                        .map(|this| {
+                            if rtl {
+                                this.border_l(px(SERVER_LIST_ITEM_BORDER_WIDTH))
+                            } else {
+                                this.border_r(px(SERVER_LIST_ITEM_BORDER_WIDTH))
+                            }
+                        })
                         .when(is_current, |this| this.border_color(list_active_border_color))
                         .child(
                             v_flex()
+                                .id(("sidebar-redis-server-content", index))
+                                .w_full()
                                 .items_center()
-                                .child(Icon::new(IconName::LayoutDashboard))
-                                .child(Label::new(name).text_ellipsis().text_xs()),
+                                .child(
+                                    Icon::new(IconName::LayoutDashboard)
+                                        .when_some(accent, |this, color| this.text_color(color)),
+                                )
+                                .child(Label::new(name).text_ellipsis().text_xs())
+                                .when_some(server.label, |this, label| {
+                                    this.child(
+                                        Label::new(label)
+                                            .text_ellipsis()
+                                            .text_xs()
+                                            .font_semibold()
+                                            .when_some(accent, |this, color| this.text_color(color)),
+                                    )
+                                })
+                                .tooltip(move |window, cx| Tooltip::new(tooltip.clone()).build(window, cx)),
                         )
                         .on_click(move |_, _window, cx| {
                             // Don't do anything if already selected
@@ -163,12 +207,11 @@ impl ZedisSidebar {
                                 return;
                             }
 
-                            // Determine target route based on home/server
-                            let route = if is_home { Route::Home } else { Route::Editor };
-
                             // Update global route
                             cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
                                 store.update(cx, |state, cx| {
+                                    // Determine target route based on home/server
+                                    let route = if is_home { Route::Home } else { state.server_route() };
                                     state.go_to(route, cx);
                                     state.set_selected_server((server_id.to_string(), 0), cx);
                                 });
@@ -187,7 +230,13 @@ impl Render for ZedisSidebar {
             .size_full()
             .id("sidebar-container")
             .justify_start()
-            .border_r_1()
+            .map(|this| {
+                if cx.global::<ZedisGlobalStore>().read(cx).is_rtl() {
+                    this.border_l_1()
+                } else {
+                    this.border_r_1()
+                }
+            })
             .border_color(cx.theme().border)
             .child(div().flex_1().size_full().child(self.render_server_list(window, cx)))
     }
@@ -18,9 +18,9 @@
 /// periodic `SLOWLOG GET` refresh cycle. Columns: Timestamp, Duration,
 /// Command, Client. Rows are sortable by arrival order (newest first).
 use crate::connection::{SlowLogEntry, list_commands};
-use crate::states::{ServerEvent, ZedisServerState, i18n_common, i18n_slowlog_editor};
+use crate::helpers::format_timestamp_ms;
+use crate::states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_slowlog_editor};
 use crate::{assets::CustomIconName, constants::SIDEBAR_WIDTH};
-use chrono::TimeZone;
 use gpui::{ClipboardItem, Edges, Entity, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::button::ButtonVariants;
 use gpui_component::input::{Input, InputEvent, InputState};
@@ -72,19 +72,15 @@ struct SlowLogRow {
 impl SlowLogRow {
     /// Converts a raw [`SlowLogEntry`] from the server into a display-ready row.
     ///
-    /// - `timestamp` is formatted as local time (`YYYY-MM-DD HH:MM:SS`).
+    /// - `timestamp` is formatted as local or UTC time (`YYYY-MM-DD HH:MM:SS`).
     /// - `duration` is formatted as a human-readable string (e.g. `"12ms"`).
     /// - `command` / `args` are split by checking whether the first two tokens
     ///   form a known two-word command (e.g. `"CONFIG GET"`, `"SLOWLOG GET"`).
     ///   If so, both tokens become the command; otherwise only the first token is
     ///   used. All tokens are upper-cased for consistent display.
     /// - `client` combines the peer address with the optional connection name.
-    fn from_entry(entry: &SlowLogEntry) -> Self {
-        let timestamp = chrono::Local
-            .timestamp_opt(entry.timestamp, 0)
-            .single()
-            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-            .unwrap_or_default();
+    fn from_entry(entry: &SlowLogEntry, utc: bool) -> Self {
+        let timestamp = format_timestamp_ms(entry.timestamp * 1000, "%Y-%m-%d %H:%M:%S", utc).unwrap_or_default();
 
         let duration_ms = entry.duration.as_millis() as u64;
         let duration = humantime::format_duration(Duration::from_millis(duration_ms)).to_string();
@@ -297,6 +293,7 @@ impl TableDelegate for SlowlogTableDelegate {
 
         let group_name: SharedString = format!("slowlog-td-{}-{}", row_ix, col_ix).into();
         let copied_message = i18n_common(cx, "copied_to_clipboard");
+        let copy_tooltip = i18n_common(cx, "copy_tooltip");
         h_flex()
             .size_full()
             .when_some(column.paddings, |this, paddings| this.paddings(paddings))
@@ -320,6 +317,7 @@ impl TableDelegate for SlowlogTableDelegate {
                         Button::new(("copy-cell", row_ix * 100 + col_ix))
                             .ghost()
                             .icon(IconName::Copy)
+                            .tooltip(copy_tooltip.clone())
                             .on_click(move |_, window, cx: &mut gpui::App| {
                                 cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
                                 window.push_notification(Notification::info(copied_message.clone()), cx);
@@ -445,8 +443,9 @@ impl ZedisSlowlogEditor {
     /// Reads the current slow-log entries from the server state and converts them
     /// into display rows.
     fn build_all_rows(server_state: &Entity<ZedisServerState>, cx: &gpui::App) -> Vec<SlowLogRow> {
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
         let entries = server_state.read(cx).slow_logs();
-        entries.iter().map(SlowLogRow::from_entry).collect()
+        entries.iter().map(|entry| SlowLogRow::from_entry(entry, utc)).collect()
     }
 
     /// Extracts unique command names from rows, sorted alphabetically.
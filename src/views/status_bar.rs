@@ -12,28 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::running_tasks::ZedisRunningTasks;
 use crate::{
     assets::CustomIconName,
-    connection::RedisClientDescription,
-    constants::STATUS_BAR_HEIGHT,
-    helpers::humanize_keystroke,
+    connection::{AclPermissions, RedisClientDescription, ServerFlavor, get_server},
+    constants::{STATUS_BAR_HEIGHT, STATUS_BAR_TAB_INDEX},
+    helpers::{humanize_keystroke, server_accent_color},
     states::{
         ErrorMessage, GlobalEvent, Route, ServerEvent, ServerTask, ViewMode, ZedisGlobalStore, ZedisServerState,
-        get_session_option, i18n_sidebar, i18n_status_bar, save_session_option,
+        i18n_sidebar, i18n_status_bar, save_session_option,
     },
 };
-use gpui::{Entity, Hsla, SharedString, Subscription, Task, TextAlign, Window, div, prelude::*};
+use gpui::{App, ClipboardItem, Entity, Hsla, SharedString, Subscription, Task, TextAlign, Window, div, prelude::*};
 use gpui_component::select::{SearchableVec, Select, SelectEvent, SelectItem, SelectState};
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable,
+    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable, WindowExt,
     button::{Button, ButtonVariants},
     h_flex,
     label::Label,
+    notification::Notification,
+    spinner::Spinner,
     tooltip::Tooltip,
 };
+use rust_i18n::t;
 use std::{sync::Arc, time::Duration};
 use tracing::{debug, info};
-use zedis_ui::ZedisDivider;
+use zedis_ui::{ZedisDialog, ZedisDivider};
 
 /// Formats the database size and scan count string "count/total".
 #[inline]
@@ -68,25 +72,19 @@ fn format_latency(latency: Option<Duration>, cx: &Context<ZedisStatusBar>) -> (S
 
 /// Formats the node count and version information.
 #[inline]
-fn format_nodes(nodes: (usize, usize), version: &str) -> SharedString {
-    format!("{} / {} (v{})", nodes.0, nodes.1, version).into()
+fn format_nodes(nodes: (usize, usize), flavor: ServerFlavor, version: &str) -> SharedString {
+    format!("{} / {} ({} v{})", nodes.0, nodes.1, flavor.as_str(), version).into()
 }
 
 #[inline]
 fn format_nodes_description(description: Arc<RedisClientDescription>, cx: &Context<ZedisStatusBar>) -> SharedString {
     let t = i18n_sidebar(cx, "server_type");
+    let flavor = i18n_sidebar(cx, "server_flavor");
     let master_nodes = i18n_sidebar(cx, "master_nodes");
     let slave_nodes = i18n_sidebar(cx, "slave_nodes");
     let mut messages = Vec::with_capacity(4);
 
-    messages.push(format!(
-        "Valkey: {}",
-        if description.is_valkey {
-            i18n_sidebar(cx, "yes")
-        } else {
-            i18n_sidebar(cx, "no")
-        }
-    ));
+    messages.push(format!("{flavor}: {}", description.flavor.as_str()));
     messages.push(format!("{t}: {}", description.server_type.as_str()));
     messages.push(format!("{master_nodes}: {}", description.master_nodes));
     if !description.slave_nodes.is_empty() {
@@ -95,6 +93,23 @@ fn format_nodes_description(description: Arc<RedisClientDescription>, cx: &Conte
     messages.join("\n").into()
 }
 
+/// Returns why a feature is disabled when the ACL user may not run the command it needs.
+fn acl_denied_tooltip(acl: &AclPermissions, command: &str, cx: &App) -> Option<SharedString> {
+    if acl.allows(command) {
+        return None;
+    }
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    Some(
+        t!(
+            "status_bar.acl_denied",
+            user = acl.user,
+            command = command,
+            locale = locale
+        )
+        .into(),
+    )
+}
+
 // --- Local State ---
 
 #[derive(Default)]
@@ -106,10 +121,22 @@ struct StatusBarServerState {
     used_memory: SharedString,
     clients: SharedString,
     nodes: SharedString,
+    supports_hot_keys: bool,
     scan_finished: bool,
     soft_wrap: bool,
+    line_numbers: bool,
     nodes_description: SharedString,
     slow_log_tips: SharedString,
+    /// Why metrics are disabled for the ACL user
+    metrics_denied: Option<SharedString>,
+    /// Why the clients list is disabled for the ACL user
+    clients_denied: Option<SharedString>,
+    /// Why the slow logs are disabled for the ACL user
+    slowlog_denied: Option<SharedString>,
+    /// Accent color name of the server, shown as the top border
+    color: Option<SharedString>,
+    /// Short environment tag of the server, e.g. `PROD`
+    label: Option<SharedString>,
 }
 
 #[derive(Debug, Clone)]
@@ -195,6 +222,8 @@ impl ZedisStatusBar {
                         this.state.error = None;
                     }
                 }
+                // re-render the running tasks indicator
+                ServerEvent::TaskFinished(_) => {}
                 ServerEvent::ValueLoaded => {
                     let state = server_state.read(cx);
                     this.should_reset_viewer_mode = Some(true);
@@ -297,6 +326,10 @@ impl ZedisStatusBar {
         } else {
             self.state.server_state.size = SharedString::default();
         }
+        // The color and label may have been edited while the server stays selected
+        let server = get_server(&self.state.server_state.server_id).ok();
+        self.state.server_state.color = server.as_ref().and_then(|server| server.color.clone().map(Into::into));
+        self.state.server_state.label = server.and_then(|server| server.label.map(Into::into));
         self.state.data_format = None;
         self.state.error = None;
     }
@@ -322,7 +355,9 @@ impl ZedisStatusBar {
             )
         };
 
+        let nodes_description = state.nodes_description();
         let slow_log_tips = format!("{} / {}", state.last_slow_log_count(), state.slow_logs().len()).into();
+        let acl = state.acl_permissions();
         self.state.server_state = StatusBarServerState {
             supports_db_selection: state.supports_db_selection(),
             server_id: state.server_id().to_string().into(),
@@ -330,11 +365,16 @@ impl ZedisStatusBar {
             latency: format_latency(Some(Duration::from_millis(redis_info.metrics.latency_ms)), cx),
             used_memory: used_memory.into(),
             clients: clients.into(),
-            nodes: format_nodes(state.nodes(), state.version()),
+            nodes: format_nodes(state.nodes(), nodes_description.flavor, state.version()),
+            supports_hot_keys: nodes_description.flavor.supports_command("OBJECT FREQ"),
             scan_finished: state.scan_completed(),
             slow_log_tips,
             soft_wrap: state.soft_wrap(),
-            nodes_description: format_nodes_description(state.nodes_description().clone(), cx),
+            line_numbers: state.line_numbers(),
+            nodes_description: format_nodes_description(nodes_description, cx),
+            metrics_denied: acl_denied_tooltip(acl, "INFO", cx),
+            clients_denied: acl_denied_tooltip(acl, "CLIENT LIST", cx),
+            slowlog_denied: acl_denied_tooltip(acl, "SLOWLOG GET", cx),
         };
     }
     /// Start the heartbeat task
@@ -361,11 +401,26 @@ impl ZedisStatusBar {
         );
         let readonly_tooltip = i18n_status_bar(cx, "toggle_readonly_tooltip");
 
+        let accent = server_state
+            .color
+            .as_ref()
+            .and_then(|color| server_accent_color(color, cx));
+
         ZedisDivider::new()
             .child(
                 h_flex()
                     .items_center()
                     .gap_2()
+                    .when_some(server_state.label.clone(), |this, label| {
+                        this.child(
+                            Label::new(label)
+                                .text_xs()
+                                .px_1()
+                                .rounded_sm()
+                                .text_color(cx.theme().background)
+                                .bg(accent.unwrap_or(cx.theme().muted_foreground)),
+                        )
+                    })
                     .child(
                         Button::new("zedis-status-bar-server-terminal")
                             .ghost()
@@ -439,6 +494,21 @@ impl ZedisStatusBar {
                             )
                             .id("zedis-servers")
                             .tooltip(move |window, cx| Tooltip::new(nodes_description.clone()).build(window, cx)),
+                    )
+                    .child(
+                        Button::new("zedis-status-bar-copy-connection-info")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::Copy)
+                            .tooltip(i18n_status_bar(cx, "copy_connection_info_tooltip"))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                let summary = this.server_state.read(cx).connection_summary();
+                                cx.write_to_clipboard(ClipboardItem::new_string(summary));
+                                window.push_notification(
+                                    Notification::info(i18n_status_bar(cx, "connection_info_copied")),
+                                    cx,
+                                );
+                            })),
                     ),
             )
             .child(
@@ -453,7 +523,13 @@ impl ZedisStatusBar {
                                     .ghost()
                                     .small()
                                     .icon(CustomIconName::Activity)
-                                    .tooltip(i18n_status_bar(cx, "toggle_metrics_tooltip"))
+                                    .disabled(server_state.metrics_denied.is_some())
+                                    .tooltip(
+                                        server_state
+                                            .metrics_denied
+                                            .clone()
+                                            .unwrap_or_else(|| i18n_status_bar(cx, "toggle_metrics_tooltip")),
+                                    )
                                     .on_click(cx.listener(|_this, _, _window, cx| {
                                         cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
                                             state.toggle_route((Route::Metrics, Route::Editor), cx);
@@ -477,7 +553,45 @@ impl ZedisStatusBar {
                                         });
                                     })),
                             )
-                            .child(Label::new(server_state.used_memory.clone())),
+                            .child(Label::new(server_state.used_memory.clone()))
+                            .when(server_state.supports_hot_keys, |this| {
+                                this.child(
+                                    Button::new("zedis-status-bar-server-hot-keys")
+                                        .ghost()
+                                        .small()
+                                        .icon(CustomIconName::Zap)
+                                        .tooltip(i18n_status_bar(cx, "toggle_hot_keys_tooltip"))
+                                        .on_click(cx.listener(|_this, _, _window, cx| {
+                                            cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                                                state.toggle_route((Route::HotKeys, Route::Editor), cx);
+                                            });
+                                        })),
+                                )
+                            })
+                            .child(
+                                Button::new("zedis-status-bar-server-ttl-histogram")
+                                    .ghost()
+                                    .small()
+                                    .icon(CustomIconName::Clock3)
+                                    .tooltip(i18n_status_bar(cx, "toggle_ttl_histogram_tooltip"))
+                                    .on_click(cx.listener(|_this, _, _window, cx| {
+                                        cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                                            state.toggle_route((Route::TtlHistogram, Route::Editor), cx);
+                                        });
+                                    })),
+                            )
+                            .child(
+                                Button::new("zedis-status-bar-value-diff")
+                                    .ghost()
+                                    .small()
+                                    .icon(CustomIconName::GitCompare)
+                                    .tooltip(i18n_status_bar(cx, "toggle_value_diff_tooltip"))
+                                    .on_click(cx.listener(|_this, _, _window, cx| {
+                                        cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
+                                            state.toggle_route((Route::ValueDiff, Route::Editor), cx);
+                                        });
+                                    })),
+                            ),
                     )
                     .child(
                         h_flex()
@@ -487,7 +601,13 @@ impl ZedisStatusBar {
                                     .ghost()
                                     .small()
                                     .icon(Icon::new(CustomIconName::AudioWaveform))
-                                    .tooltip(i18n_status_bar(cx, "toggle_clients_tooltip"))
+                                    .disabled(server_state.clients_denied.is_some())
+                                    .tooltip(
+                                        server_state
+                                            .clients_denied
+                                            .clone()
+                                            .unwrap_or_else(|| i18n_status_bar(cx, "toggle_clients_tooltip")),
+                                    )
                                     .on_click(cx.listener(|_this, _, _window, cx| {
                                         cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
                                             state.toggle_route((Route::Clients, Route::Editor), cx);
@@ -504,7 +624,13 @@ impl ZedisStatusBar {
                                     .ghost()
                                     .small()
                                     .icon(CustomIconName::Snail)
-                                    .tooltip(i18n_status_bar(cx, "toggle_slowlog_tooltip"))
+                                    .disabled(server_state.slowlog_denied.is_some())
+                                    .tooltip(
+                                        server_state
+                                            .slowlog_denied
+                                            .clone()
+                                            .unwrap_or_else(|| i18n_status_bar(cx, "toggle_slowlog_tooltip")),
+                                    )
                                     .on_click(cx.listener(|_this, _, _window, cx| {
                                         cx.global::<ZedisGlobalStore>().clone().update(cx, |state, cx| {
                                             state.toggle_route((Route::Slowlog, Route::Editor), cx);
@@ -517,7 +643,7 @@ impl ZedisStatusBar {
     }
     fn render_editor_settings(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let server_state = &self.state.server_state;
-        Button::new("soft-wrap")
+        let soft_wrap_btn = Button::new("soft-wrap")
             .ghost()
             .xsmall()
             .when(server_state.soft_wrap, |this| this.icon(IconName::Check))
@@ -532,11 +658,30 @@ impl ZedisStatusBar {
                 cx.notify();
 
                 let server_id = this.state.server_state.server_id.clone();
-                if let Ok(mut option) = get_session_option(server_id.as_str()) {
-                    option.soft_wrap = Some(soft_wrap);
-                    save_session_option(server_id.as_str(), option, cx);
-                }
-            }))
+                save_session_option(server_id.as_str(), |option| option.soft_wrap = Some(soft_wrap), cx);
+            }));
+        let line_numbers_btn = Button::new("line-numbers")
+            .ghost()
+            .xsmall()
+            .when(server_state.line_numbers, |this| this.icon(IconName::Check))
+            .tooltip(i18n_status_bar(cx, "line_numbers_tooltip"))
+            .label(i18n_status_bar(cx, "line_numbers"))
+            .on_click(cx.listener(|this, _, _window, cx| {
+                let line_numbers = !this.state.server_state.line_numbers;
+                this.state.server_state.line_numbers = line_numbers;
+                this.server_state.update(cx, |state, cx| {
+                    state.set_line_numbers(line_numbers, cx);
+                });
+                cx.notify();
+
+                let server_id = this.state.server_state.server_id.clone();
+                save_session_option(
+                    server_id.as_str(),
+                    |option| option.line_numbers = Some(line_numbers),
+                    cx,
+                );
+            }));
+        h_flex().child(soft_wrap_btn).child(line_numbers_btn)
     }
     fn render_data_format(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let Some(data_format) = self.state.data_format.clone() else {
@@ -561,20 +706,79 @@ impl ZedisStatusBar {
             .child(Select::new(&self.viewer_mode_state).appearance(false))
     }
 
+    /// Renders the number of running tasks, clicking it lists them with a cancel button.
+    ///
+    /// The periodic info refresh is not counted so the indicator does not blink on every heartbeat.
+    fn render_running_tasks(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let count = self
+            .server_state
+            .read(cx)
+            .running_tasks()
+            .iter()
+            .filter(|item| item.task != ServerTask::RefreshRedisInfo)
+            .count();
+        if count == 0 {
+            return h_flex().into_any_element();
+        }
+        h_flex()
+            .id("zedis-status-bar-running-tasks")
+            .gap_1()
+            .items_center()
+            .cursor_pointer()
+            .child(Spinner::new().xsmall().color(cx.theme().muted_foreground))
+            .child(Label::new(count.to_string()).text_xs())
+            .tooltip(|window, cx| Tooltip::new(i18n_status_bar(cx, "running_tasks_tooltip")).build(window, cx))
+            .on_click(cx.listener(|this, _, window, cx| {
+                let server_state = this.server_state.clone();
+                let view = cx.new(|cx| ZedisRunningTasks::new(server_state, cx));
+                ZedisDialog::new(i18n_status_bar(cx, "running_tasks"))
+                    .alert()
+                    .child(move || view.clone())
+                    .open(window, cx);
+            }))
+            .into_any_element()
+    }
+
     /// Render the error message
     fn render_errors(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let Some(data) = &self.state.error else {
             return h_flex().flex_1();
         };
-        // error message is always on the right
-        h_flex().flex_1().child(
-            Label::new(data.message.clone())
-                .mr_2()
-                .w_full()
-                .text_xs()
-                .text_color(cx.theme().red)
-                .text_align(TextAlign::Right),
-        )
+        let details = data.details.clone();
+        let rtl = cx.global::<ZedisGlobalStore>().read(cx).is_rtl();
+        // error message is always on the trailing side
+        h_flex()
+            .flex_1()
+            .items_center()
+            .child(
+                div()
+                    .id("zedis-status-bar-error")
+                    .flex_1()
+                    .min_w_0()
+                    .child(
+                        Label::new(data.message.clone())
+                            .mr_2()
+                            .w_full()
+                            .text_xs()
+                            .text_color(cx.theme().red)
+                            .text_align(if rtl { TextAlign::Left } else { TextAlign::Right }),
+                    )
+                    .tooltip({
+                        let details = details.clone();
+                        move |window, cx| Tooltip::new(details.clone()).build(window, cx)
+                    }),
+            )
+            .child(
+                Button::new("zedis-status-bar-copy-error")
+                    .ghost()
+                    .xsmall()
+                    .icon(IconName::Copy)
+                    .tooltip(i18n_status_bar(cx, "copy_error_details_tooltip"))
+                    .on_click(cx.listener(move |_this, _, window, cx| {
+                        cx.write_to_clipboard(ClipboardItem::new_string(details.to_string()));
+                        window.push_notification(Notification::info(i18n_status_bar(cx, "copied_error_details")), cx);
+                    })),
+            )
     }
 }
 
@@ -600,14 +804,31 @@ impl Render for ZedisStatusBar {
             });
         }
         h_flex()
+            .id("zedis-status-bar")
+            .tab_group()
+            .tab_index(STATUS_BAR_TAB_INDEX)
             .justify_between()
+            .when(cx.global::<ZedisGlobalStore>().read(cx).is_rtl(), |this| {
+                this.flex_row_reverse()
+            })
             .h(STATUS_BAR_HEIGHT)
             .text_sm()
             .py_1p5()
             .px_4()
             .gap_2()
-            .border_t_1()
-            .border_color(cx.theme().border)
+            .map(|this| {
+                // An accented server (e.g. production) gets a thicker colored top border
+                match self
+                    .state
+                    .server_state
+                    .color
+                    .as_ref()
+                    .and_then(|color| server_accent_color(color, cx))
+                {
+                    Some(color) => this.border_t_2().border_color(color),
+                    None => this.border_t_1().border_color(cx.theme().border),
+                }
+            })
             .text_color(cx.theme().muted_foreground)
             .child(
                 ZedisDivider::new()
@@ -623,5 +844,6 @@ impl Render for ZedisStatusBar {
                     }),
             )
             .child(self.render_errors(window, cx))
+            .child(self.render_running_tasks(window, cx))
     }
 }
@@ -15,13 +15,40 @@
 use crate::{
     components::ZedisKvFetcher,
     components::{KvTableColumn, KvTableMode},
-    helpers::fast_contains_ignore_case,
-    states::{KeyType, RedisValue, ZedisServerState},
+    helpers::{fast_contains_ignore_case, format_timestamp_ms},
+    states::{KeyType, RedisValue, ZedisGlobalStore, ZedisServerState},
     views::{ZedisKvTable, kv_table::define_kv_editor},
 };
 use gpui::{App, Entity, SharedString, Window, prelude::*};
 use zedis_ui::ZedisFormFieldType;
 
+const ENTRY_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+/// Returns the millisecond timestamp of a stream entry id (`<ms>-<seq>`).
+fn entry_id_timestamp(entry_id: &str) -> Option<i64> {
+    let (ms, seq) = entry_id.split_once('-')?;
+    seq.parse::<u64>().ok()?;
+    ms.parse::<i64>().ok()
+}
+
+/// Builds the table columns for a stream: the entry id followed by the
+/// union of field names across all loaded entries.
+fn new_stream_columns(fields: &[SharedString]) -> Vec<KvTableColumn> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if index == 0 {
+                KvTableColumn::new_auto_created("Entry Id")
+            } else {
+                KvTableColumn::new(field.as_str(), None)
+                    .field_type(ZedisFormFieldType::Editor)
+                    .data_driven()
+            }
+        })
+        .collect()
+}
+
 /// Manages Redis Stream values and their display state.
 ///
 /// Handles both filtered and unfiltered views of stream data, maintaining
@@ -94,6 +121,13 @@ impl ZedisStreamValues {
         self.visible_entry_indexes = visible_entry_indexes;
         self.visible_item_indexes = Some(visible_item_indexes);
     }
+
+    /// Returns the entry id at the specified visible index.
+    fn entry_id(&self, index: usize) -> Option<SharedString> {
+        let stream = self.value.stream_value()?;
+        let real_index = *self.visible_entry_indexes.get(index)?;
+        stream.get_entry_id(real_index)
+    }
 }
 
 impl ZedisKvFetcher for ZedisStreamValues {
@@ -112,6 +146,11 @@ impl ZedisKvFetcher for ZedisStreamValues {
     fn primary_index(&self) -> usize {
         1
     }
+    /// Re-derives columns from the loaded entries, so fields that only appear
+    /// in later pages get their own column instead of rendering as `--`.
+    fn columns(&self) -> Option<Vec<KvTableColumn>> {
+        Some(new_stream_columns(&self.fields))
+    }
     fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
         let fields = value.stream_fields();
         let mut this = Self {
@@ -146,6 +185,27 @@ impl ZedisKvFetcher for ZedisStreamValues {
         stream_value.get_field_value(real_row_ix, field)
     }
 
+    /// Returns all fields of the entry in their original order, including
+    /// fields that have no column in the table.
+    fn row_detail(&self, row_ix: usize) -> Option<Vec<(SharedString, SharedString)>> {
+        let stream_value = self.value.stream_value()?;
+        let real_row_ix = *self.visible_entry_indexes.get(row_ix)?;
+        let (_, fields) = stream_value.values.get(real_row_ix)?;
+        Some(fields.clone())
+    }
+
+    /// Shows the entry id's timestamp as a datetime, honoring the UTC preference.
+    fn cell_tooltip(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<SharedString> {
+        if col_ix != 1 {
+            return None;
+        }
+        let entry_id = self.entry_id(row_ix)?;
+        let ts = entry_id_timestamp(&entry_id)?;
+        let utc = cx.global::<ZedisGlobalStore>().read(cx).utc_time();
+        let suffix = if utc { " UTC" } else { "" };
+        format_timestamp_ms(ts, ENTRY_TIME_FORMAT, utc).map(|time| format!("{time}{suffix}").into())
+    }
+
     /// Returns the total count of entries in the Redis stream (from XLEN).
     fn count(&self) -> usize {
         self.value.stream_value().map_or(0, |v| v.size)
@@ -236,24 +296,88 @@ impl ZedisStreamEditor {
         };
 
         let table_state = cx.new(|cx| {
-            ZedisKvTable::<ZedisStreamValues>::new(
-                fields
-                    .iter()
-                    .enumerate()
-                    .map(|(index, field)| {
-                        if index == 0 {
-                            KvTableColumn::new_auto_created("Entry Id")
-                        } else {
-                            KvTableColumn::new(field.as_str(), None).field_type(ZedisFormFieldType::Editor)
-                        }
-                    })
-                    .collect(),
-                server_state,
-                window,
-                cx,
-            )
-            .mode(KvTableMode::ADD | KvTableMode::REMOVE | KvTableMode::FILTER)
+            ZedisKvTable::<ZedisStreamValues>::new(new_stream_columns(&fields), server_state, window, cx)
+                .mode(KvTableMode::ADD | KvTableMode::REMOVE | KvTableMode::FILTER)
         });
         Self { table_state }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::{RedisStreamEntry, RedisStreamValue, RedisValueData};
+    use std::sync::Arc;
+
+    fn entry(id: &'static str, fields: &[(&'static str, &'static str)]) -> RedisStreamEntry {
+        (
+            id.into(),
+            fields
+                .iter()
+                .map(|(field, value)| (SharedString::from(*field), SharedString::from(*value)))
+                .collect(),
+        )
+    }
+
+    fn stream_value(values: Vec<RedisStreamEntry>) -> RedisValue {
+        RedisValue {
+            key_type: KeyType::Stream,
+            data: Some(RedisValueData::Stream(Arc::new(RedisStreamValue {
+                size: values.len(),
+                values,
+                ..Default::default()
+            }))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_stream_columns_disjoint_fields() {
+        let value = stream_value(vec![
+            entry("1-0", &[("name", "a"), ("age", "1")]),
+            entry("2-0", &[("city", "x")]),
+            entry("3-0", &[("age", "3"), ("zip", "100")]),
+        ]);
+        let fields = value.stream_fields();
+        assert_eq!(
+            fields,
+            vec![
+                SharedString::from("Entry Id"),
+                "name".into(),
+                "age".into(),
+                "city".into(),
+                "zip".into(),
+            ]
+        );
+
+        let names: Vec<SharedString> = new_stream_columns(&fields)
+            .into_iter()
+            .map(|column| column.name)
+            .collect();
+        assert_eq!(names, fields);
+
+        let stream = value.stream_value().unwrap();
+        assert_eq!(stream.get_field_value(1, &"city".into()), Some("x".into()));
+        assert_eq!(stream.get_field_value(1, &"name".into()), None);
+        assert_eq!(stream.get_field_value(2, &"zip".into()), Some("100".into()));
+    }
+
+    #[test]
+    fn test_stream_columns_grow_with_loaded_pages() {
+        let first_page = stream_value(vec![entry("1-0", &[("name", "a")])]);
+        assert_eq!(first_page.stream_fields().len(), 2);
+
+        let next_page = stream_value(vec![entry("1-0", &[("name", "a")]), entry("2-0", &[("level", "warn")])]);
+        let fields = next_page.stream_fields();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[2], SharedString::from("level"));
+    }
+
+    #[test]
+    fn test_stream_columns_empty_stream() {
+        let value = stream_value(vec![]);
+        let columns = new_stream_columns(&value.stream_fields());
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].name, SharedString::from("Entry Id"));
+    }
+}
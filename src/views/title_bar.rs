@@ -15,7 +15,9 @@
 use crate::helpers::MemuAction;
 use crate::{
     assets::CustomIconName,
-    states::{FontSize, FontSizeAction, LocaleAction, SettingsAction, ThemeAction, ZedisGlobalStore, i18n_sidebar},
+    states::{
+        FontSize, FontSizeAction, LOCALES, LocaleAction, SettingsAction, ThemeAction, ZedisGlobalStore, i18n_sidebar,
+    },
 };
 use gpui::{App, Context, Corner, Window, prelude::*};
 use gpui_component::{
@@ -37,7 +39,7 @@ impl ZedisTitleBar {
         let store = cx.global::<ZedisGlobalStore>().read(cx);
         let (font_size, locale, theme) = (store.font_size(), store.locale(), store.theme());
 
-        this
+        let mut menu = this
             // font size menu
             .label(i18n_sidebar(cx, "font_size"))
             .menu_with_check(
@@ -57,10 +59,15 @@ impl ZedisTitleBar {
             )
             .separator()
             // language menu
-            .label(i18n_sidebar(cx, "lang"))
-            .menu_with_check("中文", locale == "zh", Box::new(LocaleAction::Zh))
-            .menu_with_check("English", locale == "en", Box::new(LocaleAction::En))
-            .separator()
+            .label(i18n_sidebar(cx, "lang"));
+        for (code, name) in LOCALES {
+            menu = menu.menu_with_check(
+                *name,
+                locale == *code,
+                Box::new(LocaleAction { locale: (*code).into() }),
+            );
+        }
+        menu.separator()
             // theme menu
             .label(i18n_sidebar(cx, "theme"))
             .menu_with_check(
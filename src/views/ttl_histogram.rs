@@ -0,0 +1,351 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Key TTL histogram.
+///
+/// Scans keys matching a pattern, pipelines `TTL` per batch and groups the
+/// results into expiry buckets, to check whether cache keys actually expire.
+use super::memory_analysis::format_thousands;
+use super::metrics::{ChartParams, make_bar_canvas};
+use crate::assets::CustomIconName;
+use crate::connection::get_connection_manager;
+use crate::error::Error;
+use crate::states::{ZedisServerState, i18n_ttl_histogram};
+use gpui::{Entity, SharedString, Task, Window, div, prelude::*, px};
+use gpui_component::button::ButtonVariants;
+use gpui_component::input::{Input, InputState};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName, Sizable, StyledExt, button::Button, h_flex, label::Label, v_flex,
+};
+use std::time::{Duration, Instant};
+use tracing::error;
+use zedis_ui::ZedisDivider;
+
+const DEFAULT_SCAN_COUNT: u64 = 200;
+const DEFAULT_PATTERN: &str = "*";
+const CHART_HEIGHT: f32 = 320.;
+
+/// Upper bounds (in seconds, exclusive) of the bounded TTL buckets.
+const MINUTE_SECS: i64 = 60;
+const HOUR_SECS: i64 = 60 * MINUTE_SECS;
+const DAY_SECS: i64 = 24 * HOUR_SECS;
+
+/// Locale keys of the buckets, in display order.
+const BUCKET_KEYS: [&str; 5] = ["no_expiry", "lt_1m", "lt_1h", "lt_1d", "gt_1d"];
+
+/// Returns the bucket index of a TTL in seconds (`-1` means no expiry).
+fn bucket_index(ttl: i64) -> usize {
+    match ttl {
+        ttl if ttl < 0 => 0,
+        ttl if ttl < MINUTE_SECS => 1,
+        ttl if ttl < HOUR_SECS => 2,
+        ttl if ttl < DAY_SECS => 3,
+        _ => 4,
+    }
+}
+
+pub struct ZedisTtlHistogram {
+    server_state: Entity<ZedisServerState>,
+    pattern_input_state: Entity<InputState>,
+    /// Key count per bucket, see `BUCKET_KEYS`
+    buckets: [u64; 5],
+    /// Number of keys with a TTL result
+    sampled: u64,
+    running: bool,
+    progress: SharedString,
+    /// Error from the last scan
+    error: Option<SharedString>,
+    scan_task: Option<Task<()>>,
+    /// Database key count fetched on load.
+    dbsize: Option<u64>,
+}
+
+impl ZedisTtlHistogram {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut gpui::Context<Self>) -> Self {
+        let pattern_input_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_ttl_histogram(cx, "pattern_placeholder"))
+                .default_value(DEFAULT_PATTERN)
+        });
+        let dbsize = server_state.read(cx).dbsize();
+        Self {
+            server_state,
+            pattern_input_state,
+            buckets: [0; 5],
+            sampled: 0,
+            running: false,
+            progress: SharedString::default(),
+            error: None,
+            scan_task: None,
+            dbsize,
+        }
+    }
+
+    fn stop(&mut self, cx: &mut gpui::Context<Self>) {
+        self.scan_task.take();
+        self.running = false;
+        cx.notify();
+    }
+
+    /// Scans the keys matching the pattern, pipelining `TTL` per batch, and updates
+    /// the buckets after each batch. Dropping the task via `stop` cancels the scan.
+    fn sample_ttl(&mut self, cx: &mut gpui::Context<Self>) {
+        let pattern = self.pattern_input_state.read(cx).value().trim().to_string();
+        let pattern = if pattern.is_empty() {
+            DEFAULT_PATTERN.to_string()
+        } else {
+            pattern
+        };
+        self.running = true;
+        self.progress = "0%".into();
+        self.buckets = [0; 5];
+        self.sampled = 0;
+        self.error = None;
+
+        let server_state = self.server_state.read(cx);
+        let server_id = server_state.server_id().to_string();
+        let db = server_state.db();
+        let dbsize = self.dbsize.unwrap_or(0);
+
+        self.scan_task = Some(cx.spawn(async move |handle, cx| {
+            let mut cursors: Option<Vec<u64>> = None;
+            let mut scanned: u64 = 0;
+            let min_sleep = Duration::from_micros(500);
+            let max_sleep = Duration::from_millis(20);
+
+            loop {
+                let scan_task = cx.background_spawn({
+                    let server_id = server_id.clone();
+                    let pattern = pattern.clone();
+                    let cursors = cursors.clone();
+                    async move {
+                        let start = Instant::now();
+                        let client = get_connection_manager().get_client(&server_id, db).await?;
+                        let result = client.sample_scan_ttl(&pattern, DEFAULT_SCAN_COUNT, cursors).await?;
+                        // Throttle to leave room for other clients
+                        smol::Timer::after(start.elapsed().mul_f64(0.5).clamp(min_sleep, max_sleep)).await;
+                        Ok::<(u64, Vec<u64>, Vec<i64>), Error>(result)
+                    }
+                });
+
+                let (count, new_cursors, ttls) = match scan_task.await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        error!(error = %e, "Failed to sample scan for ttl histogram");
+                        let message: SharedString = e.to_string().into();
+                        let _ = handle.update(cx, |this, _cx| {
+                            this.error = Some(message);
+                        });
+                        break;
+                    }
+                };
+                // SCAN iterates the whole keyspace, MATCH only filters the returned keys
+                scanned += DEFAULT_SCAN_COUNT.max(count);
+                let done = new_cursors.iter().all(|c| *c == 0);
+                let pct = if dbsize > 0 {
+                    ((scanned as f32 / dbsize as f32) * 100.0).min(99.0) as u32
+                } else {
+                    99
+                };
+
+                let _ = handle.update(cx, |this, cx| {
+                    for ttl in ttls {
+                        this.buckets[bucket_index(ttl)] += 1;
+                        this.sampled += 1;
+                    }
+                    this.progress = format!("{pct}%").into();
+                    cx.notify();
+                });
+
+                if done {
+                    break;
+                }
+                cursors = Some(new_cursors);
+            }
+
+            let _ = handle.update(cx, |this, cx| {
+                this.running = false;
+                if this.error.is_none() {
+                    this.progress = "100%".into();
+                }
+                cx.notify();
+            });
+        }));
+
+        cx.notify();
+    }
+
+    fn render_toolbar_functions(&self, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let stat_item = |cx: &mut gpui::Context<Self>, key: &'static str, value: SharedString| {
+            h_flex()
+                .gap_1()
+                .child(
+                    Label::new(i18n_ttl_histogram(cx, key))
+                        .text_color(cx.theme().muted_foreground)
+                        .text_sm(),
+                )
+                .child(Label::new(value).text_sm().font_weight(gpui::FontWeight::MEDIUM))
+        };
+
+        ZedisDivider::new()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .when_some(self.dbsize, |this, dbsize| {
+                        this.child(stat_item(cx, "dbsize", format_thousands(dbsize).into()))
+                    })
+                    .when(!self.progress.is_empty(), |this| {
+                        this.child(stat_item(cx, "sampled", format_thousands(self.sampled).into()))
+                            .child(stat_item(cx, "progress", self.progress.clone()))
+                    }),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(
+                        Input::new(&self.pattern_input_state)
+                            .small()
+                            .w(px(200.))
+                            .disabled(self.running),
+                    )
+                    .child(if self.running {
+                        Button::new("stop-ttl-histogram")
+                            .danger()
+                            .small()
+                            .label(i18n_ttl_histogram(cx, "stop"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.stop(cx);
+                            }))
+                    } else {
+                        Button::new("start-ttl-histogram")
+                            .primary()
+                            .small()
+                            .label(i18n_ttl_histogram(cx, "start"))
+                            .on_click(cx.listener(|this, _, _window, cx| {
+                                this.sample_ttl(cx);
+                            }))
+                    }),
+            )
+    }
+
+    fn render_chart(&self, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let labels: Vec<SharedString> = BUCKET_KEYS.iter().map(|key| i18n_ttl_histogram(cx, key)).collect();
+        let values: Vec<f64> = self.buckets.iter().map(|count| *count as f64).collect();
+        let y_max = values.iter().copied().fold(0., f64::max).max(1.);
+        let params = ChartParams {
+            dates: labels.clone(),
+            y_max,
+            y_format: Box::new(|v| format_thousands(v as u64)),
+            tick_margin: 1,
+            border: cx.theme().border,
+            muted_fg: cx.theme().muted_foreground,
+            baseline: None,
+        };
+        let sampled = self.sampled.max(1) as f64;
+        let summary = labels.into_iter().zip(self.buckets).map(|(label, count)| {
+            h_flex()
+                .gap_1()
+                .child(Label::new(label).text_sm().text_color(cx.theme().muted_foreground))
+                .child(
+                    Label::new(format!(
+                        "{} ({:.1}%)",
+                        format_thousands(count),
+                        count as f64 * 100.0 / sampled
+                    ))
+                    .text_sm()
+                    .font_weight(gpui::FontWeight::MEDIUM),
+                )
+        });
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .p_4()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius_lg)
+            .child(
+                div()
+                    .w_full()
+                    .h(px(CHART_HEIGHT))
+                    .child(make_bar_canvas(params, values, cx.theme().chart_2)),
+            )
+            .child(h_flex().flex_wrap().gap_4().children(summary))
+    }
+}
+
+impl gpui::Render for ZedisTtlHistogram {
+    fn render(&mut self, _window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .overflow_hidden()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.))
+                    .px_4()
+                    .justify_between()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Icon::new(CustomIconName::Clock3))
+                            .child(Label::new(i18n_ttl_histogram(cx, "title")).text_color(cx.theme().foreground)),
+                    )
+                    .child(self.render_toolbar_functions(cx)),
+            )
+            .child({
+                let mut body = v_flex()
+                    .flex_1()
+                    .w_full()
+                    .p_2()
+                    .min_h_0()
+                    .gap_2()
+                    .id("ttl-histogram-body")
+                    .overflow_y_scroll();
+
+                if let Some(message) = self.error.clone() {
+                    body = body.child(
+                        h_flex()
+                            .w_full()
+                            .gap_2()
+                            .px_3()
+                            .py_2()
+                            .rounded(cx.theme().radius)
+                            .bg(cx.theme().warning.opacity(0.1))
+                            .child(Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning))
+                            .child(Label::new(message).text_sm().text_color(cx.theme().warning)),
+                    );
+                }
+
+                if self.sampled > 0 {
+                    body = body.child(self.render_chart(cx));
+                } else if !self.running {
+                    body =
+                        body.child(div().size_full().flex().items_center().justify_center().child(
+                            Label::new(i18n_ttl_histogram(cx, "no_data")).text_color(cx.theme().muted_foreground),
+                        ));
+                }
+
+                body
+            })
+            .into_any_element()
+    }
+}
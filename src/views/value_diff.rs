@@ -0,0 +1,453 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Value diff view.
+///
+/// Loads two keys, from the same or different servers and dbs, and shows their
+/// structural diff side by side, e.g. to compare config keys across environments.
+use crate::assets::CustomIconName;
+use crate::connection::get_servers;
+use crate::error::Error;
+use crate::states::{DiffEntry, DiffKind, ValueDiff, ZedisServerState, diff_values, i18n_value_diff, load_diff_value};
+use gpui::{App, Entity, Hsla, SharedString, Task, UniformListScrollHandle, Window, div, prelude::*, px, uniform_list};
+use gpui_component::button::ButtonVariants;
+use gpui_component::input::{Input, InputState};
+use gpui_component::select::{Select, SelectItem, SelectState};
+use gpui_component::{
+    ActiveTheme, Disableable, Icon, IconName, IndexPath, Sizable, StyledExt, button::Button, h_flex, label::Label,
+    v_flex,
+};
+use tracing::error;
+use zedis_ui::ZedisDivider;
+
+const ROW_HEIGHT: f32 = 28.;
+const NAME_COLUMN_WIDTH: f32 = 200.;
+
+#[derive(Debug, Clone)]
+struct ServerOption {
+    name: SharedString,
+    id: SharedString,
+}
+
+impl SelectItem for ServerOption {
+    type Value = SharedString;
+    fn title(&self) -> SharedString {
+        self.name.clone()
+    }
+    fn value(&self) -> &Self::Value {
+        &self.id
+    }
+}
+
+/// Server, db and key of one side of the diff
+struct DiffSide {
+    server_state: Entity<SelectState<Vec<ServerOption>>>,
+    db_state: Entity<InputState>,
+    key_state: Entity<InputState>,
+}
+
+impl DiffSide {
+    fn new(
+        servers: Vec<ServerOption>,
+        server_id: &str,
+        db: usize,
+        key: SharedString,
+        window: &mut Window,
+        cx: &mut gpui::Context<ZedisValueDiff>,
+    ) -> Self {
+        let found = servers.iter().position(|item| item.id == server_id).map(IndexPath::new);
+        let server_state = cx.new(|cx| SelectState::new(servers, found, window, cx));
+        let db_state = cx.new(|cx| InputState::new(window, cx).default_value(db.to_string()));
+        let key_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(i18n_value_diff(cx, "key_placeholder"))
+                .default_value(key)
+        });
+        Self {
+            server_state,
+            db_state,
+            key_state,
+        }
+    }
+
+    /// Returns the server id, db and key, or None if any of them is missing or invalid
+    fn target(&self, cx: &App) -> Option<(SharedString, usize, SharedString)> {
+        let server_id = self.server_state.read(cx).selected_value()?.clone();
+        let db = self.db_state.read(cx).value().trim().parse::<usize>().ok()?;
+        let key = self.key_state.read(cx).value();
+        if key.is_empty() {
+            return None;
+        }
+        Some((server_id, db, key))
+    }
+
+    /// Returns a short description of the side, e.g. `local/0: config:app`
+    fn description(&self, cx: &App) -> SharedString {
+        let server = self.server_state.read(cx).selected_value().cloned().unwrap_or_default();
+        let db = self.db_state.read(cx).value();
+        let key = self.key_state.read(cx).value();
+        format!("{server}/{db}: {key}").into()
+    }
+}
+
+pub struct ZedisValueDiff {
+    left: DiffSide,
+    right: DiffSide,
+    diff: Option<ValueDiff>,
+    /// Descriptions of the compared sides, captured when the comparison started
+    titles: (SharedString, SharedString),
+    /// Hide the unchanged entries
+    only_changes: bool,
+    loading: bool,
+    /// Error of the last comparison
+    error: Option<SharedString>,
+    compare_task: Option<Task<()>>,
+    scroll_handle: UniformListScrollHandle,
+}
+
+impl ZedisValueDiff {
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut gpui::Context<Self>) -> Self {
+        let servers: Vec<ServerOption> = get_servers()
+            .unwrap_or_default()
+            .iter()
+            .map(|server| ServerOption {
+                name: server.name.clone().into(),
+                id: server.id.clone().into(),
+            })
+            .collect();
+        let state = server_state.read(cx);
+        let server_id = state.server_id().to_string();
+        let db = state.db();
+        let key = state.key().unwrap_or_default();
+        let left = DiffSide::new(servers.clone(), &server_id, db, key.clone(), window, cx);
+        let right = DiffSide::new(servers, &server_id, db, key, window, cx);
+        Self {
+            left,
+            right,
+            diff: None,
+            titles: Default::default(),
+            only_changes: true,
+            loading: false,
+            error: None,
+            compare_task: None,
+            scroll_handle: UniformListScrollHandle::new(),
+        }
+    }
+
+    /// Loads both values in the background and computes their diff.
+    fn compare(&mut self, cx: &mut gpui::Context<Self>) {
+        let (Some(left), Some(right)) = (self.left.target(cx), self.right.target(cx)) else {
+            self.error = Some(i18n_value_diff(cx, "target_required"));
+            cx.notify();
+            return;
+        };
+        self.loading = true;
+        self.error = None;
+        self.titles = (self.left.description(cx), self.right.description(cx));
+
+        self.compare_task = Some(cx.spawn(async move |handle, cx| {
+            let task = cx.background_spawn(async move {
+                let a = load_diff_value(&left.0, left.1, &left.2).await?;
+                let b = load_diff_value(&right.0, right.1, &right.2).await?;
+                Ok::<ValueDiff, Error>(diff_values(&a, &b))
+            });
+            let result = task.await;
+            let _ = handle.update(cx, |this, cx| {
+                this.loading = false;
+                match result {
+                    Ok(diff) => {
+                        this.diff = Some(diff);
+                    }
+                    Err(e) => {
+                        error!(error = %e, "Failed to load values for diff");
+                        this.diff = None;
+                        this.error = Some(e.to_string().into());
+                    }
+                }
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+
+    /// Entries shown in the table, honoring the "only changes" filter
+    fn visible_entries(&self) -> Vec<DiffEntry> {
+        let Some(diff) = &self.diff else {
+            return vec![];
+        };
+        diff.entries
+            .iter()
+            .filter(|entry| !self.only_changes || entry.kind != DiffKind::Unchanged)
+            .cloned()
+            .collect()
+    }
+
+    fn render_side(&self, id: &'static str, side: &DiffSide, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        v_flex()
+            .flex_1()
+            .gap_2()
+            .child(
+                Label::new(i18n_value_diff(cx, id))
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().w(px(180.)).child(Select::new(&side.server_state).small()))
+                    .child(Input::new(&side.db_state).small().w(px(60.)))
+                    .child(Input::new(&side.key_state).small().flex_1()),
+            )
+    }
+
+    fn render_summary(&self, diff: &ValueDiff, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let stat_item = |cx: &mut gpui::Context<Self>, key: &'static str, count: usize, color: Hsla| {
+            h_flex()
+                .gap_1()
+                .child(
+                    Label::new(i18n_value_diff(cx, key))
+                        .text_color(cx.theme().muted_foreground)
+                        .text_sm(),
+                )
+                .child(
+                    Label::new(count.to_string())
+                        .text_sm()
+                        .text_color(color)
+                        .font_weight(gpui::FontWeight::MEDIUM),
+                )
+        };
+        let message = if diff.type_mismatch {
+            Some(format!(
+                "{} ({} / {})",
+                i18n_value_diff(cx, "type_mismatch"),
+                diff.left_type.as_str(),
+                diff.right_type.as_str()
+            ))
+        } else if diff.truncated {
+            Some(i18n_value_diff(cx, "truncated").to_string())
+        } else {
+            None
+        };
+
+        ZedisDivider::new()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .when(diff.is_identical(), |this| {
+                        this.child(
+                            Label::new(i18n_value_diff(cx, "identical"))
+                                .text_sm()
+                                .text_color(cx.theme().success),
+                        )
+                    })
+                    .when(!diff.type_mismatch && !diff.is_identical(), |this| {
+                        this.child(stat_item(cx, "added", diff.count(DiffKind::Added), cx.theme().success))
+                            .child(stat_item(
+                                cx,
+                                "removed",
+                                diff.count(DiffKind::Removed),
+                                cx.theme().danger,
+                            ))
+                            .child(stat_item(
+                                cx,
+                                "changed",
+                                diff.count(DiffKind::Changed),
+                                cx.theme().warning,
+                            ))
+                    })
+                    .when_some(message, |this, message| {
+                        this.child(
+                            h_flex()
+                                .gap_1()
+                                .child(Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning))
+                                .child(Label::new(message).text_sm().text_color(cx.theme().warning)),
+                        )
+                    }),
+            )
+            .child(
+                Button::new("value-diff-only-changes")
+                    .small()
+                    .map(|this| {
+                        if self.only_changes {
+                            this.primary()
+                        } else {
+                            this.ghost()
+                        }
+                    })
+                    .label(i18n_value_diff(cx, "only_changes"))
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.only_changes = !this.only_changes;
+                        cx.notify();
+                    })),
+            )
+    }
+
+    fn render_table(&self, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let entries = self.visible_entries();
+        let header = |text: SharedString| {
+            Label::new(text)
+                .text_sm()
+                .font_weight(gpui::FontWeight::MEDIUM)
+                .truncate()
+        };
+
+        v_flex()
+            .flex_1()
+            .min_h_0()
+            .w_full()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .child(
+                h_flex()
+                    .h(px(ROW_HEIGHT))
+                    .px_2()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .bg(cx.theme().table_head)
+                    .child(
+                        div()
+                            .w(px(NAME_COLUMN_WIDTH))
+                            .child(header(i18n_value_diff(cx, "name"))),
+                    )
+                    .child(div().flex_1().min_w_0().child(header(self.titles.0.clone())))
+                    .child(div().flex_1().min_w_0().child(header(self.titles.1.clone()))),
+            )
+            .child(
+                uniform_list("value-diff-entries", entries.len(), {
+                    let added = cx.theme().success.opacity(0.12);
+                    let removed = cx.theme().danger.opacity(0.12);
+                    let changed = cx.theme().warning.opacity(0.12);
+                    let muted = cx.theme().muted_foreground;
+                    let border = cx.theme().border;
+                    move |range, _window, _cx| {
+                        range
+                            .map(|index| {
+                                let entry = &entries[index];
+                                let bg = match entry.kind {
+                                    DiffKind::Added => Some(added),
+                                    DiffKind::Removed => Some(removed),
+                                    DiffKind::Changed => Some(changed),
+                                    DiffKind::Unchanged => None,
+                                };
+                                let cell = |value: &Option<SharedString>| {
+                                    div()
+                                        .flex_1()
+                                        .min_w_0()
+                                        .overflow_hidden()
+                                        .child(Label::new(value.clone().unwrap_or_default()).text_sm().truncate())
+                                };
+                                h_flex()
+                                    .id(("value-diff-entry", index))
+                                    .h(px(ROW_HEIGHT))
+                                    .px_2()
+                                    .gap_2()
+                                    .border_b_1()
+                                    .border_color(border)
+                                    .when_some(bg, |this, bg| this.bg(bg))
+                                    .child(
+                                        div().w(px(NAME_COLUMN_WIDTH)).overflow_hidden().child(
+                                            Label::new(entry.name.clone()).text_sm().text_color(muted).truncate(),
+                                        ),
+                                    )
+                                    .child(cell(&entry.left))
+                                    .child(cell(&entry.right))
+                            })
+                            .collect()
+                    }
+                })
+                .flex_1()
+                .track_scroll(&self.scroll_handle),
+            )
+    }
+}
+
+impl gpui::Render for ZedisValueDiff {
+    fn render(&mut self, _window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let mut body = v_flex().flex_1().w_full().min_h_0().p_2().gap_2().child(
+            h_flex()
+                .w_full()
+                .gap_4()
+                .items_end()
+                .child(self.render_side("left", &self.left, cx))
+                .child(self.render_side("right", &self.right, cx))
+                .child(
+                    Button::new("value-diff-compare")
+                        .primary()
+                        .small()
+                        .loading(self.loading)
+                        .disabled(self.loading)
+                        .label(i18n_value_diff(cx, "compare"))
+                        .on_click(cx.listener(|this, _, _window, cx| {
+                            this.compare(cx);
+                        })),
+                ),
+        );
+
+        if let Some(message) = self.error.clone() {
+            body = body.child(
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .rounded(cx.theme().radius)
+                    .bg(cx.theme().warning.opacity(0.1))
+                    .child(Icon::new(IconName::TriangleAlert).text_color(cx.theme().warning))
+                    .child(Label::new(message).text_sm().text_color(cx.theme().warning)),
+            );
+        }
+
+        if let Some(diff) = self.diff.as_ref() {
+            body = body.child(self.render_summary(diff, cx));
+            if !diff.type_mismatch {
+                body = body.child(self.render_table(cx));
+            }
+        } else if !self.loading {
+            body = body.child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(Label::new(i18n_value_diff(cx, "no_data")).text_color(cx.theme().muted_foreground)),
+            );
+        }
+
+        v_flex()
+            .size_full()
+            .overflow_hidden()
+            .gap_2()
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(px(40.))
+                    .px_4()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Icon::new(CustomIconName::GitCompare))
+                            .child(Label::new(i18n_value_diff(cx, "title")).text_color(cx.theme().foreground)),
+                    ),
+            )
+            .child(body)
+            .into_any_element()
+    }
+}
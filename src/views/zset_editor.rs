@@ -26,10 +26,11 @@
 use crate::{
     components::KvTableColumn,
     components::ZedisKvFetcher,
-    states::{KeyType, RedisValue, ZedisServerState},
+    states::{KeyType, RedisValue, SortOrder, ZaddFlags, ZedisServerState},
     views::{ZedisKvTable, kv_table::define_kv_editor},
 };
 use gpui::{App, Entity, SharedString, Window, prelude::*};
+use gpui_component::table::ColumnSort;
 use zedis_ui::ZedisFormFieldType;
 
 /// Data adapter for Redis ZSET values to work with the KV table component.
@@ -65,6 +66,11 @@ impl ZedisKvFetcher for ZedisZsetValues {
         }
     }
 
+    /// The length column shows the size of the member.
+    fn value_column(&self) -> Option<usize> {
+        Some(1)
+    }
+
     /// Returns the total cardinality of the ZSET (from Redis ZCARD).
     fn count(&self) -> usize {
         self.value.zset_value().map_or(0, |v| v.size)
@@ -124,22 +130,41 @@ impl ZedisKvFetcher for ZedisZsetValues {
         });
     }
 
+    /// Sorts the loaded members by score (column 2) or by member name.
+    ///
+    /// The default order falls back to the score order the members were loaded in (ZRANGE or ZREVRANGE).
+    fn sort(&self, col_ix: usize, sort: ColumnSort, cx: &mut App) {
+        let loaded_desc = self
+            .value
+            .zset_value()
+            .is_some_and(|zset| zset.sort_order == SortOrder::Desc);
+        let (by_score, descending) = match sort {
+            ColumnSort::Ascending => (col_ix == 2, false),
+            ColumnSort::Descending => (col_ix == 2, true),
+            ColumnSort::Default => (true, loaded_desc),
+        };
+        self.server_state.update(cx, |this, cx| {
+            this.sort_zset_value(by_score, descending, cx);
+        });
+    }
+
     /// Adds a new member to the ZSET.
     ///
-    /// Creates a form with member and score input fields and handles submission
-    /// by calling the server state's `add_zset_value` method.
+    /// Creates a form with member and score input fields plus the ZADD flag checkboxes,
+    /// and handles submission by calling the server state's `add_zset_value` method.
     fn handle_add_value(&self, values: Vec<SharedString>, _window: &mut Window, cx: &mut App) {
-        if values.len() != 2 {
+        if values.len() < 2 {
             return;
         }
 
         let server_state = self.server_state.clone();
         // Parse score from string (default to 0.0 if invalid)
         let score = values[1].parse::<f64>().unwrap_or(0.0);
+        let flags = ZaddFlags::from_values(&values[2..]);
 
         // Execute the add operation on server state
         server_state.update(cx, |this, cx| {
-            this.add_zset_value(values[0].clone(), score, cx);
+            this.add_zset_value(values[0].clone(), score, flags, cx);
         });
     }
 
@@ -183,8 +208,10 @@ impl ZedisZsetEditor {
         let table_state = cx.new(|cx| {
             ZedisKvTable::<ZedisZsetValues>::new(
                 vec![
-                    KvTableColumn::new_flex("Value").field_type(ZedisFormFieldType::Editor),
-                    KvTableColumn::new("Score", Some(150.)),
+                    KvTableColumn::new_flex("Value")
+                        .field_type(ZedisFormFieldType::Editor)
+                        .sortable(),
+                    KvTableColumn::new("Score", Some(150.)).sortable(),
                 ],
                 server_state,
                 window,